@@ -256,7 +256,7 @@ impl AccountBrands {
         account_id: &str,
         brand_id: &str,
         logo_type: &str,
-    ) -> Result<()> {
+    ) -> Result<bytes::Bytes> {
         let url = format!(
             "/v2.1/accounts/{}/brands/{}/logos/{}",
             crate::progenitor_support::encode_path(&account_id.to_string()),
@@ -264,7 +264,40 @@ impl AccountBrands {
             crate::progenitor_support::encode_path(&logo_type.to_string()),
         );
 
-        self.client.get(&url, None).await
+        self.client.get_bytes(&url, None).await
+    }
+
+    /**
+     * Gets a brand logo.
+     *
+     * Like `brand_logo_get`, but returns the response once its status is checked, so the file can be read in chunks with `reqwest::Response::chunk`.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/brands/{brandId}/logos/{logoType}` endpoint.
+     *
+     * This method returns a specific logo that is used in a brand.
+     *
+     * **Note**: Branding for either signing or sending must be enabled for the account (`canSelfBrandSend` , `canSelfBrandSign`, or both of these account settings must be **true**).
+     *
+     * **Parameters:**
+     *
+     * * `account_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
+     * * `brand_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
+     * * `logo_type: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
+     */
+    pub async fn brand_logo_get_stream(
+        &self,
+        account_id: &str,
+        brand_id: &str,
+        logo_type: &str,
+    ) -> Result<reqwest::Response> {
+        let url = format!(
+            "/v2.1/accounts/{}/brands/{}/logos/{}",
+            crate::progenitor_support::encode_path(&account_id.to_string()),
+            crate::progenitor_support::encode_path(&brand_id.to_string()),
+            crate::progenitor_support::encode_path(&logo_type.to_string()),
+        );
+
+        self.client.get_stream(&url, None).await
     }
 
     /**
@@ -226,7 +226,7 @@ impl AccountSignatures {
         image_type: &str,
         signature_id: &str,
         include_chrome: &str,
-    ) -> Result<()> {
+    ) -> Result<bytes::Bytes> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !include_chrome.is_empty() {
             query_args.push(("include_chrome".to_string(), include_chrome.to_string()));
@@ -240,7 +240,49 @@ impl AccountSignatures {
             query_
         );
 
-        self.client.get(&url, None).await
+        self.client.get_bytes(&url, None).await
+    }
+
+    /**
+     * Returns a signature image, initials, or stamp.
+     *
+     * Like `get_signature_image`, but returns the response once its status is checked, so the file can be read in chunks with `reqwest::Response::chunk`.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/signatures/{signatureId}/{imageType}` endpoint.
+     *
+     *
+     *
+     * **Parameters:**
+     *
+     * * `account_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
+     * * `image_type: &str` -- Specificies the type of image. Valid values are:
+     *   
+     *   - `signature_image`
+     *   - `initials_image`.
+     * * `signature_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
+     * * `include_chrome: &str` -- When **true**, the chrome (or frame containing the added line and identifier) is included with the signature image.
+     */
+    pub async fn get_signature_image_stream(
+        &self,
+        account_id: &str,
+        image_type: &str,
+        signature_id: &str,
+        include_chrome: &str,
+    ) -> Result<reqwest::Response> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !include_chrome.is_empty() {
+            query_args.push(("include_chrome".to_string(), include_chrome.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/v2.1/accounts/{}/signatures/{}/{}?{}",
+            crate::progenitor_support::encode_path(&account_id.to_string()),
+            crate::progenitor_support::encode_path(&signature_id.to_string()),
+            crate::progenitor_support::encode_path(&image_type.to_string()),
+            query_
+        );
+
+        self.client.get_stream(&url, None).await
     }
 
     /**
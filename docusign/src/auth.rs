@@ -0,0 +1,349 @@
+//! Authenticate as a user with the JWT grant, and find the base URI of their account.
+//!
+//! Services that act on behalf of a user sign a JWT with the RSA key of their
+//! integration and exchange it for an access token, once the user has granted
+//! consent at `JwtCredentials::consent_url`. DocuSign accounts are hosted in
+//! different regions, so the client then asks `/oauth/userinfo` for the base URI
+//! of the account and sends every request there. Tokens from the JWT grant
+//! last an hour and can't be refreshed, so the client keeps the credentials and
+//! gets a new token shortly before the last one expires.
+//!
+//! ```no_run
+//! # async fn run() -> anyhow::Result<()> {
+//! use docusign::auth::{Environment, JwtCredentials};
+//!
+//! let credentials = JwtCredentials::new(
+//!     "integration-key",
+//!     "user-id",
+//!     std::fs::read("private.pem")?,
+//! )
+//! .with_environment(Environment::Demo);
+//!
+//! let (client, account) = docusign::Client::new_from_jwt(credentials).await?;
+//! let info = client.accounts().get(&account.account_id, "").await?;
+//! println!("using {} at {}", info.account_name, account.base_uri);
+//! # Ok(())
+//! # }
+//! ```
+use std::{
+    sync::{Arc, RwLock},
+    time::{self, Duration, Instant},
+};
+
+use anyhow::{anyhow, Result};
+use jsonwebtoken as jwt;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::AccessToken;
+
+/// How long assertions are valid for. DocuSign accepts up to an hour.
+const ASSERTION_LIFE: time::Duration = time::Duration::from_secs(60 * 60);
+
+/// How long before a token expires to get a new one, so requests in flight don't
+/// use an expired token.
+const RENEW_BEFORE: Duration = Duration::from_secs(5 * 60);
+
+/// The grant type of the JWT grant.
+const JWT_GRANT_TYPE: &str = "urn:ietf:params:oauth:grant-type:jwt-bearer";
+
+/// The DocuSign environment an integration authenticates against.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Environment {
+    /// The developer sandbox, at `account-d.docusign.com`.
+    Demo,
+    Production,
+}
+
+impl Environment {
+    /// The host of the authentication server.
+    pub fn auth_server(&self) -> &'static str {
+        match self {
+            Environment::Demo => "account-d.docusign.com",
+            Environment::Production => "account.docusign.com",
+        }
+    }
+}
+
+/// Credentials for the JWT grant, which gets access tokens for a user without
+/// them signing in, and the token they got last.
+pub struct JwtCredentials {
+    /// The integration key of the app, also known as the client id.
+    pub integration_key: String,
+    /// The id of the user to act as.
+    pub user_id: String,
+    /// The RSA private key of the integration, as PEM.
+    pub private_key: Vec<u8>,
+    pub environment: Environment,
+    pub scopes: Vec<String>,
+    /// The account to use, or the user's default account if empty.
+    pub account_id: String,
+
+    cached: RwLock<Option<CachedToken>>,
+}
+
+struct CachedToken {
+    access_token: String,
+    expires_at: Instant,
+}
+
+impl std::fmt::Debug for JwtCredentials {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("JwtCredentials")
+            .field("integration_key", &self.integration_key)
+            .field("user_id", &self.user_id)
+            .field("private_key", &"vec![***]")
+            .field("environment", &self.environment)
+            .field("scopes", &self.scopes)
+            .field("account_id", &self.account_id)
+            .finish()
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct JwtClaims<'a> {
+    iss: &'a str,
+    sub: &'a str,
+    aud: &'a str,
+    iat: u64,
+    exp: u64,
+    scope: String,
+}
+
+impl JwtCredentials {
+    /// Credentials for the production environment, with the `signature` and
+    /// `impersonation` scopes.
+    pub fn new<I, U>(integration_key: I, user_id: U, private_key: Vec<u8>) -> Self
+    where
+        I: ToString,
+        U: ToString,
+    {
+        JwtCredentials {
+            integration_key: integration_key.to_string(),
+            user_id: user_id.to_string(),
+            private_key,
+            environment: Environment::Production,
+            scopes: vec!["signature".to_string(), "impersonation".to_string()],
+            account_id: String::new(),
+            cached: RwLock::new(None),
+        }
+    }
+
+    pub fn with_environment(mut self, environment: Environment) -> Self {
+        self.environment = environment;
+        self
+    }
+
+    /// Request these scopes. `impersonation` is added if it's missing, since the
+    /// JWT grant requires it.
+    pub fn with_scopes(mut self, scopes: &[&str]) -> Self {
+        self.scopes = scopes.iter().map(|s| s.to_string()).collect();
+        if !self.scopes.iter().any(|s| s == "impersonation") {
+            self.scopes.push("impersonation".to_string());
+        }
+        self
+    }
+
+    /// Use this account rather than the user's default one.
+    pub fn with_account_id(mut self, account_id: &str) -> Self {
+        self.account_id = account_id.to_string();
+        self
+    }
+
+    /// The URL where the user grants the integration consent to act on their behalf,
+    /// which they need to do once before the JWT grant works.
+    pub fn consent_url(&self, redirect_uri: &str) -> String {
+        format!(
+            "https://{}/oauth/auth?response_type=code&scope={}&client_id={}&redirect_uri={}",
+            self.environment.auth_server(),
+            urlencoding::encode(&self.scopes.join(" ")),
+            urlencoding::encode(&self.integration_key),
+            urlencoding::encode(redirect_uri)
+        )
+    }
+
+    /// A signed assertion to exchange for an access token.
+    pub fn assertion(&self) -> Result<String> {
+        let now = time::SystemTime::now().duration_since(time::UNIX_EPOCH)?;
+        let claims = JwtClaims {
+            iss: &self.integration_key,
+            sub: &self.user_id,
+            aud: self.environment.auth_server(),
+            iat: now.as_secs(),
+            exp: (now + ASSERTION_LIFE).as_secs(),
+            scope: self.scopes.join(" "),
+        };
+
+        let key = jwt::EncodingKey::from_rsa_pem(&self.private_key)?;
+        Ok(jwt::encode(
+            &jwt::Header::new(jwt::Algorithm::RS256),
+            &claims,
+            &key,
+        )?)
+    }
+
+    /// The last token, if it isn't about to expire.
+    pub fn cached_token(&self) -> Option<String> {
+        let cached = self.cached.read().unwrap_or_else(|e| e.into_inner());
+        cached
+            .as_ref()
+            .filter(|t| Instant::now() + RENEW_BEFORE < t.expires_at)
+            .map(|t| t.access_token.to_string())
+    }
+
+    /// Use a token until it expires, for example one saved by another process.
+    pub fn cache_token(&self, token: &AccessToken) {
+        let expires_in = Duration::from_secs(token.expires_in.max(0) as u64);
+        *self.cached.write().unwrap_or_else(|e| e.into_inner()) = Some(CachedToken {
+            access_token: token.access_token.to_string(),
+            expires_at: Instant::now() + expires_in,
+        });
+    }
+
+    /// Get a new token with the JWT grant, and cache it.
+    pub async fn get_access_token(&self, client: &reqwest::Client) -> Result<AccessToken> {
+        let assertion = self.assertion()?;
+        let params = [("grant_type", JWT_GRANT_TYPE), ("assertion", &assertion)];
+        let resp = client
+            .post(format!(
+                "https://{}/oauth/token",
+                self.environment.auth_server()
+            ))
+            .header(reqwest::header::ACCEPT, "application/json")
+            .form(&params)
+            .send()
+            .await?;
+
+        let status = resp.status();
+        if !status.is_success() {
+            // Errors like `consent_required` are in the body.
+            return Err(anyhow!(
+                "code: {}, error: {:?}",
+                status,
+                resp.text().await.unwrap_or_default()
+            ));
+        }
+
+        let t: AccessToken = resp.json().await?;
+        self.cache_token(&t);
+
+        Ok(t)
+    }
+
+    /// The cached token, or a new one if it's about to expire.
+    ///
+    /// Concurrent requests that find the token expired each get a new one, and the
+    /// last one is cached.
+    pub async fn token(&self, client: &reqwest::Client) -> Result<String> {
+        if let Some(token) = self.cached_token() {
+            return Ok(token);
+        }
+
+        Ok(self.get_access_token(client).await?.access_token)
+    }
+}
+
+/// The user an access token belongs to, and the accounts they can use.
+#[derive(Debug, JsonSchema, Clone, Default, Serialize, Deserialize)]
+pub struct UserInfo {
+    /// The id of the user.
+    #[serde(default)]
+    pub sub: String,
+    #[serde(default)]
+    pub name: String,
+    #[serde(default)]
+    pub email: String,
+    #[serde(default)]
+    pub accounts: Vec<UserAccount>,
+}
+
+impl UserInfo {
+    /// The user's default account.
+    pub fn default_account(&self) -> Option<&UserAccount> {
+        self.accounts
+            .iter()
+            .find(|a| a.is_default)
+            .or_else(|| self.accounts.first())
+    }
+
+    pub fn account(&self, account_id: &str) -> Option<&UserAccount> {
+        self.accounts.iter().find(|a| a.account_id == account_id)
+    }
+}
+
+/// An account a user can use.
+#[derive(Debug, JsonSchema, Clone, Default, Serialize, Deserialize)]
+pub struct UserAccount {
+    #[serde(default)]
+    pub account_id: String,
+    #[serde(default)]
+    pub account_name: String,
+    #[serde(default)]
+    pub is_default: bool,
+    /// Where the account is hosted, like `https://demo.docusign.net`.
+    #[serde(default)]
+    pub base_uri: String,
+}
+
+impl UserAccount {
+    /// The host to send the account's API requests to.
+    pub fn api_host(&self) -> String {
+        format!("{}/restapi", self.base_uri.trim_end_matches('/'))
+    }
+}
+
+impl crate::Client {
+    /// Create a client for the user of the JWT credentials, authenticated and
+    /// sending requests to the host of their account.
+    ///
+    /// Tokens from the JWT grant expire after an hour and can't be refreshed, so
+    /// the client keeps the credentials and gets a new token before it expires.
+    pub async fn new_from_jwt(credentials: JwtCredentials) -> Result<(Self, UserAccount)> {
+        let mut client = crate::Client::new(&credentials.integration_key, "", "", "", "");
+        let environment = credentials.environment;
+        let account_id = credentials.account_id.to_string();
+        client.jwt_credentials = Some(Arc::new(credentials));
+
+        client.get_jwt_access_token().await?;
+        let account = client.select_account(environment, &account_id).await?;
+        Ok((client, account))
+    }
+
+    /// Get a new access token with the JWT credentials of the client, for example
+    /// to save it for other processes. Requests renew the token themselves.
+    pub async fn get_jwt_access_token(&self) -> Result<AccessToken> {
+        match &self.jwt_credentials {
+            Some(credentials) => credentials.get_access_token(&self.client).await,
+            None => Err(anyhow!("the client has no JWT credentials")),
+        }
+    }
+
+    /// Get the user the access token belongs to, and their accounts.
+    pub async fn user_info(&self, environment: Environment) -> Result<UserInfo> {
+        let url = format!("https://{}/oauth/userinfo", environment.auth_server());
+        self.request(reqwest::Method::GET, &url, None).await
+    }
+
+    /// Send requests to the host of an account of the user, or of their default
+    /// account if `account_id` is empty.
+    pub async fn select_account(
+        &mut self,
+        environment: Environment,
+        account_id: &str,
+    ) -> Result<UserAccount> {
+        let info = self.user_info(environment).await?;
+        let account = if account_id.is_empty() {
+            info.default_account()
+        } else {
+            info.account(account_id)
+        }
+        .ok_or_else(|| match account_id {
+            "" => anyhow!("user {} has no accounts", info.sub),
+            id => anyhow!("user {} has no account {}", info.sub, id),
+        })?
+        .clone();
+
+        self.host = account.api_host();
+        Ok(account)
+    }
+}
@@ -32,7 +32,7 @@ impl Comments {
         account_id: &str,
         envelope_id: &str,
         encoding: &str,
-    ) -> Result<()> {
+    ) -> Result<bytes::Bytes> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !encoding.is_empty() {
             query_args.push(("encoding".to_string(), encoding.to_string()));
@@ -45,6 +45,44 @@ impl Comments {
             query_
         );
 
-        self.client.get(&url, None).await
+        self.client.get_bytes(&url, None).await
+    }
+
+    /**
+     * Gets a PDF transcript of all of the comments in an envelope.
+     *
+     * Like `get_transcript`, but returns the response once its status is checked, so the file can be read in chunks with `reqwest::Response::chunk`.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/comments/transcript` endpoint.
+     *
+     * Retrieves a PDF file containing all of the comments that senders and recipients have added to the documents in an envelope.
+     *
+     * **Note**: Comments are disabled by default. To use the comments feature, an account administrator must enable comments on the account (in the `accountSettingsInformation` object, set the `enableSigningExtensionComments` property to **true**).
+     *
+     * **Parameters:**
+     *
+     * * `account_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
+     * * `envelope_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
+     * * `encoding: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
+     */
+    pub async fn get_transcript_stream(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        encoding: &str,
+    ) -> Result<reqwest::Response> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !encoding.is_empty() {
+            query_args.push(("encoding".to_string(), encoding.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/v2.1/accounts/{}/envelopes/{}/comments/transcript?{}",
+            crate::progenitor_support::encode_path(&account_id.to_string()),
+            crate::progenitor_support::encode_path(&envelope_id.to_string()),
+            query_
+        );
+
+        self.client.get_stream(&url, None).await
     }
 }
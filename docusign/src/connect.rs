@@ -0,0 +1,488 @@
+//! Verify and parse the events Connect sends to webhooks.
+//!
+//! Connect signs each event with the HMAC keys of its configuration, in the
+//! `X-DocuSign-Signature-1` to `X-DocuSign-Signature-N` headers. Events come as
+//! JSON, or as XML in the legacy format, and both are parsed into a `ConnectEvent`.
+//!
+//! ```no_run
+//! # fn handle(headers: Vec<(String, String)>, body: Vec<u8>) -> anyhow::Result<()> {
+//! use docusign::connect::{verify_signature, ConnectEvent, ConnectEventKind};
+//!
+//! verify_signature(headers, &body, &["hmac-key"])?;
+//!
+//! let event = ConnectEvent::from_slice(&body)?;
+//! if event.kind == ConnectEventKind::EnvelopeCompleted {
+//!     for field in &event.fields {
+//!         println!("{} = {}", field.label, field.value);
+//!     }
+//! }
+//! # Ok(())
+//! # }
+//! ```
+use anyhow::{anyhow, bail, Result};
+use quick_xml::{events::Event, Reader};
+use ring::hmac;
+use serde_json::{json, Value};
+
+/// The prefix of the headers with the signatures of an event, followed by the
+/// number of the HMAC key that signed it.
+pub const SIGNATURE_HEADER_PREFIX: &str = "x-docusign-signature-";
+
+/// The signature of an event body with an HMAC key, as sent in the signature headers.
+pub fn sign(secret: &str, body: &[u8]) -> String {
+    let key = hmac::Key::new(hmac::HMAC_SHA256, secret.as_bytes());
+    base64::encode(hmac::sign(&key, body).as_ref())
+}
+
+/// Verify that an event was signed with one of the HMAC keys `secrets`.
+///
+/// Connect sends a signature for every key of the configuration, so any one of
+/// them matching any one of the secrets verifies the event. Keep the old secret in
+/// `secrets` while rotating keys.
+pub fn verify_signature<I, K, V>(headers: I, body: &[u8], secrets: &[&str]) -> Result<()>
+where
+    I: IntoIterator<Item = (K, V)>,
+    K: AsRef<str>,
+    V: AsRef<str>,
+{
+    let signatures: Vec<Vec<u8>> = headers
+        .into_iter()
+        .filter(|(k, _)| {
+            k.as_ref()
+                .to_lowercase()
+                .starts_with(SIGNATURE_HEADER_PREFIX)
+        })
+        .filter_map(|(_, v)| base64::decode(v.as_ref().trim()).ok())
+        .collect();
+    if signatures.is_empty() {
+        bail!("the event has no X-DocuSign-Signature headers");
+    }
+
+    for secret in secrets {
+        let key = hmac::Key::new(hmac::HMAC_SHA256, secret.as_bytes());
+        if signatures
+            .iter()
+            .any(|signature| hmac::verify(&key, body, signature).is_ok())
+        {
+            return Ok(());
+        }
+    }
+
+    Err(anyhow!(
+        "none of the {} X-DocuSign-Signature headers match the HMAC keys",
+        signatures.len()
+    ))
+}
+
+/// What happened to an envelope or one of its recipients.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ConnectEventKind {
+    EnvelopeCreated,
+    EnvelopeSent,
+    EnvelopeResent,
+    EnvelopeDelivered,
+    EnvelopeCompleted,
+    EnvelopeDeclined,
+    EnvelopeVoided,
+    EnvelopeCorrected,
+    EnvelopePurged,
+    EnvelopeDeleted,
+    EnvelopeDiscarded,
+    RecipientSent,
+    RecipientResent,
+    RecipientDelivered,
+    RecipientCompleted,
+    RecipientDeclined,
+    RecipientAuthenticationFailed,
+    RecipientAutoResponded,
+    RecipientDelegated,
+    RecipientReassigned,
+    RecipientFinishLater,
+    /// An event this client doesn't know, by its name.
+    Other(String),
+}
+
+impl ConnectEventKind {
+    /// The name of the event in JSON events, like `envelope-completed`.
+    pub fn name(&self) -> &str {
+        match self {
+            ConnectEventKind::EnvelopeCreated => "envelope-created",
+            ConnectEventKind::EnvelopeSent => "envelope-sent",
+            ConnectEventKind::EnvelopeResent => "envelope-resent",
+            ConnectEventKind::EnvelopeDelivered => "envelope-delivered",
+            ConnectEventKind::EnvelopeCompleted => "envelope-completed",
+            ConnectEventKind::EnvelopeDeclined => "envelope-declined",
+            ConnectEventKind::EnvelopeVoided => "envelope-voided",
+            ConnectEventKind::EnvelopeCorrected => "envelope-corrected",
+            ConnectEventKind::EnvelopePurged => "envelope-purge",
+            ConnectEventKind::EnvelopeDeleted => "envelope-deleted",
+            ConnectEventKind::EnvelopeDiscarded => "envelope-discard",
+            ConnectEventKind::RecipientSent => "recipient-sent",
+            ConnectEventKind::RecipientResent => "recipient-resent",
+            ConnectEventKind::RecipientDelivered => "recipient-delivered",
+            ConnectEventKind::RecipientCompleted => "recipient-completed",
+            ConnectEventKind::RecipientDeclined => "recipient-declined",
+            ConnectEventKind::RecipientAuthenticationFailed => "recipient-authenticationfailed",
+            ConnectEventKind::RecipientAutoResponded => "recipient-autoresponded",
+            ConnectEventKind::RecipientDelegated => "recipient-delegate",
+            ConnectEventKind::RecipientReassigned => "recipient-reassign",
+            ConnectEventKind::RecipientFinishLater => "recipient-finish-later",
+            ConnectEventKind::Other(name) => name,
+        }
+    }
+
+    pub fn from_name(name: &str) -> Self {
+        match name {
+            "envelope-created" => ConnectEventKind::EnvelopeCreated,
+            "envelope-sent" => ConnectEventKind::EnvelopeSent,
+            "envelope-resent" => ConnectEventKind::EnvelopeResent,
+            "envelope-delivered" => ConnectEventKind::EnvelopeDelivered,
+            "envelope-completed" => ConnectEventKind::EnvelopeCompleted,
+            "envelope-declined" => ConnectEventKind::EnvelopeDeclined,
+            "envelope-voided" => ConnectEventKind::EnvelopeVoided,
+            "envelope-corrected" => ConnectEventKind::EnvelopeCorrected,
+            "envelope-purge" => ConnectEventKind::EnvelopePurged,
+            "envelope-deleted" => ConnectEventKind::EnvelopeDeleted,
+            "envelope-discard" => ConnectEventKind::EnvelopeDiscarded,
+            "recipient-sent" => ConnectEventKind::RecipientSent,
+            "recipient-resent" => ConnectEventKind::RecipientResent,
+            "recipient-delivered" => ConnectEventKind::RecipientDelivered,
+            "recipient-completed" => ConnectEventKind::RecipientCompleted,
+            "recipient-declined" => ConnectEventKind::RecipientDeclined,
+            "recipient-authenticationfailed" => ConnectEventKind::RecipientAuthenticationFailed,
+            "recipient-autoresponded" => ConnectEventKind::RecipientAutoResponded,
+            "recipient-delegate" => ConnectEventKind::RecipientDelegated,
+            "recipient-reassign" => ConnectEventKind::RecipientReassigned,
+            "recipient-finish-later" => ConnectEventKind::RecipientFinishLater,
+            name => ConnectEventKind::Other(name.to_string()),
+        }
+    }
+
+    /// The event for an envelope changing to a status, for legacy XML events,
+    /// which only have the status.
+    fn from_envelope_status(status: &str) -> Self {
+        match status.to_lowercase().as_str() {
+            "created" => ConnectEventKind::EnvelopeCreated,
+            "sent" => ConnectEventKind::EnvelopeSent,
+            "delivered" => ConnectEventKind::EnvelopeDelivered,
+            "signed" | "completed" => ConnectEventKind::EnvelopeCompleted,
+            "declined" => ConnectEventKind::EnvelopeDeclined,
+            "voided" => ConnectEventKind::EnvelopeVoided,
+            status => ConnectEventKind::Other(format!("envelope-{}", status)),
+        }
+    }
+
+    pub fn is_recipient_event(&self) -> bool {
+        self.name().starts_with("recipient-")
+    }
+}
+
+impl std::fmt::Display for ConnectEventKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+/// The value of a tab or document field when the event was sent.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct DocumentField {
+    /// The recipient who filled in the tab, or empty for document fields.
+    pub recipient_id: String,
+    pub document_id: String,
+    pub label: String,
+    pub value: String,
+}
+
+/// An event sent by Connect.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConnectEvent {
+    pub kind: ConnectEventKind,
+    /// The account of the envelope, which legacy XML events don't include.
+    pub account_id: String,
+    pub envelope_id: String,
+    /// The recipient of recipient events.
+    pub recipient_id: String,
+    pub generated_date_time: String,
+    /// The envelope, as much of it as the configuration includes in events.
+    pub envelope: Option<crate::types::Envelope>,
+    /// The values of the tabs and document fields of the envelope.
+    pub fields: Vec<DocumentField>,
+}
+
+impl ConnectEvent {
+    /// Parse an event in JSON or in the legacy XML format.
+    pub fn from_slice(body: &[u8]) -> Result<Self> {
+        let body = std::str::from_utf8(body)?;
+        if body.trim_start().starts_with('<') {
+            ConnectEvent::from_xml(body)
+        } else {
+            ConnectEvent::from_json(body)
+        }
+    }
+
+    /// Parse an event in JSON, as sent by configurations with the `json` event
+    /// message format.
+    pub fn from_json(body: &str) -> Result<Self> {
+        let event: Value = serde_json::from_str(body)?;
+        let data = &event["data"];
+        let summary = &data["envelopeSummary"];
+
+        let envelope = if summary.is_object() {
+            Some(serde_json::from_value(summary.clone())?)
+        } else {
+            None
+        };
+
+        let mut fields = Vec::new();
+        for recipients in summary["recipients"]
+            .as_object()
+            .into_iter()
+            .flat_map(|r| r.values())
+        {
+            for recipient in recipients.as_array().into_iter().flatten() {
+                let recipient_id = str_value(&recipient["recipientId"]);
+                for tabs in recipient["tabs"]
+                    .as_object()
+                    .into_iter()
+                    .flat_map(|t| t.values())
+                {
+                    for tab in tabs.as_array().into_iter().flatten() {
+                        let value = match tab.get("value") {
+                            Some(value) => str_value(value),
+                            None => str_value(&tab["selected"]),
+                        };
+                        fields.push(DocumentField {
+                            recipient_id: recipient_id.to_string(),
+                            document_id: str_value(&tab["documentId"]),
+                            label: str_value(&tab["tabLabel"]),
+                            value,
+                        });
+                    }
+                }
+            }
+        }
+        for document in summary["envelopeDocuments"]
+            .as_array()
+            .into_iter()
+            .flatten()
+        {
+            for field in document["documentFields"].as_array().into_iter().flatten() {
+                fields.push(DocumentField {
+                    recipient_id: String::new(),
+                    document_id: str_value(&document["documentId"]),
+                    label: str_value(&field["name"]),
+                    value: str_value(&field["value"]),
+                });
+            }
+        }
+
+        let mut envelope_id = str_value(&data["envelopeId"]);
+        if envelope_id.is_empty() {
+            envelope_id = str_value(&summary["envelopeId"]);
+        }
+
+        Ok(ConnectEvent {
+            kind: ConnectEventKind::from_name(&str_value(&event["event"])),
+            account_id: str_value(&data["accountId"]),
+            envelope_id,
+            recipient_id: str_value(&data["recipientId"]),
+            generated_date_time: str_value(&event["generatedDateTime"]),
+            envelope,
+            fields,
+        })
+    }
+
+    /// Parse an event in the legacy XML format, a `DocuSignEnvelopeInformation`.
+    pub fn from_xml(body: &str) -> Result<Self> {
+        let root = Element::parse(body)?;
+        if root.name != "DocuSignEnvelopeInformation" {
+            bail!(
+                "expected a DocuSignEnvelopeInformation, got a {}",
+                root.name
+            );
+        }
+        let status = root
+            .child("EnvelopeStatus")
+            .ok_or_else(|| anyhow!("the event has no EnvelopeStatus"))?;
+
+        let mut envelope = json!({
+            "envelopeId": status.text_of("EnvelopeID"),
+            "status": status.text_of("Status").to_lowercase(),
+            "emailSubject": status.text_of("Subject"),
+        });
+        for (element, property) in &[
+            ("Created", "createdDateTime"),
+            ("Sent", "sentDateTime"),
+            ("Delivered", "deliveredDateTime"),
+            ("Completed", "completedDateTime"),
+            ("Declined", "declinedDateTime"),
+            ("Voided", "voidedDateTime"),
+            ("VoidReason", "voidedReason"),
+        ] {
+            let value = status.text_of(element);
+            if !value.is_empty() {
+                envelope[*property] = json!(value);
+            }
+        }
+
+        let mut fields = Vec::new();
+        let mut recipients = json!({});
+        for recipient in status
+            .children("RecipientStatuses")
+            .flat_map(|r| r.children("RecipientStatus"))
+        {
+            let recipient_id = recipient.text_of("RecipientId");
+            let kind = match recipient.text_of("Type").as_str() {
+                "Signer" => "signers",
+                "CarbonCopy" => "carbonCopies",
+                "CertifiedDelivery" => "certifiedDeliveries",
+                "InPersonSigner" => "inPersonSigners",
+                "Agent" => "agents",
+                "Editor" => "editors",
+                "Intermediary" => "intermediaries",
+                _ => continue,
+            };
+            if recipients[kind].is_null() {
+                recipients[kind] = json!([]);
+            }
+            let mut r = json!({
+                "recipientIdGuid": recipient_id,
+                "email": recipient.text_of("Email"),
+                "name": recipient.text_of("UserName"),
+                "routingOrder": recipient.text_of("RoutingOrder"),
+                "status": recipient.text_of("Status").to_lowercase(),
+            });
+            for (element, property) in &[
+                ("Sent", "sentDateTime"),
+                ("Delivered", "deliveredDateTime"),
+                ("Signed", "signedDateTime"),
+                ("Declined", "declinedDateTime"),
+                ("DeclineReason", "declinedReason"),
+            ] {
+                let value = recipient.text_of(element);
+                if !value.is_empty() {
+                    r[*property] = json!(value);
+                }
+            }
+            if let Some(list) = recipients[kind].as_array_mut() {
+                list.push(r);
+            }
+
+            for tab in recipient
+                .children("TabStatuses")
+                .flat_map(|t| t.children("TabStatus"))
+            {
+                fields.push(DocumentField {
+                    recipient_id: recipient_id.to_string(),
+                    document_id: tab.text_of("DocumentID"),
+                    label: tab.text_of("TabLabel"),
+                    value: tab.text_of("TabValue"),
+                });
+            }
+        }
+        envelope["recipients"] = recipients;
+
+        envelope["envelopeDocuments"] = status
+            .children("DocumentStatuses")
+            .flat_map(|d| d.children("DocumentStatus"))
+            .map(|d| {
+                json!({
+                    "documentId": d.text_of("ID"),
+                    "name": d.text_of("Name"),
+                    "order": d.text_of("Sequence"),
+                })
+            })
+            .collect();
+
+        Ok(ConnectEvent {
+            kind: ConnectEventKind::from_envelope_status(&status.text_of("Status")),
+            account_id: String::new(),
+            envelope_id: status.text_of("EnvelopeID"),
+            recipient_id: String::new(),
+            generated_date_time: status.text_of("TimeGenerated"),
+            envelope: Some(serde_json::from_value(envelope)?),
+            fields,
+        })
+    }
+}
+
+/// A JSON value as a string, where numbers and booleans are also strings.
+fn str_value(value: &Value) -> String {
+    match value {
+        Value::String(s) => s.to_string(),
+        Value::Null => String::new(),
+        value => value.to_string(),
+    }
+}
+
+/// An XML element, with only what's needed to read Connect events: the local
+/// names of elements and their text. Attributes are ignored.
+#[derive(Debug, Default)]
+struct Element {
+    name: String,
+    text: String,
+    children: Vec<Element>,
+}
+
+impl Element {
+    fn child(&self, name: &str) -> Option<&Element> {
+        self.children.iter().find(|c| c.name == name)
+    }
+
+    fn children<'a>(&'a self, name: &'a str) -> impl Iterator<Item = &'a Element> {
+        self.children.iter().filter(move |c| c.name == name)
+    }
+
+    /// The text of the first child named `name`, or empty.
+    fn text_of(&self, name: &str) -> String {
+        self.child(name)
+            .map(|c| c.text.trim().to_string())
+            .unwrap_or_default()
+    }
+
+    /// Parse the root element of a document.
+    fn parse(xml: &str) -> Result<Element> {
+        let mut reader = Reader::from_str(xml);
+        let mut buf = Vec::new();
+        let mut stack: Vec<Element> = Vec::new();
+        loop {
+            let element = match reader.read_event(&mut buf)? {
+                Event::Start(e) => {
+                    stack.push(Element {
+                        name: reader.decode(e.local_name())?.to_string(),
+                        ..Default::default()
+                    });
+                    None
+                }
+                Event::Empty(e) => Some(Element {
+                    name: reader.decode(e.local_name())?.to_string(),
+                    ..Default::default()
+                }),
+                // The reader checks that end tags match their start tags.
+                Event::End(_) => stack.pop(),
+                Event::Text(e) | Event::CData(e) => {
+                    if let Some(parent) = stack.last_mut() {
+                        parent.text.push_str(&e.unescape_and_decode(&reader)?);
+                    }
+                    None
+                }
+                // Events don't have one, and the entities it could declare aren't
+                // expanded.
+                Event::DocType(_) => bail!("unexpected document type declaration"),
+                Event::Eof => match stack.first() {
+                    Some(element) => bail!("unclosed element {}", element.name),
+                    None => bail!("no XML element"),
+                },
+                _ => None,
+            };
+            buf.clear();
+
+            if let Some(element) = element {
+                match stack.last_mut() {
+                    Some(parent) => parent.children.push(element),
+                    None => return Ok(element),
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,187 @@
+//! Download the documents of an envelope, including the combined PDF, the ZIP
+//! archive and the certificate of completion.
+//!
+//! ```no_run
+//! # async fn run(client: docusign::Client) -> anyhow::Result<()> {
+//! use docusign::downloads::{DocumentDownloadOps, DocumentSelector, DownloadOptions};
+//!
+//! let selector = DocumentSelector::Archive;
+//! let archive = client
+//!     .envelope_documents()
+//!     .download("account-id", "envelope-id", &selector, &DownloadOptions::default())
+//!     .await?;
+//! std::fs::write(format!("envelope.{}", selector.file_extension()), archive)?;
+//! # Ok(())
+//! # }
+//! ```
+use anyhow::Result;
+
+/// Which document of an envelope to download.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum DocumentSelector {
+    /// A single document, by its id.
+    Document(String),
+    /// All the documents in one PDF, with the certificate of completion if the
+    /// account attaches it to completed envelopes.
+    Combined,
+    /// A ZIP archive of all the documents and the certificate of completion.
+    Archive,
+    /// The certificate of completion.
+    Certificate,
+    /// The documents of a PDF portfolio.
+    Portfolio,
+}
+
+impl DocumentSelector {
+    /// The id DocuSign uses for the document.
+    pub fn as_str(&self) -> &str {
+        match self {
+            DocumentSelector::Document(id) => id,
+            DocumentSelector::Combined => "combined",
+            DocumentSelector::Archive => "archive",
+            DocumentSelector::Certificate => "certificate",
+            DocumentSelector::Portfolio => "portfolio",
+        }
+    }
+
+    /// The file extension of the download. Single documents are returned as PDFs.
+    pub fn file_extension(&self) -> &'static str {
+        match self {
+            DocumentSelector::Archive => "zip",
+            _ => "pdf",
+        }
+    }
+}
+
+impl std::fmt::Display for DocumentSelector {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl From<&str> for DocumentSelector {
+    fn from(s: &str) -> Self {
+        match s {
+            "combined" => DocumentSelector::Combined,
+            "archive" => DocumentSelector::Archive,
+            "certificate" => DocumentSelector::Certificate,
+            "portfolio" => DocumentSelector::Portfolio,
+            id => DocumentSelector::Document(id.to_string()),
+        }
+    }
+}
+
+/// Options for a download. Unset options use the DocuSign defaults.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct DownloadOptions {
+    /// Whether to include the certificate of completion in combined downloads.
+    pub certificate: Option<bool>,
+    /// Whether to add the account's watermark to documents of incomplete envelopes.
+    pub watermark: Option<bool>,
+    /// Highlight changed fields in yellow, and optional signatures in red.
+    pub show_changes: bool,
+    /// The language of the certificate of completion, like `fr` or `pt_BR`.
+    pub language: String,
+}
+
+impl DownloadOptions {
+    pub fn with_certificate(mut self, certificate: bool) -> Self {
+        self.certificate = Some(certificate);
+        self
+    }
+
+    pub fn with_watermark(mut self, watermark: bool) -> Self {
+        self.watermark = Some(watermark);
+        self
+    }
+
+    pub fn with_changes_shown(mut self) -> Self {
+        self.show_changes = true;
+        self
+    }
+
+    pub fn with_language(mut self, language: &str) -> Self {
+        self.language = language.to_string();
+        self
+    }
+}
+
+fn flag(value: Option<bool>) -> String {
+    value.map(|v| v.to_string()).unwrap_or_default()
+}
+
+#[async_trait::async_trait]
+pub trait DocumentDownloadOps {
+    /// Download a document of an envelope.
+    async fn download(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        document: &DocumentSelector,
+        options: &DownloadOptions,
+    ) -> Result<bytes::Bytes>;
+
+    /// Download a document of an envelope, reading it in chunks with
+    /// `reqwest::Response::chunk` rather than into memory.
+    async fn download_stream(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        document: &DocumentSelector,
+        options: &DownloadOptions,
+    ) -> Result<reqwest::Response>;
+}
+
+#[async_trait::async_trait]
+impl DocumentDownloadOps for crate::envelope_documents::EnvelopeDocuments {
+    /// Download a document of an envelope.
+    async fn download(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        document: &DocumentSelector,
+        options: &DownloadOptions,
+    ) -> Result<bytes::Bytes> {
+        self.documents_get_document(
+            account_id,
+            document.as_str(),
+            envelope_id,
+            &flag(options.certificate),
+            "",
+            "",
+            "",
+            &options.language,
+            "",
+            "",
+            &flag(Some(options.show_changes).filter(|v| *v)),
+            &flag(options.watermark),
+        )
+        .await
+    }
+
+    /// Download a document of an envelope, reading it in chunks with
+    /// `reqwest::Response::chunk` rather than into memory.
+    async fn download_stream(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        document: &DocumentSelector,
+        options: &DownloadOptions,
+    ) -> Result<reqwest::Response> {
+        self.documents_get_document_stream(
+            account_id,
+            document.as_str(),
+            envelope_id,
+            &flag(options.certificate),
+            "",
+            "",
+            "",
+            &options.language,
+            "",
+            "",
+            &flag(Some(options.show_changes).filter(|v| *v)),
+            &flag(options.watermark),
+        )
+        .await
+    }
+}
@@ -0,0 +1,617 @@
+//! Compose envelopes from documents, recipients and tabs.
+//!
+//! Documents get ids in the order they're added, starting at 1, and recipients
+//! get ids in the order they're added across all kinds. Documents larger than
+//! the chunked upload threshold are uploaded with `ChunkedUploads` before the
+//! envelope is created, instead of being inlined.
+//!
+//! ```no_run
+//! # async fn run(client: docusign::Client) -> anyhow::Result<()> {
+//! use docusign::envelope_builder::{EnvelopeBuilder, Recipient, Tab, TabPosition};
+//!
+//! let summary = EnvelopeBuilder::new("Please sign the offer letter")
+//!     .document_from_path("offer.pdf")?
+//!     .recipient(
+//!         Recipient::signer("Alice Smith", "alice@example.com")
+//!             .with_tab(Tab::sign_here(TabPosition::anchor("/sig1/")))
+//!             .with_tab(Tab::date_signed(TabPosition::at(1, 2, 400, 650))),
+//!     )
+//!     .recipient(
+//!         Recipient::carbon_copy("HR", "hr@example.com").with_routing_order(2),
+//!     )
+//!     .send(&client, "account-id")
+//!     .await?;
+//! println!("sent envelope {}", summary.envelope_id);
+//! # Ok(())
+//! # }
+//! ```
+use std::path::Path;
+
+use anyhow::{anyhow, bail, Result};
+
+use crate::types::{
+    self, CarbonCopy, Document, EnvelopeDefinition, EnvelopeRecipientTabs, EnvelopeRecipients,
+    EnvelopeSummary, InPersonSigner, Signer,
+};
+
+/// Documents larger than this are uploaded in chunks by default.
+pub const DEFAULT_CHUNKED_UPLOAD_THRESHOLD: usize = 10 * 1024 * 1024;
+
+/// The size of each part of a chunked upload.
+const CHUNK_SIZE: usize = 5 * 1024 * 1024;
+
+/// Where to place a tab.
+#[derive(Debug, Clone, PartialEq)]
+pub enum TabPosition {
+    /// Next to every occurrence of a string in the documents, offset in pixels.
+    Anchor {
+        text: String,
+        x_offset: i64,
+        y_offset: i64,
+    },
+    /// At coordinates in pixels on a page of a document, by their 1-based ids.
+    At {
+        document: usize,
+        page: u32,
+        x: u32,
+        y: u32,
+    },
+}
+
+impl TabPosition {
+    pub fn anchor(text: &str) -> Self {
+        TabPosition::Anchor {
+            text: text.to_string(),
+            x_offset: 0,
+            y_offset: 0,
+        }
+    }
+
+    pub fn at(document: usize, page: u32, x: u32, y: u32) -> Self {
+        TabPosition::At {
+            document,
+            page,
+            x,
+            y,
+        }
+    }
+
+    /// Offset an anchored position, in pixels. Coordinates are moved by the offset.
+    pub fn offset(self, dx: i64, dy: i64) -> Self {
+        match self {
+            TabPosition::Anchor {
+                text,
+                x_offset,
+                y_offset,
+            } => TabPosition::Anchor {
+                text,
+                x_offset: x_offset + dx,
+                y_offset: y_offset + dy,
+            },
+            TabPosition::At {
+                document,
+                page,
+                x,
+                y,
+            } => TabPosition::At {
+                document,
+                page,
+                x: (x as i64 + dx).max(0) as u32,
+                y: (y as i64 + dy).max(0) as u32,
+            },
+        }
+    }
+}
+
+/// The kind of a tab.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TabKind {
+    SignHere,
+    InitialHere,
+    DateSigned,
+    FullName,
+    Text,
+    Checkbox,
+}
+
+/// Build a tab of one of the generated tab types, which share their position
+/// fields, for a recipient.
+macro_rules! place_tab {
+    ($tab:expr, $recipient_id:expr, $ty:ident { $($field:ident: $value:expr),* $(,)? }) => {{
+        let mut tab = types::$ty {
+            recipient_id: $recipient_id.to_string(),
+            tab_label: $tab.label.to_string(),
+            $($field: $value,)*
+            ..Default::default()
+        };
+        match &$tab.position {
+            TabPosition::Anchor {
+                text,
+                x_offset,
+                y_offset,
+            } => {
+                tab.anchor_string = text.to_string();
+                tab.anchor_units = "pixels".to_string();
+                tab.anchor_x_offset = x_offset.to_string();
+                tab.anchor_y_offset = y_offset.to_string();
+                tab.anchor_ignore_if_not_present = "false".to_string();
+            }
+            TabPosition::At {
+                document,
+                page,
+                x,
+                y,
+            } => {
+                tab.document_id = document.to_string();
+                tab.page_number = page.to_string();
+                tab.x_position = x.to_string();
+                tab.y_position = y.to_string();
+            }
+        }
+        tab
+    }};
+}
+
+/// A field for a recipient to fill in or sign.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Tab {
+    pub kind: TabKind,
+    pub position: TabPosition,
+    pub label: String,
+    /// The initial value of a text, date signed or full name tab, or `"true"`
+    /// for a checked checkbox. Signature and initial tabs have no value.
+    pub value: String,
+    /// Whether the recipient must fill in the tab, or the DocuSign default if `None`.
+    pub required: Option<bool>,
+}
+
+impl Tab {
+    pub fn new(kind: TabKind, position: TabPosition) -> Self {
+        Tab {
+            kind,
+            position,
+            label: String::new(),
+            value: String::new(),
+            required: None,
+        }
+    }
+
+    pub fn sign_here(position: TabPosition) -> Self {
+        Tab::new(TabKind::SignHere, position)
+    }
+
+    pub fn initial_here(position: TabPosition) -> Self {
+        Tab::new(TabKind::InitialHere, position)
+    }
+
+    pub fn date_signed(position: TabPosition) -> Self {
+        Tab::new(TabKind::DateSigned, position)
+    }
+
+    pub fn full_name(position: TabPosition) -> Self {
+        Tab::new(TabKind::FullName, position)
+    }
+
+    pub fn text(position: TabPosition, label: &str) -> Self {
+        Tab::new(TabKind::Text, position).with_label(label)
+    }
+
+    pub fn checkbox(position: TabPosition, label: &str) -> Self {
+        Tab::new(TabKind::Checkbox, position).with_label(label)
+    }
+
+    pub fn with_label(mut self, label: &str) -> Self {
+        self.label = label.to_string();
+        self
+    }
+
+    pub fn with_value(mut self, value: &str) -> Self {
+        self.value = value.to_string();
+        self
+    }
+
+    pub fn required(mut self, required: bool) -> Self {
+        self.required = Some(required);
+        self
+    }
+
+    /// Add the tab to a recipient's tabs.
+    fn add_to(&self, tabs: &mut EnvelopeRecipientTabs, recipient_id: &str) {
+        let required = self
+            .required
+            .map(|required| required.to_string())
+            .unwrap_or_default();
+        // Signature and initial tabs are marked optional rather than required.
+        let optional = self
+            .required
+            .map(|required| (!required).to_string())
+            .unwrap_or_default();
+
+        match self.kind {
+            TabKind::SignHere => tabs.sign_here_tabs.push(place_tab!(
+                self,
+                recipient_id,
+                SignHere { optional: optional }
+            )),
+            TabKind::InitialHere => tabs.initial_here_tabs.push(place_tab!(
+                self,
+                recipient_id,
+                InitialHere { optional: optional }
+            )),
+            TabKind::DateSigned => tabs.date_signed_tabs.push(place_tab!(
+                self,
+                recipient_id,
+                DateSigned {
+                    value: self.value.to_string()
+                }
+            )),
+            TabKind::FullName => tabs.full_name_tabs.push(place_tab!(
+                self,
+                recipient_id,
+                FullName {
+                    value: self.value.to_string()
+                }
+            )),
+            TabKind::Text => tabs.text_tabs.push(place_tab!(
+                self,
+                recipient_id,
+                Text {
+                    value: self.value.to_string(),
+                    required: required,
+                }
+            )),
+            TabKind::Checkbox => tabs.checkbox_tabs.push(place_tab!(
+                self,
+                recipient_id,
+                Checkbox {
+                    selected: self.value.to_string(),
+                    required: required,
+                }
+            )),
+        }
+    }
+}
+
+/// The kind of a recipient.
+#[derive(Debug, Clone, PartialEq)]
+pub enum RecipientKind {
+    Signer,
+    /// Receives a copy of the completed envelope.
+    CarbonCopy,
+    /// Signs in person with a host, who gets the emails.
+    InPersonSigner {
+        host_name: String,
+        host_email: String,
+    },
+}
+
+/// A recipient of an envelope.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Recipient {
+    pub kind: RecipientKind,
+    pub name: String,
+    pub email: String,
+    /// The order the recipient gets the envelope in, starting at 1. Recipients
+    /// with the same routing order get it at the same time.
+    pub routing_order: u32,
+    /// Set for signers who sign embedded in your app rather than from an email.
+    pub client_user_id: String,
+    pub tabs: Vec<Tab>,
+}
+
+impl Recipient {
+    fn new(kind: RecipientKind, name: &str, email: &str) -> Self {
+        Recipient {
+            kind,
+            name: name.to_string(),
+            email: email.to_string(),
+            routing_order: 1,
+            client_user_id: String::new(),
+            tabs: Vec::new(),
+        }
+    }
+
+    pub fn signer(name: &str, email: &str) -> Self {
+        Recipient::new(RecipientKind::Signer, name, email)
+    }
+
+    pub fn carbon_copy(name: &str, email: &str) -> Self {
+        Recipient::new(RecipientKind::CarbonCopy, name, email)
+    }
+
+    /// A signer who signs in person with `host_name`, who is emailed at `host_email`.
+    pub fn in_person(signer_name: &str, host_name: &str, host_email: &str) -> Self {
+        Recipient::new(
+            RecipientKind::InPersonSigner {
+                host_name: host_name.to_string(),
+                host_email: host_email.to_string(),
+            },
+            signer_name,
+            "",
+        )
+    }
+
+    pub fn with_routing_order(mut self, routing_order: u32) -> Self {
+        self.routing_order = routing_order;
+        self
+    }
+
+    pub fn with_client_user_id(mut self, client_user_id: &str) -> Self {
+        self.client_user_id = client_user_id.to_string();
+        self
+    }
+
+    pub fn with_tab(mut self, tab: Tab) -> Self {
+        self.tabs.push(tab);
+        self
+    }
+
+    /// The recipient's tabs, or `None` if it has none.
+    fn tabs(&self, recipient_id: &str) -> Option<EnvelopeRecipientTabs> {
+        if self.tabs.is_empty() {
+            return None;
+        }
+
+        let mut tabs = EnvelopeRecipientTabs::default();
+        for tab in &self.tabs {
+            tab.add_to(&mut tabs, recipient_id);
+        }
+        Some(tabs)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct Attachment {
+    name: String,
+    file_extension: String,
+    contents: Vec<u8>,
+}
+
+/// Compose an envelope, and send it or save it as a draft.
+#[derive(Debug, Clone, PartialEq)]
+pub struct EnvelopeBuilder {
+    email_subject: String,
+    email_blurb: String,
+    documents: Vec<Attachment>,
+    recipients: Vec<Recipient>,
+    chunked_upload_threshold: usize,
+}
+
+impl EnvelopeBuilder {
+    pub fn new(email_subject: &str) -> Self {
+        EnvelopeBuilder {
+            email_subject: email_subject.to_string(),
+            email_blurb: String::new(),
+            documents: Vec::new(),
+            recipients: Vec::new(),
+            chunked_upload_threshold: DEFAULT_CHUNKED_UPLOAD_THRESHOLD,
+        }
+    }
+
+    /// Set the message in the body of the email to recipients.
+    pub fn with_email_blurb(mut self, email_blurb: &str) -> Self {
+        self.email_blurb = email_blurb.to_string();
+        self
+    }
+
+    /// Upload documents larger than `threshold` bytes in chunks.
+    pub fn with_chunked_upload_threshold(mut self, threshold: usize) -> Self {
+        self.chunked_upload_threshold = threshold;
+        self
+    }
+
+    /// Add a document. Its file type is taken from the extension of `name`, or
+    /// detected for PDFs.
+    pub fn document(mut self, name: &str, contents: Vec<u8>) -> Self {
+        let file_extension = match Path::new(name).extension() {
+            Some(ext) => ext.to_string_lossy().to_lowercase(),
+            None if contents.starts_with(b"%PDF") => "pdf".to_string(),
+            None => String::new(),
+        };
+        self.documents.push(Attachment {
+            name: name.to_string(),
+            file_extension,
+            contents,
+        });
+        self
+    }
+
+    /// Add a document from a file, named after the file.
+    pub fn document_from_path<P>(self, path: P) -> Result<Self>
+    where
+        P: AsRef<Path>,
+    {
+        let path = path.as_ref();
+        let name = path
+            .file_name()
+            .ok_or_else(|| anyhow!("{} is not a file", path.display()))?
+            .to_string_lossy()
+            .to_string();
+        let contents = std::fs::read(path)?;
+        Ok(self.document(&name, contents))
+    }
+
+    pub fn recipient(mut self, recipient: Recipient) -> Self {
+        self.recipients.push(recipient);
+        self
+    }
+
+    /// The envelope definition, with every document inlined.
+    pub fn build(&self) -> Result<EnvelopeDefinition> {
+        self.definition(&[])
+    }
+
+    /// Send the envelope to its recipients.
+    pub async fn send(self, client: &crate::Client, account_id: &str) -> Result<EnvelopeSummary> {
+        self.create(client, account_id, "sent").await
+    }
+
+    /// Save the envelope as a draft, to send later.
+    pub async fn save_draft(
+        self,
+        client: &crate::Client,
+        account_id: &str,
+    ) -> Result<EnvelopeSummary> {
+        self.create(client, account_id, "created").await
+    }
+
+    async fn create(
+        self,
+        client: &crate::Client,
+        account_id: &str,
+        status: &str,
+    ) -> Result<EnvelopeSummary> {
+        let mut remote_urls = Vec::with_capacity(self.documents.len());
+        for document in &self.documents {
+            if document.contents.len() > self.chunked_upload_threshold {
+                remote_urls.push(chunked_upload(client, account_id, &document.contents).await?);
+            } else {
+                remote_urls.push(String::new());
+            }
+        }
+
+        let mut envelope = self.definition(&remote_urls)?;
+        envelope.status = status.to_string();
+        client
+            .envelopes()
+            .post(account_id, "", "", "", "", &envelope)
+            .await
+    }
+
+    /// The envelope definition, with the documents that have a remote URL in
+    /// `remote_urls` referring to it rather than inlined.
+    fn definition(&self, remote_urls: &[String]) -> Result<EnvelopeDefinition> {
+        if self.documents.is_empty() {
+            bail!("an envelope needs at least one document");
+        }
+        if self.recipients.is_empty() {
+            bail!("an envelope needs at least one recipient");
+        }
+
+        let mut documents = Vec::with_capacity(self.documents.len());
+        for (i, document) in self.documents.iter().enumerate() {
+            if document.file_extension.is_empty() {
+                bail!(
+                    "cannot tell the file type of document {:?}, name it with an extension",
+                    document.name
+                );
+            }
+
+            let mut body = Document {
+                document_id: (i + 1).to_string(),
+                name: document.name.to_string(),
+                file_extension: document.file_extension.to_string(),
+                ..Default::default()
+            };
+            match remote_urls.get(i).filter(|url| !url.is_empty()) {
+                Some(url) => body.remote_url = url.to_string(),
+                None => body.document_base_64 = base64::encode(&document.contents),
+            }
+            documents.push(body);
+        }
+
+        let mut recipients = EnvelopeRecipients::default();
+        for (i, recipient) in self.recipients.iter().enumerate() {
+            for tab in &recipient.tabs {
+                if let TabPosition::At { document, .. } = tab.position {
+                    if document == 0 || document > self.documents.len() {
+                        bail!(
+                            "a tab of {} is on document {}, which does not exist",
+                            recipient.name,
+                            document
+                        );
+                    }
+                }
+            }
+
+            let recipient_id = (i + 1).to_string();
+            let routing_order = recipient.routing_order.to_string();
+            let tabs = recipient.tabs(&recipient_id);
+            match &recipient.kind {
+                RecipientKind::Signer => recipients.signers.push(Signer {
+                    recipient_id,
+                    routing_order,
+                    client_user_id: recipient.client_user_id.to_string(),
+                    name: recipient.name.to_string(),
+                    email: recipient.email.to_string(),
+                    tabs,
+                    ..Default::default()
+                }),
+                RecipientKind::CarbonCopy => recipients.carbon_copies.push(CarbonCopy {
+                    recipient_id,
+                    routing_order,
+                    client_user_id: recipient.client_user_id.to_string(),
+                    name: recipient.name.to_string(),
+                    email: recipient.email.to_string(),
+                    tabs,
+                    ..Default::default()
+                }),
+                RecipientKind::InPersonSigner {
+                    host_name,
+                    host_email,
+                } => recipients.in_person_signers.push(InPersonSigner {
+                    recipient_id,
+                    routing_order,
+                    client_user_id: recipient.client_user_id.to_string(),
+                    signer_name: recipient.name.to_string(),
+                    host_name: host_name.to_string(),
+                    host_email: host_email.to_string(),
+                    tabs,
+                    ..Default::default()
+                }),
+            }
+        }
+
+        Ok(EnvelopeDefinition {
+            email_subject: self.email_subject.to_string(),
+            email_blurb: self.email_blurb.to_string(),
+            documents,
+            recipients: Some(recipients),
+            ..Default::default()
+        })
+    }
+}
+
+/// Upload contents in chunks, returning the URI to refer to them by.
+async fn chunked_upload(
+    client: &crate::Client,
+    account_id: &str,
+    contents: &[u8],
+) -> Result<String> {
+    let uploads = client.chunked_uploads();
+    let mut chunks = contents.chunks(CHUNK_SIZE);
+
+    let first = chunks.next().unwrap_or_default();
+    let upload = uploads
+        .post(
+            account_id,
+            &crate::types::ChunkedUploadRequest {
+                chunked_upload_id: String::new(),
+                data: base64::encode(first),
+            },
+        )
+        .await?;
+
+    for (i, chunk) in chunks.enumerate() {
+        uploads
+            .put_upload_part(
+                account_id,
+                &upload.chunked_upload_id,
+                &(i + 1).to_string(),
+                &crate::types::ChunkedUploadRequest {
+                    chunked_upload_id: upload.chunked_upload_id.to_string(),
+                    data: base64::encode(chunk),
+                },
+            )
+            .await?;
+    }
+
+    let committed = uploads
+        .put(account_id, &upload.chunked_upload_id, "commit")
+        .await?;
+    if committed.chunked_upload_uri.is_empty() {
+        Ok(upload.chunked_upload_uri)
+    } else {
+        Ok(committed.chunked_upload_uri)
+    }
+}
@@ -207,7 +207,7 @@ impl EnvelopeDocuments {
         shared_user_id: &str,
         show_changes: &str,
         watermark: &str,
-    ) -> Result<()> {
+    ) -> Result<bytes::Bytes> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !certificate.is_empty() {
             query_args.push(("certificate".to_string(), certificate.to_string()));
@@ -248,7 +248,93 @@ impl EnvelopeDocuments {
             query_
         );
 
-        self.client.get(&url, None).await
+        self.client.get_bytes(&url, None).await
+    }
+
+    /**
+     * Gets a document from an envelope.
+     *
+     * Like `documents_get_document`, but returns the response once its status is checked, so the file can be read in chunks with `reqwest::Response::chunk`.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/documents/{documentId}` endpoint.
+     *
+     * Retrieves the specified document from the envelope. If the account has the Highlight Data Changes feature enabled, there is an option to request that any changes in the envelope be highlighted.
+     *
+     * **Parameters:**
+     *
+     * * `account_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
+     * * `document_id: &str` -- This parameter takes the following special keywords:
+     *   
+     *   - `combined`: Retrieves a PDF file that contains the combined content of all of the documents. If the account option **Attach certification of completion to envelope** is on, then the Certificate of Completion is also included in the PDF file. You set this account option in the Admin tool on the **Signing Settings** screen, or by setting the `attachCompletedEnvelope` property in the `accountSettings` object to **true**.
+     *   - `archive`: Retrieves a ZIP archive that contains all of the PDF documents and the Certificate of Completion.
+     *   .
+     * * `envelope_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
+     * * `certificate: &str` -- When set to **false**, the envelope signing certificate is removed from the download.
+     * * `documents_by_userid: &str` -- When set to **true**, allows recipients to get documents by their user id. For example, if a user is included in two different routing orders with different visibilities, using this parameter returns all of the documents from both routing orders.
+     * * `encoding: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
+     * * `encrypt: &str` -- When set to **true**, the PDF bytes returned in the response are encrypted for all the key managers configured on your DocuSign account. You can decrypt the documents by using the Key Manager DecryptDocument API method. For more information about Key Manager, see the DocuSign Security Appliance Installation Guide that your organization received from DocuSign.
+     * * `language: &str` -- Specifies the language for the Certificate of Completion in the response. The supported languages are: Chinese Simplified (zh_CN), Chinese Traditional (zh_TW), Dutch (nl), English US (en), French (fr), German (de), Italian (it), Japanese (ja), Korean (ko), Portuguese (pt), Portuguese (Brazil) (pt_BR), Russian (ru), Spanish (es). .
+     * * `recipient_id: &str` -- Allows the sender to retrieve the documents as one of the recipients that they control. The `documents_by_userid` parameter must be set to **false** for this functionality to work.
+     * * `shared_user_id: &str` -- The ID of a shared user that you want to impersonate in order to retrieve their view of the list of documents. This parameter is used in the context of a shared inbox (i.e., when you share envelopes from one user to another through the RADmin console).
+     * * `show_changes: &str` -- When set to **true**, any changed fields for the returned PDF are highlighted in yellow and optional signatures or initials outlined in red. .
+     * * `watermark: &str` -- When set to **true**, the account has the watermark feature enabled, and the envelope is not complete, then the watermark for the account is added to the PDF documents. This option can remove the watermark. .
+     */
+    pub async fn documents_get_document_stream(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        envelope_id: &str,
+        certificate: &str,
+        documents_by_userid: &str,
+        encoding: &str,
+        encrypt: &str,
+        language: &str,
+        recipient_id: &str,
+        shared_user_id: &str,
+        show_changes: &str,
+        watermark: &str,
+    ) -> Result<reqwest::Response> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !certificate.is_empty() {
+            query_args.push(("certificate".to_string(), certificate.to_string()));
+        }
+        if !documents_by_userid.is_empty() {
+            query_args.push((
+                "documents_by_userid".to_string(),
+                documents_by_userid.to_string(),
+            ));
+        }
+        if !encoding.is_empty() {
+            query_args.push(("encoding".to_string(), encoding.to_string()));
+        }
+        if !encrypt.is_empty() {
+            query_args.push(("encrypt".to_string(), encrypt.to_string()));
+        }
+        if !language.is_empty() {
+            query_args.push(("language".to_string(), language.to_string()));
+        }
+        if !recipient_id.is_empty() {
+            query_args.push(("recipient_id".to_string(), recipient_id.to_string()));
+        }
+        if !shared_user_id.is_empty() {
+            query_args.push(("shared_user_id".to_string(), shared_user_id.to_string()));
+        }
+        if !show_changes.is_empty() {
+            query_args.push(("show_changes".to_string(), show_changes.to_string()));
+        }
+        if !watermark.is_empty() {
+            query_args.push(("watermark".to_string(), watermark.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/v2.1/accounts/{}/envelopes/{}/documents/{}?{}",
+            crate::progenitor_support::encode_path(&account_id.to_string()),
+            crate::progenitor_support::encode_path(&envelope_id.to_string()),
+            crate::progenitor_support::encode_path(&document_id.to_string()),
+            query_
+        );
+
+        self.client.get_stream(&url, None).await
     }
 
     /**
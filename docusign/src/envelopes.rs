@@ -1138,7 +1138,7 @@ impl Envelopes {
         max_height: &str,
         max_width: &str,
         show_changes: &str,
-    ) -> Result<()> {
+    ) -> Result<bytes::Bytes> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !dpi.is_empty() {
             query_args.push(("dpi".to_string(), dpi.to_string()));
@@ -1162,7 +1162,64 @@ impl Envelopes {
             query_
         );
 
-        self.client.get(&url, None).await
+        self.client.get_bytes(&url, None).await
+    }
+
+    /**
+     * Gets a page image from an envelope for display.
+     *
+     * Like `pages_get_page_image_envelopes`, but returns the response once its status is checked, so the file can be read in chunks with `reqwest::Response::chunk`.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/documents/{documentId}/pages/{pageNumber}/page_image` endpoint.
+     *
+     * Returns an image of a page in a document for display.
+     *
+     * **Parameters:**
+     *
+     * * `account_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
+     * * `document_id: &str` -- The `documentId` is set by the API client. It is an integer that falls between `1` and 2,147,483,647. The value is encoded as a string without commas. The values `1`, `2`, `3`, and so on are typically used to identify the first few documents in an envelope. Tab definitions include a `documentId` property that specifies the document on which to place the tab.
+     * * `envelope_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
+     * * `page_number: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
+     * * `dpi: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
+     * * `max_height: &str` -- Sets the maximum height for the page image in pixels. The DPI is recalculated based on this setting.
+     * * `max_width: &str` -- Sets the maximum width for the page image in pixels. The DPI is recalculated based on this setting.
+     * * `show_changes: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
+     */
+    pub async fn pages_get_page_image_envelopes_stream(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        envelope_id: &str,
+        page_number: &str,
+        dpi: &str,
+        max_height: &str,
+        max_width: &str,
+        show_changes: &str,
+    ) -> Result<reqwest::Response> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !dpi.is_empty() {
+            query_args.push(("dpi".to_string(), dpi.to_string()));
+        }
+        if !max_height.is_empty() {
+            query_args.push(("max_height".to_string(), max_height.to_string()));
+        }
+        if !max_width.is_empty() {
+            query_args.push(("max_width".to_string(), max_width.to_string()));
+        }
+        if !show_changes.is_empty() {
+            query_args.push(("show_changes".to_string(), show_changes.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/v2.1/accounts/{}/envelopes/{}/documents/{}/pages/{}/page_image?{}",
+            crate::progenitor_support::encode_path(&account_id.to_string()),
+            crate::progenitor_support::encode_path(&envelope_id.to_string()),
+            crate::progenitor_support::encode_path(&document_id.to_string()),
+            crate::progenitor_support::encode_path(&page_number.to_string()),
+            query_
+        );
+
+        self.client.get_stream(&url, None).await
     }
 
     /**
@@ -1285,7 +1342,7 @@ impl Envelopes {
         envelope_id: &str,
         recipient_id: &str,
         include_chrome: &str,
-    ) -> Result<()> {
+    ) -> Result<bytes::Bytes> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !include_chrome.is_empty() {
             query_args.push(("include_chrome".to_string(), include_chrome.to_string()));
@@ -1299,7 +1356,54 @@ impl Envelopes {
             query_
         );
 
-        self.client.get(&url, None).await
+        self.client.get_bytes(&url, None).await
+    }
+
+    /**
+     * Gets the initials image for a user.
+     *
+     * Like `recipients_get_recipient_initials_image`, but returns the response once its status is checked, so the file can be read in chunks with `reqwest::Response::chunk`.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/recipients/{recipientId}/initials_image` endpoint.
+     *
+     * Retrieves the initials image for the specified user. The image is returned in the same format as it was uploaded. In the request you can specify if the chrome (the added line and identifier around the initial image) is returned with the image.
+     *
+     * The userId specified in the endpoint must match the authenticated user's user id and the user must be a member of the account.
+     *
+     * The `signatureIdOrName` paramter accepts signature ID or signature name. DocuSign recommends you use signature ID (`signatureId`), since some names contain characters that do not properly URL encode. If you use the user name, it is likely that the name includes spaces and you might need to URL encode the name before using it in the endpoint.
+     *
+     * For example: "Bob Smith" to "Bob%20Smith"
+     *
+     * Older envelopes might only contain chromed images. If getting the non-chromed image fails, try getting the chromed image.
+     *
+     * **Parameters:**
+     *
+     * * `account_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
+     * * `envelope_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
+     * * `recipient_id: &str` -- A local reference that senders use to map recipients to other objects, such as specific document tabs. Within an envelope, each `recipientId` must be unique, but there is no uniqueness requirement across envelopes. For example, many envelopes assign the first recipient a `recipientId` of `1`.
+     * * `include_chrome: &str` -- The added line and identifier around the initial image. Note: Older envelopes might only have chromed images. If getting the non-chromed image fails, try getting the chromed image.
+     */
+    pub async fn recipients_get_recipient_initials_image_stream(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        recipient_id: &str,
+        include_chrome: &str,
+    ) -> Result<reqwest::Response> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !include_chrome.is_empty() {
+            query_args.push(("include_chrome".to_string(), include_chrome.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/v2.1/accounts/{}/envelopes/{}/recipients/{}/initials_image?{}",
+            crate::progenitor_support::encode_path(&account_id.to_string()),
+            crate::progenitor_support::encode_path(&envelope_id.to_string()),
+            crate::progenitor_support::encode_path(&recipient_id.to_string()),
+            query_
+        );
+
+        self.client.get_stream(&url, None).await
     }
 
     /**
@@ -1390,7 +1494,7 @@ impl Envelopes {
         envelope_id: &str,
         recipient_id: &str,
         include_chrome: &str,
-    ) -> Result<()> {
+    ) -> Result<bytes::Bytes> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !include_chrome.is_empty() {
             query_args.push(("include_chrome".to_string(), include_chrome.to_string()));
@@ -1404,7 +1508,54 @@ impl Envelopes {
             query_
         );
 
-        self.client.get(&url, None).await
+        self.client.get_bytes(&url, None).await
+    }
+
+    /**
+     * Retrieve signature image information for a signer/sign-in-person recipient.
+     *
+     * Like `recipients_get_recipient_signature_image`, but returns the response once its status is checked, so the file can be read in chunks with `reqwest::Response::chunk`.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/recipients/{recipientId}/signature_image` endpoint.
+     *
+     * Retrieves the specified user signature image. The image is returned in the same format as uploaded. In the request you can specify if the chrome (the added line and identifier around the initial image) is returned with the image.
+     *
+     * The userId specified in the endpoint must match the authenticated user's user ID and the user must be a member of the account.
+     *
+     * The `signatureIdOrName` parameter accepts signature ID or signature name. DocuSign recommends you use signature ID (`signatureId`), since some names contain characters that don't properly URL encode. If you use the user name, it is likely that the name includes spaces and you might need to URL encode the name before using it in the endpoint.
+     *
+     * For example: "Bob Smith" to "Bob%20Smith"
+     *
+     * Older envelopes might only have chromed images. If getting the non-chromed image fails, try getting the chromed image.
+     *
+     * **Parameters:**
+     *
+     * * `account_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
+     * * `envelope_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
+     * * `recipient_id: &str` -- A local reference that senders use to map recipients to other objects, such as specific document tabs. Within an envelope, each `recipientId` must be unique, but there is no uniqueness requirement across envelopes. For example, many envelopes assign the first recipient a `recipientId` of `1`.
+     * * `include_chrome: &str` -- When set to **true**, the response includes the chromed version of the signature image.
+     */
+    pub async fn recipients_get_recipient_signature_image_stream(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        recipient_id: &str,
+        include_chrome: &str,
+    ) -> Result<reqwest::Response> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !include_chrome.is_empty() {
+            query_args.push(("include_chrome".to_string(), include_chrome.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/v2.1/accounts/{}/envelopes/{}/recipients/{}/signature_image?{}",
+            crate::progenitor_support::encode_path(&account_id.to_string()),
+            crate::progenitor_support::encode_path(&envelope_id.to_string()),
+            crate::progenitor_support::encode_path(&recipient_id.to_string()),
+            query_
+        );
+
+        self.client.get_stream(&url, None).await
     }
 
     /**
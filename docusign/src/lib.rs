@@ -201,6 +201,8 @@ pub mod account_watermarks;
 pub mod accounts;
 /// .
 pub mod appliance_info;
+/// Authenticate as a user with the JWT grant, and find the base URI of their account.
+pub mod auth;
 /// You can configure automatic archiving of emails sent from all of your DocuSign accounts.
 ///
 ///For more information, see [Email Archive Configuration](https://support.docusign.com/en/guides/ndse-admin-guide-email-archive-configuration).
@@ -324,6 +326,8 @@ pub mod cloud_storage_providers;
 ///
 ///For more information, see [Comments Settings](https://support.docusign.com/en/guides/ndse-admin-guide-comments-settings).
 pub mod comments;
+/// Verify and parse the events Connect sends to webhooks.
+pub mod connect;
 /// The `ConnectConfigurations` resource enables you to configure the DocuSign Connect service for your account.
 ///
 ///You can use this resource to configure account-level webhooks that send notifications about every envelope sent from your account. You can set account-level webhooks to listen for events for envelopes sent by a specific user on your account, by multiple specific users, or from any of the users on your account. These events will be tracked, and can be delivered to a listening application.
@@ -359,6 +363,8 @@ pub mod custom_tabs;
 /// **Note**: Responsive Signing is disabled by default. To use this functionality, an account administrator must switch the account setting `enableResponsiveSigning` to **true**.
 ///Also note that Smart Sections (creating a signable HTML document that uses collapsible sections and rotating tables) are premium features. To request them, contact your DocuSign account manager.
 pub mod document_responsive_html_preview;
+/// Download envelope documents, including the combined, archive and certificate documents.
+pub mod downloads;
 /// The ENoteConfigurations resource provides methods that allow you to manage
 ///information for the eNote eOriginal integration.
 ///.
@@ -366,6 +372,8 @@ pub mod e_note_configurations;
 /// The EnvelopeAttachments resource provides methods that allow you to manage attachments.
 ///.
 pub mod envelope_attachments;
+/// Compose envelopes from documents, recipients and tabs.
+pub mod envelope_builder;
 /// The `EnvelopeConsumerDisclosures` resource provides methods that enable you to retrieve the Electronic Record and Signature Disclosure (ERSD) for an envelope recipient. This is the disclosure that displays to each new recipient who is going to sign or add other information, or who is required to view the documents you send to them. The recipient must read and agree to the terms of the disclosure before they can access and take action on the documents you send. The ERSD does not apply to copy-only recipients, but does apply to recipients who must sign or view your documents.
 ///
 ///You can retrieve either the default ERSD that DocuSign provides for U.S.-based transactions, or a custom ERSD.
@@ -2221,6 +2229,8 @@ pub struct Client {
     redirect_uri: String,
 
     client: reqwest::Client,
+    // Set for the JWT grant, which gets and renews its own tokens.
+    jwt_credentials: Option<std::sync::Arc<crate::auth::JwtCredentials>>,
 }
 
 use schemars::JsonSchema;
@@ -2296,6 +2306,7 @@ impl Client {
                     refresh_token: refresh_token.to_string(),
 
                     client: c,
+                    jwt_credentials: None,
                 }
             }
             Err(e) => panic!("creating reqwest client failed: {:?}", e),
@@ -2426,7 +2437,13 @@ impl Client {
     async fn url_and_auth(&self, uri: &str) -> Result<(reqwest::Url, Option<String>)> {
         let parsed_url = uri.parse::<reqwest::Url>();
 
-        let auth = format!("Bearer {}", self.token);
+        let auth = format!(
+            "Bearer {}",
+            match &self.jwt_credentials {
+                Some(credentials) => credentials.token(&self.client).await?,
+                None => self.token.to_string(),
+            }
+        );
         parsed_url.map(|u| (u, Some(auth))).map_err(Error::from)
     }
 
@@ -2912,6 +2929,91 @@ impl Client {
         .await
     }
 
+    /// Send a request and return the body of a successful response as bytes, for
+    /// endpoints that return files rather than JSON.
+    #[allow(dead_code)]
+    async fn request_bytes(
+        &self,
+        method: reqwest::Method,
+        uri: &str,
+        body: Option<reqwest::Body>,
+    ) -> Result<bytes::Bytes> {
+        let response = self.request_stream(method, uri, body).await?;
+        Ok(response.bytes().await?)
+    }
+
+    /// Send a request and return a successful response without reading its body, so
+    /// large files can be read in chunks with `reqwest::Response::chunk`.
+    #[allow(dead_code)]
+    async fn request_stream(
+        &self,
+        method: reqwest::Method,
+        uri: &str,
+        body: Option<reqwest::Body>,
+    ) -> Result<reqwest::Response> {
+        let u = if uri.starts_with("https://") {
+            uri.to_string()
+        } else {
+            (self.host.clone() + uri).to_string()
+        };
+        let (url, auth) = self.url_and_auth(&u).await?;
+
+        let instance = <&Client>::clone(&self);
+
+        let mut req = instance.client.request(method, url);
+
+        // Accept whatever type of file the endpoint returns.
+        req = req.header(
+            reqwest::header::ACCEPT,
+            reqwest::header::HeaderValue::from_static("*/*"),
+        );
+
+        if let Some(auth_str) = auth {
+            req = req.header(http::header::AUTHORIZATION, &*auth_str);
+        }
+
+        if let Some(body) = body {
+            req = req.body(body);
+        }
+
+        log::debug!("request: {:?}", &req);
+        let response = req.send().await?;
+
+        let status = response.status();
+        if status.is_success() {
+            return Ok(response);
+        }
+
+        let response_body = response.bytes().await?;
+        let error = if response_body.is_empty() {
+            anyhow!("code: {}, empty response", status)
+        } else {
+            anyhow!(
+                "code: {}, error: {:?}",
+                status,
+                String::from_utf8_lossy(&response_body),
+            )
+        };
+
+        Err(error)
+    }
+
+    #[allow(dead_code)]
+    async fn get_bytes(&self, uri: &str, message: Option<reqwest::Body>) -> Result<bytes::Bytes> {
+        self.request_bytes(http::Method::GET, &(self.host.to_string() + uri), message)
+            .await
+    }
+
+    #[allow(dead_code)]
+    async fn get_stream(
+        &self,
+        uri: &str,
+        message: Option<reqwest::Body>,
+    ) -> Result<reqwest::Response> {
+        self.request_stream(http::Method::GET, &(self.host.to_string() + uri), message)
+            .await
+    }
+
     /// The AcccountBrands resource provides methods that enable you to create and manage brands for an account.
     ///
     ///Branding enables you to add the look and feel of your organization's brand to the sending, signing, and email processes, making it easier for recipients to identify envelopes coming from your organization.
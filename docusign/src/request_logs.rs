@@ -73,7 +73,35 @@ impl RequestLogs {
             crate::progenitor_support::encode_path(&request_log_id.to_string()),
         );
 
-        self.client.get(&url, None).await
+        self.client.get_bytes(&url, None).await
+    }
+
+    /**
+     * Gets a request logging log file.
+     *
+     * Like `api_get`, but returns the response once its status is checked, so the file can be read in chunks with `reqwest::Response::chunk`.
+     *
+     * This function performs a `GET` to the `/v2.1/diagnostics/request_logs/{requestLogId}` endpoint.
+     *
+     * Retrieves information for a single log entry.
+     *
+     * **Request**
+     * The `requestLogfId` property can be retrieved by getting the list of log entries. The Content-Transfer-Encoding header can be set to base64 to retrieve the API request/response as base 64 string. Otherwise the bytes of the request/response are returned.
+     *
+     * **Response**
+     * If the Content-Transfer-Encoding header was set to base64, the log is returned as a base64 string.
+     *
+     * **Parameters:**
+     *
+     * * `request_log_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
+     */
+    pub async fn api_get_stream(&self, request_log_id: &str) -> Result<reqwest::Response> {
+        let url = format!(
+            "/v2.1/diagnostics/request_logs/{}",
+            crate::progenitor_support::encode_path(&request_log_id.to_string()),
+        );
+
+        self.client.get_stream(&url, None).await
     }
 
     /**
@@ -142,7 +142,7 @@ impl TemplateDocuments {
         template_id: &str,
         encrypt: &str,
         show_changes: &str,
-    ) -> Result<()> {
+    ) -> Result<bytes::Bytes> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !encrypt.is_empty() {
             query_args.push(("encrypt".to_string(), encrypt.to_string()));
@@ -159,7 +159,53 @@ impl TemplateDocuments {
             query_
         );
 
-        self.client.get(&url, None).await
+        self.client.get_bytes(&url, None).await
+    }
+
+    /**
+     * Gets PDF documents from a template.
+     *
+     * Like `documents_get`, but returns the response once its status is checked, so the file can be read in chunks with `reqwest::Response::chunk`.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/templates/{templateId}/documents/{documentId}` endpoint.
+     *
+     * This method retrieves one or more PDF documents from the template that you specify.
+     *
+     * You can specify the ID of the document to retrieve, or pass in the value `combined` to retrieve all documents in the template as a single PDF file.
+     *
+     * **Parameters:**
+     *
+     * * `account_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
+     * * `document_id: &str` -- The `documentId` is set by the API client. It is an integer that falls between `1` and 2,147,483,647. The value is encoded as a string without commas. The values `1`, `2`, `3`, and so on are typically used to identify the first few documents in an envelope. Tab definitions include a `documentId` property that specifies the document on which to place the tab.
+     * * `template_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
+     * * `encrypt: &str` -- When set to **true**, the PDF bytes returned in the response are encrypted for all the key managers configured on your DocuSign account. You can decrypt the documents by using the Key Manager DecryptDocument API method. For more information about Key Manager, see the DocuSign Security Appliance Installation Guide that your organization received from DocuSign.
+     * * `show_changes: &str` -- When set to **true**, any document fields that a recipient changed are highlighted in yellow in the returned PDF document, and optional signatures or initials are outlined in red.
+     */
+    pub async fn documents_get_stream(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        template_id: &str,
+        encrypt: &str,
+        show_changes: &str,
+    ) -> Result<reqwest::Response> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !encrypt.is_empty() {
+            query_args.push(("encrypt".to_string(), encrypt.to_string()));
+        }
+        if !show_changes.is_empty() {
+            query_args.push(("show_changes".to_string(), show_changes.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/v2.1/accounts/{}/templates/{}/documents/{}?{}",
+            crate::progenitor_support::encode_path(&account_id.to_string()),
+            crate::progenitor_support::encode_path(&template_id.to_string()),
+            crate::progenitor_support::encode_path(&document_id.to_string()),
+            query_
+        );
+
+        self.client.get_stream(&url, None).await
     }
 
     /**
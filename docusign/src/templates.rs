@@ -484,7 +484,7 @@ impl Templates {
         max_height: &str,
         max_width: &str,
         show_changes: &str,
-    ) -> Result<()> {
+    ) -> Result<bytes::Bytes> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !dpi.is_empty() {
             query_args.push(("dpi".to_string(), dpi.to_string()));
@@ -508,7 +508,64 @@ impl Templates {
             query_
         );
 
-        self.client.get(&url, None).await
+        self.client.get_bytes(&url, None).await
+    }
+
+    /**
+     * Gets a page image from a template for display.
+     *
+     * Like `pages_get_page_image_templates`, but returns the response once its status is checked, so the file can be read in chunks with `reqwest::Response::chunk`.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/templates/{templateId}/documents/{documentId}/pages/{pageNumber}/page_image` endpoint.
+     *
+     * Retrieves a page image for display from the specified template.
+     *
+     * **Parameters:**
+     *
+     * * `account_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
+     * * `document_id: &str` -- The `documentId` is set by the API client. It is an integer that falls between `1` and 2,147,483,647. The value is encoded as a string without commas. The values `1`, `2`, `3`, and so on are typically used to identify the first few documents in an envelope. Tab definitions include a `documentId` property that specifies the document on which to place the tab.
+     * * `page_number: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
+     * * `template_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
+     * * `dpi: &str` -- The number of dots per inch (DPI) for the resulting images. Valid values are 1-310 DPI. The default value is 94.
+     * * `max_height: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
+     * * `max_width: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
+     * * `show_changes: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
+     */
+    pub async fn pages_get_page_image_templates_stream(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        page_number: &str,
+        template_id: &str,
+        dpi: &str,
+        max_height: &str,
+        max_width: &str,
+        show_changes: &str,
+    ) -> Result<reqwest::Response> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !dpi.is_empty() {
+            query_args.push(("dpi".to_string(), dpi.to_string()));
+        }
+        if !max_height.is_empty() {
+            query_args.push(("max_height".to_string(), max_height.to_string()));
+        }
+        if !max_width.is_empty() {
+            query_args.push(("max_width".to_string(), max_width.to_string()));
+        }
+        if !show_changes.is_empty() {
+            query_args.push(("show_changes".to_string(), show_changes.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/v2.1/accounts/{}/templates/{}/documents/{}/pages/{}/page_image?{}",
+            crate::progenitor_support::encode_path(&account_id.to_string()),
+            crate::progenitor_support::encode_path(&template_id.to_string()),
+            crate::progenitor_support::encode_path(&document_id.to_string()),
+            crate::progenitor_support::encode_path(&page_number.to_string()),
+            query_
+        );
+
+        self.client.get_stream(&url, None).await
     }
 
     /**
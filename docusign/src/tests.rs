@@ -1 +1,311 @@
+#[test]
+fn test_envelope_builder_definition() {
+    use crate::envelope_builder::{EnvelopeBuilder, Recipient, Tab, TabPosition};
 
+    let builder = EnvelopeBuilder::new("Please sign")
+        .document("Offer Letter.PDF", b"%PDF-1.4".to_vec())
+        .document("terms", b"%PDF-1.7".to_vec())
+        .recipient(
+            Recipient::signer("Alice", "alice@example.com")
+                .with_tab(Tab::sign_here(TabPosition::anchor("/sig1/").offset(10, -5)))
+                .with_tab(Tab::text(TabPosition::at(2, 1, 100, 200), "title").required(true))
+                .with_tab(Tab::initial_here(TabPosition::at(1, 2, 50, 60)).required(false))
+                .with_tab(Tab::checkbox(TabPosition::at(1, 2, 50, 90), "agree").with_value("true")),
+        )
+        .recipient(Recipient::carbon_copy("HR", "hr@example.com").with_routing_order(2))
+        .recipient(Recipient::in_person("Bob", "Carol", "carol@example.com"));
+
+    let envelope = serde_json::to_value(builder.build().unwrap()).unwrap();
+    assert_eq!(envelope["emailSubject"], "Please sign");
+    assert_eq!(envelope["documents"][0]["documentId"], "1");
+    assert_eq!(envelope["documents"][0]["fileExtension"], "pdf");
+    assert_eq!(envelope["documents"][1]["documentId"], "2");
+    assert_eq!(envelope["documents"][1]["fileExtension"], "pdf");
+    assert_eq!(envelope["documents"][1]["documentBase64"], "JVBERi0xLjc=");
+
+    let recipients = &envelope["recipients"];
+    let signer = &recipients["signers"][0];
+    assert_eq!(signer["recipientId"], "1");
+    assert_eq!(signer["routingOrder"], "1");
+    let sign_here = &signer["tabs"]["signHereTabs"][0];
+    assert_eq!(sign_here["anchorString"], "/sig1/");
+    assert_eq!(sign_here["anchorXOffset"], "10");
+    assert_eq!(sign_here["anchorYOffset"], "-5");
+    assert_eq!(sign_here["recipientId"], "1");
+    let text = &signer["tabs"]["textTabs"][0];
+    assert_eq!(text["documentId"], "2");
+    assert_eq!(text["xPosition"], "100");
+    assert_eq!(text["tabLabel"], "title");
+    assert_eq!(text["required"], "true");
+    let initial_here = &signer["tabs"]["initialHereTabs"][0];
+    assert_eq!(initial_here["pageNumber"], "2");
+    assert_eq!(initial_here["optional"], "true");
+    let checkbox = &signer["tabs"]["checkboxTabs"][0];
+    assert_eq!(checkbox["tabLabel"], "agree");
+    assert_eq!(checkbox["selected"], "true");
+
+    assert_eq!(recipients["carbonCopies"][0]["recipientId"], "2");
+    assert_eq!(recipients["carbonCopies"][0]["routingOrder"], "2");
+    assert_eq!(recipients["inPersonSigners"][0]["recipientId"], "3");
+    assert_eq!(recipients["inPersonSigners"][0]["signerName"], "Bob");
+    assert_eq!(
+        recipients["inPersonSigners"][0]["hostEmail"],
+        "carol@example.com"
+    );
+
+    // Tabs must refer to documents that exist.
+    assert!(EnvelopeBuilder::new("Please sign")
+        .document("a.pdf", vec![])
+        .recipient(
+            Recipient::signer("Alice", "alice@example.com")
+                .with_tab(Tab::sign_here(TabPosition::at(2, 1, 0, 0)))
+        )
+        .build()
+        .is_err());
+}
+
+#[test]
+fn test_document_selector() {
+    use crate::downloads::DocumentSelector;
+
+    assert_eq!(DocumentSelector::from("archive"), DocumentSelector::Archive);
+    assert_eq!(
+        DocumentSelector::from("3"),
+        DocumentSelector::Document("3".to_string())
+    );
+    assert_eq!(DocumentSelector::Certificate.to_string(), "certificate");
+    assert_eq!(DocumentSelector::Archive.file_extension(), "zip");
+    assert_eq!(DocumentSelector::Combined.file_extension(), "pdf");
+}
+
+#[test]
+fn test_user_info_accounts() {
+    use crate::auth::{Environment, JwtCredentials, UserInfo};
+
+    let info: UserInfo = serde_json::from_str(
+        r#"{
+            "sub": "4799e5e9-1559-4915-9862-cf4713bbcace",
+            "name": "Susan Smart",
+            "email": "susan.smart@example.com",
+            "accounts": [
+                {
+                    "account_id": "18b4799a-xxxx-xxxx-xxxx-b5b4b8a97604",
+                    "is_default": false,
+                    "account_name": "Sandbox",
+                    "base_uri": "https://demo.docusign.net"
+                },
+                {
+                    "account_id": "7fe3e8b8-xxxx-xxxx-xxxx-3e6e56b1e0ff",
+                    "is_default": true,
+                    "account_name": "Production",
+                    "base_uri": "https://eu.docusign.net/"
+                }
+            ]
+        }"#,
+    )
+    .unwrap();
+
+    let account = info.default_account().unwrap();
+    assert_eq!(account.account_name, "Production");
+    assert_eq!(account.api_host(), "https://eu.docusign.net/restapi");
+    assert_eq!(
+        info.account("18b4799a-xxxx-xxxx-xxxx-b5b4b8a97604")
+            .unwrap()
+            .api_host(),
+        "https://demo.docusign.net/restapi"
+    );
+    assert!(info.account("missing").is_none());
+
+    let credentials = JwtCredentials::new("key", "user", vec![])
+        .with_environment(Environment::Demo)
+        .with_scopes(&["signature"]);
+    assert_eq!(
+        credentials.consent_url("https://example.com/callback"),
+        "https://account-d.docusign.com/oauth/auth?response_type=code&scope=signature%20impersonation&client_id=key&redirect_uri=https%3A%2F%2Fexample.com%2Fcallback"
+    );
+}
+
+#[test]
+fn test_jwt_token_cache() {
+    use crate::{auth::JwtCredentials, AccessToken};
+
+    let credentials = JwtCredentials::new("key", "user", vec![]);
+    assert_eq!(credentials.cached_token(), None);
+
+    credentials.cache_token(&AccessToken {
+        access_token: "fresh".to_string(),
+        expires_in: 3600,
+        ..Default::default()
+    });
+    assert_eq!(credentials.cached_token(), Some("fresh".to_string()));
+
+    // Tokens about to expire are renewed before they are used.
+    credentials.cache_token(&AccessToken {
+        access_token: "stale".to_string(),
+        expires_in: 60,
+        ..Default::default()
+    });
+    assert_eq!(credentials.cached_token(), None);
+}
+
+#[test]
+fn test_connect_signature() {
+    use crate::connect::{sign, verify_signature};
+
+    let body = br#"{"event":"envelope-sent"}"#;
+    let signature = sign("new-key", body);
+    let headers = vec![
+        ("Content-Type", "application/json".to_string()),
+        ("X-DocuSign-Signature-1", sign("old-key", b"another body")),
+        ("X-DocuSign-Signature-2", signature),
+    ];
+
+    verify_signature(headers.clone(), body, &["other-key", "new-key"]).unwrap();
+    assert!(verify_signature(headers.clone(), body, &["old-key"]).is_err());
+    assert!(verify_signature(headers, b"tampered", &["new-key"]).is_err());
+    assert!(verify_signature(Vec::<(&str, &str)>::new(), body, &["new-key"]).is_err());
+}
+
+#[test]
+fn test_connect_event_json() {
+    use crate::connect::{ConnectEvent, ConnectEventKind};
+
+    let event = ConnectEvent::from_slice(
+        br#"{
+            "event": "recipient-completed",
+            "apiVersion": "v2.1",
+            "generatedDateTime": "2021-06-01T12:00:00.0000000Z",
+            "data": {
+                "accountId": "account-1",
+                "envelopeId": "envelope-1",
+                "recipientId": "1",
+                "envelopeSummary": {
+                    "status": "completed",
+                    "emailSubject": "Please sign",
+                    "recipients": {
+                        "signers": [{
+                            "recipientId": "1",
+                            "email": "alice@example.com",
+                            "tabs": {
+                                "textTabs": [{"tabLabel": "title", "value": "CEO", "documentId": "1"}],
+                                "checkboxTabs": [{"tabLabel": "agree", "selected": "true", "documentId": "1"}]
+                            }
+                        }]
+                    },
+                    "envelopeDocuments": [{
+                        "documentId": "1",
+                        "documentFields": [{"name": "contract", "value": "42"}]
+                    }]
+                }
+            }
+        }"#,
+    )
+    .unwrap();
+
+    assert_eq!(event.kind, ConnectEventKind::RecipientCompleted);
+    assert!(event.kind.is_recipient_event());
+    assert_eq!(event.account_id, "account-1");
+    assert_eq!(event.envelope_id, "envelope-1");
+    assert_eq!(event.recipient_id, "1");
+    let envelope = event.envelope.unwrap();
+    assert_eq!(envelope.status, "completed");
+    assert_eq!(
+        envelope.recipients.unwrap().signers[0].email,
+        "alice@example.com"
+    );
+    let fields: Vec<_> = event
+        .fields
+        .iter()
+        .map(|f| (f.recipient_id.as_str(), f.label.as_str(), f.value.as_str()))
+        .collect();
+    assert_eq!(
+        fields,
+        vec![
+            ("1", "agree", "true"),
+            ("1", "title", "CEO"),
+            ("", "contract", "42")
+        ]
+    );
+}
+
+#[test]
+fn test_connect_event_xml() {
+    use crate::connect::{ConnectEvent, ConnectEventKind};
+
+    let event = ConnectEvent::from_slice(
+        br#"<?xml version="1.0" encoding="utf-8"?>
+<DocuSignEnvelopeInformation xmlns:xsi="http://www.w3.org/2001/XMLSchema-instance" xmlns="http://www.docusign.net/API/3.0">
+  <EnvelopeStatus>
+    <RecipientStatuses>
+      <RecipientStatus>
+        <Type>Signer</Type>
+        <Email>alice@example.com</Email>
+        <UserName>Alice &amp; Co</UserName>
+        <RoutingOrder>1</RoutingOrder>
+        <Signed>2021-06-01T11:59:00.123</Signed>
+        <DeclineReason xsi:nil="true" />
+        <Status>Completed</Status>
+        <RecipientId>6a1b2c3d-0000-0000-0000-000000000001</RecipientId>
+        <TabStatuses>
+          <TabStatus>
+            <TabType>Custom</TabType>
+            <TabLabel>title</TabLabel>
+            <TabValue><![CDATA[CEO <acting>]]></TabValue>
+            <DocumentID>1</DocumentID>
+          </TabStatus>
+        </TabStatuses>
+      </RecipientStatus>
+    </RecipientStatuses>
+    <TimeGenerated>2021-06-01T12:00:00.123</TimeGenerated>
+    <EnvelopeID>envelope-1</EnvelopeID>
+    <Subject>Please sign</Subject>
+    <Status>Completed</Status>
+    <Completed>2021-06-01T11:59:00.123</Completed>
+    <DocumentStatuses>
+      <DocumentStatus>
+        <ID>1</ID>
+        <Name>contract.pdf</Name>
+        <Sequence>1</Sequence>
+      </DocumentStatus>
+    </DocumentStatuses>
+  </EnvelopeStatus>
+</DocuSignEnvelopeInformation>"#,
+    )
+    .unwrap();
+
+    assert_eq!(event.kind, ConnectEventKind::EnvelopeCompleted);
+    assert_eq!(event.envelope_id, "envelope-1");
+    assert_eq!(event.generated_date_time, "2021-06-01T12:00:00.123");
+    let envelope = event.envelope.unwrap();
+    assert_eq!(envelope.status, "completed");
+    assert_eq!(envelope.email_subject, "Please sign");
+    assert_eq!(envelope.completed_date_time, "2021-06-01T11:59:00.123");
+    assert_eq!(envelope.envelope_documents[0].name, "contract.pdf");
+    let signer = &envelope.recipients.unwrap().signers[0];
+    assert_eq!(signer.name, "Alice & Co");
+    assert_eq!(signer.status, "completed");
+    assert_eq!(event.fields.len(), 1);
+    assert_eq!(event.fields[0].label, "title");
+    assert_eq!(event.fields[0].value, "CEO <acting>");
+    assert_eq!(
+        event.fields[0].recipient_id,
+        "6a1b2c3d-0000-0000-0000-000000000001"
+    );
+
+    assert!(ConnectEvent::from_slice(b"<Other></Other>").is_err());
+    assert!(ConnectEvent::from_slice(b"<DocuSignEnvelopeInformation>").is_err());
+    assert!(ConnectEvent::from_slice(
+        b"<DocuSignEnvelopeInformation><EnvelopeStatus></Status></DocuSignEnvelopeInformation>"
+    )
+    .is_err());
+
+    let err = ConnectEvent::from_slice(
+        br#"<?xml version="1.0"?>
+<!DOCTYPE DocuSignEnvelopeInformation [<!ENTITY status "Completed">]>
+<DocuSignEnvelopeInformation>
+  <EnvelopeStatus><Status>&status;</Status></EnvelopeStatus>
+</DocuSignEnvelopeInformation>"#,
+    )
+    .unwrap_err();
+    assert!(err.to_string().contains("document type"));
+}
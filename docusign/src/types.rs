@@ -4721,7 +4721,7 @@ pub struct EnvelopeLocks {
 }
 
 /// All of the tabs associated with a recipient. Each property is a list of a type of tab.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct EnvelopeRecipientTabs {
     /**
      * All of the tabs associated with a recipient. Each property is a list of a type of tab.
@@ -5154,7 +5154,7 @@ pub struct EnvelopeRecipientTabs {
 }
 
 /// Envelope recipients
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct EnvelopeRecipients {
     /**
      * Envelope recipients
@@ -25023,7 +25023,7 @@ pub struct CaptiveRecipientInformation {
 }
 
 /// Contains information about a carbon copy recipient. Carbon copy recipients get a copy of the envelope but don't need to sign, initial, date or add information to any of the documents.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct CarbonCopy {
     /**
      * Contains information about a carbon copy recipient. Carbon copy recipients get a copy of the envelope but don't need to sign, initial, date or add information to any of the documents.
@@ -26418,7 +26418,7 @@ pub struct CertifiedDelivery {
 }
 
 /// A tab that allows the recipient to select a yes/no (on/off) option.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Checkbox {
     /**
      * A tab that allows the recipient to select a yes/no (on/off) option.
@@ -35922,7 +35922,7 @@ pub struct Date {
 
 /// A tab that displays the date that the recipient signed the
 /// document.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct DateSigned {
     /**
      * A tab that displays the date that the recipient signed the
@@ -38342,7 +38342,7 @@ pub struct DobInformationInput {
 }
 
 /// A document object.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Document {
     /**
      * A document object.
@@ -55154,7 +55154,7 @@ pub struct FormulaTab {
 }
 
 /// A tab that displays the recipient's full name.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct FullName {
     /**
      * A tab that displays the recipient's full name.
@@ -56340,7 +56340,7 @@ pub struct IdCheckSecurityStep {
 /// see the [EnvelopeRecipients resource][resource].
 ///
 /// [resource]: https://developers.docusign.com/docs/esign-rest-api/reference/Envelopes/EnvelopeRecipients#in-person-signer-recipient
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct InPersonSigner {
     /**
      * Contains information about an in-person recipient. This is a DocuSign user,
@@ -57947,7 +57947,7 @@ pub struct InPersonSigner {
 
 /// A tab that allows the recipient to initial the document. May
 /// be optional.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct InitialHere {
     /**
      * A tab that allows the recipient to initial the document. May
@@ -80546,7 +80546,7 @@ pub struct SharedItem {
 
 /// A tab that allows the recipient to sign a document. May be
 /// optional.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct SignHere {
     /**
      * A tab that allows the recipient to sign a document. May be
@@ -81670,7 +81670,7 @@ pub struct SignatureUserDef {
 }
 
 /// A complex type containing information about a signer recipient. A signer is a recipient who must take action on a document, such as sign, initial, date, or add data to form fields on a document.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Signer {
     /**
      * A complex type containing information about a signer recipient. A signer is a recipient who must take action on a document, such as sign, initial, date, or add data to form fields on a document.
@@ -88655,7 +88655,7 @@ pub struct TemplateSummary {
 }
 
 /// A tab that allows the recipient to enter any type of text.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Text {
     /**
      * A tab that allows the recipient to enter any type of text.
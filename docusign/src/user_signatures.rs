@@ -293,7 +293,7 @@ impl UserSignatures {
         signature_id: &str,
         user_id: &str,
         include_chrome: &str,
-    ) -> Result<()> {
+    ) -> Result<bytes::Bytes> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !include_chrome.is_empty() {
             query_args.push(("include_chrome".to_string(), include_chrome.to_string()));
@@ -308,7 +308,61 @@ impl UserSignatures {
             query_
         );
 
-        self.client.get(&url, None).await
+        self.client.get_bytes(&url, None).await
+    }
+
+    /**
+     * Retrieves the user initials image or the  user signature image for the specified user.
+     *
+     * Like `get_signature_image`, but returns the response once its status is checked, so the file can be read in chunks with `reqwest::Response::chunk`.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/users/{userId}/signatures/{signatureId}/{imageType}` endpoint.
+     *
+     * Retrieves the specified initials image or signature image for the specified user. The image is returned in the same format in which it was uploaded. In the request you can specify if the chrome (the added line and identifier around the initial image) is returned with the image.
+     *
+     * The userId property specified in the endpoint must match the authenticated user's user ID and the user must be a member of the account.
+     *
+     * The `signatureId` parameter accepts a signature ID or a signature name. DocuSign recommends you use signature ID (`signatureId`), since some names contain characters that do not properly encode into a URL. If you use the user name, it is likely that the name includes spaces. In that case, URL encode the name before using it in the endpoint.
+     *
+     * For example encode "Bob Smith" as "Bob%20Smith".
+     *
+     * **Note**: Older envelopes might only have chromed images. If getting the non-chromed image fails, try getting the chromed image.
+     *
+     * **Parameters:**
+     *
+     * * `account_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
+     * * `image_type: &str` -- Specificies the type of image. Valid values are:
+     *   
+     *   - `signature_image`
+     *   - `initials_image`.
+     * * `signature_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
+     * * `user_id: &str` -- The ID of the user to access. Generally this is the ID of the current authenticated user, but if the authenticated user is an Administrator on the account, `userId` can represent another user whom the Administrator is accessing.
+     *   .
+     * * `include_chrome: &str` -- When **true**, the chrome (or frame containing the added line and identifier) is included with the signature image.
+     */
+    pub async fn get_signature_image_stream(
+        &self,
+        account_id: &str,
+        image_type: &str,
+        signature_id: &str,
+        user_id: &str,
+        include_chrome: &str,
+    ) -> Result<reqwest::Response> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !include_chrome.is_empty() {
+            query_args.push(("include_chrome".to_string(), include_chrome.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/v2.1/accounts/{}/users/{}/signatures/{}/{}?{}",
+            crate::progenitor_support::encode_path(&account_id.to_string()),
+            crate::progenitor_support::encode_path(&user_id.to_string()),
+            crate::progenitor_support::encode_path(&signature_id.to_string()),
+            crate::progenitor_support::encode_path(&image_type.to_string()),
+            query_
+        );
+
+        self.client.get_stream(&url, None).await
     }
 
     /**
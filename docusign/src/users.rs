@@ -355,7 +355,7 @@ impl Users {
         account_id: &str,
         user_id: &str,
         encoding: &str,
-    ) -> Result<()> {
+    ) -> Result<bytes::Bytes> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !encoding.is_empty() {
             query_args.push(("encoding".to_string(), encoding.to_string()));
@@ -368,7 +368,48 @@ impl Users {
             query_
         );
 
-        self.client.get(&url, None).await
+        self.client.get_bytes(&url, None).await
+    }
+
+    /**
+     * Retrieves the user profile image for the specified user.
+     *
+     * Like `profile_image_get`, but returns the response once its status is checked, so the file can be read in chunks with `reqwest::Response::chunk`.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/users/{userId}/profile/image` endpoint.
+     *
+     * Retrieves the user profile picture for the specified user. The image is returned in the same format as uploaded.
+     *
+     * The userId parameter specified in the endpoint must match the authenticated user's user ID and the user must be a member of the specified account.
+     *
+     * If successful, the response returns a 200 - OK and the user profile image.
+     *
+     * **Parameters:**
+     *
+     * * `account_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
+     * * `user_id: &str` -- The ID of the user to access. Generally this is the ID of the current authenticated user, but if the authenticated user is an Administrator on the account, `userId` can represent another user whom the Administrator is accessing.
+     *   .
+     * * `encoding: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
+     */
+    pub async fn profile_image_get_stream(
+        &self,
+        account_id: &str,
+        user_id: &str,
+        encoding: &str,
+    ) -> Result<reqwest::Response> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !encoding.is_empty() {
+            query_args.push(("encoding".to_string(), encoding.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/v2.1/accounts/{}/users/{}/profile/image?{}",
+            crate::progenitor_support::encode_path(&account_id.to_string()),
+            crate::progenitor_support::encode_path(&user_id.to_string()),
+            query_
+        );
+
+        self.client.get_stream(&url, None).await
     }
 
     /**
@@ -11,7 +11,7 @@ pub struct Client {
     client: reqwest::Client,
     credentials: Option<crate::auth::Credentials>,
     #[cfg(feature = "httpcache")]
-    http_cache: crate::http_cache::BoxedHttpCache,
+    http_cache: crate::http_cache::BoxedAsyncHttpCache,
 }
 
 impl Client {
@@ -64,7 +64,7 @@ impl Client {
             agent: agent.into(),
             client: http,
             credentials: credentials.into(),
-            http_cache,
+            http_cache: std::sync::Arc::new(http_cache),
         }
     }
 
@@ -90,6 +90,13 @@ impl Client {
         self.credentials = credentials.into();
     }
 
+    /// Use a cache backed by async storage for conditional requests.
+    #[cfg(feature = "httpcache")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "httpcache")))]
+    pub fn set_http_cache(&mut self, http_cache: crate::http_cache::BoxedAsyncHttpCache) {
+        self.http_cache = http_cache;
+    }
+
     fn credentials(&self, authentication: crate::auth::AuthenticationConstraint) -> Option<&crate::auth::Credentials> {
         match (authentication, self.credentials.as_ref()) {
             (crate::auth::AuthenticationConstraint::Unconstrained, creds) => creds,
@@ -132,6 +139,10 @@ impl Client {
                 let auth = format!("Bearer {}", jwt.token());
                 parsed_url.map(|u| (u, Some(auth))).map_err(Error::from)
             }
+            Some(&crate::auth::Credentials::UserToken(ref user_token)) => {
+                let auth = format!("token {}", user_token.access_token().await?);
+                parsed_url.map(|u| (u, Some(auth))).map_err(Error::from)
+            }
             Some(&crate::auth::Credentials::InstallationToken(ref apptoken)) => {
                 if let Some(token) = apptoken.token() {
                     let auth = format!("token {}", token);
@@ -177,15 +188,7 @@ impl Client {
         let mut req = instance.client.request(method, url);
 
         #[cfg(feature = "httpcache")]
-        let mut req = {
-            let mut req = instance.client.request(method.clone(), url);
-            if method == http::Method::GET {
-                if let Ok(etag) = instance.http_cache.lookup_etag(&uri2) {
-                    req = req.header(http::header::IF_NONE_MATCH, etag);
-                }
-            }
-            req
-        };
+        let mut req = instance.client.request(method.clone(), url);
 
         req = req.header(http::header::USER_AGENT, &*instance.agent);
         req = req.header(
@@ -202,13 +205,37 @@ impl Client {
             req = req.body(body);
         }
         log::debug!("request: {:?}", &req);
-        let response = req.send().await?;
 
-        #[cfg(feature = "httpcache")]
-        let instance2 = <&Client>::clone(&self);
+        #[cfg(not(feature = "httpcache"))]
+        let response = req.send().await?;
 
         #[cfg(feature = "httpcache")]
-        let uri3 = uri.to_string();
+        let (response, cached_body) = {
+            let etag = if method == http::Method::GET {
+                instance.http_cache.lookup_etag(&uri2).await.ok()
+            } else {
+                None
+            };
+            // Keep a copy of the request without the etag, so we can fall back to it if
+            // the cached body has gone missing by the time we get a 304.
+            match (etag, req.try_clone()) {
+                (Some(etag), Some(unconditional)) => {
+                    let response = req.header(http::header::IF_NONE_MATCH, etag).send().await?;
+                    if response.status() == http::StatusCode::NOT_MODIFIED {
+                        match instance.http_cache.lookup_body(&uri2).await {
+                            Ok(body) => (response, Some(body)),
+                            Err(e) => {
+                                log::info!("failed to look up cached body, refetching: {}", e);
+                                (unconditional.send().await?, None)
+                            }
+                        }
+                    } else {
+                        (response, None)
+                    }
+                }
+                _ => (req.send().await?, None),
+            }
+        };
 
         #[cfg(not(feature = "httpcache"))]
         let (remaining, reset) = crate::utils::get_header_values(response.headers());
@@ -231,12 +258,12 @@ impl Client {
             {
                 if let Some(etag) = etag {
                     let next_link = link.as_ref().and_then(|l| crate::utils::next_link(l));
-                    if let Err(e) = instance2.http_cache.cache_response(
-                        &uri3,
+                    if let Err(e) = instance.http_cache.cache_response(
+                        &uri2,
                         &response_body,
                         &etag,
                         &next_link,
-                    ) {
+                    ).await {
                         // failing to cache isn't fatal, so just log & swallow the error
                         log::info!("failed to cache body & etag: {}", e);
                     }
@@ -254,11 +281,11 @@ impl Client {
                 // header when cargo builds with --cfg feature="httpcache"
                 #[cfg(feature = "httpcache")]
                 {
-                    let body = instance2.http_cache.lookup_body(&uri3).unwrap();
-                    let out = serde_json::from_str::<Out>(&body).unwrap();
+                    let body = cached_body.ok_or_else(|| anyhow!("code: {}, no cached body for {}", status, uri2))?;
+                    let out = serde_json::from_str::<Out>(&body)?;
                     let link = match link {
                         Some(link) => Ok(Some(link)),
-                        None => instance2.http_cache.lookup_next_link(&uri3)
+                        None => instance.http_cache.lookup_next_link(&uri2).await
                                     .map(|next_link| next_link.map(|next| {
                                         let next = hyperx::header::LinkValue::new(next).push_rel(hyperx::header::RelationType::Next);
                                         hyperx::header::Link::new(vec![next])
@@ -326,6 +353,40 @@ impl Client {
         ).await
     }
 
+    /// Fetch the raw bytes of a resource with the `application/vnd.github.raw` media type.
+    async fn get_raw(&self, uri: &str) -> Result<Vec<u8>> {
+        let (url, auth) = self
+            .url_and_auth(
+                &(self.host.clone() + uri),
+                crate::auth::AuthenticationConstraint::Unconstrained,
+            )
+            .await?;
+
+        let mut req = self.client.get(url);
+        req = req.header(http::header::USER_AGENT, &*self.agent);
+        req = req.header(http::header::ACCEPT, "application/vnd.github.raw");
+        if let Some(auth_str) = auth {
+            req = req.header(http::header::AUTHORIZATION, &*auth_str);
+        }
+        log::debug!("request: {:?}", &req);
+
+        let response = req.send().await?;
+        let status = response.status();
+        let response_body = response.bytes().await?;
+
+        if status.is_success() {
+            Ok(response_body.to_vec())
+        } else if response_body.is_empty() {
+            Err(anyhow!("code: {}, empty response", status))
+        } else {
+            Err(anyhow!(
+                "code: {}, error: {:?}",
+                status,
+                String::from_utf8_lossy(&response_body),
+            ))
+        }
+    }
+
     async fn get_all_pages<D>(&self, uri: &str,  _message: Option<reqwest::Body>) -> Result<Vec<D>>
     where
         D: serde::de::DeserializeOwned + 'static + Send,
@@ -466,6 +527,11 @@ impl Client {
         }
 
         Ok(global_items)
+    }
+
+    /// Query the GitHub GraphQL v4 API with the same credentials and host.
+    pub fn graphql(&self) -> graphql::GraphQL {
+        graphql::GraphQL::new(self.clone())
     }"#;
 
 pub fn generate_client_generic_token(
@@ -513,6 +579,10 @@ pub fn generate_client_generic_token(
         "".to_string()
     };
 
+    let (http_cache_struct, http_cache_fn, http_cache_with) = http_cache_client_parts(proper_name);
+    let (account_credentials_struct, account_credentials_fn) =
+        account_credentials_client_parts(proper_name);
+
     format!(
         r#"use std::env;
 
@@ -533,6 +603,8 @@ pub struct Client {{
     {}
 
     client: reqwest::Client,
+    {}
+    {}
 }}
 
 {}
@@ -575,6 +647,8 @@ impl Client {{
                     {}
 
                     client: c,
+                    {}
+                    {}
                 }}
             }}
             Err(e) => panic!("creating reqwest client failed: {{:?}}", e),
@@ -593,6 +667,8 @@ impl Client {{
 
     {}
 
+    {}
+
 
 
     {}
@@ -601,11 +677,16 @@ impl Client {{
         token_endpoint.trim_start_matches("https://"),
         user_consent_endpoint.trim_start_matches("https://"),
         add_post_header_struct,
+        http_cache_struct,
+        account_credentials_struct,
         ACCESS_TOKEN_STRUCT_TEMPLATE,
         add_post_header_type,
         add_post_header_args,
         add_post_header_args_where,
         add_post_header_fn,
+        http_cache_fn,
+        account_credentials_fn,
+        http_cache_with,
         new_from_env,
         TOKEN_AUTH_TEMPLATE,
         get_shared_functions(proper_name, add_post_header)
@@ -718,6 +799,8 @@ where
                 refresh_token: refresh_token.to_string(),
 
                 client: c,
+                #[cfg(feature = "httpcache")]
+                http_cache: <dyn crate::http_cache::AsyncHttpCache>::noop(),
             }
         },
         Err(e) => panic!("creating reqwest client failed: {:?}", e),
@@ -726,6 +809,8 @@ where
 "#;
 
 pub fn generate_client_generic_api_key(proper_name: &str, add_post_header: &str) -> String {
+    let (http_cache_struct, http_cache_fn, http_cache_with) = http_cache_client_parts(proper_name);
+
     format!(
         r#"use std::env;
 
@@ -736,6 +821,7 @@ pub struct Client {{
     token: String,
 
     client: reqwest::Client,
+    {}
 }}
 
 impl Client {{
@@ -756,6 +842,7 @@ impl Client {{
                     token: token.to_string(),
 
                     client: c,
+                    {}
                 }}
             }}
             Err(e) => panic!("creating reqwest client failed: {{:?}}", e),
@@ -772,6 +859,8 @@ impl Client {{
         c
      }}
 
+    {}
+
     /// Create a new Client struct from environment variables. It
     /// takes a type that can convert into
     /// an &str (`String` or `Vec<u8>` for example). As long as the function is
@@ -788,6 +877,9 @@ impl Client {{
     }}
 
     {}"#,
+        http_cache_struct,
+        http_cache_fn,
+        http_cache_with,
         proper_name.to_uppercase().replace('.', ""),
         proper_name.to_uppercase().replace('.', ""),
         get_shared_functions(proper_name, add_post_header)
@@ -816,6 +908,57 @@ fn get_shared_functions(proper_name: &str, add_post_header: &str) -> String {
         "Bearer".to_string()
     };
 
+    // Google clients return error responses as a `utils::HttpError`, so sync can
+    // tell an expired sync token apart from other errors. They also take `http://`
+    // hosts, so sync can be tested against a local server.
+    let (absolute_uri, error_response) = if http_error_supported(proper_name) {
+        (
+            r#"uri.starts_with("https://") || uri.starts_with("http://")"#,
+            "anyhow::Error::from(crate::utils::HttpError::new(status, &response_body))",
+        )
+    } else {
+        (r#"uri.starts_with("https://")"#, ERROR_RESPONSE)
+    };
+
+    // Clients with an HTTP cache build their requests separately from sending them,
+    // so GET requests can be made conditional on the cached etag.
+    let (request_raw_signature, request_raw_return, request_body) =
+        if http_cache_supported(proper_name) {
+            (
+                BUILD_REQUEST_SIGNATURE,
+                "Ok(req)",
+                HTTP_CACHE_REQUEST_BODY_TEMPLATE
+                    .replace(r#"uri.starts_with("https://")"#, absolute_uri),
+            )
+        } else {
+            (
+                REQUEST_RAW_SIGNATURE,
+                "Ok(req.send().await?)",
+                String::new(),
+            )
+        };
+    let (request_prelude, request_with_links_prelude) = if http_cache_supported(proper_name) {
+        (
+            "let (status, _, response_body) = self.request_body(method, uri, body).await?;",
+            "let (status, link, response_body) = self.request_body(method, uri, body).await?;",
+        )
+    } else {
+        (REQUEST_PRELUDE, REQUEST_WITH_LINKS_PRELUDE)
+    };
+
+    // Clients with credentials get their tokens themselves, and renew them before
+    // they expire.
+    let token = match renewing_credentials(proper_name) {
+        Some((field, _, _)) => format!(
+            r#"match &self.{} {{
+        Some(credentials) => credentials.token(&self.client).await?,
+        None => self.token.to_string(),
+    }}"#,
+            field
+        ),
+        None => "self.token".to_string(),
+    };
+
     format!(
         r#"
 async fn url_and_auth(
@@ -824,18 +967,13 @@ async fn url_and_auth(
 ) -> Result<(reqwest::Url, Option<String>)> {{
     let parsed_url = uri.parse::<reqwest::Url>();
 
-    let auth = format!("{} {{}}", self.token);
+    let auth = format!("{} {{}}", {});
     parsed_url.map(|u| (u, Some(auth))).map_err(Error::from)
 }}
 
-async fn request_raw(
-    &self,
-    method: reqwest::Method,
-    uri: &str,
-    body: Option<reqwest::Body>,
-) -> Result<reqwest::Response>
+{}
 {{
-    let u = if uri.starts_with("https://") {{
+    let u = if {absolute_uri} {{
         uri.to_string()
     }} else {{
         (self.host.clone() + uri).to_string()
@@ -866,8 +1004,9 @@ async fn request_raw(
         req = req.body(body);
     }}
     log::debug!("request: {{:?}}", &req);
-    Ok(req.send().await?)
+    {}
 }}
+{}
 
 async fn request<Out>(
     &self,
@@ -878,11 +1017,7 @@ async fn request<Out>(
     where
     Out: serde::de::DeserializeOwned + 'static + Send,
 {{
-    let response = self.request_raw(method, uri, body).await?;
-
-    let status = response.status();
-
-    let response_body = response.bytes().await?;
+    {}
 
     if status.is_success() {{
         log::debug!("response payload {{}}", String::from_utf8_lossy(&response_body));
@@ -893,15 +1028,7 @@ async fn request<Out>(
         }};
         parsed_response.map_err(Error::from)
     }} else {{
-        let error = if response_body.is_empty() {{
-            anyhow!("code: {{}}, empty response", status)
-        }} else {{
-            anyhow!(
-                "code: {{}}, error: {{:?}}",
-                status,
-                String::from_utf8_lossy(&response_body),
-            )
-        }};
+        let error = {error_response};
 
         Err(error)
     }}
@@ -916,16 +1043,7 @@ async fn request_with_links<Out>(
 where
     Out: serde::de::DeserializeOwned + 'static + Send,
 {{
-    let response = self.request_raw(method, uri, body).await?;
-
-    let status = response.status();
-    let link = response
-        .headers()
-        .get(http::header::LINK)
-        .and_then(|l| l.to_str().ok())
-        .and_then(|l| l.parse().ok());
-
-    let response_body = response.bytes().await?;
+    {}
 
     if status.is_success() {{
         log::debug!("response payload {{}}", String::from_utf8_lossy(&response_body));
@@ -937,15 +1055,7 @@ where
         }};
         parsed_response.map(|out| (link, out)).map_err(Error::from)
     }} else {{
-        let error = if response_body.is_empty() {{
-            anyhow!("code: {{}}, empty response", status)
-        }} else {{
-            anyhow!(
-                "code: {{}}, error: {{:?}}",
-                status,
-                String::from_utf8_lossy(&response_body),
-            )
-        }};
+        let error = {error_response};
         Err(error)
     }}
 }}
@@ -960,7 +1070,7 @@ async fn post_form<Out>(
     where
     Out: serde::de::DeserializeOwned + 'static + Send,
 {{
-    let u = if uri.starts_with("https://") {{
+    let u = if {absolute_uri} {{
         uri.to_string()
     }} else {{
         (self.host.clone() + uri).to_string()
@@ -1007,15 +1117,7 @@ async fn post_form<Out>(
         }};
         parsed_response.map_err(Error::from)
     }} else {{
-        let error = if response_body.is_empty() {{
-            anyhow!("code: {{}}, empty response", status)
-        }} else {{
-            anyhow!(
-                "code: {{}}, error: {{:?}}",
-                status,
-                String::from_utf8_lossy(&response_body),
-            )
-        }};
+        let error = {error_response};
 
         Err(error)
     }}
@@ -1032,7 +1134,7 @@ async fn request_with_accept_mime<Out>(
     where
     Out: serde::de::DeserializeOwned + 'static + Send,
 {{
-    let u = if uri.starts_with("https://") {{
+    let u = if {absolute_uri} {{
         uri.to_string()
     }} else {{
         (self.host.clone() + uri).to_string()
@@ -1072,15 +1174,7 @@ async fn request_with_accept_mime<Out>(
         }};
         parsed_response.map_err(Error::from)
     }} else {{
-        let error = if response_body.is_empty() {{
-            anyhow!("code: {{}}, empty response", status)
-        }} else {{
-            anyhow!(
-                "code: {{}}, error: {{:?}}",
-                status,
-                String::from_utf8_lossy(&response_body),
-            )
-        }};
+        let error = {error_response};
 
         Err(error)
     }}
@@ -1098,7 +1192,7 @@ async fn request_with_mime<Out>(
     where
     Out: serde::de::DeserializeOwned + 'static + Send,
 {{
-    let u = if uri.starts_with("https://") {{
+    let u = if {absolute_uri} {{
         uri.to_string()
     }} else {{
         (self.host.clone() + uri).to_string()
@@ -1154,15 +1248,7 @@ async fn request_with_mime<Out>(
         }};
         parsed_response.map_err(Error::from)
     }} else {{
-        let error = if response_body.is_empty() {{
-            anyhow!("code: {{}}, empty response", status)
-        }} else {{
-            anyhow!(
-                "code: {{}}, error: {{:?}}",
-                status,
-                String::from_utf8_lossy(&response_body),
-            )
-        }};
+        let error = {error_response};
 
         Err(error)
     }}
@@ -1300,11 +1386,341 @@ where
         &(self.host.to_string() + uri),
         message,
     ).await
-}}"#,
-        bearer, post_header_args
+}}{}"#,
+        bearer,
+        token,
+        request_raw_signature,
+        post_header_args,
+        request_raw_return,
+        request_body,
+        request_prelude,
+        request_with_links_prelude,
+        if binary_responses_supported(proper_name) {
+            BINARY_RESPONSE_FUNCTIONS
+        } else {
+            ""
+        },
+        absolute_uri = absolute_uri,
+        error_response = error_response,
+    )
+}
+
+/// Returns if the API returns error responses as a `utils::HttpError`, which
+/// carries their status.
+pub fn http_error_supported(proper_name: &str) -> bool {
+    proper_name.starts_with("Google")
+}
+
+const ERROR_RESPONSE: &str = r#"if response_body.is_empty() {
+        anyhow!("code: {}, empty response", status)
+    } else {
+        anyhow!(
+            "code: {}, error: {:?}",
+            status,
+            String::from_utf8_lossy(&response_body),
+        )
+    }"#;
+
+/// Returns if the API has endpoints that return files, like PDFs and images, that
+/// should be returned as bytes rather than decoded as JSON.
+pub fn binary_responses_supported(proper_name: &str) -> bool {
+    proper_name == "DocuSign"
+}
+
+const BINARY_RESPONSE_FUNCTIONS: &str = r#"
+
+/// Send a request and return the body of a successful response as bytes, for
+/// endpoints that return files rather than JSON.
+#[allow(dead_code)]
+async fn request_bytes(
+    &self,
+    method: reqwest::Method,
+    uri: &str,
+    body: Option<reqwest::Body>,
+) -> Result<bytes::Bytes> {
+    let response = self.request_stream(method, uri, body).await?;
+    Ok(response.bytes().await?)
+}
+
+/// Send a request and return a successful response without reading its body, so
+/// large files can be read in chunks with `reqwest::Response::chunk`.
+#[allow(dead_code)]
+async fn request_stream(
+    &self,
+    method: reqwest::Method,
+    uri: &str,
+    body: Option<reqwest::Body>,
+) -> Result<reqwest::Response> {
+    let u = if uri.starts_with("https://") {
+        uri.to_string()
+    } else {
+        (self.host.clone() + uri).to_string()
+    };
+    let (url, auth) = self.url_and_auth(&u).await?;
+
+    let instance = <&Client>::clone(&self);
+
+    let mut req = instance.client.request(method, url);
+
+    // Accept whatever type of file the endpoint returns.
+    req = req.header(
+        reqwest::header::ACCEPT,
+        reqwest::header::HeaderValue::from_static("*/*"),
+    );
+
+    if let Some(auth_str) = auth {
+        req = req.header(http::header::AUTHORIZATION, &*auth_str);
+    }
+
+    if let Some(body) = body {
+        req = req.body(body);
+    }
+
+    log::debug!("request: {:?}", &req);
+    let response = req.send().await?;
+
+    let status = response.status();
+    if status.is_success() {
+        return Ok(response);
+    }
+
+    let response_body = response.bytes().await?;
+    let error = if response_body.is_empty() {
+        anyhow!("code: {}, empty response", status)
+    } else {
+        anyhow!(
+            "code: {}, error: {:?}",
+            status,
+            String::from_utf8_lossy(&response_body),
+        )
+    };
+
+    Err(error)
+}
+
+#[allow(dead_code)]
+async fn get_bytes(&self, uri: &str, message: Option<reqwest::Body>) -> Result<bytes::Bytes> {
+    self.request_bytes(
+        http::Method::GET,
+        &(self.host.to_string() + uri),
+        message,
+    ).await
+}
+
+#[allow(dead_code)]
+async fn get_stream(&self, uri: &str, message: Option<reqwest::Body>) -> Result<reqwest::Response> {
+    self.request_stream(
+        http::Method::GET,
+        &(self.host.to_string() + uri),
+        message,
+    ).await
+}"#;
+
+/// The struct field, constructor field and builder method for the HTTP cache of a
+/// client, if the API supports it.
+fn http_cache_client_parts(proper_name: &str) -> (&'static str, &'static str, &'static str) {
+    if !http_cache_supported(proper_name) {
+        return ("", "", "");
+    }
+
+    (
+        r#"#[cfg(feature = "httpcache")]
+    http_cache: crate::http_cache::BoxedAsyncHttpCache,"#,
+        r#"#[cfg(feature = "httpcache")]
+                    http_cache: <dyn crate::http_cache::AsyncHttpCache>::noop(),"#,
+        r#"/// Use a cache for conditional requests with the etags returned by the API.
+    #[cfg(feature = "httpcache")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "httpcache")))]
+    pub fn with_http_cache(&self, http_cache: crate::http_cache::BoxedAsyncHttpCache) -> Self {
+        let mut c = self.clone();
+        c.http_cache = http_cache;
+        c
+    }"#,
     )
 }
 
+/// Returns the client field, type and comment for the credentials of an API
+/// whose clients can get and renew their own tokens: the account credentials of
+/// Zoom Server-to-Server OAuth apps, or the DocuSign JWT grant.
+pub fn renewing_credentials(
+    proper_name: &str,
+) -> Option<(&'static str, &'static str, &'static str)> {
+    match proper_name {
+        "Zoom" => Some((
+            "account_credentials",
+            "crate::server_to_server::AccountCredentials",
+            "Set for Server-to-Server OAuth apps, which get and renew their own tokens.",
+        )),
+        "DocuSign" => Some((
+            "jwt_credentials",
+            "crate::auth::JwtCredentials",
+            "Set for the JWT grant, which gets and renews its own tokens.",
+        )),
+        _ => None,
+    }
+}
+
+/// The struct field and constructor field for the credentials of a client, if
+/// the API supports them.
+fn account_credentials_client_parts(proper_name: &str) -> (String, String) {
+    match renewing_credentials(proper_name) {
+        Some((field, ty, comment)) => (
+            format!(
+                "// {}\n    {}: Option<std::sync::Arc<{}>>,",
+                comment, field, ty
+            ),
+            format!("{}: None,", field),
+        ),
+        None => (String::new(), String::new()),
+    }
+}
+
+/// Returns if the API returns `ETag`s we can use for conditional requests with the
+/// `httpcache` feature.
+pub fn http_cache_supported(proper_name: &str) -> bool {
+    proper_name.starts_with("Google") || proper_name == "Okta" || proper_name == "Shopify"
+}
+
+const REQUEST_RAW_SIGNATURE: &str = r#"async fn request_raw(
+    &self,
+    method: reqwest::Method,
+    uri: &str,
+    body: Option<reqwest::Body>,
+) -> Result<reqwest::Response>"#;
+
+const BUILD_REQUEST_SIGNATURE: &str = r#"async fn build_request(
+    &self,
+    method: reqwest::Method,
+    uri: &str,
+    body: Option<reqwest::Body>,
+) -> Result<reqwest::RequestBuilder>"#;
+
+const REQUEST_PRELUDE: &str = r#"let response = self.request_raw(method, uri, body).await?;
+
+    let status = response.status();
+
+    let response_body = response.bytes().await?;"#;
+
+const REQUEST_WITH_LINKS_PRELUDE: &str = r#"let response = self.request_raw(method, uri, body).await?;
+
+    let status = response.status();
+    let link = response
+        .headers()
+        .get(http::header::LINK)
+        .and_then(|l| l.to_str().ok())
+        .and_then(|l| l.parse().ok());
+
+    let response_body = response.bytes().await?;"#;
+
+const HTTP_CACHE_REQUEST_BODY_TEMPLATE: &str = r#"
+async fn request_raw(
+    &self,
+    method: reqwest::Method,
+    uri: &str,
+    body: Option<reqwest::Body>,
+) -> Result<reqwest::Response>
+{
+    let req = self.build_request(method, uri, body).await?;
+    Ok(req.send().await?)
+}
+
+/// Send a request and return the status, link header and body of the response.
+///
+/// With the `httpcache` feature, GET requests are made conditional on the etag of
+/// the cached response, if there is one, and the cached body is returned if the
+/// response has not been modified.
+async fn request_body(
+    &self,
+    method: reqwest::Method,
+    uri: &str,
+    body: Option<reqwest::Body>,
+) -> Result<(reqwest::StatusCode, Option<hyperx::header::Link>, bytes::Bytes)>
+{
+    #[cfg(not(feature = "httpcache"))]
+    let response = self.request_raw(method, uri, body).await?;
+
+    #[cfg(feature = "httpcache")]
+    let u = if uri.starts_with("https://") {
+        uri.to_string()
+    } else {
+        (self.host.clone() + uri).to_string()
+    };
+    #[cfg(feature = "httpcache")]
+    let etag = if method == reqwest::Method::GET {
+        self.http_cache.lookup_etag(&u).await.ok()
+    } else {
+        None
+    };
+    #[cfg(feature = "httpcache")]
+    let response = match etag {
+        Some(etag) => {
+            let req = self.build_request(method, uri, body).await?;
+            // Keep a copy of the request without the etag, so we can fall back to it if
+            // the cached body has gone missing by the time we get a 304.
+            let unconditional = req.try_clone();
+            let response = req.header(http::header::IF_NONE_MATCH, etag).send().await?;
+            if response.status() != http::StatusCode::NOT_MODIFIED {
+                response
+            } else {
+                match self.http_cache.lookup_body(&u).await {
+                    Ok(body) => {
+                        let link = self
+                            .http_cache
+                            .lookup_next_link(&u)
+                            .await
+                            .unwrap_or_default()
+                            .map(|next| {
+                                let next = hyperx::header::LinkValue::new(next)
+                                    .push_rel(hyperx::header::RelationType::Next);
+                                hyperx::header::Link::new(vec![next])
+                            });
+                        return Ok((http::StatusCode::OK, link, body.into()));
+                    }
+                    Err(e) => match unconditional {
+                        Some(req) => {
+                            log::info!("failed to look up cached body, refetching: {}", e);
+                            req.send().await?
+                        }
+                        None => return Err(e),
+                    },
+                }
+            }
+        }
+        None => self.request_raw(method, uri, body).await?,
+    };
+
+    let status = response.status();
+    let link = response
+        .headers()
+        .get(http::header::LINK)
+        .and_then(|l| l.to_str().ok())
+        .and_then(|l| l.parse().ok());
+    #[cfg(feature = "httpcache")]
+    let etag = response
+        .headers()
+        .get(http::header::ETAG)
+        .map(|etag| etag.as_bytes().to_vec());
+
+    let response_body = response.bytes().await?;
+
+    #[cfg(feature = "httpcache")]
+    if status.is_success() {
+        if let Some(etag) = etag {
+            let next_link = link.as_ref().and_then(|l| crate::utils::next_link(l));
+            if let Err(e) = self
+                .http_cache
+                .cache_response(&u, &response_body, &etag, &next_link)
+                .await
+            {
+                // failing to cache isn't fatal, so just log & swallow the error
+                log::info!("failed to cache body & etag: {}", e);
+            }
+        }
+    }
+
+    Ok((status, link, response_body))
+}"#;
+
 const TOKEN_AUTH_TEMPLATE: &str = r#"
 /// Return a user consent url with an optional set of scopes.
 /// If no scopes are provided, they will not be passed in the url.
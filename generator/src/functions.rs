@@ -81,7 +81,8 @@ pub fn generate_files(
                                 response_type: &str,
                                 template: &str,
                                 fn_inner: &str,
-                                fn_name: &str| {
+                                fn_name: &str,
+                                batch: bool| {
                 // Print the function docs.
                 a(docs);
 
@@ -91,12 +92,18 @@ pub fn generate_files(
                     a("#[async_recursion::async_recursion]");
                 }
 
-                if bounds.is_empty() {
+                if batch {
+                    // Adding a call to a batch doesn't send anything.
+                    a(&format!("pub fn {}(", fn_name,));
+                } else if bounds.is_empty() {
                     a(&format!("pub async fn {}(", fn_name,));
                 } else {
                     a(&format!("pub async fn {}<{}>(", fn_name, bounds.join(", ")));
                 }
                 a("&self,");
+                if batch {
+                    a("batch: &mut crate::batch::Batch,");
+                }
 
                 if !fn_params_str.is_empty() {
                     a(&fn_params_str.join(" "));
@@ -106,7 +113,14 @@ pub fn generate_files(
                     a(&format!("body: {}", bp));
                 }
 
-                a(&format!(") -> Result<{}> {{", response_type));
+                if batch {
+                    a(&format!(
+                        ") -> Result<crate::batch::BatchCall<{}>> {{",
+                        response_type
+                    ));
+                } else {
+                    a(&format!(") -> Result<{}> {{", response_type));
+                }
 
                 a(template);
 
@@ -226,7 +240,17 @@ pub fn generate_files(
             let (mut response_type, tid, inner_response_type, pagination_property) =
                 get_response_type(&od, ts, o)?;
 
-            if proper_name == "GitHub" && response_type == "crate::types::Data" {
+            // Responses with the shape of another type are aliases of it, and are
+            // handled like that type.
+            let aliased_tid = ts.alias_target(&tid).unwrap_or_else(|| tid.clone());
+            let aliased_type = if aliased_tid == tid {
+                response_type.to_string()
+            } else {
+                ts.render_type(&aliased_tid, false)?
+            };
+            let alias_type = response_type.to_string();
+
+            if proper_name == "GitHub" && aliased_type == "crate::types::Data" {
                 response_type = "()".to_string();
             }
             // We shouldn't ever have an optional response type, thats just annoying.
@@ -248,6 +272,16 @@ pub fn generate_files(
                 false,
             )?;
 
+            // Files like PDFs and images are returned as bytes, instead of being decoded
+            // as JSON.
+            let binary = crate::client::binary_responses_supported(proper_name)
+                && http::Method::GET == m
+                && is_binary_response(o);
+            if binary {
+                fn_inner = "self.client.get_bytes(&url, None).await".to_string();
+                response_type = "bytes::Bytes".to_string();
+            }
+
             // TODO: don't special case this.
             if p == "/jobs/{id}/transcript" || p == "/jobs/{id}/captions" {
                 fn_inner =
@@ -256,7 +290,7 @@ pub fn generate_files(
                 response_type = "String".to_string();
             }
 
-            if let Some(te) = ts.id_to_entry.get(&tid) {
+            if let Some(te) = ts.id_to_entry.get(&aliased_tid) {
                 // If we have a one of, we can generate a few different subfunctions to
                 // help as well.
                 if let crate::TypeDetails::OneOf(one_of, _) = &te.details {
@@ -275,6 +309,7 @@ pub fn generate_files(
                                 oid.trim_start_matches(&tag).trim_start_matches('_'),
                                 to_snake_case(&rt.replace("crate::types::", ""))
                             ))),
+                            false,
                         );
                     }
                 }
@@ -288,14 +323,21 @@ pub fn generate_files(
                 frt = inner_response_type.to_string();
             }
 
+            // The function is named after the type it returns, not its alias.
+            let fn_rt = if frt == alias_type {
+                aliased_type.to_string()
+            } else {
+                frt.to_string()
+            };
+
             let mut fn_name = oid
                 .trim_start_matches(&tag)
                 .trim_start_matches('_')
                 .to_string();
             if proper_name != "GitHub"
-                && !frt.starts_with("Vec<")
-                && !frt.ends_with("Response")
-                && !frt.ends_with("Summary")
+                && !fn_rt.starts_with("Vec<")
+                && !fn_rt.ends_with("Response")
+                && !fn_rt.ends_with("Summary")
                 && http::Method::GET == m
                 && !fn_name.ends_with("address")
                 && !fn_name.ends_with("has")
@@ -332,8 +374,57 @@ pub fn generate_files(
                 &template,
                 &fn_inner,
                 &fn_name,
+                false,
             );
 
+            // Calls to Google APIs with a batch endpoint can also be sent in a batch.
+            if let Some(batch_inner) = get_batch_fn_inner(proper_name, m, &body_func, &fn_inner) {
+                let batch_fn_name = format!("{}_batch", fn_name);
+                fn_names.push(batch_fn_name.clone() + &tag);
+                print_fn(
+                    &docs.replacen(
+                        "* This function performs",
+                        &format!(
+                            "* Like `{}`, but adds the call to a `Batch` to be sent with others, instead of sending it.\n*\n* This function performs",
+                            fn_name
+                        ),
+                        1,
+                    ),
+                    &bounds,
+                    &fn_params_str,
+                    &body_param,
+                    &frt,
+                    &template,
+                    &batch_inner,
+                    &batch_fn_name,
+                    true,
+                );
+            }
+
+            // Files can also be streamed, rather than read into memory.
+            if binary {
+                let stream_fn_name = format!("{}_stream", fn_name);
+                fn_names.push(stream_fn_name.clone() + &tag);
+                print_fn(
+                    &docs.replacen(
+                        "* This function performs",
+                        &format!(
+                            "* Like `{}`, but returns the response once its status is checked, so the file can be read in chunks with `reqwest::Response::chunk`.\n*\n* This function performs",
+                            fn_name
+                        ),
+                        1,
+                    ),
+                    &bounds,
+                    &fn_params_str,
+                    &body_param,
+                    "reqwest::Response",
+                    &template,
+                    "self.client.get_stream(&url, None).await",
+                    &stream_fn_name,
+                    false,
+                );
+            }
+
             // If we are returning a list of things and we have page, etc as
             // params, let's get all the pages.
             if frt.starts_with("Vec<") && http::Method::GET == m {
@@ -409,6 +500,7 @@ pub fn generate_files(
                     &template,
                     &fn_inner,
                     &fn_name,
+                    false,
                 );
             }
 
@@ -446,7 +538,7 @@ fn get_response_type_from_object(
     let mut tid = TypeId(0);
 
     if let Some(s) = s {
-        tid = ts.select(Some(&clean_name(&object_name)), s, "")?;
+        tid = ts.select_response(&clean_name(&object_name), s)?;
 
         if let openapiv3::ReferenceOr::Reference { reference } = s {
             tid = ts.select_ref(Some(&clean_name(&object_name)), reference)?;
@@ -468,6 +560,11 @@ fn get_response_type_from_object(
         et = ts.id_to_entry.get(id).unwrap();
     }
 
+    // Response types with the shape of another type are aliases of it.
+    if let crate::TypeDetails::Alias(id, _) = &et.details {
+        et = ts.id_to_entry.get(id).unwrap();
+    }
+
     if let crate::TypeDetails::Object(p, _) = &et.details {
         // For Ramp, the pagination values are passed _in_ the resulting
         // struct, so we want to ignore them and just get the data.
@@ -530,6 +627,31 @@ fn get_response_type_from_object(
     Ok((og_rt, tid, "".to_string(), "".to_string()))
 }
 
+/// Returns if the successful response of an operation is a file, like a PDF or an
+/// image, rather than JSON.
+fn is_binary_response(o: &openapiv3::Operation) -> bool {
+    use openapiv3::{SchemaKind, StringFormat, Type, VariantOrUnknownOrEmpty::Item};
+
+    let first = match o.responses.responses.first() {
+        Some((_, r)) => r,
+        None => return false,
+    };
+    let i = match first.item() {
+        Ok(i) => i,
+        Err(_) => return false,
+    };
+    if i.content.contains_key("application/json") {
+        return false;
+    }
+
+    i.content.values().any(|mt| {
+        matches!(
+            mt.schema.as_ref().and_then(|s| s.item().ok()).map(|s| &s.schema_kind),
+            Some(SchemaKind::Type(Type::String(st))) if matches!(st.format, Item(StringFormat::Binary))
+        )
+    })
+}
+
 fn get_response_type(
     od: &str,
     ts: &mut TypeSpace,
@@ -601,7 +723,7 @@ fn get_response_type(
 
             if let Some(s) = &mt.schema {
                 let object_name = format!("{} response", oid_to_object_name(od));
-                let tid = ts.select(Some(&clean_name(&object_name)), s, "")?;
+                let tid = ts.select_response(&clean_name(&object_name), s)?;
                 let rt = ts.render_type(&tid, false)?;
                 return Ok((rt, tid, "".to_string(), "".to_string()));
             }
@@ -1004,6 +1126,36 @@ fn get_fn_inner(
         .to_string())
 }
 
+/// The inner of the function that adds an operation to a batch instead of
+/// sending it, for APIs with a batch endpoint. Only operations that send a JSON
+/// body, or none, and return their whole response are batched.
+fn get_batch_fn_inner(
+    proper_name: &str,
+    m: &str,
+    body_func: &Option<String>,
+    fn_inner: &str,
+) -> Option<String> {
+    crate::batch::google_batch_path(proper_name)?;
+
+    let (body, batch_body) = match body_func.as_deref() {
+        None => ("None", "None"),
+        Some("json") => (
+            "Some(reqwest::Body::from(serde_json::to_vec(body)?))",
+            "Some(serde_json::to_string(body)?)",
+        ),
+        Some(_) => return None,
+    };
+    if fn_inner != format!("self.client.{}(&url, {}).await", m.to_lowercase(), body) {
+        return None;
+    }
+
+    Some(format!(
+        "Ok(batch.add(reqwest::Method::{}, &url, {}))",
+        m.to_uppercase(),
+        batch_body
+    ))
+}
+
 fn get_fn_docs(
     o: &openapiv3::Operation,
     m: &str,
@@ -1,5 +1,9 @@
+mod batch;
 mod client;
 mod functions;
+mod http_cache;
+mod long_running;
+mod notifications;
 mod template;
 mod types;
 mod utils;
@@ -525,6 +529,12 @@ pub enum TypeDetails {
     Unknown,
     Basic(String, openapiv3::SchemaData),
     NamedType(TypeId, openapiv3::SchemaData),
+    /*
+     * A response type named after its operation (or component) that has the
+     * same shape as a type named elsewhere. It is rendered as a `pub type` so
+     * the name of the response doesn't depend on which schema was seen first.
+     */
+    Alias(TypeId, openapiv3::SchemaData),
     Enum(Vec<String>, openapiv3::SchemaData),
     Array(TypeId, openapiv3::SchemaData),
     Optional(TypeId, openapiv3::SchemaData),
@@ -582,10 +592,18 @@ impl TypeDetails {
         false
     }
 
+    pub fn is_alias(&self) -> bool {
+        if let TypeDetails::Alias(..) = self {
+            return true;
+        }
+        false
+    }
+
     pub fn description(&self) -> String {
         let desc = match self {
             TypeDetails::Basic(_, d) => d.description.as_ref(),
             TypeDetails::NamedType(_, d) => d.description.as_ref(),
+            TypeDetails::Alias(_, d) => d.description.as_ref(),
             TypeDetails::Enum(_, d) => d.description.as_ref(),
             TypeDetails::Array(_, d) => d.description.as_ref(),
             TypeDetails::Optional(_, d) => d.description.as_ref(),
@@ -625,6 +643,11 @@ impl PartialEq for TypeDetails {
                     return i == oi;
                 }
             }
+            TypeDetails::Alias(i, _d) => {
+                if let TypeDetails::Alias(oi, _od) = other {
+                    return i == oi;
+                }
+            }
             TypeDetails::Enum(s, _d) => {
                 if let TypeDetails::Enum(os, _od) = other {
                     return s == os;
@@ -697,6 +720,17 @@ impl PartialEq for TypeId {
     }
 }
 
+/*
+ * Fields whose spec lists the values they take one at a time, but that take a
+ * comma separated list of them, so they are strings rather than enums, by API,
+ * type and field. Zoom's `Recurrence.weekly_days` is "1,3,5" for Sundays,
+ * Tuesdays and Thursdays.
+ */
+const COMMA_SEPARATED_FIELDS: &[(&str, &str, &str)] = &[
+    ("Zoom", "recurrence", "weekly_days"),
+    ("Zoom", "recurrence_webinar", "weekly_days"),
+];
+
 #[derive(Debug, Clone)]
 pub struct TypeSpace {
     next_id: u64,
@@ -708,14 +742,27 @@ pub struct TypeSpace {
      */
     name_to_id: BTreeMap<String, TypeId>,
     id_to_entry: BTreeMap<TypeId, TypeEntry>,
+    /*
+     * Responses that were selected under their own name, by the name they
+     * would be aliased as. A type with the same shape and a shorter name can
+     * rename them later on, so they are checked again in
+     * `alias_renamed_responses` before the types are generated.
+     */
+    response_names: BTreeMap<String, (String, TypeId)>,
+    /*
+     * The proper name of the API, for the workarounds that only apply to one.
+     */
+    proper_name: String,
 }
 
 impl TypeSpace {
-    fn new() -> TypeSpace {
+    fn new(proper_name: &str) -> TypeSpace {
         TypeSpace {
             next_id: 1,
             name_to_id: BTreeMap::new(),
             id_to_entry: BTreeMap::new(),
+            response_names: BTreeMap::new(),
+            proper_name: proper_name.to_string(),
         }
     }
 
@@ -739,6 +786,13 @@ impl TypeSpace {
                      */
                     format!("named type of {}", self.describe(itid))
                 }
+                TypeDetails::Alias(itid, _) => {
+                    if let Some(n) = &te.name {
+                        format!("alias {} of {}", n, self.describe(itid))
+                    } else {
+                        format!("[ALIAS {} !NONAME?]", tid.0)
+                    }
+                }
                 TypeDetails::Enum(..) => {
                     if let Some(n) = &te.name {
                         format!("enum {}", n)
@@ -824,6 +878,7 @@ impl TypeSpace {
                         Some(schema_data)
                     }
                 }
+                TypeDetails::Alias(id, _) => self.get_schema_data_for_id(id),
                 TypeDetails::Enum(_, schema_data) => Some(schema_data),
                 TypeDetails::Array(_, schema_data) => Some(schema_data),
                 TypeDetails::Optional(id, schema_data) => {
@@ -876,6 +931,18 @@ impl TypeSpace {
             match &te.details {
                 TypeDetails::Basic(t, _) => Ok(t.to_string()),
                 TypeDetails::NamedType(itid, _) => self.render_type(itid, in_mod),
+                TypeDetails::Alias(..) => {
+                    if let Some(n) = &te.name {
+                        let struct_name = struct_name(n);
+                        if in_mod {
+                            Ok(struct_name)
+                        } else {
+                            Ok(format!("crate::types::{}", struct_name))
+                        }
+                    } else {
+                        bail!("alias type {:?} does not have a name?", tid);
+                    }
+                }
                 TypeDetails::Enum(..) => {
                     if let Some(n) = &te.name {
                         let struct_name = struct_name(n);
@@ -1131,6 +1198,7 @@ impl TypeSpace {
                                 if (nt.details.is_enum()
                                     || nt.details.is_object()
                                     || nt.details.is_named_type()
+                                    || nt.details.is_alias()
                                     || nt.details.is_one_of()
                                     || details.is_any_of()
                                     || details.is_all_of())
@@ -1361,6 +1429,103 @@ impl TypeSpace {
         }
     }
 
+    /**
+     * Select the type of a response body, named after its operation or
+     * component.
+     *
+     * Types with the same shape are merged under whichever name was seen
+     * first, so the merged type might be named after some other operation, and
+     * that name changes whenever the spec does. If that happens, the response
+     * gets its own name as an alias of the merged type. Responses with a title
+     * are named after it instead, and only aliased if it was merged away too.
+     */
+    fn select_response(
+        &mut self,
+        name: &str,
+        s: &openapiv3::ReferenceOr<openapiv3::Schema>,
+    ) -> Result<TypeId> {
+        let tid = self.select(Some(name), s, "")?;
+        if let openapiv3::ReferenceOr::Reference { .. } = s {
+            // References are named after the schema they point to.
+            return Ok(tid);
+        }
+
+        let te = self.id_to_entry.get(&tid).unwrap();
+        if !(te.details.is_object()
+            || te.details.is_enum()
+            || te.details.is_one_of()
+            || te.details.is_any_of()
+            || te.details.is_all_of())
+        {
+            // Vectors and basic types aren't named.
+            return Ok(tid);
+        }
+
+        let own_name = match s {
+            openapiv3::ReferenceOr::Item(i) => match i.schema_data.title.as_deref() {
+                Some(t) if !t.is_empty() => t,
+                _ => name,
+            },
+            openapiv3::ReferenceOr::Reference { .. } => name,
+        };
+        if let Some(n) = &te.name {
+            if struct_name(n) == struct_name(own_name) {
+                self.response_names
+                    .insert(name.to_string(), (own_name.to_string(), tid.clone()));
+                return Ok(tid);
+            }
+        }
+
+        self.add_alias(name, tid)
+    }
+
+    /**
+     * Alias the responses that were selected under their own name, but have
+     * since been renamed after a type with the same shape.
+     */
+    fn alias_renamed_responses(&mut self) -> Result<()> {
+        for (name, (own_name, tid)) in self.response_names.clone() {
+            let te = self.id_to_entry.get(&tid).unwrap();
+            if let Some(n) = &te.name {
+                if struct_name(n) == struct_name(&own_name) {
+                    continue;
+                }
+            }
+
+            self.add_alias(&name, tid)?;
+        }
+
+        Ok(())
+    }
+
+    fn add_alias(&mut self, name: &str, tid: TypeId) -> Result<TypeId> {
+        if self.name_to_id.get(name) == Some(&tid) {
+            // The merged type was renamed from this name, which still points
+            // at it. The alias takes the name over.
+            self.name_to_id.remove(name);
+        }
+
+        let details = TypeDetails::Alias(tid, Default::default());
+        self.add_if_not_exists(Some(name.to_string()), details, "", true)
+    }
+
+    /**
+     * The type that a response alias stands for, through a reference to the
+     * alias, or `None` if the type isn't an alias.
+     */
+    fn alias_target(&self, tid: &TypeId) -> Option<TypeId> {
+        let mut te = self.id_to_entry.get(tid)?;
+        if let TypeDetails::NamedType(itid, _) = &te.details {
+            te = self.id_to_entry.get(itid)?;
+        }
+
+        if let TypeDetails::Alias(itid, _) = &te.details {
+            return Some(itid.clone());
+        }
+
+        None
+    }
+
     fn select_box(
         &mut self,
         name: Option<&str>,
@@ -1596,8 +1761,18 @@ impl TypeSpace {
                         VariantOrUnknownOrEmpty::{Empty, Item, Unknown},
                     };
 
+                    let comma_separated = name
+                        .map(|n| {
+                            COMMA_SEPARATED_FIELDS.contains(&(
+                                self.proper_name.as_str(),
+                                to_snake_case(parent_name).as_str(),
+                                n,
+                            ))
+                        })
+                        .unwrap_or(false);
+
                     // We have an enumeration.
-                    if !st.enumeration.is_empty() {
+                    if !st.enumeration.is_empty() && !comma_separated {
                         // Enum types must have a consistent name.
                         let name = clean_name(match (name, s.schema_data.title.as_deref()) {
                             (Some(n), None) => n,
@@ -2277,11 +2452,81 @@ fn gen(
     a("");
     if proper_name == "GitHub" {
         a("pub mod auth;");
+        a("/// Report linter and test results as check runs.");
+        a("pub mod check_run_reporter;");
+        a("/// Query the GitHub GraphQL v4 API.");
+        a("pub mod graphql;");
+        a(r#"#[cfg(feature = "httpcache")]"#);
+        a(r#"#[cfg_attr(docsrs, doc(cfg(feature = "httpcache")))]"#);
+        a("pub mod http_cache;");
+    }
+    if crate::batch::google_batch_path(proper_name).is_some() {
+        a("/// Send many API calls in one HTTP request.");
+        a("pub mod batch;");
+    }
+    if crate::notifications::google_push_notifications_supported(proper_name) {
+        a("/// Manage push notification channels.");
+        a("pub mod notifications;");
+    }
+    if crate::long_running::google_long_running_supported(proper_name, api, &tags) {
+        a("/// Wait for long-running operations to finish.");
+        a("pub mod long_running;");
+    }
+    if crate::client::http_cache_supported(proper_name) {
         a(r#"#[cfg(feature = "httpcache")]"#);
         a(r#"#[cfg_attr(docsrs, doc(cfg(feature = "httpcache")))]"#);
         a("pub mod http_cache;");
     }
-    if proper_name == "Google Drive"
+    if proper_name == "Google Admin" {
+        a("/// Reconcile the directory with a desired state of users and groups.");
+        a("pub mod directory_sync;");
+    }
+    if proper_name == "Google Calendar" {
+        a("/// Parse, serialize and expand recurrence rules.");
+        a("pub mod recurrence;");
+        a("/// Find free meeting slots from free/busy information.");
+        a("pub mod scheduling;");
+    }
+    if proper_name == "Google Sheets" {
+        a("/// Build spreadsheet batch updates and map their replies.");
+        a("pub mod batch_update;");
+        a("/// Typed A1 and R1C1 ranges.");
+        a("pub mod range;");
+        a("/// Map the rows of a table to and from structs.");
+        a("pub mod table;");
+    }
+    if proper_name == "Google Drive" {
+        a("/// Build search queries for files, with their values escaped.");
+        a("pub mod query;");
+    }
+    if proper_name == "DocuSign" {
+        a("/// Authenticate as a user with the JWT grant, and find the base URI of their account.");
+        a("pub mod auth;");
+        a("/// Verify and parse the events Connect sends to webhooks.");
+        a("pub mod connect;");
+        a("/// Download envelope documents, including the combined, archive and certificate documents.");
+        a("pub mod downloads;");
+        a("/// Compose envelopes from documents, recipients and tabs.");
+        a("pub mod envelope_builder;");
+    }
+    if proper_name == "Zoom" {
+        a("/// Archive cloud recordings, resuming downloads that were interrupted.");
+        a("pub mod recording_downloader;");
+        a("/// Export reports over any range of dates, one row at a time.");
+        a("pub mod report_export;");
+        a("/// Schedule meetings with typed recurrence, settings and time zones.");
+        a("pub mod scheduling;");
+        a("/// Authenticate with the account credentials of a Server-to-Server OAuth app.");
+        a("pub mod server_to_server;");
+        a("/// Verify and parse the webhooks Zoom sends to apps.");
+        a("pub mod webhooks;");
+    }
+    if proper_name == "Google Calendar" || proper_name == "Google Drive" {
+        a("/// Incrementally sync changes with page and sync tokens.");
+        a("pub mod sync;");
+    }
+    if proper_name == "GitHub"
+        || proper_name == "Google Drive"
         || proper_name == "Google Sheets"
         || proper_name == "SendGrid"
         || proper_name == "Rev.ai"
@@ -2827,7 +3072,8 @@ fn main() -> Result<()> {
     /*
      * Grab all the types defined by schemas and parameters.
      */
-    let mut ts = TypeSpace::new();
+    let proper_name = args.opt_str("proper-name").unwrap();
+    let mut ts = TypeSpace::new(&proper_name);
     let mut parameters: BTreeMap<String, &openapiv3::Parameter> = BTreeMap::new();
 
     if let Some(components) = &api.components {
@@ -2944,7 +3190,7 @@ fn main() -> Result<()> {
                         }
 
                         let object_name = format!("{} response", name);
-                        let id = ts.select(Some(&clean_name(&object_name)), s, "")?;
+                        let id = ts.select_response(&clean_name(&object_name), s)?;
 
                         // Insert the named type for our reference.
                         // DO NOT CLEAN THE NAME HERE.
@@ -2967,7 +3213,6 @@ fn main() -> Result<()> {
      * In addition to types defined in schemas, types may be defined inline in
      * request and response bodies.
      */
-    let proper_name = args.opt_str("proper-name").unwrap();
     let mut tags: Vec<String> = Default::default();
     for (pn, p) in api.paths.iter() {
         let op = p.item()?;
@@ -3066,7 +3311,7 @@ fn main() -> Result<()> {
                  * Get the response body type for each status code:
                  */
                 let mut res: Vec<String> = Default::default();
-                for (_, r) in o.responses.responses.iter() {
+                for (i, (_, r)) in o.responses.responses.iter().enumerate() {
                     match r {
                         openapiv3::ReferenceOr::Item(ri) => {
                             for (ct, mt) in &ri.content {
@@ -3091,8 +3336,13 @@ fn main() -> Result<()> {
                                         // Otherwise we can parse the object.
                                         let object_name =
                                             format!("{} response", oid_to_object_name(&od));
-                                        let id =
-                                            ts.select(Some(&clean_name(&object_name)), s, "")?;
+                                        // Only the first response is returned by the
+                                        // function, so only it is named after it.
+                                        let id = if i == 0 {
+                                            ts.select_response(&clean_name(&object_name), s)?
+                                        } else {
+                                            ts.select(Some(&clean_name(&object_name)), s, "")?
+                                        };
                                         let rt = ts.render_type(&id, false)?;
                                         res.push(format!("{} {:?}", rt, id));
                                     }
@@ -3156,6 +3406,9 @@ fn main() -> Result<()> {
     } else {
         String::new()
     };
+    let long_running =
+        crate::long_running::google_long_running_supported(&proper_name, &api, &tags);
+
     let fail = match gen(
         &api,
         &proper_name,
@@ -3179,7 +3432,15 @@ fn main() -> Result<()> {
              */
             let mut uuid_lib = "".to_string();
             let mut yup_oauth2_lib = "".to_string();
-            if proper_name != "GitHub" {
+            if proper_name == "GitHub" {
+                uuid_lib = r#"
+async-trait = "^0.1.51"
+base64 = "^0.12"
+futures = "0.3"
+graphql_client = { version = "0.13", optional = true }
+tokio = { version = "1.8.0", features = ["sync", "time"] }"#
+                    .to_string();
+            } else {
                 uuid_lib = r#"
 bytes = { version = "1", features = ["serde"] }
 async-trait = "^0.1.51"
@@ -3188,6 +3449,46 @@ uuid = { version = "^0.8", features = ["serde", "v4"] }"#
                     .to_string();
             }
 
+            if long_running {
+                uuid_lib.push_str(
+                    r#"
+tokio = { version = "1.8.0", features = ["time"] }"#,
+                );
+            }
+
+            if proper_name == "Google Admin" {
+                uuid_lib.push_str(
+                    r#"
+futures = "0.3""#,
+                );
+            }
+
+            if proper_name == "DocuSign" {
+                uuid_lib.push_str(
+                    r#"
+base64 = "^0.12"
+quick-xml = "0.22"
+ring = "0.16""#,
+                );
+            }
+
+            if proper_name == "Zoom" {
+                uuid_lib.push_str(
+                    r#"
+chrono-tz = "0.6"
+csv = "1"
+ring = "0.16"
+tokio = { version = "1.8.0", features = ["fs", "io-util"] }"#,
+                );
+            }
+
+            if proper_name == "Google Calendar" {
+                uuid_lib.push_str(
+                    r#"
+chrono-tz = "0.6""#,
+                );
+            }
+
             if proper_name.starts_with("Google") {
                 yup_oauth2_lib = r#"
 base64 = "^0.12"
@@ -3321,9 +3622,50 @@ rustdoc-args = ["--cfg", "docsrs"]
             utilsrs.push("utils.rs");
             save(utilsrs, utils.as_str())?;
 
+            /*
+             * Create the Rust batch module for the Google clients:
+             */
+            if let Some(batch) = crate::batch::generate_batch(&proper_name, &name) {
+                let mut batchrs = src.clone();
+                batchrs.push("batch.rs");
+                save(batchrs, batch.as_str())?;
+            }
+
+            /*
+             * Create the Rust notifications module for the Google clients with push notifications:
+             */
+            if crate::notifications::google_push_notifications_supported(&proper_name) {
+                let notifications =
+                    crate::notifications::generate_notifications(&proper_name, &name);
+                let mut notificationsrs = src.clone();
+                notificationsrs.push("notifications.rs");
+                save(notificationsrs, notifications.as_str())?;
+            }
+
+            /*
+             * Create the Rust long_running module for the Google clients with operations:
+             */
+            if long_running {
+                let long_running = crate::long_running::generate_long_running(&name, &api);
+                let mut long_runningrs = src.clone();
+                long_runningrs.push("long_running.rs");
+                save(long_runningrs, long_running.as_str())?;
+            }
+
+            /*
+             * Create the Rust http_cache module for the clients with the httpcache feature:
+             */
+            if proper_name == "GitHub" || crate::client::http_cache_supported(&proper_name) {
+                let http_cache = crate::http_cache::generate_http_cache(&proper_name, &name);
+                let mut http_cachers = src.clone();
+                http_cachers.push("http_cache.rs");
+                save(http_cachers, http_cache.as_str())?;
+            }
+
             /*
              * Create the Rust source types file containing the generated types:
              */
+            ts.alias_renamed_responses()?;
             let types = types::generate_types(&mut ts, &proper_name)?;
             let mut typesrs = src.clone();
             typesrs.push("types.rs");
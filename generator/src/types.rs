@@ -71,6 +71,18 @@ pub fn generate_types(ts: &mut TypeSpace, proper_name: &str) -> Result<String> {
                         || sn == "PagesHttpsCertificate"
                         || sn == "ErrorDetails"
                         || sn == "EnvelopeDefinition"
+                        || sn == "EnvelopeRecipients"
+                        || sn == "EnvelopeRecipientTabs"
+                        || sn == "Document"
+                        || sn == "Signer"
+                        || sn == "CarbonCopy"
+                        || sn == "InPersonSigner"
+                        || sn == "SignHere"
+                        || sn == "InitialHere"
+                        || sn == "DateSigned"
+                        || sn == "FullName"
+                        || sn == "Text"
+                        || sn == "Checkbox"
                         || sn == "Event"
                         || sn == "User"
                         || sn == "Group"
@@ -146,6 +158,7 @@ pub fn generate_types(ts: &mut TypeSpace, proper_name: &str) -> Result<String> {
                                 || rt.starts_with("Vec<")
                                 || rt.starts_with("Option<")
                                 || rt.starts_with("HashMap<")
+                                || rt.starts_with("std::collections::HashMap<")
                             {
                                 a(r#"#[serde(default,"#);
                                 if rt == "String" {
@@ -271,6 +284,11 @@ pub fn generate_types(ts: &mut TypeSpace, proper_name: &str) -> Result<String> {
                 TypeDetails::Basic(..) => {}
                 TypeDetails::Unknown => {}
                 TypeDetails::NamedType(..) => {}
+                TypeDetails::Alias(itid, _) => {
+                    let rt = ts.render_type(itid, true)?;
+                    a(&format!("pub type {} = {};", sn, rt));
+                    a("");
+                }
                 TypeDetails::Array(..) => {}
                 TypeDetails::Optional(..) => {}
             }
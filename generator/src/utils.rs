@@ -726,11 +726,89 @@ impl From<MediaType> for mime::Mime {
 
 "#;
 
+const ZOOM_TEMPLATE: &str = r#"/// The longest range of dates the API lists recordings and reports for.
+const MAX_WINDOW_DAYS: i64 = 30;
+
+/// Split the range of dates from `from` to `to`, inclusive, into ranges the API
+/// lists recordings and reports for.
+pub fn date_windows(
+    from: chrono::NaiveDate,
+    to: chrono::NaiveDate,
+) -> Vec<(chrono::NaiveDate, chrono::NaiveDate)> {
+    let mut windows = Vec::new();
+    let mut start = from;
+    while start <= to {
+        let end = std::cmp::min(start + chrono::Duration::days(MAX_WINDOW_DAYS - 1), to);
+        windows.push((start, end));
+        start = end + chrono::Duration::days(1);
+    }
+    windows
+}
+
+/// The meeting UUID to put in a path. The path isn't encoded for `/`, so UUIDs
+/// with one are encoded here, and those that start with `/` or contain `//` have
+/// to be encoded twice.
+pub fn meeting_path_id(uuid: &str) -> String {
+    if uuid.starts_with('/') || uuid.contains("//") {
+        urlencoding::encode(&urlencoding::encode(uuid)).to_string()
+    } else if uuid.contains('/') {
+        urlencoding::encode(uuid).to_string()
+    } else {
+        uuid.to_string()
+    }
+}
+"#;
+
+const HTTP_ERROR_TEMPLATE: &str = r#"/// An error response from the API.
+///
+/// Requests return it as their `anyhow::Error`, so the status of a failed request
+/// can be matched with `HttpError::status_of`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct HttpError {
+    pub status: http::StatusCode,
+    pub body: String,
+}
+
+impl HttpError {
+    pub fn new(status: http::StatusCode, body: &[u8]) -> Self {
+        HttpError {
+            status,
+            body: String::from_utf8_lossy(body).to_string(),
+        }
+    }
+
+    /// The status of the response an error is for, if it is an error response.
+    pub fn status_of(e: &anyhow::Error) -> Option<http::StatusCode> {
+        e.downcast_ref::<HttpError>().map(|e| e.status)
+    }
+}
+
+impl fmt::Display for HttpError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.body.is_empty() {
+            write!(f, "code: {}, empty response", self.status)
+        } else {
+            write!(f, "code: {}, error: {:?}", self.status, self.body)
+        }
+    }
+}
+
+impl std::error::Error for HttpError {}
+"#;
+
 pub fn generate_utils(proper_name: &str) -> String {
     let mut optional = String::new();
     if proper_name == "GitHub" {
         optional = GITHUB_TEMPLATE.to_string();
+    } else if proper_name == "Zoom" {
+        optional = ZOOM_TEMPLATE.to_string();
     }
 
-    format!("{}\n{}", optional, TEMPLATE)
+    let http_error = if crate::client::http_error_supported(proper_name) {
+        HTTP_ERROR_TEMPLATE
+    } else {
+        ""
+    };
+
+    format!("{}\n{}\n{}", optional, TEMPLATE, http_error)
 }
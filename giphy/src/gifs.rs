@@ -85,7 +85,7 @@ impl Gifs {
         offset: i64,
         rating: &str,
         lang: &str,
-    ) -> Result<crate::types::GetGifsByResponse> {
+    ) -> Result<crate::types::SearchGifsResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !lang.is_empty() {
             query_args.push(("lang".to_string(), lang.to_string()));
@@ -120,7 +120,7 @@ impl Gifs {
      *
      * * `s: &str` -- The unique bit.ly URL for this GIF.
      */
-    pub async fn translate(&self, s: &str) -> Result<crate::types::RandomGifResponse> {
+    pub async fn translate(&self, s: &str) -> Result<crate::types::TranslateGifResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !s.is_empty() {
             query_args.push(("s".to_string(), s.to_string()));
@@ -150,7 +150,7 @@ impl Gifs {
         limit: i64,
         offset: i64,
         rating: &str,
-    ) -> Result<crate::types::GetGifsByResponse> {
+    ) -> Result<crate::types::TrendingGifsResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if limit > 0 {
             query_args.push(("limit".to_string(), limit.to_string()));
@@ -179,7 +179,7 @@ impl Gifs {
      *
      * * `gif_id: i64` -- Filters results by specified GIF ID.
      */
-    pub async fn get_gifs(&self, gif_id: i64) -> Result<crate::types::RandomGifResponse> {
+    pub async fn get_gifs(&self, gif_id: i64) -> Result<crate::types::GetGifByResponse> {
         let url = format!(
             "/gifs/{}",
             crate::progenitor_support::encode_path(&gif_id.to_string()),
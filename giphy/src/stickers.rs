@@ -25,7 +25,11 @@ impl Stickers {
      * * `tag: &str` -- The unique bit.ly URL for this GIF.
      * * `rating: &str` -- The unique bit.ly URL for this GIF.
      */
-    pub async fn random(&self, tag: &str, rating: &str) -> Result<crate::types::RandomGifResponse> {
+    pub async fn random(
+        &self,
+        tag: &str,
+        rating: &str,
+    ) -> Result<crate::types::RandomStickerResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !rating.is_empty() {
             query_args.push(("rating".to_string(), rating.to_string()));
@@ -62,7 +66,7 @@ impl Stickers {
         offset: i64,
         rating: &str,
         lang: &str,
-    ) -> Result<crate::types::GetGifsByResponse> {
+    ) -> Result<crate::types::SearchStickersResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !lang.is_empty() {
             query_args.push(("lang".to_string(), lang.to_string()));
@@ -97,7 +101,7 @@ impl Stickers {
      *
      * * `s: &str` -- The unique bit.ly URL for this GIF.
      */
-    pub async fn translate(&self, s: &str) -> Result<crate::types::RandomGifResponse> {
+    pub async fn translate(&self, s: &str) -> Result<crate::types::TranslateStickerResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !s.is_empty() {
             query_args.push(("s".to_string(), s.to_string()));
@@ -127,7 +131,7 @@ impl Stickers {
         limit: i64,
         offset: i64,
         rating: &str,
-    ) -> Result<crate::types::GetGifsByResponse> {
+    ) -> Result<crate::types::TrendingStickersResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if limit > 0 {
             query_args.push(("limit".to_string(), limit.to_string()));
@@ -572,3 +572,19 @@ pub struct RandomGifResponse {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub meta: Option<Meta>,
 }
+
+pub type SearchGifsResponse = GetGifsByResponse;
+
+pub type TranslateGifResponse = RandomGifResponse;
+
+pub type TrendingGifsResponse = GetGifsByResponse;
+
+pub type GetGifByResponse = RandomGifResponse;
+
+pub type RandomStickerResponse = RandomGifResponse;
+
+pub type SearchStickersResponse = GetGifsByResponse;
+
+pub type TranslateStickerResponse = RandomGifResponse;
+
+pub type TrendingStickersResponse = GetGifsByResponse;
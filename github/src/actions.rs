@@ -1671,7 +1671,7 @@ impl Actions {
         repo: &str,
         per_page: i64,
         page: i64,
-    ) -> Result<crate::types::ActionsListSelfHostedRunnersOrgResponse> {
+    ) -> Result<crate::types::ActionsListSelfHostedRunnersRepoResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if page > 0 {
             query_args.push(("page".to_string(), page.to_string()));
@@ -1923,7 +1923,7 @@ impl Actions {
         per_page: i64,
         page: i64,
         created: Option<chrono::DateTime<chrono::Utc>>,
-    ) -> Result<crate::types::ActionsListWorkflowRunsResponse> {
+    ) -> Result<crate::types::ActionsListWorkflowRunsRepoResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !actor.is_empty() {
             query_args.push(("actor".to_string(), actor.to_string()));
@@ -2126,7 +2126,7 @@ impl Actions {
         run_id: i64,
         per_page: i64,
         page: i64,
-    ) -> Result<crate::types::ActionsListArtifactsRepoResponse> {
+    ) -> Result<crate::types::ActionsListWorkflowRunArtifactsResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if page > 0 {
             query_args.push(("page".to_string(), page.to_string()));
@@ -2976,7 +2976,7 @@ impl Actions {
         environment_name: &str,
         per_page: i64,
         page: i64,
-    ) -> Result<crate::types::ActionsListRepoSecretsResponse> {
+    ) -> Result<crate::types::ActionsListEnvironmentSecretsResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if page > 0 {
             query_args.push(("page".to_string(), page.to_string()));
@@ -251,7 +251,7 @@ impl Activity {
     pub async fn mark_notifications_as_read(
         &self,
         body: &crate::types::ActivityMarkNotificationsAsReadRequest,
-    ) -> Result<crate::types::Error> {
+    ) -> Result<crate::types::ActivityMarkNotificationsAsReadResponse> {
         let url = "/notifications".to_string();
         self.client
             .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
@@ -631,7 +631,7 @@ impl Activity {
         owner: &str,
         repo: &str,
         body: &crate::types::ActivityMarkRepoNotificationsAsReadRequest,
-    ) -> Result<crate::types::PullsUpdateBranchResponse> {
+    ) -> Result<crate::types::ActivityMarkRepoNotificationsAsReadResponse> {
         let url = format!(
             "/repos/{}/{}/notifications",
             crate::progenitor_support::encode_path(&owner.to_string()),
@@ -667,7 +667,7 @@ impl Activity {
         repo: &str,
         per_page: i64,
         page: i64,
-    ) -> Result<crate::types::ActivityListStargazersRepoResponseAnyOf> {
+    ) -> Result<crate::types::ActivityListStargazersRepoResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if page > 0 {
             query_args.push(("page".to_string(), page.to_string()));
@@ -1376,7 +1376,7 @@ impl Activity {
         direction: crate::types::Order,
         per_page: i64,
         page: i64,
-    ) -> Result<crate::types::ActivityListReposStarredByUserResponseAnyOf> {
+    ) -> Result<crate::types::ActivityListReposStarredByUserResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !direction.to_string().is_empty() {
             query_args.push(("direction".to_string(), direction.to_string()));
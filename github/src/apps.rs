@@ -44,7 +44,7 @@ impl Apps {
     pub async fn create_from_manifest(
         &self,
         code: &str,
-    ) -> Result<crate::types::AppsCreateFromManifestResponseAllOf> {
+    ) -> Result<crate::types::AppsCreateFromManifestResponseData> {
         let url = format!(
             "/app-manifests/{}/conversions",
             crate::progenitor_support::encode_path(&code.to_string()),
@@ -719,7 +719,7 @@ impl Apps {
         &self,
         per_page: i64,
         page: i64,
-    ) -> Result<crate::types::AppsListInstallationReposResponse> {
+    ) -> Result<crate::types::AppsListReposAccessibleInstallationResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if page > 0 {
             query_args.push(("page".to_string(), page.to_string()));
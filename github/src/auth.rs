@@ -9,6 +9,9 @@ use anyhow::Result;
 use jsonwebtoken as jwt;
 use serde::Serialize;
 
+/// The GitHub App manifest flow and the OAuth web application and device flows.
+pub mod oauth;
+
 // We use 9 minutes for the life to give some buffer for clock drift between
 // our clock and GitHub's. The absolute max is 10 minutes.
 const MAX_JWT_TOKEN_LIFE: time::Duration = time::Duration::from_secs(60 * 9);
@@ -40,6 +43,9 @@ pub enum Credentials {
     /// JWT-based App Installation Token
     /// https://developer.github.com/apps/building-github-apps/authenticating-with-github-apps/
     InstallationToken(InstallationTokenGenerator),
+    /// Expiring user token, refreshed by the client just before it expires.
+    /// https://docs.github.com/en/developers/apps/building-github-apps/refreshing-user-to-server-access-tokens
+    UserToken(oauth::RefreshingUserToken),
 }
 
 impl fmt::Debug for Credentials {
@@ -64,6 +70,10 @@ impl fmt::Debug for Credentials {
                 .field("installation_id", &generator.installation_id)
                 .field("jwt_credential", &"***")
                 .finish(),
+            Credentials::UserToken(_) => f
+                .debug_tuple("Credentials::UserToken")
+                .field(&"***")
+                .finish(),
         }
     }
 }
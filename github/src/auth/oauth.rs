@@ -0,0 +1,468 @@
+//! Helpers for the GitHub App manifest flow and the OAuth web application and
+//! device flows.
+//!
+//! These endpoints live on the web host (`https://github.com`, or the root of a
+//! GitHub Enterprise Server instance) rather than the API host, so they use their
+//! own HTTP client instead of the API `Client`.
+//!
+//! ```no_run
+//! # async fn run() -> anyhow::Result<()> {
+//! use octorust::auth::oauth::OAuthApp;
+//!
+//! let app = OAuthApp::new("Iv1.0123456789abcdef");
+//! let code = app.request_device_code(&["repo".to_string()]).await?;
+//! println!("Enter {} at {}", code.user_code, code.verification_uri);
+//!
+//! let token = app.poll_device_token(&code).await?;
+//! let github = octorust::Client::new("my-cli", token.credentials())?;
+//!
+//! // Or, if the app has expiring user tokens, let the client refresh it.
+//! let github = octorust::Client::new("my-cli", token.refreshing_credentials(&app))?;
+//! # Ok(())
+//! # }
+//! ```
+use std::{collections::BTreeMap, sync::Arc, time};
+
+use anyhow::{anyhow, bail, Result};
+use serde::{Deserialize, Serialize};
+
+use crate::auth::Credentials;
+
+/// The web host for GitHub.com.
+pub const DEFAULT_WEB_HOST: &str = "https://github.com";
+
+/// The device flow grant type.
+const DEVICE_CODE_GRANT_TYPE: &str = "urn:ietf:params:oauth:grant-type:device_code";
+
+/// How much to increase the polling interval by when told to slow down.
+const SLOW_DOWN_INCREMENT: time::Duration = time::Duration::from_secs(5);
+
+/// An OAuth App or GitHub App, used to authorize users.
+#[derive(Debug, Clone)]
+pub struct OAuthApp {
+    client_id: String,
+    client_secret: String,
+    host: String,
+    http: reqwest::Client,
+}
+
+impl OAuthApp {
+    /// Create an app for the device flow, which only needs a client id.
+    pub fn new<I>(client_id: I) -> Self
+    where
+        I: ToString,
+    {
+        OAuthApp {
+            client_id: client_id.to_string(),
+            client_secret: String::new(),
+            host: DEFAULT_WEB_HOST.to_string(),
+            http: reqwest::Client::new(),
+        }
+    }
+
+    /// Set the client secret, which the web application flow and refreshing tokens
+    /// require.
+    pub fn with_client_secret<S>(mut self, client_secret: S) -> Self
+    where
+        S: ToString,
+    {
+        self.client_secret = client_secret.to_string();
+        self
+    }
+
+    /// Override the web host, e.g. for GitHub Enterprise Server.
+    pub fn with_host<H>(mut self, host: H) -> Self
+    where
+        H: ToString,
+    {
+        self.host = host.to_string().trim_end_matches('/').to_string();
+        self
+    }
+
+    /// Use a custom HTTP client.
+    pub fn with_http_client(mut self, http: reqwest::Client) -> Self {
+        self.http = http;
+        self
+    }
+
+    /// Return the URL to send a user to in the web application flow.
+    ///
+    /// GitHub redirects back to `redirect_uri` with a `code` to pass to
+    /// `exchange_code`, along with the `state`, which should be checked against the
+    /// value passed here.
+    pub fn authorize_url(&self, redirect_uri: &str, scopes: &[String], state: &str) -> String {
+        let mut query_args = vec![("client_id", self.client_id.as_str())];
+        if !redirect_uri.is_empty() {
+            query_args.push(("redirect_uri", redirect_uri));
+        }
+        let scope = scopes.join(" ");
+        if !scope.is_empty() {
+            query_args.push(("scope", &scope));
+        }
+        if !state.is_empty() {
+            query_args.push(("state", state));
+        }
+
+        format!(
+            "{}/login/oauth/authorize?{}",
+            self.host,
+            serde_urlencoded::to_string(&query_args).unwrap()
+        )
+    }
+
+    /// Exchange the `code` from the web application flow for a user token.
+    pub async fn exchange_code(&self, code: &str, redirect_uri: &str) -> Result<UserToken> {
+        let mut params = vec![
+            ("client_id", self.client_id.as_str()),
+            ("client_secret", self.client_secret.as_str()),
+            ("code", code),
+        ];
+        if !redirect_uri.is_empty() {
+            params.push(("redirect_uri", redirect_uri));
+        }
+
+        self.access_token(&params).await?.into_token()
+    }
+
+    /// Get a new user token with the refresh token of an expiring user token.
+    pub async fn refresh(&self, refresh_token: &str) -> Result<UserToken> {
+        let params = [
+            ("client_id", self.client_id.as_str()),
+            ("client_secret", self.client_secret.as_str()),
+            ("grant_type", "refresh_token"),
+            ("refresh_token", refresh_token),
+        ];
+
+        self.access_token(&params).await?.into_token()
+    }
+
+    /// Refresh `token` if it has expired, or will within a minute.
+    ///
+    /// Returns whether the token was refreshed.
+    pub async fn refresh_if_expired(&self, token: &mut UserToken) -> Result<bool> {
+        if !token.expires_within(chrono::Duration::minutes(1)) {
+            return Ok(false);
+        }
+
+        let refresh_token = token
+            .refresh_token
+            .as_deref()
+            .ok_or_else(|| anyhow!("token has expired and has no refresh token"))?;
+        *token = self.refresh(refresh_token).await?;
+
+        Ok(true)
+    }
+
+    /// Start the device flow.
+    ///
+    /// The user should be shown the `user_code` and asked to enter it at the
+    /// `verification_uri`, while `poll_device_token` waits for them to do so.
+    pub async fn request_device_code(&self, scopes: &[String]) -> Result<DeviceCode> {
+        let scope = scopes.join(" ");
+        let params = [("client_id", self.client_id.as_str()), ("scope", &scope)];
+
+        let body = self.post_form("/login/device/code", &params).await?;
+        match serde_json::from_slice::<DeviceCode>(&body) {
+            Ok(code) => Ok(code),
+            Err(_) => Err(serde_json::from_slice::<AccessTokenResponse>(&body)?
+                .into_token()
+                .err()
+                .unwrap_or_else(|| anyhow!("invalid device code response"))),
+        }
+    }
+
+    /// Poll for the user token once the user has entered the code from
+    /// `request_device_code`, respecting the polling interval and `slow_down`
+    /// responses.
+    pub async fn poll_device_token(&self, device_code: &DeviceCode) -> Result<UserToken> {
+        let params = [
+            ("client_id", self.client_id.as_str()),
+            ("device_code", device_code.device_code.as_str()),
+            ("grant_type", DEVICE_CODE_GRANT_TYPE),
+        ];
+
+        let deadline =
+            time::Instant::now() + time::Duration::from_secs(device_code.expires_in.max(0) as u64);
+        let mut interval = time::Duration::from_secs(device_code.interval.max(1) as u64);
+        loop {
+            tokio::time::sleep(interval).await;
+            if time::Instant::now() > deadline {
+                bail!("the device code has expired");
+            }
+
+            let response = self.access_token(&params).await?;
+            match response.error.as_str() {
+                "authorization_pending" => continue,
+                "slow_down" => {
+                    // GitHub sends the new minimum interval, otherwise add 5 seconds.
+                    interval = match response.interval {
+                        Some(i) if i > 0 => time::Duration::from_secs(i as u64),
+                        _ => interval + SLOW_DOWN_INCREMENT,
+                    };
+                }
+                _ => return response.into_token(),
+            }
+        }
+    }
+
+    async fn access_token(&self, params: &[(&str, &str)]) -> Result<AccessTokenResponse> {
+        let body = self.post_form("/login/oauth/access_token", params).await?;
+        Ok(serde_json::from_slice(&body)?)
+    }
+
+    async fn post_form(&self, path: &str, params: &[(&str, &str)]) -> Result<Vec<u8>> {
+        let response = self
+            .http
+            .post(format!("{}{}", self.host, path))
+            .header(reqwest::header::ACCEPT, "application/json")
+            .form(params)
+            .send()
+            .await?;
+
+        let status = response.status();
+        let body = response.bytes().await?;
+        if !status.is_success() {
+            bail!(
+                "code: {}, error: {:?}",
+                status,
+                String::from_utf8_lossy(&body)
+            );
+        }
+
+        Ok(body.to_vec())
+    }
+}
+
+/// The response to a device code request.
+#[derive(Debug, Clone, Deserialize)]
+pub struct DeviceCode {
+    pub device_code: String,
+    pub user_code: String,
+    pub verification_uri: String,
+    /// The number of seconds before the codes expire.
+    pub expires_in: i64,
+    /// The minimum number of seconds to wait between polling for the token.
+    pub interval: i64,
+}
+
+/// A user access token, which may expire and come with a refresh token if the app
+/// has expiring user tokens enabled.
+#[derive(Debug, Clone, PartialEq)]
+pub struct UserToken {
+    pub access_token: String,
+    pub token_type: String,
+    pub scope: String,
+    pub expires_at: Option<chrono::DateTime<chrono::Utc>>,
+    pub refresh_token: Option<String>,
+    pub refresh_token_expires_at: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+impl UserToken {
+    /// The credentials to create a `Client` with.
+    pub fn credentials(&self) -> Credentials {
+        Credentials::Token(self.access_token.clone())
+    }
+
+    /// The credentials to create a `Client` with that refreshes the token with
+    /// `app` before it expires.
+    pub fn refreshing_credentials(&self, app: &OAuthApp) -> Credentials {
+        Credentials::UserToken(RefreshingUserToken::new(app.clone(), self.clone()))
+    }
+
+    /// Returns if the token has expired, or will within `duration`.
+    pub fn expires_within(&self, duration: chrono::Duration) -> bool {
+        match self.expires_at {
+            Some(expires_at) => expires_at - duration <= chrono::Utc::now(),
+            None => false,
+        }
+    }
+}
+
+/// A user token that the `Client` refreshes with the app that issued it.
+///
+/// Clones share the token, so the one the client has refreshed it to can be read
+/// back with `user_token` and stored.
+#[derive(Debug, Clone)]
+pub struct RefreshingUserToken {
+    app: OAuthApp,
+    token: Arc<tokio::sync::Mutex<UserToken>>,
+}
+
+impl RefreshingUserToken {
+    pub fn new(app: OAuthApp, token: UserToken) -> Self {
+        RefreshingUserToken {
+            app,
+            token: Arc::new(tokio::sync::Mutex::new(token)),
+        }
+    }
+
+    /// The current user token.
+    pub async fn user_token(&self) -> UserToken {
+        self.token.lock().await.clone()
+    }
+
+    /// The access token to send, after refreshing the user token if it has expired
+    /// or will within a minute.
+    ///
+    /// The lock is held while refreshing, since a refresh token can only be used
+    /// once.
+    pub async fn access_token(&self) -> Result<String> {
+        let mut token = self.token.lock().await;
+        if self.app.refresh_if_expired(&mut token).await? {
+            log::debug!("user token expired, refreshed it");
+        }
+
+        Ok(token.access_token.clone())
+    }
+}
+
+impl PartialEq for RefreshingUserToken {
+    fn eq(&self, other: &RefreshingUserToken) -> bool {
+        Arc::ptr_eq(&self.token, &other.token)
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct AccessTokenResponse {
+    #[serde(default)]
+    access_token: String,
+    #[serde(default)]
+    token_type: String,
+    #[serde(default)]
+    scope: String,
+    #[serde(default)]
+    expires_in: Option<i64>,
+    #[serde(default)]
+    refresh_token: Option<String>,
+    #[serde(default)]
+    refresh_token_expires_in: Option<i64>,
+    #[serde(default)]
+    error: String,
+    #[serde(default)]
+    error_description: String,
+    #[serde(default)]
+    interval: Option<i64>,
+}
+
+impl AccessTokenResponse {
+    fn into_token(self) -> Result<UserToken> {
+        if !self.error.is_empty() {
+            bail!("{}: {}", self.error, self.error_description);
+        }
+        if self.access_token.is_empty() {
+            bail!("no access token in response");
+        }
+
+        let now = chrono::Utc::now();
+        Ok(UserToken {
+            access_token: self.access_token,
+            token_type: self.token_type,
+            scope: self.scope,
+            expires_at: self.expires_in.map(|s| now + chrono::Duration::seconds(s)),
+            refresh_token: self.refresh_token.filter(|t| !t.is_empty()),
+            refresh_token_expires_at: self
+                .refresh_token_expires_in
+                .map(|s| now + chrono::Duration::seconds(s)),
+        })
+    }
+}
+
+/// A GitHub App manifest.
+///
+/// The user is sent to GitHub with the manifest by submitting the form from
+/// `manifest_form_html`. Once they create the app, GitHub redirects to the
+/// `redirect_url` with a `code` to pass to `Apps::create_from_manifest`, which
+/// returns the app's id, private key and webhook secret.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct AppManifest {
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub name: String,
+    pub url: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hook_attributes: Option<HookAttributes>,
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub redirect_url: String,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub callback_urls: Vec<String>,
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub setup_url: String,
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub description: String,
+    pub public: bool,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub default_events: Vec<String>,
+    /// Permission names mapped to `read` or `write`.
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub default_permissions: BTreeMap<String, String>,
+    pub request_oauth_on_install: bool,
+    pub setup_on_update: bool,
+}
+
+/// The webhook of a GitHub App manifest.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct HookAttributes {
+    pub url: String,
+    pub active: bool,
+}
+
+/// Return the URL to post a manifest to, for an app owned by `org` or, if that is
+/// empty, by the user.
+pub fn manifest_url(host: &str, org: &str, state: &str) -> String {
+    let host = host.trim_end_matches('/');
+    let mut url = if org.is_empty() {
+        format!("{}/settings/apps/new", host)
+    } else {
+        format!(
+            "{}/organizations/{}/settings/apps/new",
+            host,
+            crate::progenitor_support::encode_path(org)
+        )
+    };
+    if !state.is_empty() {
+        url.push('?');
+        url.push_str(&serde_urlencoded::to_string([("state", state)]).unwrap());
+    }
+
+    url
+}
+
+/// Return an HTML page that submits the manifest to GitHub when it loads.
+pub fn manifest_form_html(
+    manifest: &AppManifest,
+    host: &str,
+    org: &str,
+    state: &str,
+) -> Result<String> {
+    let manifest = serde_json::to_string(manifest)?;
+
+    Ok(format!(
+        r#"<!DOCTYPE html>
+<html>
+<body onload="document.forms[0].submit()">
+<form action="{}" method="post">
+<input type="hidden" name="manifest" value="{}">
+<noscript><input type="submit" value="Create GitHub App"></noscript>
+</form>
+</body>
+</html>
+"#,
+        escape_html(&manifest_url(host, org, state)),
+        escape_html(&manifest)
+    ))
+}
+
+fn escape_html(s: &str) -> String {
+    let mut escaped = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            c => escaped.push(c),
+        }
+    }
+
+    escaped
+}
@@ -0,0 +1,356 @@
+//! Report the results of a linter or test run as a check run.
+//!
+//! The checks API only accepts 50 annotations per request, so the
+//! `CheckRunReporter` buffers annotations and sends them in batches as the
+//! check run progresses.
+//!
+//! ```no_run
+//! # async fn run(github: octorust::Client, sarif: &str) -> anyhow::Result<()> {
+//! use octorust::{
+//!     check_run_reporter::{sarif_annotations, CheckRunReporter},
+//!     types::ChecksCreateRequestConclusion,
+//! };
+//!
+//! let mut reporter =
+//!     CheckRunReporter::create(github.checks(), "owner", "repo", "clippy", "c0ffee").await?;
+//! reporter.extend(sarif_annotations(sarif)?).await?;
+//! reporter
+//!     .finish(ChecksCreateRequestConclusion::Success, "No problems found.")
+//!     .await?;
+//! # Ok(())
+//! # }
+//! ```
+use anyhow::Result;
+use futures::{pin_mut, Stream, StreamExt};
+use serde::Deserialize;
+
+use crate::{
+    checks::Checks,
+    types::{
+        AnnotationLevel, Annotations, CheckRun, ChecksCreateRequest, ChecksCreateRequestConclusion,
+        ChecksUpdateRequest, ChecksUpdateRequestOutput, JobStatus,
+    },
+};
+
+/// The maximum number of annotations the API accepts in a single request.
+pub const MAX_ANNOTATIONS_PER_REQUEST: usize = 50;
+
+/// Creates a check run, streams annotations to it in batches and completes it.
+pub struct CheckRunReporter {
+    checks: Checks,
+    owner: String,
+    repo: String,
+    check_run_id: i64,
+    title: String,
+    pending: Vec<Annotations>,
+    reported: usize,
+}
+
+impl CheckRunReporter {
+    /// Create an `in_progress` check run named `name` for the commit `head_sha`.
+    pub async fn create(
+        checks: Checks,
+        owner: &str,
+        repo: &str,
+        name: &str,
+        head_sha: &str,
+    ) -> Result<Self> {
+        let check_run = checks
+            .create(
+                owner,
+                repo,
+                &ChecksCreateRequest {
+                    actions: Default::default(),
+                    completed_at: None,
+                    conclusion: None,
+                    details_url: Default::default(),
+                    external_id: Default::default(),
+                    head_sha: head_sha.to_string(),
+                    name: name.to_string(),
+                    output: None,
+                    started_at: Some(chrono::Utc::now()),
+                    status: Some(JobStatus::InProgress),
+                },
+            )
+            .await?;
+
+        Ok(CheckRunReporter {
+            checks,
+            owner: owner.to_string(),
+            repo: repo.to_string(),
+            check_run_id: check_run.id,
+            title: name.to_string(),
+            pending: Vec::new(),
+            reported: 0,
+        })
+    }
+
+    /// The id of the check run being reported to.
+    pub fn check_run_id(&self) -> i64 {
+        self.check_run_id
+    }
+
+    /// Set the title of the check run output, which defaults to the name of the check run.
+    pub fn set_title(&mut self, title: &str) {
+        self.title = title.to_string();
+    }
+
+    /// Add an annotation, sending a batch to GitHub once enough are pending.
+    pub async fn annotate(&mut self, annotation: Annotations) -> Result<()> {
+        self.pending.push(annotation);
+        if self.pending.len() >= MAX_ANNOTATIONS_PER_REQUEST {
+            self.flush().await?;
+        }
+
+        Ok(())
+    }
+
+    /// Add every annotation from an iterator, sending them in batches as they are read.
+    pub async fn extend<I>(&mut self, annotations: I) -> Result<()>
+    where
+        I: IntoIterator<Item = Annotations>,
+    {
+        for annotation in annotations {
+            self.annotate(annotation).await?;
+        }
+
+        Ok(())
+    }
+
+    /// Add every annotation from a stream, sending them in batches as they arrive.
+    pub async fn extend_stream<S>(&mut self, annotations: S) -> Result<()>
+    where
+        S: Stream<Item = Annotations>,
+    {
+        pin_mut!(annotations);
+        while let Some(annotation) = annotations.next().await {
+            self.annotate(annotation).await?;
+        }
+
+        Ok(())
+    }
+
+    /// Send any pending annotations to GitHub.
+    ///
+    /// A batch stays pending until GitHub accepts it, so a failed flush can be retried.
+    pub async fn flush(&mut self) -> Result<()> {
+        while !self.pending.is_empty() {
+            self.send_batch().await?;
+        }
+
+        Ok(())
+    }
+
+    /// Send any pending annotations and complete the check run with a conclusion and
+    /// a markdown summary.
+    ///
+    /// If a request fails, the annotations that weren't sent stay pending and `finish`
+    /// can be called again.
+    pub async fn finish(
+        &mut self,
+        conclusion: ChecksCreateRequestConclusion,
+        summary: &str,
+    ) -> Result<CheckRun> {
+        // Everything but the last batch is sent while the run is still in progress, so
+        // the final update carries the conclusion along with the remaining annotations.
+        while self.pending.len() > MAX_ANNOTATIONS_PER_REQUEST {
+            self.send_batch().await?;
+        }
+
+        let check_run = self
+            .update(&self.pending, summary.to_string(), Some(conclusion))
+            .await?;
+        self.reported += self.pending.len();
+        self.pending.clear();
+        Ok(check_run)
+    }
+
+    /// Send the next batch of pending annotations, and only then drop it.
+    async fn send_batch(&mut self) -> Result<()> {
+        let n = self.pending.len().min(MAX_ANNOTATIONS_PER_REQUEST);
+        let summary = format!("{} annotations reported so far.", self.reported + n);
+        self.update(&self.pending[..n], summary, None).await?;
+        self.pending.drain(..n);
+        self.reported += n;
+
+        Ok(())
+    }
+
+    async fn update(
+        &self,
+        annotations: &[Annotations],
+        summary: String,
+        conclusion: Option<ChecksCreateRequestConclusion>,
+    ) -> Result<CheckRun> {
+        let completed = conclusion.is_some();
+        self.checks
+            .update(
+                &self.owner,
+                &self.repo,
+                self.check_run_id,
+                &ChecksUpdateRequest {
+                    actions: Default::default(),
+                    completed_at: if completed {
+                        Some(chrono::Utc::now())
+                    } else {
+                        None
+                    },
+                    conclusion,
+                    details_url: Default::default(),
+                    external_id: Default::default(),
+                    name: Default::default(),
+                    output: Some(ChecksUpdateRequestOutput {
+                        annotations: annotations.to_vec(),
+                        images: Default::default(),
+                        summary,
+                        text: Default::default(),
+                        title: self.title.clone(),
+                    }),
+                    started_at: None,
+                    status: Some(if completed {
+                        JobStatus::Completed
+                    } else {
+                        JobStatus::InProgress
+                    }),
+                },
+            )
+            .await
+    }
+}
+
+/// Create an annotation for the lines `start_line..=end_line` of `path`.
+pub fn annotation(
+    path: &str,
+    start_line: i64,
+    end_line: i64,
+    level: AnnotationLevel,
+    message: &str,
+) -> Annotations {
+    Annotations {
+        annotation_level: level,
+        end_column: 0,
+        end_line,
+        message: message.to_string(),
+        path: path.to_string(),
+        raw_details: Default::default(),
+        start_column: 0,
+        start_line,
+        title: Default::default(),
+    }
+}
+
+#[derive(Deserialize)]
+struct SarifLog {
+    #[serde(default)]
+    runs: Vec<SarifRun>,
+}
+
+#[derive(Deserialize)]
+struct SarifRun {
+    #[serde(default)]
+    results: Vec<SarifResult>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifResult {
+    #[serde(default)]
+    rule_id: String,
+    #[serde(default)]
+    level: Option<String>,
+    message: SarifMessage,
+    #[serde(default)]
+    locations: Vec<SarifLocation>,
+}
+
+#[derive(Deserialize)]
+struct SarifMessage {
+    #[serde(default)]
+    text: String,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifLocation {
+    physical_location: Option<SarifPhysicalLocation>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifPhysicalLocation {
+    artifact_location: SarifArtifactLocation,
+    #[serde(default)]
+    region: SarifRegion,
+}
+
+#[derive(Deserialize)]
+struct SarifArtifactLocation {
+    uri: String,
+}
+
+#[derive(Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifRegion {
+    #[serde(default)]
+    start_line: i64,
+    #[serde(default)]
+    end_line: i64,
+    #[serde(default)]
+    start_column: i64,
+    #[serde(default)]
+    end_column: i64,
+}
+
+/// Map the results of a SARIF 2.1.0 log into annotations.
+///
+/// Each result is annotated at its first physical location, and results without a
+/// location in a file are skipped. SARIF `error`s become `failure`s, `warning`s stay
+/// `warning`s and everything else becomes a `notice`.
+pub fn sarif_annotations(sarif: &str) -> Result<Vec<Annotations>> {
+    let log: SarifLog = serde_json::from_str(sarif)?;
+
+    let mut annotations = Vec::new();
+    for result in log.runs.into_iter().flat_map(|r| r.results) {
+        let location = match result
+            .locations
+            .into_iter()
+            .find_map(|l| l.physical_location)
+        {
+            Some(l) => l,
+            None => continue,
+        };
+
+        // The SARIF default level is `warning`.
+        let level = match result.level.as_deref().unwrap_or("warning") {
+            "error" => AnnotationLevel::Failure,
+            "warning" => AnnotationLevel::Warning,
+            _ => AnnotationLevel::Notice,
+        };
+
+        let region = location.region;
+        let start_line = region.start_line.max(1);
+        let end_line = region.end_line.max(start_line);
+
+        let mut annotation = annotation(
+            location
+                .artifact_location
+                .uri
+                .trim_start_matches("file://")
+                .trim_start_matches("./"),
+            start_line,
+            end_line,
+            level,
+            &result.message.text,
+        );
+        annotation.title = result.rule_id;
+        // The API only accepts columns for annotations on a single line.
+        if start_line == end_line && region.start_column > 0 {
+            annotation.start_column = region.start_column;
+            // SARIF end columns are exclusive, and the API's are inclusive.
+            annotation.end_column = (region.end_column - 1).max(region.start_column);
+        }
+        annotations.push(annotation);
+    }
+
+    Ok(annotations)
+}
@@ -318,7 +318,7 @@ impl Checks {
         filter: crate::types::ActionsListJobsWorkflowRunFilter,
         per_page: i64,
         page: i64,
-    ) -> Result<crate::types::ChecksListRefResponse> {
+    ) -> Result<crate::types::ChecksListSuiteResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !check_name.is_empty() {
             query_args.push(("check_name".to_string(), check_name.to_string()));
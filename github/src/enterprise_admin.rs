@@ -91,7 +91,8 @@ impl EnterpriseAdmin {
         enterprise: &str,
         per_page: i64,
         page: i64,
-    ) -> Result<crate::types::EnterpriseAdminListOrgAccessSelfHostedRunnerGroupInResponse> {
+    ) -> Result<crate::types::EnterpriseAdminListSelectedOrganizationsEnabledGithubActionsResponse>
+    {
         let mut query_args: Vec<(String, String)> = Default::default();
         if page > 0 {
             query_args.push(("page".to_string(), page.to_string()));
@@ -585,7 +586,7 @@ impl EnterpriseAdmin {
         runner_group_id: i64,
         per_page: i64,
         page: i64,
-    ) -> Result<crate::types::ActionsListSelfHostedRunnersInGroupOrgResponse> {
+    ) -> Result<crate::types::EnterpriseAdminListSelfHostedRunnersInGroupResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if page > 0 {
             query_args.push(("page".to_string(), page.to_string()));
@@ -0,0 +1,253 @@
+//! A client for the GitHub GraphQL v4 API.
+//!
+//! This shares the credentials, host and rate limit handling of the REST
+//! `Client`, so anything that can be called over REST can be mixed with
+//! GraphQL queries using the same client.
+//!
+//! ```no_run
+//! # async fn run(github: octorust::Client) -> anyhow::Result<()> {
+//! #[derive(serde::Deserialize)]
+//! struct Repo {
+//!     name: String,
+//! }
+//!
+//! let repos: Vec<Repo> = github
+//!     .graphql()
+//!     .paginate(
+//!         r#"query($login: String!, $after: String) {
+//!             organization(login: $login) {
+//!                 repositories(first: 100, after: $after) {
+//!                     nodes { name }
+//!                     pageInfo { hasNextPage endCursor }
+//!                 }
+//!             }
+//!         }"#,
+//!         serde_json::json!({ "login": "oxidecomputer" }),
+//!         "/organization/repositories",
+//!     )
+//!     .await?;
+//! # Ok(())
+//! # }
+//! ```
+use anyhow::{anyhow, Result};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+
+use crate::Client;
+
+/// The name of the variable the cursor is passed as when paginating.
+pub const CURSOR_VARIABLE: &str = "after";
+
+/// The body of a GraphQL request.
+#[derive(Debug, Clone, Serialize)]
+pub struct Request<'a, V> {
+    pub query: &'a str,
+    pub variables: V,
+}
+
+/// The body of a GraphQL response.
+#[derive(Debug, Clone, Deserialize)]
+pub struct Response<D> {
+    pub data: Option<D>,
+    #[serde(default)]
+    pub errors: Vec<ResponseError>,
+}
+
+/// An error returned in the `errors` list of a GraphQL response.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ResponseError {
+    pub message: String,
+    /// The kind of error, for example `NOT_FOUND` or `RATE_LIMITED`.
+    #[serde(default, rename = "type")]
+    pub type_: String,
+    #[serde(default)]
+    pub path: Vec<serde_json::Value>,
+}
+
+impl std::fmt::Display for ResponseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.type_.is_empty() {
+            write!(f, "{}", self.message)
+        } else {
+            write!(f, "{}: {}", self.type_, self.message)
+        }
+    }
+}
+
+/// The `pageInfo` of a connection.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PageInfo {
+    #[serde(default)]
+    pub has_next_page: bool,
+    #[serde(default)]
+    pub end_cursor: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct Edge<T> {
+    node: T,
+}
+
+/// A single page of a connection, with either `nodes` or `edges { node }` selected.
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Connection<T> {
+    #[serde(default = "Vec::new")]
+    nodes: Vec<T>,
+    #[serde(default = "Vec::new")]
+    edges: Vec<Edge<T>>,
+    #[serde(default)]
+    page_info: PageInfo,
+}
+
+/// Run GraphQL queries with the credentials and host of a `Client`. Get one with
+/// `Client::graphql`.
+pub struct GraphQL {
+    pub client: Client,
+}
+
+impl GraphQL {
+    #[doc(hidden)]
+    pub fn new(client: Client) -> Self {
+        GraphQL { client }
+    }
+
+    /// The URL of the GraphQL endpoint for the host the client was created with.
+    pub fn endpoint(&self) -> String {
+        endpoint(&self.client.host)
+    }
+
+    /// Run a query or mutation and return the `data` of the response.
+    ///
+    /// Any entries in the `errors` list of the response are returned as an error.
+    pub async fn query<V, D>(&self, query: &str, variables: V) -> Result<D>
+    where
+        V: Serialize,
+        D: DeserializeOwned + 'static + Send,
+    {
+        let response: Response<D> = self.request(query, variables).await?;
+
+        if !response.errors.is_empty() {
+            return Err(anyhow!(
+                "graphql errors: {}",
+                response
+                    .errors
+                    .iter()
+                    .map(|e| e.to_string())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ));
+        }
+
+        response
+            .data
+            .ok_or_else(|| anyhow!("graphql response contained no data"))
+    }
+
+    /// Run a query or mutation and return the full response, including any partial
+    /// `data` alongside the `errors`.
+    pub async fn request<V, D>(&self, query: &str, variables: V) -> Result<Response<D>>
+    where
+        V: Serialize,
+        D: DeserializeOwned + 'static + Send,
+    {
+        let body = Request { query, variables };
+
+        let (_, response) = self
+            .client
+            .request(
+                http::Method::POST,
+                &self.endpoint(),
+                Some(reqwest::Body::from(serde_json::to_vec(&body)?)),
+                crate::utils::MediaType::Json,
+                crate::auth::AuthenticationConstraint::Unconstrained,
+            )
+            .await?;
+
+        Ok(response)
+    }
+
+    /// Run a query generated with the `graphql_client` derive, which checks the
+    /// query against the GitHub schema at compile time.
+    ///
+    /// ```ignore
+    /// #[derive(graphql_client::GraphQLQuery)]
+    /// #[graphql(schema_path = "github.schema.graphql", query_path = "viewer.graphql")]
+    /// struct Viewer;
+    ///
+    /// let data = github.graphql().execute::<Viewer>(viewer::Variables).await?;
+    /// ```
+    #[cfg(feature = "graphql_client")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "graphql_client")))]
+    pub async fn execute<Q>(&self, variables: Q::Variables) -> Result<Q::ResponseData>
+    where
+        Q: graphql_client::GraphQLQuery,
+        Q::ResponseData: 'static + Send,
+    {
+        let body = Q::build_query(variables);
+        self.query(body.query, body.variables).await
+    }
+
+    /// Run a query repeatedly, following the cursor of a connection until there are
+    /// no more pages, and return all of the nodes.
+    ///
+    /// `connection` is a JSON pointer to the connection within the `data` of the
+    /// response, e.g. `/repository/pullRequests`. The connection must select
+    /// `pageInfo { hasNextPage endCursor }` and either `nodes` or `edges { node }`,
+    /// and the query must pass a `$after: String` variable as its `after` argument.
+    pub async fn paginate<T>(
+        &self,
+        query: &str,
+        variables: serde_json::Value,
+        connection: &str,
+    ) -> Result<Vec<T>>
+    where
+        T: DeserializeOwned,
+    {
+        let mut variables = match variables {
+            serde_json::Value::Object(m) => m,
+            serde_json::Value::Null => Default::default(),
+            v => return Err(anyhow!("graphql variables must be an object, got {}", v)),
+        };
+
+        let mut global_items = Vec::new();
+        loop {
+            let mut data: serde_json::Value = self
+                .query(query, serde_json::Value::Object(variables.clone()))
+                .await?;
+
+            let page = data
+                .pointer_mut(connection)
+                .map(serde_json::Value::take)
+                .ok_or_else(|| anyhow!("no connection found at {} in response", connection))?;
+            let mut page: Connection<T> = serde_json::from_value(page)?;
+
+            global_items.append(&mut page.nodes);
+            global_items.extend(page.edges.into_iter().map(|e| e.node));
+
+            match page.page_info.end_cursor {
+                Some(cursor) if page.page_info.has_next_page => {
+                    variables.insert(
+                        CURSOR_VARIABLE.to_string(),
+                        serde_json::Value::String(cursor),
+                    );
+                }
+                _ => break,
+            }
+        }
+
+        Ok(global_items)
+    }
+}
+
+/// Get the GraphQL endpoint for a REST API host.
+///
+/// GitHub.com serves GraphQL from `https://api.github.com/graphql` while GitHub
+/// Enterprise Server serves REST from `/api/v3` and GraphQL from `/api/graphql`.
+pub(crate) fn endpoint(host: &str) -> String {
+    let host = host.trim_end_matches('/');
+    match host.strip_suffix("/api/v3") {
+        Some(base) => format!("{}/api/graphql", base),
+        None => format!("{}/graphql", host),
+    }
+}
@@ -1,12 +1,13 @@
 //! Implements <https://tools.ietf.org/html/rfc7232> Conditional Requests.
 use std::{
-    collections::hash_map::DefaultHasher,
+    collections::{hash_map::DefaultHasher, HashMap},
     ffi::OsStr,
     fmt::Debug,
     fs,
     hash::{Hash, Hasher},
     io,
     path::{Path, PathBuf},
+    sync::{Arc, Mutex},
 };
 
 use anyhow::{Error, Result};
@@ -15,6 +16,9 @@ use http::Uri;
 /// A type for an HTTP cache.
 pub type BoxedHttpCache = Box<dyn HttpCache + Send + Sync>;
 
+/// A type for an async HTTP cache, shared between clones of a client.
+pub type BoxedAsyncHttpCache = Arc<dyn AsyncHttpCache + Send + Sync>;
+
 /// Implements a cached response and looking up the etag and next link.
 pub trait HttpCache: HttpCacheClone + Debug {
     fn cache_response(
@@ -47,6 +51,52 @@ impl Clone for BoxedHttpCache {
     }
 }
 
+/// Implements a cached response and looking up the etag and next link for caches
+/// backed by async storage, such as Redis or sqlite.
+///
+/// Every `BoxedHttpCache` is also an `AsyncHttpCache`.
+#[async_trait::async_trait]
+pub trait AsyncHttpCache: Debug {
+    async fn cache_response(
+        &self,
+        uri: &str,
+        body: &[u8],
+        etag: &[u8],
+        next_link: &Option<String>,
+    ) -> Result<()>;
+    async fn lookup_etag(&self, uri: &str) -> Result<String>;
+    async fn lookup_body(&self, uri: &str) -> Result<String>;
+    async fn lookup_next_link(&self, uri: &str) -> Result<Option<String>>;
+}
+
+impl dyn AsyncHttpCache {
+    pub fn noop() -> BoxedAsyncHttpCache {
+        Arc::new(<dyn HttpCache>::noop())
+    }
+}
+
+#[async_trait::async_trait]
+impl AsyncHttpCache for BoxedHttpCache {
+    async fn cache_response(
+        &self,
+        uri: &str,
+        body: &[u8],
+        etag: &[u8],
+        next_link: &Option<String>,
+    ) -> Result<()> {
+        HttpCache::cache_response(&**self, uri, body, etag, next_link)
+    }
+    async fn lookup_etag(&self, uri: &str) -> Result<String> {
+        HttpCache::lookup_etag(&**self, uri)
+    }
+    async fn lookup_body(&self, uri: &str) -> Result<String> {
+        HttpCache::lookup_body(&**self, uri)
+    }
+    async fn lookup_next_link(&self, uri: &str) -> Result<Option<String>> {
+        HttpCache::lookup_next_link(&**self, uri)
+    }
+}
+
 /// Noop type for no cache.
 #[derive(Clone, Debug)]
 pub struct NoCache;
@@ -66,6 +116,102 @@ impl HttpCache for NoCache {
     }
 }
 
+/// An in-memory cache holding at most `capacity` responses, evicting the least
+/// recently used response when it is full.
+///
+/// Clones share the same underlying storage.
+#[derive(Clone, Debug)]
+pub struct InMemoryCache {
+    capacity: usize,
+    inner: Arc<Mutex<InMemoryEntries>>,
+}
+
+#[derive(Debug, Default)]
+struct InMemoryEntries {
+    entries: HashMap<String, InMemoryEntry>,
+    // Incremented on every access so the smallest `last_used` is the least recently used.
+    clock: u64,
+}
+
+#[derive(Debug)]
+struct InMemoryEntry {
+    body: String,
+    etag: String,
+    next_link: Option<String>,
+    last_used: u64,
+}
+
+impl InMemoryCache {
+    pub fn new(capacity: usize) -> InMemoryCache {
+        InMemoryCache {
+            capacity,
+            inner: Default::default(),
+        }
+    }
+
+    fn lookup<T>(&self, uri: &str, f: impl FnOnce(&InMemoryEntry) -> T) -> Result<T> {
+        let mut inner = self.inner.lock().unwrap();
+        inner.clock += 1;
+        let clock = inner.clock;
+        match inner.entries.get_mut(uri) {
+            Some(entry) => {
+                entry.last_used = clock;
+                Ok(f(entry))
+            }
+            None => no_read("No response cached"),
+        }
+    }
+}
+
+impl HttpCache for InMemoryCache {
+    fn cache_response(
+        &self,
+        uri: &str,
+        body: &[u8],
+        etag: &[u8],
+        next_link: &Option<String>,
+    ) -> Result<()> {
+        if self.capacity == 0 {
+            return Ok(());
+        }
+
+        let mut inner = self.inner.lock().unwrap();
+        inner.clock += 1;
+        let entry = InMemoryEntry {
+            body: String::from_utf8(body.to_vec())?,
+            etag: String::from_utf8(etag.to_vec())?,
+            next_link: next_link.clone(),
+            last_used: inner.clock,
+        };
+
+        if !inner.entries.contains_key(uri) && inner.entries.len() >= self.capacity {
+            let oldest = inner
+                .entries
+                .iter()
+                .min_by_key(|(_, e)| e.last_used)
+                .map(|(k, _)| k.clone());
+            if let Some(oldest) = oldest {
+                inner.entries.remove(&oldest);
+            }
+        }
+        inner.entries.insert(uri.to_string(), entry);
+
+        Ok(())
+    }
+
+    fn lookup_etag(&self, uri: &str) -> Result<String> {
+        self.lookup(uri, |e| e.etag.clone())
+    }
+
+    fn lookup_body(&self, uri: &str) -> Result<String> {
+        self.lookup(uri, |e| e.body.clone())
+    }
+
+    fn lookup_next_link(&self, uri: &str) -> Result<Option<String>> {
+        Ok(self.lookup(uri, |e| e.next_link.clone()).unwrap_or(None))
+    }
+}
+
 /// A file based cache.
 #[derive(Clone, Debug)]
 pub struct FileBasedCache {
@@ -88,7 +234,6 @@ impl HttpCache for FileBasedCache {
         next_link: &Option<String>,
     ) -> Result<()> {
         let mut path = cache_path(&self.root, uri, "json");
-        //println!("caching body at path: {}", path.display());
         if let Some(parent) = path.parent() {
             fs::create_dir_all(parent)?;
         }
@@ -130,30 +275,30 @@ impl HttpCache for FileBasedCache {
 /// assert_eq!(
 ///     cache_path(
 ///         &PathBuf::from("/home/.github/cache"),
-///         "https://api.github.com/users/dwijnand/repos",
+///         "https://api.example.com/users/jane/items",
 ///         "json"
 ///     ),
-///     PathBuf::from("/home/.github/cache/v1/https/api.github.com/users/dwijnand/repos.json"),
+///     PathBuf::from("/home/.github/cache/v1/https/api.example.com/users/jane/items.json"),
 /// );
 /// assert_eq!(
 ///     cache_path(
 ///         &PathBuf::from("/home/.github/cache"),
-///         "https://api.github.com/users/dwijnand/repos?page=2",
+///         "https://api.example.com/users/jane/items?page=2",
 ///         "json"
 ///     ),
 ///     PathBuf::from(
-///         "/home/.github/cache/v1/https/api.github.com/users/dwijnand/repos/6dd58bde8abb0869.\
+///         "/home/.github/cache/v1/https/api.example.com/users/jane/items/6dd58bde8abb0869.\
 ///              json"
 ///     ),
 /// );
 /// assert_eq!(
 ///     cache_path(
 ///         &PathBuf::from("/home/.github/cache"),
-///         "https://api.github.com/users/dwijnand/repos?page=2&per_page=5",
+///         "https://api.example.com/users/jane/items?page=2&per_page=5",
 ///         "json"
 ///     ),
 ///     PathBuf::from(
-///         "/home/.github/cache/v1/https/api.github.com/users/dwijnand/repos/d862dcd2d85cebca.\
+///         "/home/.github/cache/v1/https/api.example.com/users/jane/items/d862dcd2d85cebca.\
 ///              json"
 ///     ),
 /// );
@@ -168,8 +313,8 @@ pub fn cache_path<S: AsRef<OsStr>>(dir: &Path, uri: &str, extension: S) -> PathB
     let mut path = dir.to_path_buf();
     path.push("v1");
     path.push(parts.scheme.expect("no URI scheme").as_str()); // https
-    path.push(parts.authority.expect("no URI authority").as_str()); // api.github.com
-    path.push(Path::new(&uri.path()[1..])); // users/dwijnand/repos
+    path.push(parts.authority.expect("no URI authority").as_str()); // api.example.com
+    path.push(Path::new(&uri.path()[1..])); // users/jane/items
     if let Some(query) = uri.query() {
         path.push(hash1(query, DefaultHasher::new())); // fa269019d5035d5f
     }
@@ -178,7 +323,6 @@ pub fn cache_path<S: AsRef<OsStr>>(dir: &Path, uri: &str, extension: S) -> PathB
 }
 
 fn read_to_string<P: AsRef<Path>>(path: P) -> Result<String> {
-    //println!("reading path: {}", path.as_ref().display());
     fs::read_to_string(path).map_err(Error::from)
 }
 
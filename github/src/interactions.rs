@@ -28,7 +28,7 @@ impl Interactions {
     pub async fn get_restrictions_for_org(
         &self,
         org: &str,
-    ) -> Result<crate::types::InteractionsGetRestrictionsResponseAnyOf> {
+    ) -> Result<crate::types::InteractionsGetRestrictionsOrgResponse> {
         let url = format!(
             "/orgs/{}/interaction-limits",
             crate::progenitor_support::encode_path(&org.to_string()),
@@ -105,7 +105,7 @@ impl Interactions {
         &self,
         owner: &str,
         repo: &str,
-    ) -> Result<crate::types::InteractionsGetRestrictionsResponseAnyOf> {
+    ) -> Result<crate::types::InteractionsGetRestrictionsRepoResponse> {
         let url = format!(
             "/repos/{}/{}/interaction-limits",
             crate::progenitor_support::encode_path(&owner.to_string()),
@@ -181,7 +181,7 @@ impl Interactions {
      */
     pub async fn get_restrictions_for_authenticated_user(
         &self,
-    ) -> Result<crate::types::InteractionsGetRestrictionsResponseAnyOf> {
+    ) -> Result<crate::types::InteractionsGetRestrictionsResponse> {
         let url = "/user/interaction-limits".to_string();
         self.client.get(&url, None).await
     }
@@ -181,6 +181,8 @@ pub mod apps;
 pub mod auth;
 /// Monitor charges and usage from Actions and Packages.
 pub mod billing;
+/// Report linter and test results as check runs.
+pub mod check_run_reporter;
 /// Rich interactions with checks run by your integrations.
 pub mod checks;
 /// Retrieve code scanning alerts from a repository.
@@ -197,6 +199,8 @@ pub mod gists;
 pub mod git;
 /// View gitignore templates.
 pub mod gitignore;
+/// Query the GitHub GraphQL v4 API.
+pub mod graphql;
 #[cfg(feature = "httpcache")]
 #[cfg_attr(docsrs, doc(cfg(feature = "httpcache")))]
 pub mod http_cache;
@@ -238,6 +242,7 @@ pub mod secret_scanning;
 pub mod teams;
 #[cfg(test)]
 mod tests;
+pub mod traits;
 pub mod types;
 /// Interact with and view information about users and also current user.
 pub mod users;
@@ -276,7 +281,7 @@ pub struct Client {
     client: reqwest::Client,
     credentials: Option<crate::auth::Credentials>,
     #[cfg(feature = "httpcache")]
-    http_cache: crate::http_cache::BoxedHttpCache,
+    http_cache: crate::http_cache::BoxedAsyncHttpCache,
 }
 
 impl Client {
@@ -329,7 +334,7 @@ impl Client {
             agent: agent.into(),
             client: http,
             credentials: credentials.into(),
-            http_cache,
+            http_cache: std::sync::Arc::new(http_cache),
         }
     }
 
@@ -355,6 +360,13 @@ impl Client {
         self.credentials = credentials.into();
     }
 
+    /// Use a cache backed by async storage for conditional requests.
+    #[cfg(feature = "httpcache")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "httpcache")))]
+    pub fn set_http_cache(&mut self, http_cache: crate::http_cache::BoxedAsyncHttpCache) {
+        self.http_cache = http_cache;
+    }
+
     fn credentials(
         &self,
         authentication: crate::auth::AuthenticationConstraint,
@@ -403,6 +415,10 @@ impl Client {
                 let auth = format!("Bearer {}", jwt.token());
                 parsed_url.map(|u| (u, Some(auth))).map_err(Error::from)
             }
+            Some(&crate::auth::Credentials::UserToken(ref user_token)) => {
+                let auth = format!("token {}", user_token.access_token().await?);
+                parsed_url.map(|u| (u, Some(auth))).map_err(Error::from)
+            }
             Some(&crate::auth::Credentials::InstallationToken(ref apptoken)) => {
                 if let Some(token) = apptoken.token() {
                     let auth = format!("token {}", token);
@@ -453,15 +469,7 @@ impl Client {
         let mut req = instance.client.request(method, url);
 
         #[cfg(feature = "httpcache")]
-        let mut req = {
-            let mut req = instance.client.request(method.clone(), url);
-            if method == http::Method::GET {
-                if let Ok(etag) = instance.http_cache.lookup_etag(&uri2) {
-                    req = req.header(http::header::IF_NONE_MATCH, etag);
-                }
-            }
-            req
-        };
+        let mut req = instance.client.request(method.clone(), url);
 
         req = req.header(http::header::USER_AGENT, &*instance.agent);
         req = req.header(
@@ -484,13 +492,37 @@ impl Client {
             req = req.body(body);
         }
         log::debug!("request: {:?}", &req);
-        let response = req.send().await?;
 
-        #[cfg(feature = "httpcache")]
-        let instance2 = <&Client>::clone(&self);
+        #[cfg(not(feature = "httpcache"))]
+        let response = req.send().await?;
 
         #[cfg(feature = "httpcache")]
-        let uri3 = uri.to_string();
+        let (response, cached_body) = {
+            let etag = if method == http::Method::GET {
+                instance.http_cache.lookup_etag(&uri2).await.ok()
+            } else {
+                None
+            };
+            // Keep a copy of the request without the etag, so we can fall back to it if
+            // the cached body has gone missing by the time we get a 304.
+            match (etag, req.try_clone()) {
+                (Some(etag), Some(unconditional)) => {
+                    let response = req.header(http::header::IF_NONE_MATCH, etag).send().await?;
+                    if response.status() == http::StatusCode::NOT_MODIFIED {
+                        match instance.http_cache.lookup_body(&uri2).await {
+                            Ok(body) => (response, Some(body)),
+                            Err(e) => {
+                                log::info!("failed to look up cached body, refetching: {}", e);
+                                (unconditional.send().await?, None)
+                            }
+                        }
+                    } else {
+                        (response, None)
+                    }
+                }
+                _ => (req.send().await?, None),
+            }
+        };
 
         #[cfg(not(feature = "httpcache"))]
         let (remaining, reset) = crate::utils::get_header_values(response.headers());
@@ -516,12 +548,11 @@ impl Client {
             {
                 if let Some(etag) = etag {
                     let next_link = link.as_ref().and_then(|l| crate::utils::next_link(l));
-                    if let Err(e) = instance2.http_cache.cache_response(
-                        &uri3,
-                        &response_body,
-                        &etag,
-                        &next_link,
-                    ) {
+                    if let Err(e) = instance
+                        .http_cache
+                        .cache_response(&uri2, &response_body, &etag, &next_link)
+                        .await
+                    {
                         // failing to cache isn't fatal, so just log & swallow the error
                         log::info!("failed to cache body & etag: {}", e);
                     }
@@ -541,13 +572,15 @@ impl Client {
             // header when cargo builds with --cfg feature="httpcache"
             #[cfg(feature = "httpcache")]
             {
-                let body = instance2.http_cache.lookup_body(&uri3).unwrap();
-                let out = serde_json::from_str::<Out>(&body).unwrap();
+                let body = cached_body
+                    .ok_or_else(|| anyhow!("code: {}, no cached body for {}", status, uri2))?;
+                let out = serde_json::from_str::<Out>(&body)?;
                 let link = match link {
                     Some(link) => Ok(Some(link)),
-                    None => instance2
+                    None => instance
                         .http_cache
-                        .lookup_next_link(&uri3)
+                        .lookup_next_link(&uri2)
+                        .await
                         .map(|next_link| {
                             next_link.map(|next| {
                                 let next = hyperx::header::LinkValue::new(next)
@@ -634,6 +667,40 @@ impl Client {
         .await
     }
 
+    /// Fetch the raw bytes of a resource with the `application/vnd.github.raw` media type.
+    async fn get_raw(&self, uri: &str) -> Result<Vec<u8>> {
+        let (url, auth) = self
+            .url_and_auth(
+                &(self.host.clone() + uri),
+                crate::auth::AuthenticationConstraint::Unconstrained,
+            )
+            .await?;
+
+        let mut req = self.client.get(url);
+        req = req.header(http::header::USER_AGENT, &*self.agent);
+        req = req.header(http::header::ACCEPT, "application/vnd.github.raw");
+        if let Some(auth_str) = auth {
+            req = req.header(http::header::AUTHORIZATION, &*auth_str);
+        }
+        log::debug!("request: {:?}", &req);
+
+        let response = req.send().await?;
+        let status = response.status();
+        let response_body = response.bytes().await?;
+
+        if status.is_success() {
+            Ok(response_body.to_vec())
+        } else if response_body.is_empty() {
+            Err(anyhow!("code: {}, empty response", status))
+        } else {
+            Err(anyhow!(
+                "code: {}, error: {:?}",
+                status,
+                String::from_utf8_lossy(&response_body),
+            ))
+        }
+    }
+
     async fn get_all_pages<D>(&self, uri: &str, _message: Option<reqwest::Body>) -> Result<Vec<D>>
     where
         D: serde::de::DeserializeOwned + 'static + Send,
@@ -795,6 +862,11 @@ impl Client {
         Ok(global_items)
     }
 
+    /// Query the GitHub GraphQL v4 API with the same credentials and host.
+    pub fn graphql(&self) -> graphql::GraphQL {
+        graphql::GraphQL::new(self.clone())
+    }
+
     /// Endpoints to manage GitHub Actions using the REST API.
     pub fn actions(&self) -> actions::Actions {
         actions::Actions::new(self.clone())
@@ -905,7 +905,7 @@ impl Orgs {
         org: &str,
         per_page: i64,
         page: i64,
-    ) -> Result<crate::types::AppsListInstallationsResponse> {
+    ) -> Result<crate::types::OrgsListAppInstallationsResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if page > 0 {
             query_args.push(("page".to_string(), page.to_string()));
@@ -3307,7 +3307,7 @@ impl Repos {
         repo: &str,
         path: &str,
         ref_: &str,
-    ) -> Result<crate::types::ReposGetContentResponseOneOf> {
+    ) -> Result<crate::types::ReposGetContentResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !ref_.is_empty() {
             query_args.push(("ref".to_string(), ref_.to_string()));
@@ -1 +1,298 @@
+#[test]
+fn test_graphql_endpoint() {
+    assert_eq!(
+        crate::graphql::endpoint("https://api.github.com"),
+        "https://api.github.com/graphql"
+    );
+    assert_eq!(
+        crate::graphql::endpoint("https://github.example.com/api/v3/"),
+        "https://github.example.com/api/graphql"
+    );
+}
 
+#[tokio::test]
+async fn test_graphql_paginate() {
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+    // Serve a connection in two pages, the first with `nodes` and the second with
+    // `edges`, which is only served for the cursor of the first.
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    tokio::spawn(async move {
+        while let Ok((mut socket, _)) = listener.accept().await {
+            let mut request = Vec::new();
+            let mut buf = vec![0; 4096];
+            let body = loop {
+                let n = socket.read(&mut buf).await.unwrap();
+                request.extend_from_slice(&buf[..n]);
+                let text = String::from_utf8_lossy(&request).to_string();
+                if let Some((head, body)) = text.split_once("\r\n\r\n") {
+                    let len = head
+                        .lines()
+                        .find_map(|l| {
+                            l.to_lowercase()
+                                .strip_prefix("content-length: ")
+                                .map(|v| v.parse::<usize>().unwrap())
+                        })
+                        .unwrap_or_default();
+                    if n == 0 || body.len() >= len {
+                        break body.to_string();
+                    }
+                }
+            };
+            let request: serde_json::Value = serde_json::from_str(&body).unwrap();
+            assert_eq!(request["variables"]["login"], "oxidecomputer");
+
+            let repositories = match request["variables"]["after"].as_str() {
+                None => serde_json::json!({
+                    "nodes": [{ "name": "a" }, { "name": "b" }],
+                    "pageInfo": { "hasNextPage": true, "endCursor": "c1" },
+                }),
+                Some("c1") => serde_json::json!({
+                    "edges": [{ "node": { "name": "c" } }],
+                    "pageInfo": { "hasNextPage": false, "endCursor": "c2" },
+                }),
+                Some(cursor) => panic!("unexpected cursor {}", cursor),
+            };
+            let body = serde_json::json!({
+                "data": { "organization": { "repositories": repositories } }
+            })
+            .to_string();
+            let response = format!(
+                "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{}",
+                body.len(),
+                body
+            );
+            socket.write_all(response.as_bytes()).await.unwrap();
+        }
+    });
+
+    #[derive(Debug, serde::Deserialize)]
+    struct Repo {
+        name: String,
+    }
+
+    let client = crate::Client::host(
+        format!("http://{}", addr),
+        "test",
+        crate::auth::Credentials::Token("token".to_string()),
+    )
+    .unwrap();
+    let graphql = client.graphql();
+    let variables = serde_json::json!({ "login": "oxidecomputer" });
+
+    let repos: Vec<Repo> = graphql
+        .paginate("query", variables.clone(), "/organization/repositories")
+        .await
+        .unwrap();
+    let names: Vec<_> = repos.iter().map(|r| r.name.as_str()).collect();
+    assert_eq!(names, vec!["a", "b", "c"]);
+
+    // The connection must be where the pointer says.
+    let err = graphql
+        .paginate::<Repo>("query", variables, "/organization/members")
+        .await
+        .unwrap_err();
+    assert!(err.to_string().contains("/organization/members"));
+}
+
+#[cfg(feature = "httpcache")]
+#[test]
+fn test_in_memory_cache_evicts_least_recently_used() {
+    use crate::http_cache::{HttpCache, InMemoryCache};
+
+    let cache = InMemoryCache::new(2);
+    cache.cache_response("/a", b"a", b"\"1\"", &None).unwrap();
+    cache
+        .cache_response("/b", b"b", b"\"2\"", &Some("/b?page=2".to_string()))
+        .unwrap();
+    // Touch /a so /b is the least recently used.
+    assert_eq!(cache.lookup_etag("/a").unwrap(), "\"1\"");
+    cache.cache_response("/c", b"c", b"\"3\"", &None).unwrap();
+
+    assert_eq!(cache.lookup_body("/a").unwrap(), "a");
+    assert!(cache.lookup_body("/b").is_err());
+    assert_eq!(cache.lookup_next_link("/b").unwrap(), None);
+    assert_eq!(cache.lookup_body("/c").unwrap(), "c");
+}
+
+#[test]
+fn test_sarif_annotations() {
+    use crate::types::AnnotationLevel;
+
+    let sarif = r#"{
+        "version": "2.1.0",
+        "runs": [{
+            "tool": { "driver": { "name": "clippy" } },
+            "results": [
+                {
+                    "ruleId": "clippy::needless_return",
+                    "level": "error",
+                    "message": { "text": "unneeded `return` statement" },
+                    "locations": [{
+                        "physicalLocation": {
+                            "artifactLocation": { "uri": "file://src/lib.rs" },
+                            "region": { "startLine": 3, "startColumn": 5, "endColumn": 14 }
+                        }
+                    }]
+                },
+                {
+                    "ruleId": "unused",
+                    "message": { "text": "unused variable" },
+                    "locations": [{
+                        "physicalLocation": {
+                            "artifactLocation": { "uri": "src/main.rs" },
+                            "region": { "startLine": 1, "endLine": 4, "startColumn": 2 }
+                        }
+                    }]
+                },
+                {
+                    "ruleId": "clippy::single_char_pattern",
+                    "level": "note",
+                    "message": { "text": "single-character string constant used as pattern" },
+                    "locations": [{
+                        "physicalLocation": {
+                            "artifactLocation": { "uri": "src/lib.rs" },
+                            "region": { "startLine": 7, "startColumn": 20 }
+                        }
+                    }]
+                },
+                { "ruleId": "no-location", "message": { "text": "skipped" } }
+            ]
+        }]
+    }"#;
+
+    let annotations = crate::check_run_reporter::sarif_annotations(sarif).unwrap();
+    assert_eq!(annotations.len(), 3);
+
+    assert_eq!(annotations[0].path, "src/lib.rs");
+    assert_eq!(annotations[0].annotation_level, AnnotationLevel::Failure);
+    assert_eq!(annotations[0].title, "clippy::needless_return");
+    assert_eq!((annotations[0].start_line, annotations[0].end_line), (3, 3));
+    assert_eq!(
+        (annotations[0].start_column, annotations[0].end_column),
+        (5, 13)
+    );
+
+    assert_eq!(annotations[1].annotation_level, AnnotationLevel::Warning);
+    assert_eq!((annotations[1].start_line, annotations[1].end_line), (1, 4));
+    assert_eq!(annotations[1].start_column, 0);
+
+    assert_eq!(annotations[2].annotation_level, AnnotationLevel::Notice);
+    assert_eq!(
+        (annotations[2].start_column, annotations[2].end_column),
+        (20, 20)
+    );
+}
+
+#[test]
+fn test_decode_content() {
+    assert_eq!(
+        crate::traits::decode_content("aGVsbG8g\nd29ybGQ=\n").unwrap(),
+        b"hello world".to_vec()
+    );
+    assert!(crate::traits::decode_content("not base64!").is_err());
+}
+
+#[test]
+fn test_oauth_urls() {
+    use crate::auth::oauth::{manifest_form_html, manifest_url, AppManifest, OAuthApp};
+
+    let app = OAuthApp::new("Iv1.abc").with_host("https://github.example.com/");
+    assert_eq!(
+        app.authorize_url(
+            "http://localhost:8080/callback",
+            &["repo".to_string(), "read:org".to_string()],
+            "xyz"
+        ),
+        "https://github.example.com/login/oauth/authorize?client_id=Iv1.abc&redirect_uri=http%3A%2F%2Flocalhost%3A8080%2Fcallback&scope=repo+read%3Aorg&state=xyz"
+    );
+
+    assert_eq!(
+        manifest_url("https://github.com", "", ""),
+        "https://github.com/settings/apps/new"
+    );
+    assert_eq!(
+        manifest_url("https://github.com", "oxidecomputer", "xyz"),
+        "https://github.com/organizations/oxidecomputer/settings/apps/new?state=xyz"
+    );
+
+    let html = manifest_form_html(
+        &AppManifest {
+            name: "Bot \"one\"".to_string(),
+            url: "https://example.com".to_string(),
+            ..Default::default()
+        },
+        "https://github.com",
+        "",
+        "",
+    )
+    .unwrap();
+    assert!(html.contains(r#"action="https://github.com/settings/apps/new""#));
+    assert!(html.contains("&quot;name&quot;:&quot;Bot \\&quot;one\\&quot;&quot;"));
+}
+
+#[tokio::test]
+async fn test_user_token_refresh() {
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+    use crate::auth::{
+        oauth::{OAuthApp, UserToken},
+        Credentials,
+    };
+
+    // Hand out a new token for a refresh, and echo the authorization of any other
+    // request.
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    tokio::spawn(async move {
+        while let Ok((mut socket, _)) = listener.accept().await {
+            let mut request = vec![0; 4096];
+            let n = socket.read(&mut request).await.unwrap();
+            let request = String::from_utf8_lossy(&request[..n]).to_lowercase();
+            let body = if request.starts_with("post /login/oauth/access_token") {
+                r#"{"access_token": "new", "token_type": "bearer", "expires_in": 28800, "refresh_token": "r2"}"#.to_string()
+            } else {
+                let auth = request
+                    .lines()
+                    .find_map(|l| l.strip_prefix("authorization: "))
+                    .unwrap_or_default();
+                serde_json::json!({ "authorization": auth }).to_string()
+            };
+            let response = format!(
+                "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{}",
+                body.len(),
+                body
+            );
+            socket.write_all(response.as_bytes()).await.unwrap();
+        }
+    });
+
+    let host = format!("http://{}", addr);
+    let app = OAuthApp::new("Iv1.abc")
+        .with_client_secret("secret")
+        .with_host(&host);
+    let expired = UserToken {
+        access_token: "old".to_string(),
+        token_type: "bearer".to_string(),
+        scope: String::new(),
+        expires_at: Some(chrono::Utc::now()),
+        refresh_token: Some("r1".to_string()),
+        refresh_token_expires_at: None,
+    };
+    let credentials = expired.refreshing_credentials(&app);
+    let client = crate::Client::host(host, "test", credentials.clone()).unwrap();
+
+    let response: serde_json::Value = client.get("/user", None).await.unwrap();
+    assert_eq!(response["authorization"], "token new");
+
+    // The credentials share the refreshed token, so it can be stored.
+    match credentials {
+        Credentials::UserToken(token) => {
+            let token = token.user_token().await;
+            assert_eq!(token.access_token, "new");
+            assert_eq!(token.refresh_token.as_deref(), Some("r2"));
+        }
+        credentials => panic!("unexpected credentials {:?}", credentials),
+    }
+}
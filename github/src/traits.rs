@@ -0,0 +1,225 @@
+use anyhow::{anyhow, Result};
+use futures::{stream, StreamExt, TryStreamExt};
+
+/// The largest file the contents API returns inline; anything bigger has to be
+/// fetched with the raw media type.
+pub const MAX_INLINE_CONTENT_SIZE: i64 = 1024 * 1024;
+
+/// A file in a repository along with its decoded contents.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RepoFile {
+    pub path: String,
+    pub sha: String,
+    pub size: i64,
+    pub content: Vec<u8>,
+}
+
+#[async_trait::async_trait]
+pub trait ContentOps {
+    /// List every entry below `path` in the repository, recursively.
+    ///
+    /// This uses a single recursive git tree request when it can, and falls back to
+    /// walking the directories with the contents API if the tree is truncated or
+    /// can't be fetched. Entries are returned with their paths relative to the root
+    /// of the repository and a `type_` of `blob`, `tree` or `commit` (for submodules).
+    async fn list_all_contents(
+        &self,
+        owner: &str,
+        repo: &str,
+        path: &str,
+        ref_: &str,
+    ) -> Result<Vec<crate::types::GitTree>>;
+
+    /// Get the decoded contents of a single file.
+    ///
+    /// Files larger than 1 MB are fetched with the raw media type, since the contents
+    /// API doesn't return them inline.
+    async fn get_file_contents(
+        &self,
+        owner: &str,
+        repo: &str,
+        path: &str,
+        ref_: &str,
+    ) -> Result<Vec<u8>>;
+
+    /// Get the decoded contents of every file below `path`, fetching at most
+    /// `concurrency` files at a time.
+    ///
+    /// Symlinks and submodules are skipped.
+    async fn get_all_files(
+        &self,
+        owner: &str,
+        repo: &str,
+        path: &str,
+        ref_: &str,
+        concurrency: usize,
+    ) -> Result<Vec<RepoFile>>;
+}
+
+#[async_trait::async_trait]
+impl ContentOps for crate::repos::Repos {
+    /// List every entry below `path` in the repository, recursively.
+    ///
+    /// This uses a single recursive git tree request when it can, and falls back to
+    /// walking the directories with the contents API if the tree is truncated or
+    /// can't be fetched. Entries are returned with their paths relative to the root
+    /// of the repository and a `type_` of `blob`, `tree` or `commit` (for submodules).
+    async fn list_all_contents(
+        &self,
+        owner: &str,
+        repo: &str,
+        path: &str,
+        ref_: &str,
+    ) -> Result<Vec<crate::types::GitTree>> {
+        let prefix = path.trim_matches('/');
+        let tree_sha = if ref_.is_empty() { "HEAD" } else { ref_ };
+
+        match crate::git::Git::new(self.client.clone())
+            .get_tree(owner, repo, tree_sha, "1")
+            .await
+        {
+            Ok(tree) if !tree.truncated => {
+                return Ok(tree
+                    .tree
+                    .into_iter()
+                    .filter(|e| {
+                        prefix.is_empty()
+                            || e.path == prefix
+                            || e.path
+                                .strip_prefix(prefix)
+                                .map(|rest| rest.starts_with('/'))
+                                .unwrap_or(false)
+                    })
+                    .collect());
+            }
+            Ok(_) => log::debug!(
+                "tree for {}/{} is truncated, walking directories",
+                owner,
+                repo
+            ),
+            Err(e) => log::debug!(
+                "failed to get tree for {}/{}, walking directories: {}",
+                owner,
+                repo,
+                e
+            ),
+        }
+
+        let mut entries = Vec::new();
+        let mut dirs = vec![prefix.to_string()];
+        while let Some(dir) = dirs.pop() {
+            for entry in self
+                .get_content_vec_entries(owner, repo, &dir, ref_)
+                .await?
+            {
+                let (type_, mode) = match entry.type_.as_str() {
+                    "dir" => {
+                        dirs.push(entry.path.clone());
+                        ("tree", "040000")
+                    }
+                    "symlink" => ("blob", "120000"),
+                    "submodule" => ("commit", "160000"),
+                    _ => ("blob", "100644"),
+                };
+                entries.push(crate::types::GitTree {
+                    mode: mode.to_string(),
+                    path: entry.path,
+                    sha: entry.sha,
+                    size: entry.size,
+                    type_: type_.to_string(),
+                    url: entry.url.map(|u| u.to_string()).unwrap_or_default(),
+                });
+            }
+        }
+
+        Ok(entries)
+    }
+
+    /// Get the decoded contents of a single file.
+    ///
+    /// Files larger than 1 MB are fetched with the raw media type, since the contents
+    /// API doesn't return them inline.
+    async fn get_file_contents(
+        &self,
+        owner: &str,
+        repo: &str,
+        path: &str,
+        ref_: &str,
+    ) -> Result<Vec<u8>> {
+        let file = self.get_content_file(owner, repo, path, ref_).await?;
+
+        match file.encoding.as_str() {
+            // Empty files are returned inline with no content.
+            "base64" if !file.content.is_empty() || file.size == 0 => decode_content(&file.content),
+            // Files over 1 MB come back with an encoding of `none` and no content.
+            _ => get_raw_contents(self, owner, repo, path, ref_).await,
+        }
+    }
+
+    /// Get the decoded contents of every file below `path`, fetching at most
+    /// `concurrency` files at a time.
+    ///
+    /// Symlinks and submodules are skipped.
+    async fn get_all_files(
+        &self,
+        owner: &str,
+        repo: &str,
+        path: &str,
+        ref_: &str,
+        concurrency: usize,
+    ) -> Result<Vec<RepoFile>> {
+        let files = self
+            .list_all_contents(owner, repo, path, ref_)
+            .await?
+            .into_iter()
+            .filter(|e| e.type_ == "blob" && e.mode != "120000");
+
+        stream::iter(files.map(|e| async move {
+            let content = if e.size > MAX_INLINE_CONTENT_SIZE {
+                get_raw_contents(self, owner, repo, &e.path, ref_).await?
+            } else {
+                self.get_file_contents(owner, repo, &e.path, ref_).await?
+            };
+
+            Ok::<_, anyhow::Error>(RepoFile {
+                path: e.path,
+                sha: e.sha,
+                size: e.size,
+                content,
+            })
+        }))
+        .buffered(concurrency.max(1))
+        .try_collect()
+        .await
+    }
+}
+
+async fn get_raw_contents(
+    repos: &crate::repos::Repos,
+    owner: &str,
+    repo: &str,
+    path: &str,
+    ref_: &str,
+) -> Result<Vec<u8>> {
+    let mut query_args: Vec<(String, String)> = Default::default();
+    if !ref_.is_empty() {
+        query_args.push(("ref".to_string(), ref_.to_string()));
+    }
+    let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+    let url = format!(
+        "/repos/{}/{}/contents/{}?{}",
+        crate::progenitor_support::encode_path(owner),
+        crate::progenitor_support::encode_path(repo),
+        crate::progenitor_support::encode_path(path),
+        query_
+    );
+
+    repos.client.get_raw(&url).await
+}
+
+/// Decode the base64 content returned by the contents and blob APIs, which is
+/// wrapped with newlines.
+pub fn decode_content(content: &str) -> Result<Vec<u8>> {
+    let content: String = content.split_whitespace().collect();
+    base64::decode(&content).map_err(|e| anyhow!("invalid base64 content: {}", e))
+}
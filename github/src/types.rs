@@ -24913,6 +24913,8 @@ impl Default for Per {
     }
 }
 
+pub type AcceptedResponse = Data;
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PullsMergeResponse {
     #[serde(
@@ -25236,6 +25238,8 @@ pub struct AppsCreateFromManifestResponseAllOf {
     pub apps_create_from_manifest_response: AppsCreateFromManifestResponse,
 }
 
+pub type AppsCreateFromManifestResponseData = AppsCreateFromManifestResponseAllOf;
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct AppsUpdateWebhookConfigAppRequest {
     #[serde(
@@ -25987,6 +25991,8 @@ pub struct ActivityMarkNotificationsAsReadRequest {
     pub read: Option<bool>,
 }
 
+pub type ActivityMarkNotificationsAsReadResponse = Error;
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ActivitySetThreadSubscriptionRequest {
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -26522,6 +26528,8 @@ pub struct InteractionsGetRestrictionsResponseAnyOf {
     pub interaction_limits: InteractionLimits,
 }
 
+pub type InteractionsGetRestrictionsOrgResponse = InteractionsGetRestrictionsResponseAnyOf;
+
 /**
  * Specify role for new member. Can be one of:  
  *   \* `admin` - Organization owners with full administrative rights to the organization and complete access to all repositories and teams.  
@@ -26763,6 +26771,8 @@ pub struct MigrationsStartRequest {
     pub repositories: Vec<String>,
 }
 
+pub type OrgsConvertMemberOutsideCollaboratorResponse = Data;
+
 /**
  * The state of the package, either active or deleted.
  */
@@ -27390,6 +27400,8 @@ pub struct ProjectsMoveCardRequest {
     pub position: String,
 }
 
+pub type ProjectsMoveCardResponse = Data;
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ProjectsMoveCardResponseErrors {
     #[serde(
@@ -27419,7 +27431,7 @@ pub struct ProjectsMoveCardResponseErrors {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct ProjectsMoveCardResponse {
+pub struct ProjectsMoveCardResponseData {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -27592,6 +27604,8 @@ pub struct ProjectsMoveColumnRequest {
     pub position: String,
 }
 
+pub type ProjectsMoveColumnResponse = Data;
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ProjectsUpdateRequest {
     #[serde(
@@ -27771,6 +27785,8 @@ pub struct ActionsSetGithubPermissionsRepositoryRequest {
     pub enabled: Option<bool>,
 }
 
+pub type ActionsListSelfHostedRunnersRepoResponse = ActionsListSelfHostedRunnersOrgResponse;
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ActionsListWorkflowRunsResponse {
     #[serde(
@@ -27787,6 +27803,10 @@ pub struct ActionsListWorkflowRunsResponse {
     pub workflow_runs: Vec<WorkflowRun>,
 }
 
+pub type ActionsListWorkflowRunArtifactsResponse = ActionsListArtifactsRepoResponse;
+
+pub type ActionsCancelWorkflowRunResponse = Data;
+
 /**
  * Filters jobs by their `completed_at` timestamp. Can be one of:  
  *   \* `latest`: Returns jobs from the most recent execution of the workflow run.  
@@ -27859,6 +27879,8 @@ pub struct ActionsReviewPendingDeploymentsRunRequest {
     pub state: EnvironmentApprovalState,
 }
 
+pub type ActionsReRunWorkflowResponse = Data;
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ActionsListRepoSecretsResponse {
     #[serde(
@@ -27891,6 +27913,8 @@ pub struct ActionsCreateUpdateRepoSecretRequest {
     pub key_id: String,
 }
 
+pub type ActionsCreateUpdateRepoSecretResponse = Data;
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ActionsListRepoWorkflowsResponse {
     #[serde(
@@ -28809,6 +28833,8 @@ pub struct ChecksListRefResponse {
     pub total_count: i64,
 }
 
+pub type ChecksRerequestSuiteResponse = Data;
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct CodeScanningUpdateAlertRequest {
     /**
@@ -28998,6 +29024,8 @@ impl std::convert::From<ReposGetContentResponseOneOf> for Vec<Entries> {
     }
 }
 
+pub type ReposGetContentResponse = ReposGetContentResponseOneOf;
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ReposCreateUpdateFileContentsRequest {
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -29934,6 +29962,8 @@ pub struct MigrationsSetLfsPreferenceRequest {
     pub use_lfs: UseLfs,
 }
 
+pub type InteractionsGetRestrictionsRepoResponse = InteractionsGetRestrictionsResponseAnyOf;
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ReposUpdateInvitationRequest {
     /**
@@ -31231,6 +31261,8 @@ pub struct ActivityListStargazersRepoResponseAnyOf {
     pub stargazer_vector: Vec<Stargazer>,
 }
 
+pub type ActivityListStargazersRepoResponse = ActivityListStargazersRepoResponseAnyOf;
+
 /**
  * The state of the status. Can be one of `error`, `failure`, `pending`, or `success`.
  */
@@ -31358,6 +31390,8 @@ pub struct ReposCreateUsingTemplateRequest {
     pub private: Option<bool>,
 }
 
+pub type ActionsListEnvironmentSecretsResponse = ActionsListRepoSecretsResponse;
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct EnterpriseAdminProvisionInviteGroupRequest {
     #[serde(
@@ -32229,6 +32263,8 @@ impl UsersGetByUsernameResponseOneOf {
     }
 }
 
+pub type UsersGetAuthenticatedResponse = UsersGetByUsernameResponseOneOf;
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct UsersUpdateAuthenticatedRequest {
     #[serde(
@@ -32375,6 +32411,8 @@ pub struct UsersCreateGpgKeyAuthenticatedRequest {
     pub armored_public_key: String,
 }
 
+pub type InteractionsGetRestrictionsResponse = InteractionsGetRestrictionsResponseAnyOf;
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct UsersCreatePublicSshKeyAuthenticatedRequest {
     #[serde(
@@ -32576,6 +32614,8 @@ pub struct ReposCreateRequest {
     pub team_id: i64,
 }
 
+pub type UsersGetByUsernameResponse = UsersGetByUsernameResponseOneOf;
+
 /**
  * Identifies which additional information you'd like to receive about the person's hovercard. Can be `organization`, `repository`, `issue`, `pull_request`. **Required** when using `subject_id`.
  */
@@ -32664,3 +32704,23 @@ pub struct ActivityListReposStarredByUserResponseAnyOf {
     #[serde(flatten)]
     pub starred_repository_vector: Vec<StarredRepository>,
 }
+
+pub type ActivityListReposStarredByUserResponse = ActivityListReposStarredByUserResponseAnyOf;
+
+pub type ActionsListWorkflowRunsRepoResponse = ActionsListWorkflowRunsResponse;
+
+pub type ActivityMarkRepoNotificationsAsReadResponse = PullsUpdateBranchResponse;
+
+pub type AppsListReposAccessibleInstallationResponse = AppsListInstallationReposResponse;
+
+pub type ChecksListSuiteResponse = ChecksListRefResponse;
+
+pub type EnterpriseAdminListSelectedOrganizationsEnabledGithubActionsResponse =
+    EnterpriseAdminListOrgAccessSelfHostedRunnerGroupInResponse;
+
+pub type EnterpriseAdminListSelfHostedRunnersInGroupResponse =
+    ActionsListSelfHostedRunnersInGroupOrgResponse;
+
+pub type OrgsListAppInstallationsResponse = AppsListInstallationsResponse;
+
+pub type PreviewHeaderMissingResponse = PullsMergeResponse;
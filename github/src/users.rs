@@ -55,7 +55,7 @@ impl Users {
      *
      * FROM: <https://docs.github.com/rest/reference/users#get-the-authenticated-user>
      */
-    pub async fn get_authenticated(&self) -> Result<crate::types::UsersGetByUsernameResponseOneOf> {
+    pub async fn get_authenticated(&self) -> Result<crate::types::UsersGetAuthenticatedResponse> {
         let url = "/user".to_string();
         self.client.get(&url, None).await
     }
@@ -866,7 +866,7 @@ impl Users {
     pub async fn get_by_username(
         &self,
         username: &str,
-    ) -> Result<crate::types::UsersGetByUsernameResponseOneOf> {
+    ) -> Result<crate::types::UsersGetByUsernameResponse> {
         let url = format!(
             "/users/{}",
             crate::progenitor_support::encode_path(&username.to_string()),
@@ -30,6 +30,30 @@ impl Asps {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Like `list`, but adds the call to a `Batch` to be sent with others, instead of sending it.
+     *
+     * This function performs a `GET` to the `/admin/directory/v1/users/{userKey}/asps` endpoint.
+     *
+     * Lists the ASPs issued by a user.
+     *
+     * **Parameters:**
+     *
+     * * `user_key: &str` -- Identifies the user in the API request. The value can be the user's primary email address, alias email address, or unique user ID.
+     */
+    pub fn list_batch(
+        &self,
+        batch: &mut crate::batch::Batch,
+        user_key: &str,
+    ) -> Result<crate::batch::BatchCall<crate::types::Asps>> {
+        let url = format!(
+            "/admin/directory/v1/users/{}/asps",
+            crate::progenitor_support::encode_path(&user_key.to_string()),
+        );
+
+        Ok(batch.add(reqwest::Method::GET, &url, None))
+    }
+
     /**
      * This function performs a `GET` to the `/admin/directory/v1/users/{userKey}/asps/{codeId}` endpoint.
      *
@@ -50,6 +74,33 @@ impl Asps {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Like `get`, but adds the call to a `Batch` to be sent with others, instead of sending it.
+     *
+     * This function performs a `GET` to the `/admin/directory/v1/users/{userKey}/asps/{codeId}` endpoint.
+     *
+     * Gets information about an ASP issued by a user.
+     *
+     * **Parameters:**
+     *
+     * * `user_key: &str` -- Identifies the user in the API request. The value can be the user's primary email address, alias email address, or unique user ID.
+     * * `code_id: i64` -- The unique ID of the ASP.
+     */
+    pub fn get_batch(
+        &self,
+        batch: &mut crate::batch::Batch,
+        user_key: &str,
+        code_id: i64,
+    ) -> Result<crate::batch::BatchCall<crate::types::Asp>> {
+        let url = format!(
+            "/admin/directory/v1/users/{}/asps/{}",
+            crate::progenitor_support::encode_path(&user_key.to_string()),
+            crate::progenitor_support::encode_path(&code_id.to_string()),
+        );
+
+        Ok(batch.add(reqwest::Method::GET, &url, None))
+    }
+
     /**
      * This function performs a `DELETE` to the `/admin/directory/v1/users/{userKey}/asps/{codeId}` endpoint.
      *
@@ -69,4 +120,31 @@ impl Asps {
 
         self.client.delete(&url, None).await
     }
+
+    /**
+     * Like `delete`, but adds the call to a `Batch` to be sent with others, instead of sending it.
+     *
+     * This function performs a `DELETE` to the `/admin/directory/v1/users/{userKey}/asps/{codeId}` endpoint.
+     *
+     * Deletes an ASP issued by a user.
+     *
+     * **Parameters:**
+     *
+     * * `user_key: &str` -- Identifies the user in the API request. The value can be the user's primary email address, alias email address, or unique user ID.
+     * * `code_id: i64` -- The unique ID of the ASP to be deleted.
+     */
+    pub fn delete_batch(
+        &self,
+        batch: &mut crate::batch::Batch,
+        user_key: &str,
+        code_id: i64,
+    ) -> Result<crate::batch::BatchCall<()>> {
+        let url = format!(
+            "/admin/directory/v1/users/{}/asps/{}",
+            crate::progenitor_support::encode_path(&user_key.to_string()),
+            crate::progenitor_support::encode_path(&code_id.to_string()),
+        );
+
+        Ok(batch.add(reqwest::Method::DELETE, &url, None))
+    }
 }
@@ -23,4 +23,25 @@ impl Channels {
             .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
             .await
     }
+
+    /**
+     * Like `admin_stop`, but adds the call to a `Batch` to be sent with others, instead of sending it.
+     *
+     * This function performs a `POST` to the `/admin/directory_v1/channels/stop` endpoint.
+     *
+     * Stops watching resources through this channel.
+     */
+    pub fn admin_stop_batch(
+        &self,
+        batch: &mut crate::batch::Batch,
+        body: &crate::types::Channel,
+    ) -> Result<crate::batch::BatchCall<()>> {
+        let url = "/admin/directory_v1/channels/stop".to_string();
+
+        Ok(batch.add(
+            reqwest::Method::POST,
+            &url,
+            Some(serde_json::to_string(body)?),
+        ))
+    }
 }
@@ -177,6 +177,43 @@ impl Chromeosdevices {
             .await
     }
 
+    /**
+     * Like `move_devices_ou`, but adds the call to a `Batch` to be sent with others, instead of sending it.
+     *
+     * This function performs a `POST` to the `/admin/directory/v1/customer/{customerId}/devices/chromeos/moveDevicesToOu` endpoint.
+     *
+     * Moves or inserts multiple Chrome OS devices to an organizational unit. You can move up to 50 devices at once.
+     *
+     * **Parameters:**
+     *
+     * * `customer_id: &str` -- Immutable ID of the Google Workspace account.
+     * * `org_unit_path: &str` -- Full path of the target organizational unit or its ID.
+     */
+    pub fn move_devices_ou_batch(
+        &self,
+        batch: &mut crate::batch::Batch,
+        customer_id: &str,
+        org_unit_path: &str,
+        body: &crate::types::ChromeOsMoveDevicesOu,
+    ) -> Result<crate::batch::BatchCall<()>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !org_unit_path.is_empty() {
+            query_args.push(("orgUnitPath".to_string(), org_unit_path.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/admin/directory/v1/customer/{}/devices/chromeos/moveDevicesToOu?{}",
+            crate::progenitor_support::encode_path(&customer_id.to_string()),
+            query_
+        );
+
+        Ok(batch.add(
+            reqwest::Method::POST,
+            &url,
+            Some(serde_json::to_string(body)?),
+        ))
+    }
+
     /**
      * This function performs a `GET` to the `/admin/directory/v1/customer/{customerId}/devices/chromeos/{deviceId}` endpoint.
      *
@@ -209,6 +246,41 @@ impl Chromeosdevices {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Like `get`, but adds the call to a `Batch` to be sent with others, instead of sending it.
+     *
+     * This function performs a `GET` to the `/admin/directory/v1/customer/{customerId}/devices/chromeos/{deviceId}` endpoint.
+     *
+     * Retrieves a Chrome OS device's properties.
+     *
+     * **Parameters:**
+     *
+     * * `customer_id: &str` -- The unique ID for the customer's Google Workspace account. As an account administrator, you can also use the `my_customer` alias to represent your account's `customerId`. The `customerId` is also returned as part of the [Users resource](/admin-sdk/directory/v1/reference/users).
+     * * `device_id: &str` -- The unique ID of the device. The `deviceId`s are returned in the response from the [chromeosdevices.list](/admin-sdk/directory/v1/reference/chromeosdevices/list) method.
+     * * `projection: crate::types::Projection` -- Determines whether the response contains the full list of properties or only a subset.
+     */
+    pub fn get_batch(
+        &self,
+        batch: &mut crate::batch::Batch,
+        customer_id: &str,
+        device_id: &str,
+        projection: crate::types::Projection,
+    ) -> Result<crate::batch::BatchCall<crate::types::ChromeOsDevice>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !projection.to_string().is_empty() {
+            query_args.push(("projection".to_string(), projection.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/admin/directory/v1/customer/{}/devices/chromeos/{}?{}",
+            crate::progenitor_support::encode_path(&customer_id.to_string()),
+            crate::progenitor_support::encode_path(&device_id.to_string()),
+            query_
+        );
+
+        Ok(batch.add(reqwest::Method::GET, &url, None))
+    }
+
     /**
      * This function performs a `PUT` to the `/admin/directory/v1/customer/{customerId}/devices/chromeos/{deviceId}` endpoint.
      *
@@ -244,6 +316,46 @@ impl Chromeosdevices {
             .await
     }
 
+    /**
+     * Like `update`, but adds the call to a `Batch` to be sent with others, instead of sending it.
+     *
+     * This function performs a `PUT` to the `/admin/directory/v1/customer/{customerId}/devices/chromeos/{deviceId}` endpoint.
+     *
+     * Updates a device's updatable properties, such as `annotatedUser`, `annotatedLocation`, `notes`, `orgUnitPath`, or `annotatedAssetId`.
+     *
+     * **Parameters:**
+     *
+     * * `customer_id: &str` -- The unique ID for the customer's Google Workspace account. As an account administrator, you can also use the `my_customer` alias to represent your account's `customerId`. The `customerId` is also returned as part of the [Users resource](/admin-sdk/directory/v1/reference/users).
+     * * `device_id: &str` -- The unique ID of the device. The `deviceId`s are returned in the response from the [chromeosdevices.list](/admin-sdk/v1/reference/chromeosdevices/list) method.
+     * * `projection: crate::types::Projection` -- Restrict information returned to a set of selected fields.
+     */
+    pub fn update_batch(
+        &self,
+        batch: &mut crate::batch::Batch,
+        customer_id: &str,
+        device_id: &str,
+        projection: crate::types::Projection,
+        body: &crate::types::ChromeOsDevice,
+    ) -> Result<crate::batch::BatchCall<crate::types::ChromeOsDevice>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !projection.to_string().is_empty() {
+            query_args.push(("projection".to_string(), projection.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/admin/directory/v1/customer/{}/devices/chromeos/{}?{}",
+            crate::progenitor_support::encode_path(&customer_id.to_string()),
+            crate::progenitor_support::encode_path(&device_id.to_string()),
+            query_
+        );
+
+        Ok(batch.add(
+            reqwest::Method::PUT,
+            &url,
+            Some(serde_json::to_string(body)?),
+        ))
+    }
+
     /**
      * This function performs a `PATCH` to the `/admin/directory/v1/customer/{customerId}/devices/chromeos/{deviceId}` endpoint.
      *
@@ -279,6 +391,46 @@ impl Chromeosdevices {
             .await
     }
 
+    /**
+     * Like `patch`, but adds the call to a `Batch` to be sent with others, instead of sending it.
+     *
+     * This function performs a `PATCH` to the `/admin/directory/v1/customer/{customerId}/devices/chromeos/{deviceId}` endpoint.
+     *
+     * Updates a device's updatable properties, such as `annotatedUser`, `annotatedLocation`, `notes`, `orgUnitPath`, or `annotatedAssetId`. This method supports [patch semantics](/admin-sdk/directory/v1/guides/performance#patch).
+     *
+     * **Parameters:**
+     *
+     * * `customer_id: &str` -- The unique ID for the customer's Google Workspace account. As an account administrator, you can also use the `my_customer` alias to represent your account's `customerId`. The `customerId` is also returned as part of the [Users resource](/admin-sdk/directory/v1/reference/users).
+     * * `device_id: &str` -- The unique ID of the device. The `deviceId`s are returned in the response from the [chromeosdevices.list](/admin-sdk/v1/reference/chromeosdevices/list) method.
+     * * `projection: crate::types::Projection` -- Restrict information returned to a set of selected fields.
+     */
+    pub fn patch_batch(
+        &self,
+        batch: &mut crate::batch::Batch,
+        customer_id: &str,
+        device_id: &str,
+        projection: crate::types::Projection,
+        body: &crate::types::ChromeOsDevice,
+    ) -> Result<crate::batch::BatchCall<crate::types::ChromeOsDevice>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !projection.to_string().is_empty() {
+            query_args.push(("projection".to_string(), projection.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/admin/directory/v1/customer/{}/devices/chromeos/{}?{}",
+            crate::progenitor_support::encode_path(&customer_id.to_string()),
+            crate::progenitor_support::encode_path(&device_id.to_string()),
+            query_
+        );
+
+        Ok(batch.add(
+            reqwest::Method::PATCH,
+            &url,
+            Some(serde_json::to_string(body)?),
+        ))
+    }
+
     /**
      * This function performs a `POST` to the `/admin/directory/v1/customer/{customerId}/devices/chromeos/{resourceId}/action` endpoint.
      *
@@ -305,4 +457,36 @@ impl Chromeosdevices {
             .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
             .await
     }
+
+    /**
+     * Like `action`, but adds the call to a `Batch` to be sent with others, instead of sending it.
+     *
+     * This function performs a `POST` to the `/admin/directory/v1/customer/{customerId}/devices/chromeos/{resourceId}/action` endpoint.
+     *
+     * Takes an action that affects a Chrome OS Device. This includes deprovisioning, disabling, and re-enabling devices. *Warning:* * Deprovisioning a device will stop device policy syncing and remove device-level printers. After a device is deprovisioned, it must be wiped before it can be re-enrolled. * Lost or stolen devices should use the disable action. * Re-enabling a disabled device will consume a device license. If you do not have sufficient licenses available when completing the re-enable action, you will receive an error. For more information about deprovisioning and disabling devices, visit the [help center](https://support.google.com/chrome/a/answer/3523633).
+     *
+     * **Parameters:**
+     *
+     * * `customer_id: &str` -- The unique ID for the customer's Google Workspace account. As an account administrator, you can also use the `my_customer` alias to represent your account's `customerId`. The `customerId` is also returned as part of the [Users resource](/admin-sdk/directory/v1/reference/users).
+     * * `resource_id: &str` -- The unique ID of the device. The `resourceId`s are returned in the response from the [chromeosdevices.list](/admin-sdk/directory/v1/reference/chromeosdevices/list) method.
+     */
+    pub fn action_batch(
+        &self,
+        batch: &mut crate::batch::Batch,
+        customer_id: &str,
+        resource_id: &str,
+        body: &crate::types::ChromeOsDeviceAction,
+    ) -> Result<crate::batch::BatchCall<()>> {
+        let url = format!(
+            "/admin/directory/v1/customer/{}/devices/chromeos/{}/action",
+            crate::progenitor_support::encode_path(&customer_id.to_string()),
+            crate::progenitor_support::encode_path(&resource_id.to_string()),
+        );
+
+        Ok(batch.add(
+            reqwest::Method::POST,
+            &url,
+            Some(serde_json::to_string(body)?),
+        ))
+    }
 }
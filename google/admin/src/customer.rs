@@ -39,6 +39,36 @@ impl Customer {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Like `admin_devices_chromeos_commands_get`, but adds the call to a `Batch` to be sent with others, instead of sending it.
+     *
+     * This function performs a `GET` to the `/admin/directory/v1/customer/{customerId}/devices/chromeos/{deviceId}/commands/{commandId}` endpoint.
+     *
+     * Gets command data a specific command issued to the device.
+     *
+     * **Parameters:**
+     *
+     * * `customer_id: &str` -- Immutable. Immutable ID of the Google Workspace account.
+     * * `device_id: &str` -- Immutable. Immutable ID of Chrome OS Device.
+     * * `command_id: &str` -- Immutable. Immutable ID of Chrome OS Device Command.
+     */
+    pub fn admin_devices_chromeos_commands_get_batch(
+        &self,
+        batch: &mut crate::batch::Batch,
+        customer_id: &str,
+        device_id: &str,
+        command_id: &str,
+    ) -> Result<crate::batch::BatchCall<crate::types::DirectoryChromeosdevicesCommand>> {
+        let url = format!(
+            "/admin/directory/v1/customer/{}/devices/chromeos/{}/commands/{}",
+            crate::progenitor_support::encode_path(&customer_id.to_string()),
+            crate::progenitor_support::encode_path(&device_id.to_string()),
+            crate::progenitor_support::encode_path(&command_id.to_string()),
+        );
+
+        Ok(batch.add(reqwest::Method::GET, &url, None))
+    }
+
     /**
      * This function performs a `POST` to the `/admin/directory/v1/customer/{customerId}/devices/chromeos/{deviceId}:issueCommand` endpoint.
      *
@@ -65,4 +95,37 @@ impl Customer {
             .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
             .await
     }
+
+    /**
+     * Like `admin_devices_chromeos_issue_command`, but adds the call to a `Batch` to be sent with others, instead of sending it.
+     *
+     * This function performs a `POST` to the `/admin/directory/v1/customer/{customerId}/devices/chromeos/{deviceId}:issueCommand` endpoint.
+     *
+     * Issues a command for the device to execute.
+     *
+     * **Parameters:**
+     *
+     * * `customer_id: &str` -- Immutable. Immutable ID of the Google Workspace account.
+     * * `device_id: &str` -- Immutable. Immutable ID of Chrome OS Device.
+     */
+    pub fn admin_devices_chromeos_issue_command_batch(
+        &self,
+        batch: &mut crate::batch::Batch,
+        customer_id: &str,
+        device_id: &str,
+        body: &crate::types::DirectoryChromeosdevicesIssueCommandRequest,
+    ) -> Result<crate::batch::BatchCall<crate::types::DirectoryChromeosdevicesIssueCommandResponse>>
+    {
+        let url = format!(
+            "/admin/directory/v1/customer/{}/devices/chromeos/{}/issueCommand",
+            crate::progenitor_support::encode_path(&customer_id.to_string()),
+            crate::progenitor_support::encode_path(&device_id.to_string()),
+        );
+
+        Ok(batch.add(
+            reqwest::Method::POST,
+            &url,
+            Some(serde_json::to_string(body)?),
+        ))
+    }
 }
@@ -30,6 +30,30 @@ impl Customers {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Like `get`, but adds the call to a `Batch` to be sent with others, instead of sending it.
+     *
+     * This function performs a `GET` to the `/admin/directory/v1/customers/{customerKey}` endpoint.
+     *
+     * Retrieves a customer.
+     *
+     * **Parameters:**
+     *
+     * * `customer_key: &str` -- Id of the customer to be retrieved.
+     */
+    pub fn get_batch(
+        &self,
+        batch: &mut crate::batch::Batch,
+        customer_key: &str,
+    ) -> Result<crate::batch::BatchCall<crate::types::Customer>> {
+        let url = format!(
+            "/admin/directory/v1/customers/{}",
+            crate::progenitor_support::encode_path(&customer_key.to_string()),
+        );
+
+        Ok(batch.add(reqwest::Method::GET, &url, None))
+    }
+
     /**
      * This function performs a `PUT` to the `/admin/directory/v1/customers/{customerKey}` endpoint.
      *
@@ -54,6 +78,35 @@ impl Customers {
             .await
     }
 
+    /**
+     * Like `update`, but adds the call to a `Batch` to be sent with others, instead of sending it.
+     *
+     * This function performs a `PUT` to the `/admin/directory/v1/customers/{customerKey}` endpoint.
+     *
+     * Updates a customer.
+     *
+     * **Parameters:**
+     *
+     * * `customer_key: &str` -- Id of the customer to be updated.
+     */
+    pub fn update_batch(
+        &self,
+        batch: &mut crate::batch::Batch,
+        customer_key: &str,
+        body: &crate::types::Customer,
+    ) -> Result<crate::batch::BatchCall<crate::types::Customer>> {
+        let url = format!(
+            "/admin/directory/v1/customers/{}",
+            crate::progenitor_support::encode_path(&customer_key.to_string()),
+        );
+
+        Ok(batch.add(
+            reqwest::Method::PUT,
+            &url,
+            Some(serde_json::to_string(body)?),
+        ))
+    }
+
     /**
      * This function performs a `PATCH` to the `/admin/directory/v1/customers/{customerKey}` endpoint.
      *
@@ -78,6 +131,35 @@ impl Customers {
             .await
     }
 
+    /**
+     * Like `patch`, but adds the call to a `Batch` to be sent with others, instead of sending it.
+     *
+     * This function performs a `PATCH` to the `/admin/directory/v1/customers/{customerKey}` endpoint.
+     *
+     * Patches a customer.
+     *
+     * **Parameters:**
+     *
+     * * `customer_key: &str` -- Id of the customer to be updated.
+     */
+    pub fn patch_batch(
+        &self,
+        batch: &mut crate::batch::Batch,
+        customer_key: &str,
+        body: &crate::types::Customer,
+    ) -> Result<crate::batch::BatchCall<crate::types::Customer>> {
+        let url = format!(
+            "/admin/directory/v1/customers/{}",
+            crate::progenitor_support::encode_path(&customer_key.to_string()),
+        );
+
+        Ok(batch.add(
+            reqwest::Method::PATCH,
+            &url,
+            Some(serde_json::to_string(body)?),
+        ))
+    }
+
     /**
      * This function performs a `GET` to the `/admin/directory/v1/{name}` endpoint.
      *
@@ -96,6 +178,30 @@ impl Customers {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Like `admin_chrome_printers_get`, but adds the call to a `Batch` to be sent with others, instead of sending it.
+     *
+     * This function performs a `GET` to the `/admin/directory/v1/{name}` endpoint.
+     *
+     * Returns a `Printer` resource (printer's config).
+     *
+     * **Parameters:**
+     *
+     * * `name: &str` -- Required. The name of the printer to retrieve. Format: customers/{customer_id}/chrome/printers/{printer_id}.
+     */
+    pub fn admin_chrome_printers_get_batch(
+        &self,
+        batch: &mut crate::batch::Batch,
+        name: &str,
+    ) -> Result<crate::batch::BatchCall<crate::types::Printer>> {
+        let url = format!(
+            "/admin/directory/v1/{}",
+            crate::progenitor_support::encode_path(&name.to_string()),
+        );
+
+        Ok(batch.add(reqwest::Method::GET, &url, None))
+    }
+
     /**
      * This function performs a `DELETE` to the `/admin/directory/v1/{name}` endpoint.
      *
@@ -114,6 +220,30 @@ impl Customers {
         self.client.delete(&url, None).await
     }
 
+    /**
+     * Like `admin_chrome_printers_delete`, but adds the call to a `Batch` to be sent with others, instead of sending it.
+     *
+     * This function performs a `DELETE` to the `/admin/directory/v1/{name}` endpoint.
+     *
+     * Deletes a `Printer`.
+     *
+     * **Parameters:**
+     *
+     * * `name: &str` -- Required. The name of the printer to be updated. Format: customers/{customer_id}/chrome/printers/{printer_id}.
+     */
+    pub fn admin_chrome_printers_delete_batch(
+        &self,
+        batch: &mut crate::batch::Batch,
+        name: &str,
+    ) -> Result<crate::batch::BatchCall<crate::types::Empty>> {
+        let url = format!(
+            "/admin/directory/v1/{}",
+            crate::progenitor_support::encode_path(&name.to_string()),
+        );
+
+        Ok(batch.add(reqwest::Method::DELETE, &url, None))
+    }
+
     /**
      * This function performs a `PATCH` to the `/admin/directory/v1/{name}` endpoint.
      *
@@ -151,6 +281,48 @@ impl Customers {
             .await
     }
 
+    /**
+     * Like `admin_chrome_printers_patch`, but adds the call to a `Batch` to be sent with others, instead of sending it.
+     *
+     * This function performs a `PATCH` to the `/admin/directory/v1/{name}` endpoint.
+     *
+     * Updates a `Printer` resource.
+     *
+     * **Parameters:**
+     *
+     * * `name: &str` -- The resource name of the Printer object, in the format customers/{customer-id}/printers/{printer-id} (During printer creation leave empty).
+     * * `clear_mask: &str` -- The list of fields to be cleared. Note, some of the fields are read only and cannot be updated. Values for not specified fields will be patched.
+     * * `update_mask: &str` -- The list of fields to be updated. Note, some of the fields are read only and cannot be updated. Values for not specified fields will be patched.
+     */
+    pub fn admin_chrome_printers_patch_batch(
+        &self,
+        batch: &mut crate::batch::Batch,
+        name: &str,
+        clear_mask: &str,
+        update_mask: &str,
+        body: &crate::types::Printer,
+    ) -> Result<crate::batch::BatchCall<crate::types::Printer>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !clear_mask.is_empty() {
+            query_args.push(("clearMask".to_string(), clear_mask.to_string()));
+        }
+        if !update_mask.is_empty() {
+            query_args.push(("updateMask".to_string(), update_mask.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/admin/directory/v1/{}?{}",
+            crate::progenitor_support::encode_path(&name.to_string()),
+            query_
+        );
+
+        Ok(batch.add(
+            reqwest::Method::PATCH,
+            &url,
+            Some(serde_json::to_string(body)?),
+        ))
+    }
+
     /**
      * This function performs a `GET` to the `/admin/directory/v1/{parent}/chrome/printers` endpoint.
      *
@@ -281,6 +453,35 @@ impl Customers {
             .await
     }
 
+    /**
+     * Like `admin_chrome_printers_create`, but adds the call to a `Batch` to be sent with others, instead of sending it.
+     *
+     * This function performs a `POST` to the `/admin/directory/v1/{parent}/chrome/printers` endpoint.
+     *
+     * Creates a printer under given Organization Unit.
+     *
+     * **Parameters:**
+     *
+     * * `parent: &str` -- Required. The name of the customer. Format: customers/{customer_id}.
+     */
+    pub fn admin_chrome_printers_create_batch(
+        &self,
+        batch: &mut crate::batch::Batch,
+        parent: &str,
+        body: &crate::types::Printer,
+    ) -> Result<crate::batch::BatchCall<crate::types::Printer>> {
+        let url = format!(
+            "/admin/directory/v1/{}/chrome/printers",
+            crate::progenitor_support::encode_path(&parent.to_string()),
+        );
+
+        Ok(batch.add(
+            reqwest::Method::POST,
+            &url,
+            Some(serde_json::to_string(body)?),
+        ))
+    }
+
     /**
      * This function performs a `POST` to the `/admin/directory/v1/{parent}/chrome/printers:batchCreatePrinters` endpoint.
      *
@@ -305,6 +506,35 @@ impl Customers {
             .await
     }
 
+    /**
+     * Like `admin_chrome_printers_batch_create`, but adds the call to a `Batch` to be sent with others, instead of sending it.
+     *
+     * This function performs a `POST` to the `/admin/directory/v1/{parent}/chrome/printers:batchCreatePrinters` endpoint.
+     *
+     * Creates printers under given Organization Unit.
+     *
+     * **Parameters:**
+     *
+     * * `parent: &str` -- Required. The name of the customer. Format: customers/{customer_id}.
+     */
+    pub fn admin_chrome_printers_batch_create_batch(
+        &self,
+        batch: &mut crate::batch::Batch,
+        parent: &str,
+        body: &crate::types::BatchCreatePrintersRequest,
+    ) -> Result<crate::batch::BatchCall<crate::types::BatchCreatePrintersResponse>> {
+        let url = format!(
+            "/admin/directory/v1/{}/chrome/printers:batchCreatePrinters",
+            crate::progenitor_support::encode_path(&parent.to_string()),
+        );
+
+        Ok(batch.add(
+            reqwest::Method::POST,
+            &url,
+            Some(serde_json::to_string(body)?),
+        ))
+    }
+
     /**
      * This function performs a `POST` to the `/admin/directory/v1/{parent}/chrome/printers:batchDeletePrinters` endpoint.
      *
@@ -329,6 +559,35 @@ impl Customers {
             .await
     }
 
+    /**
+     * Like `admin_chrome_printers_batch_delete`, but adds the call to a `Batch` to be sent with others, instead of sending it.
+     *
+     * This function performs a `POST` to the `/admin/directory/v1/{parent}/chrome/printers:batchDeletePrinters` endpoint.
+     *
+     * Deletes printers in batch.
+     *
+     * **Parameters:**
+     *
+     * * `parent: &str` -- Required. The name of the customer. Format: customers/{customer_id}.
+     */
+    pub fn admin_chrome_printers_batch_delete_batch(
+        &self,
+        batch: &mut crate::batch::Batch,
+        parent: &str,
+        body: &crate::types::BatchDeletePrintersRequest,
+    ) -> Result<crate::batch::BatchCall<crate::types::BatchDeletePrintersResponse>> {
+        let url = format!(
+            "/admin/directory/v1/{}/chrome/printers:batchDeletePrinters",
+            crate::progenitor_support::encode_path(&parent.to_string()),
+        );
+
+        Ok(batch.add(
+            reqwest::Method::POST,
+            &url,
+            Some(serde_json::to_string(body)?),
+        ))
+    }
+
     /**
      * This function performs a `GET` to the `/admin/directory/v1/{parent}/chrome/printers:listPrinterModels` endpoint.
      *
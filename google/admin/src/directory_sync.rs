@@ -0,0 +1,941 @@
+//! Reconcile the directory with a desired state of users and groups.
+//!
+//! The desired state, usually built from an HR system, lists every user that
+//! should be active, with their org unit, aliases, custom schema fields and group
+//! memberships. `DirectorySync::plan` compares it with the live directory and
+//! returns the changes to make, which can be printed for a dry run, and
+//! `DirectorySync::apply` makes them.
+//!
+//! ```no_run
+//! # async fn run(client: gsuite_api::Client) -> anyhow::Result<()> {
+//! use gsuite_api::directory_sync::{
+//!     DesiredGroup, DesiredState, DesiredUser, DirectorySync, MemberRole,
+//! };
+//!
+//! let state = DesiredState {
+//!     users: vec![DesiredUser::new("alice@example.com", "Alice", "Smith")
+//!         .with_org_unit("/Engineering")
+//!         .with_alias("asmith@example.com")
+//!         .with_custom_field("Employment", "employeeId", serde_json::json!("E123"))
+//!         .with_group("eng@example.com", MemberRole::Owner)],
+//!     groups: vec![DesiredGroup::new("eng@example.com", "Engineering")],
+//! };
+//!
+//! let sync = DirectorySync::new(client, "my_customer").with_concurrency(8);
+//! let plan = sync.plan(&state).await?;
+//! println!("{}", plan);
+//!
+//! let report = sync.apply(&plan).await;
+//! for failure in report.failures() {
+//!     println!("{}: {}", failure.change, failure.error.as_deref().unwrap_or_default());
+//! }
+//! # Ok(())
+//! # }
+//! ```
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+use anyhow::{bail, Result};
+use futures::stream::{self, StreamExt};
+use serde_json::{json, Value};
+
+use crate::types::{Group, Member, User};
+
+/// The number of changes applied at once by default.
+const DEFAULT_CONCURRENCY: usize = 4;
+
+/// The role of a member of a group.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum MemberRole {
+    Owner,
+    Manager,
+    Member,
+}
+
+impl std::fmt::Display for MemberRole {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MemberRole::Owner => "OWNER",
+            MemberRole::Manager => "MANAGER",
+            MemberRole::Member => "MEMBER",
+        }
+        .fmt(f)
+    }
+}
+
+impl std::str::FromStr for MemberRole {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.to_uppercase().as_str() {
+            "OWNER" => Ok(MemberRole::Owner),
+            "MANAGER" => Ok(MemberRole::Manager),
+            "MEMBER" => Ok(MemberRole::Member),
+            _ => bail!("invalid member role: {}", s),
+        }
+    }
+}
+
+/// A user that should be active in the directory.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct DesiredUser {
+    pub primary_email: String,
+    pub given_name: String,
+    pub family_name: String,
+    /// The org unit of the user, or empty to leave it as it is.
+    pub org_unit_path: String,
+    /// Every alias of the user. Other aliases are removed.
+    pub aliases: Vec<String>,
+    /// Custom schema fields, by schema and field name. Fields that aren't listed
+    /// are left as they are.
+    pub custom_schemas: HashMap<String, HashMap<String, Value>>,
+    /// The groups the user is a member of, by group email.
+    pub groups: HashMap<String, MemberRole>,
+    /// The password of a new user. A random one is used if empty.
+    pub password: String,
+}
+
+impl DesiredUser {
+    pub fn new(primary_email: &str, given_name: &str, family_name: &str) -> Self {
+        DesiredUser {
+            primary_email: primary_email.to_string(),
+            given_name: given_name.to_string(),
+            family_name: family_name.to_string(),
+            ..Default::default()
+        }
+    }
+
+    pub fn with_org_unit(mut self, org_unit_path: &str) -> Self {
+        self.org_unit_path = org_unit_path.to_string();
+        self
+    }
+
+    pub fn with_alias(mut self, alias: &str) -> Self {
+        self.aliases.push(alias.to_string());
+        self
+    }
+
+    pub fn with_custom_field(mut self, schema: &str, field: &str, value: Value) -> Self {
+        self.custom_schemas
+            .entry(schema.to_string())
+            .or_default()
+            .insert(field.to_string(), value);
+        self
+    }
+
+    pub fn with_group(mut self, group: &str, role: MemberRole) -> Self {
+        self.groups.insert(group.to_string(), role);
+        self
+    }
+
+    pub fn with_password(mut self, password: &str) -> Self {
+        self.password = password.to_string();
+        self
+    }
+}
+
+/// A group whose memberships are managed.
+///
+/// The user members of a managed group are exactly the desired users that list
+/// it; other users are removed. Members that are groups or the whole customer
+/// are left as they are.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct DesiredGroup {
+    pub email: String,
+    pub name: String,
+    /// The description of the group, or empty to leave it as it is.
+    pub description: String,
+}
+
+impl DesiredGroup {
+    pub fn new(email: &str, name: &str) -> Self {
+        DesiredGroup {
+            email: email.to_string(),
+            name: name.to_string(),
+            description: String::new(),
+        }
+    }
+
+    pub fn with_description(mut self, description: &str) -> Self {
+        self.description = description.to_string();
+        self
+    }
+}
+
+/// The users and groups the directory should have.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct DesiredState {
+    pub users: Vec<DesiredUser>,
+    pub groups: Vec<DesiredGroup>,
+}
+
+/// A snapshot of the live directory.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Directory {
+    pub users: Vec<User>,
+    pub groups: Vec<Group>,
+    /// The members of the managed groups that exist, by group email.
+    pub members: HashMap<String, Vec<Member>>,
+}
+
+/// A change to make to the directory.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Change {
+    InsertUser {
+        email: String,
+        body: Value,
+    },
+    /// Update the `fields` of a user, with only those fields set in `body`.
+    ///
+    /// `email` is the primary email the user has once patched, which the changes
+    /// to its aliases and memberships use, and `user_key` is the one it's patched
+    /// by. They differ when the user is renamed.
+    PatchUser {
+        email: String,
+        user_key: String,
+        fields: Vec<String>,
+        body: Value,
+    },
+    SuspendUser {
+        email: String,
+    },
+    InsertAlias {
+        email: String,
+        alias: String,
+    },
+    DeleteAlias {
+        email: String,
+        alias: String,
+    },
+    InsertGroup {
+        email: String,
+        body: Value,
+    },
+    PatchGroup {
+        email: String,
+        fields: Vec<String>,
+        body: Value,
+    },
+    AddMember {
+        group: String,
+        email: String,
+        role: MemberRole,
+    },
+    /// Change the role of a member, by the primary email of the user rather than
+    /// the alias they may have been added by.
+    UpdateMemberRole {
+        group: String,
+        email: String,
+        role: MemberRole,
+    },
+    /// Remove a member, by the primary email of the user like `UpdateMemberRole`.
+    RemoveMember {
+        group: String,
+        email: String,
+    },
+}
+
+impl Change {
+    /// Changes to aliases and memberships need their user and group to exist, so
+    /// they're made after the rest.
+    fn is_dependent(&self) -> bool {
+        matches!(
+            self,
+            Change::InsertAlias { .. }
+                | Change::DeleteAlias { .. }
+                | Change::AddMember { .. }
+                | Change::UpdateMemberRole { .. }
+                | Change::RemoveMember { .. }
+        )
+    }
+
+    /// The users and groups the change depends on.
+    fn dependencies(&self) -> Vec<&str> {
+        match self {
+            Change::InsertAlias { email, .. } | Change::DeleteAlias { email, .. } => {
+                vec![email]
+            }
+            Change::AddMember { group, email, .. }
+            | Change::UpdateMemberRole { group, email, .. }
+            | Change::RemoveMember { group, email } => vec![group, email],
+            _ => Vec::new(),
+        }
+    }
+
+    /// The user or group the change creates or updates.
+    fn target(&self) -> Option<&str> {
+        match self {
+            Change::InsertUser { email, .. }
+            | Change::PatchUser { email, .. }
+            | Change::SuspendUser { email }
+            | Change::InsertGroup { email, .. }
+            | Change::PatchGroup { email, .. } => Some(email),
+            _ => None,
+        }
+    }
+}
+
+impl std::fmt::Display for Change {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Change::InsertUser { email, body } => match body["orgUnitPath"].as_str() {
+                Some(org_unit) => write!(f, "+ user {} in {}", email, org_unit),
+                None => write!(f, "+ user {}", email),
+            },
+            Change::PatchUser {
+                email,
+                user_key,
+                fields,
+                ..
+            } if key(email) != key(user_key) => {
+                write!(
+                    f,
+                    "~ user {} (was {}): {}",
+                    email,
+                    user_key,
+                    fields.join(", ")
+                )
+            }
+            Change::PatchUser { email, fields, .. } => {
+                write!(f, "~ user {}: {}", email, fields.join(", "))
+            }
+            Change::SuspendUser { email } => write!(f, "- user {} (suspend)", email),
+            Change::InsertAlias { email, alias } => {
+                write!(f, "+ alias {} of {}", alias, email)
+            }
+            Change::DeleteAlias { email, alias } => {
+                write!(f, "- alias {} of {}", alias, email)
+            }
+            Change::InsertGroup { email, .. } => write!(f, "+ group {}", email),
+            Change::PatchGroup { email, fields, .. } => {
+                write!(f, "~ group {}: {}", email, fields.join(", "))
+            }
+            Change::AddMember { group, email, role } => {
+                write!(f, "+ member {} of {} as {}", email, group, role)
+            }
+            Change::UpdateMemberRole { group, email, role } => {
+                write!(f, "~ member {} of {} as {}", email, group, role)
+            }
+            Change::RemoveMember { group, email } => {
+                write!(f, "- member {} of {}", email, group)
+            }
+        }
+    }
+}
+
+/// The changes to make to the directory, in the order they're applied.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Plan {
+    pub changes: Vec<Change>,
+}
+
+impl Plan {
+    /// Returns if the directory is already in the desired state.
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+}
+
+impl std::fmt::Display for Plan {
+    /// A line for each change, like `+ user alice@example.com in /Engineering`.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for change in &self.changes {
+            writeln!(f, "{}", change)?;
+        }
+        Ok(())
+    }
+}
+
+/// What to do with users that aren't in the desired state.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SyncOptions {
+    /// Suspend active users that aren't in the desired state. Admins are never
+    /// suspended.
+    pub suspend_missing_users: bool,
+    /// Only consider users in these org units, and the org units under them, or
+    /// every user if empty.
+    pub org_units: Vec<String>,
+}
+
+impl Default for SyncOptions {
+    fn default() -> Self {
+        SyncOptions {
+            suspend_missing_users: true,
+            org_units: Vec::new(),
+        }
+    }
+}
+
+impl SyncOptions {
+    fn manages(&self, user: &User) -> bool {
+        self.org_units.is_empty()
+            || self.org_units.iter().any(|o| {
+                let o = o.trim_end_matches('/');
+                user.org_unit_path == o || user.org_unit_path.starts_with(&format!("{}/", o))
+            })
+    }
+}
+
+/// Compute the changes that bring the `live` directory to the `desired` state.
+pub fn diff(live: &Directory, desired: &DesiredState, options: &SyncOptions) -> Result<Plan> {
+    let groups: HashMap<String, &DesiredGroup> =
+        desired.groups.iter().map(|g| (key(&g.email), g)).collect();
+    let mut emails = HashSet::new();
+    for user in &desired.users {
+        if !emails.insert(key(&user.primary_email)) {
+            bail!("user {} is in the desired state twice", user.primary_email);
+        }
+        for group in user.groups.keys() {
+            if !groups.contains_key(&key(group)) {
+                bail!(
+                    "user {} is a member of {}, which is not a desired group",
+                    user.primary_email,
+                    group
+                );
+            }
+        }
+    }
+
+    let mut changes = Vec::new();
+
+    let live_groups: HashMap<String, &Group> =
+        live.groups.iter().map(|g| (key(&g.email), g)).collect();
+    for group in &desired.groups {
+        match live_groups.get(&key(&group.email)) {
+            None => {
+                let mut body = json!({"email": group.email, "name": group.name});
+                if !group.description.is_empty() {
+                    body["description"] = json!(group.description);
+                }
+                changes.push(Change::InsertGroup {
+                    email: group.email.to_string(),
+                    body,
+                });
+            }
+            Some(existing) => {
+                let mut body = json!({});
+                if existing.name != group.name {
+                    body["name"] = json!(group.name);
+                }
+                if !group.description.is_empty() && existing.description != group.description {
+                    body["description"] = json!(group.description);
+                }
+                if let Some(fields) = patched_fields(&body) {
+                    changes.push(Change::PatchGroup {
+                        email: group.email.to_string(),
+                        fields,
+                        body,
+                    });
+                }
+            }
+        }
+    }
+
+    let live_users: HashMap<String, &User> = live
+        .users
+        .iter()
+        .flat_map(|u| {
+            // Find users by any of their aliases too, so renames are patches.
+            std::iter::once(&u.primary_email)
+                .chain(u.aliases.iter())
+                .map(move |e| (key(e), u))
+        })
+        .collect();
+    let mut desired_users = HashSet::new();
+    for user in &desired.users {
+        match live_users.get(&key(&user.primary_email)) {
+            None => {
+                changes.push(Change::InsertUser {
+                    email: user.primary_email.to_string(),
+                    body: insert_body(user),
+                });
+                for alias in &user.aliases {
+                    changes.push(Change::InsertAlias {
+                        email: user.primary_email.to_string(),
+                        alias: alias.to_string(),
+                    });
+                }
+            }
+            Some(existing) => {
+                desired_users.insert(key(&existing.primary_email));
+                if let Some((fields, body)) = patch_body(existing, user) {
+                    changes.push(Change::PatchUser {
+                        email: user.primary_email.to_string(),
+                        user_key: existing.primary_email.to_string(),
+                        fields,
+                        body,
+                    });
+                }
+
+                let live_aliases: HashSet<String> = existing
+                    .aliases
+                    .iter()
+                    .map(|a| key(a))
+                    .filter(|a| *a != key(&user.primary_email))
+                    .collect();
+                let aliases: HashSet<String> = user.aliases.iter().map(|a| key(a)).collect();
+                for alias in &user.aliases {
+                    if !live_aliases.contains(&key(alias)) {
+                        changes.push(Change::InsertAlias {
+                            email: user.primary_email.to_string(),
+                            alias: alias.to_string(),
+                        });
+                    }
+                }
+                for alias in &existing.aliases {
+                    if !aliases.contains(&key(alias)) && key(alias) != key(&user.primary_email) {
+                        changes.push(Change::DeleteAlias {
+                            email: user.primary_email.to_string(),
+                            alias: alias.to_string(),
+                        });
+                    }
+                }
+            }
+        }
+    }
+
+    if options.suspend_missing_users {
+        for user in &live.users {
+            if !user.suspended
+                && !user.is_admin
+                && options.manages(user)
+                && !desired_users.contains(&key(&user.primary_email))
+            {
+                changes.push(Change::SuspendUser {
+                    email: user.primary_email.to_string(),
+                });
+            }
+        }
+    }
+
+    // Members are found by their primary email or an alias, but compared by the
+    // primary email of the user.
+    let primary_emails: HashMap<String, String> = live_users
+        .iter()
+        .map(|(e, u)| (e.to_string(), key(&u.primary_email)))
+        .collect();
+    let primary = |email: &str| -> String {
+        primary_emails
+            .get(&key(email))
+            .cloned()
+            .unwrap_or_else(|| key(email))
+    };
+    // Membership changes are made by primary email, since an alias a member was
+    // added by may be deleted at the same time. Desired users already have their
+    // new primary email by then.
+    let desired_emails: HashMap<String, &str> = desired
+        .users
+        .iter()
+        .map(|u| (primary(&u.primary_email), u.primary_email.as_str()))
+        .collect();
+    let member_email = |primary_email: &str, member: &Member| -> String {
+        match desired_emails.get(primary_email) {
+            Some(email) => email.to_string(),
+            None => live_users
+                .get(&key(&member.email))
+                .map(|u| u.primary_email.to_string())
+                .unwrap_or_else(|| member.email.to_string()),
+        }
+    };
+    for group in &desired.groups {
+        let mut wanted: BTreeMap<String, (&str, MemberRole)> = BTreeMap::new();
+        for user in &desired.users {
+            if let Some(role) = user
+                .groups
+                .iter()
+                .find(|(g, _)| key(g) == key(&group.email))
+                .map(|(_, r)| *r)
+            {
+                wanted.insert(primary(&user.primary_email), (&user.primary_email, role));
+            }
+        }
+
+        let members = live
+            .members
+            .get(&key(&group.email))
+            .map(|m| m.as_slice())
+            .unwrap_or_default();
+        let mut existing: HashMap<String, &Member> = HashMap::new();
+        for member in members {
+            if member.type_.is_empty() || member.type_ == "USER" {
+                existing.insert(primary(&member.email), member);
+            }
+        }
+
+        for (primary_email, (email, role)) in &wanted {
+            match existing.get(primary_email) {
+                None => changes.push(Change::AddMember {
+                    group: group.email.to_string(),
+                    email: email.to_string(),
+                    role: *role,
+                }),
+                Some(member) if member.role.parse::<MemberRole>().ok() != Some(*role) => changes
+                    .push(Change::UpdateMemberRole {
+                        group: group.email.to_string(),
+                        email: email.to_string(),
+                        role: *role,
+                    }),
+                Some(_) => {}
+            }
+        }
+        let mut removed: Vec<String> = existing
+            .iter()
+            .filter(|(e, _)| !wanted.contains_key(*e))
+            .map(|(e, m)| member_email(e, m))
+            .collect();
+        removed.sort();
+        for email in removed {
+            changes.push(Change::RemoveMember {
+                group: group.email.to_string(),
+                email,
+            });
+        }
+    }
+
+    // Keep the independent changes first, in a stable order.
+    changes.sort_by_key(|c| c.is_dependent());
+    Ok(Plan { changes })
+}
+
+fn key(email: &str) -> String {
+    email.trim().to_lowercase()
+}
+
+fn patched_fields(body: &Value) -> Option<Vec<String>> {
+    let fields: Vec<String> = body.as_object()?.keys().cloned().collect();
+    if fields.is_empty() {
+        None
+    } else {
+        Some(fields)
+    }
+}
+
+fn insert_body(user: &DesiredUser) -> Value {
+    let mut body = json!({
+        "primaryEmail": user.primary_email,
+        "name": {"givenName": user.given_name, "familyName": user.family_name},
+        "password": if user.password.is_empty() {
+            uuid::Uuid::new_v4().to_string()
+        } else {
+            user.password.to_string()
+        },
+    });
+    if !user.org_unit_path.is_empty() {
+        body["orgUnitPath"] = json!(user.org_unit_path);
+    }
+    if !user.custom_schemas.is_empty() {
+        body["customSchemas"] = json!(user.custom_schemas);
+    }
+    body
+}
+
+/// The fields of a live user that differ from the desired user, and a body that
+/// patches them.
+fn patch_body(existing: &User, user: &DesiredUser) -> Option<(Vec<String>, Value)> {
+    let mut body = json!({});
+
+    if key(&existing.primary_email) != key(&user.primary_email) {
+        body["primaryEmail"] = json!(user.primary_email);
+    }
+    let (given_name, family_name) = existing
+        .name
+        .as_ref()
+        .map(|n| (n.given_name.as_str(), n.family_name.as_str()))
+        .unwrap_or_default();
+    if given_name != user.given_name || family_name != user.family_name {
+        body["name"] = json!({"givenName": user.given_name, "familyName": user.family_name});
+    }
+    if !user.org_unit_path.is_empty() && existing.org_unit_path != user.org_unit_path {
+        body["orgUnitPath"] = json!(user.org_unit_path);
+    }
+    if existing.suspended {
+        body["suspended"] = json!(false);
+    }
+
+    let mut schemas = serde_json::Map::new();
+    for (schema, fields) in &user.custom_schemas {
+        let live = existing.custom_schemas.get(schema);
+        let changed: serde_json::Map<String, Value> = fields
+            .iter()
+            .filter(|(field, value)| live.and_then(|l| l.get(*field)) != Some(value))
+            .map(|(field, value)| (field.to_string(), value.clone()))
+            .collect();
+        if !changed.is_empty() {
+            schemas.insert(schema.to_string(), Value::Object(changed));
+        }
+    }
+    if !schemas.is_empty() {
+        body["customSchemas"] = Value::Object(schemas);
+    }
+
+    patched_fields(&body).map(|fields| (fields, body))
+}
+
+/// The result of applying a change.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChangeResult {
+    pub change: Change,
+    /// Why the change failed, or `None` if it was made.
+    pub error: Option<String>,
+}
+
+/// The results of applying a plan, in the order of its changes.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SyncReport {
+    pub results: Vec<ChangeResult>,
+}
+
+impl SyncReport {
+    /// The changes that failed, or were skipped because a change they depend on
+    /// failed.
+    pub fn failures(&self) -> impl Iterator<Item = &ChangeResult> {
+        self.results.iter().filter(|r| r.error.is_some())
+    }
+
+    /// Returns if every change was made.
+    pub fn is_success(&self) -> bool {
+        self.failures().next().is_none()
+    }
+}
+
+/// Plan and apply changes to the users and groups of a customer.
+#[derive(Clone)]
+pub struct DirectorySync {
+    client: crate::Client,
+    customer: String,
+    concurrency: usize,
+    options: SyncOptions,
+}
+
+impl DirectorySync {
+    /// Sync the directory of `customer`, like `my_customer` for the customer of
+    /// the authenticated admin.
+    pub fn new(client: crate::Client, customer: &str) -> Self {
+        DirectorySync {
+            client,
+            customer: customer.to_string(),
+            concurrency: DEFAULT_CONCURRENCY,
+            options: Default::default(),
+        }
+    }
+
+    /// Set how many changes are made, and groups fetched, at once.
+    pub fn with_concurrency(mut self, concurrency: usize) -> Self {
+        self.concurrency = concurrency.max(1);
+        self
+    }
+
+    pub fn with_options(mut self, options: SyncOptions) -> Self {
+        self.options = options;
+        self
+    }
+
+    /// Fetch the users and groups of the customer, and the members of the
+    /// desired groups that exist.
+    pub async fn fetch(&self, desired: &DesiredState) -> Result<Directory> {
+        let users = self
+            .client
+            .users()
+            .list_all(
+                &self.customer,
+                "",
+                crate::types::Event::Noop,
+                crate::types::DirectoryUsersListOrderBy::Email,
+                crate::types::DirectoryUsersListProjection::Full,
+                "",
+                "",
+                crate::types::SortOrder::Noop,
+                crate::types::ViewType::AdminView,
+            )
+            .await?;
+        let groups = self
+            .client
+            .groups()
+            .list_all(
+                &self.customer,
+                "",
+                crate::types::DirectoryGroupsListOrderBy::Email,
+                "",
+                crate::types::SortOrder::Noop,
+                "",
+            )
+            .await?;
+
+        let existing: HashSet<String> = groups.iter().map(|g| key(&g.email)).collect();
+        let managed: Vec<String> = desired
+            .groups
+            .iter()
+            .map(|g| key(&g.email))
+            .filter(|g| existing.contains(g))
+            .collect();
+        let members: Vec<Result<(String, Vec<Member>)>> = stream::iter(managed)
+            .map(|group| async move {
+                let members = self.client.members().list_all(&group, false, "").await?;
+                Ok((group, members))
+            })
+            .buffer_unordered(self.concurrency)
+            .collect()
+            .await;
+
+        Ok(Directory {
+            users,
+            groups,
+            members: members.into_iter().collect::<Result<_>>()?,
+        })
+    }
+
+    /// Compute the changes that bring the directory to the desired state, without
+    /// making them.
+    pub async fn plan(&self, desired: &DesiredState) -> Result<Plan> {
+        let live = self.fetch(desired).await?;
+        diff(&live, desired, &self.options)
+    }
+
+    /// Make the changes of a plan, reporting the result of each one.
+    ///
+    /// Users and groups are inserted, patched and suspended first, then aliases
+    /// and memberships are changed. Changes to aliases and memberships of users or
+    /// groups that failed to be inserted or patched are skipped.
+    pub async fn apply(&self, plan: &Plan) -> SyncReport {
+        let (first, second): (Vec<&Change>, Vec<&Change>) =
+            plan.changes.iter().partition(|c| !c.is_dependent());
+
+        let mut results = self.apply_all(first, &HashSet::new()).await;
+        let failed: HashSet<String> = results
+            .iter()
+            .filter(|r| r.error.is_some())
+            .filter_map(|r| r.change.target().map(key))
+            .collect();
+        results.extend(self.apply_all(second, &failed).await);
+
+        SyncReport { results }
+    }
+
+    async fn apply_all(
+        &self,
+        changes: Vec<&Change>,
+        failed: &HashSet<String>,
+    ) -> Vec<ChangeResult> {
+        let mut results: Vec<(usize, ChangeResult)> = stream::iter(changes.into_iter().enumerate())
+            .map(|(i, change)| async move {
+                let error = match change
+                    .dependencies()
+                    .iter()
+                    .find(|d| failed.contains(&key(d)))
+                {
+                    Some(dependency) => Some(format!("skipped because {} failed", dependency)),
+                    None => self.apply_change(change).await.err().map(|e| e.to_string()),
+                };
+                (
+                    i,
+                    ChangeResult {
+                        change: change.clone(),
+                        error,
+                    },
+                )
+            })
+            .buffer_unordered(self.concurrency)
+            .collect()
+            .await;
+
+        results.sort_by_key(|(i, _)| *i);
+        results.into_iter().map(|(_, r)| r).collect()
+    }
+
+    async fn apply_change(&self, change: &Change) -> Result<()> {
+        // Bodies are sent as JSON, since the generated types would also send every
+        // boolean they have, like `includeInGlobalAddressList: false`.
+        match change {
+            Change::InsertUser { body, .. } => {
+                let _: User = self
+                    .client
+                    .post("/admin/directory/v1/users", Some(json_body(body)?))
+                    .await?;
+            }
+            Change::PatchUser { user_key, body, .. } => {
+                let _: User = self
+                    .client
+                    .patch(&user_url(user_key), Some(json_body(body)?))
+                    .await?;
+            }
+            Change::SuspendUser { email } => {
+                let body = json!({"suspended": true});
+                let _: User = self
+                    .client
+                    .patch(&user_url(email), Some(json_body(&body)?))
+                    .await?;
+            }
+            Change::InsertAlias { email, alias } => {
+                let body = json!({ "alias": alias });
+                let _: crate::types::Alias = self
+                    .client
+                    .post(
+                        &format!("{}/aliases", user_url(email)),
+                        Some(json_body(&body)?),
+                    )
+                    .await?;
+            }
+            Change::DeleteAlias { email, alias } => {
+                self.client.users().aliases_delete(email, alias).await?;
+            }
+            Change::InsertGroup { body, .. } => {
+                let _: Group = self
+                    .client
+                    .post("/admin/directory/v1/groups", Some(json_body(body)?))
+                    .await?;
+            }
+            Change::PatchGroup { email, body, .. } => {
+                let _: Group = self
+                    .client
+                    .patch(&group_url(email), Some(json_body(body)?))
+                    .await?;
+            }
+            Change::AddMember { group, email, role } => {
+                let body = json!({"email": email, "role": role.to_string()});
+                let _: Member = self
+                    .client
+                    .post(
+                        &format!("{}/members", group_url(group)),
+                        Some(json_body(&body)?),
+                    )
+                    .await?;
+            }
+            Change::UpdateMemberRole { group, email, role } => {
+                let body = json!({ "role": role.to_string() });
+                let _: Member = self
+                    .client
+                    .patch(
+                        &format!(
+                            "{}/members/{}",
+                            group_url(group),
+                            crate::progenitor_support::encode_path(email)
+                        ),
+                        Some(json_body(&body)?),
+                    )
+                    .await?;
+            }
+            Change::RemoveMember { group, email } => {
+                self.client.members().delete(group, email).await?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn json_body(body: &Value) -> Result<reqwest::Body> {
+    Ok(reqwest::Body::from(serde_json::to_vec(body)?))
+}
+
+fn user_url(email: &str) -> String {
+    format!(
+        "/admin/directory/v1/users/{}",
+        crate::progenitor_support::encode_path(email)
+    )
+}
+
+fn group_url(email: &str) -> String {
+    format!(
+        "/admin/directory/v1/groups/{}",
+        crate::progenitor_support::encode_path(email)
+    )
+}
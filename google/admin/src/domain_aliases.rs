@@ -44,6 +44,41 @@ impl DomainAliases {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Like `list`, but adds the call to a `Batch` to be sent with others, instead of sending it.
+     *
+     * This function performs a `GET` to the `/admin/directory/v1/customer/{customer}/domainaliases` endpoint.
+     *
+     * Lists the domain aliases of the customer.
+     *
+     * **Parameters:**
+     *
+     * * `customer: &str` -- Immutable ID of the Google Workspace account.
+     * * `parent_domain_name: &str` -- Name of the parent domain for which domain aliases are to be fetched.
+     */
+    pub fn list_batch(
+        &self,
+        batch: &mut crate::batch::Batch,
+        customer: &str,
+        parent_domain_name: &str,
+    ) -> Result<crate::batch::BatchCall<crate::types::DomainAliases>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !parent_domain_name.is_empty() {
+            query_args.push((
+                "parentDomainName".to_string(),
+                parent_domain_name.to_string(),
+            ));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/admin/directory/v1/customer/{}/domainaliases?{}",
+            crate::progenitor_support::encode_path(&customer.to_string()),
+            query_
+        );
+
+        Ok(batch.add(reqwest::Method::GET, &url, None))
+    }
+
     /**
      * This function performs a `POST` to the `/admin/directory/v1/customer/{customer}/domainaliases` endpoint.
      *
@@ -68,6 +103,35 @@ impl DomainAliases {
             .await
     }
 
+    /**
+     * Like `insert`, but adds the call to a `Batch` to be sent with others, instead of sending it.
+     *
+     * This function performs a `POST` to the `/admin/directory/v1/customer/{customer}/domainaliases` endpoint.
+     *
+     * Inserts a domain alias of the customer.
+     *
+     * **Parameters:**
+     *
+     * * `customer: &str` -- Immutable ID of the Google Workspace account.
+     */
+    pub fn insert_batch(
+        &self,
+        batch: &mut crate::batch::Batch,
+        customer: &str,
+        body: &crate::types::DomainAlias,
+    ) -> Result<crate::batch::BatchCall<crate::types::DomainAlias>> {
+        let url = format!(
+            "/admin/directory/v1/customer/{}/domainaliases",
+            crate::progenitor_support::encode_path(&customer.to_string()),
+        );
+
+        Ok(batch.add(
+            reqwest::Method::POST,
+            &url,
+            Some(serde_json::to_string(body)?),
+        ))
+    }
+
     /**
      * This function performs a `GET` to the `/admin/directory/v1/customer/{customer}/domainaliases/{domainAliasName}` endpoint.
      *
@@ -92,6 +156,33 @@ impl DomainAliases {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Like `get`, but adds the call to a `Batch` to be sent with others, instead of sending it.
+     *
+     * This function performs a `GET` to the `/admin/directory/v1/customer/{customer}/domainaliases/{domainAliasName}` endpoint.
+     *
+     * Retrieves a domain alias of the customer.
+     *
+     * **Parameters:**
+     *
+     * * `customer: &str` -- Immutable ID of the Google Workspace account.
+     * * `domain_alias_name: &str` -- Name of domain alias to be retrieved.
+     */
+    pub fn get_batch(
+        &self,
+        batch: &mut crate::batch::Batch,
+        customer: &str,
+        domain_alias_name: &str,
+    ) -> Result<crate::batch::BatchCall<crate::types::DomainAlias>> {
+        let url = format!(
+            "/admin/directory/v1/customer/{}/domainaliases/{}",
+            crate::progenitor_support::encode_path(&customer.to_string()),
+            crate::progenitor_support::encode_path(&domain_alias_name.to_string()),
+        );
+
+        Ok(batch.add(reqwest::Method::GET, &url, None))
+    }
+
     /**
      * This function performs a `DELETE` to the `/admin/directory/v1/customer/{customer}/domainaliases/{domainAliasName}` endpoint.
      *
@@ -111,4 +202,31 @@ impl DomainAliases {
 
         self.client.delete(&url, None).await
     }
+
+    /**
+     * Like `delete`, but adds the call to a `Batch` to be sent with others, instead of sending it.
+     *
+     * This function performs a `DELETE` to the `/admin/directory/v1/customer/{customer}/domainaliases/{domainAliasName}` endpoint.
+     *
+     * Deletes a domain Alias of the customer.
+     *
+     * **Parameters:**
+     *
+     * * `customer: &str` -- Immutable ID of the Google Workspace account.
+     * * `domain_alias_name: &str` -- Name of domain alias to be retrieved.
+     */
+    pub fn delete_batch(
+        &self,
+        batch: &mut crate::batch::Batch,
+        customer: &str,
+        domain_alias_name: &str,
+    ) -> Result<crate::batch::BatchCall<()>> {
+        let url = format!(
+            "/admin/directory/v1/customer/{}/domainaliases/{}",
+            crate::progenitor_support::encode_path(&customer.to_string()),
+            crate::progenitor_support::encode_path(&domain_alias_name.to_string()),
+        );
+
+        Ok(batch.add(reqwest::Method::DELETE, &url, None))
+    }
 }
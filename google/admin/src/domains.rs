@@ -30,6 +30,30 @@ impl Domains {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Like `list`, but adds the call to a `Batch` to be sent with others, instead of sending it.
+     *
+     * This function performs a `GET` to the `/admin/directory/v1/customer/{customer}/domains` endpoint.
+     *
+     * Lists the domains of the customer.
+     *
+     * **Parameters:**
+     *
+     * * `customer: &str` -- Immutable ID of the Google Workspace account.
+     */
+    pub fn list_batch(
+        &self,
+        batch: &mut crate::batch::Batch,
+        customer: &str,
+    ) -> Result<crate::batch::BatchCall<crate::types::Domains2>> {
+        let url = format!(
+            "/admin/directory/v1/customer/{}/domains",
+            crate::progenitor_support::encode_path(&customer.to_string()),
+        );
+
+        Ok(batch.add(reqwest::Method::GET, &url, None))
+    }
+
     /**
      * This function performs a `POST` to the `/admin/directory/v1/customer/{customer}/domains` endpoint.
      *
@@ -54,6 +78,35 @@ impl Domains {
             .await
     }
 
+    /**
+     * Like `insert`, but adds the call to a `Batch` to be sent with others, instead of sending it.
+     *
+     * This function performs a `POST` to the `/admin/directory/v1/customer/{customer}/domains` endpoint.
+     *
+     * Inserts a domain of the customer.
+     *
+     * **Parameters:**
+     *
+     * * `customer: &str` -- Immutable ID of the Google Workspace account.
+     */
+    pub fn insert_batch(
+        &self,
+        batch: &mut crate::batch::Batch,
+        customer: &str,
+        body: &crate::types::Domains,
+    ) -> Result<crate::batch::BatchCall<crate::types::Domains>> {
+        let url = format!(
+            "/admin/directory/v1/customer/{}/domains",
+            crate::progenitor_support::encode_path(&customer.to_string()),
+        );
+
+        Ok(batch.add(
+            reqwest::Method::POST,
+            &url,
+            Some(serde_json::to_string(body)?),
+        ))
+    }
+
     /**
      * This function performs a `GET` to the `/admin/directory/v1/customer/{customer}/domains/{domainName}` endpoint.
      *
@@ -74,6 +127,33 @@ impl Domains {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Like `get`, but adds the call to a `Batch` to be sent with others, instead of sending it.
+     *
+     * This function performs a `GET` to the `/admin/directory/v1/customer/{customer}/domains/{domainName}` endpoint.
+     *
+     * Retrieves a domain of the customer.
+     *
+     * **Parameters:**
+     *
+     * * `customer: &str` -- Immutable ID of the Google Workspace account.
+     * * `domain_name: &str` -- Name of domain to be retrieved.
+     */
+    pub fn get_batch(
+        &self,
+        batch: &mut crate::batch::Batch,
+        customer: &str,
+        domain_name: &str,
+    ) -> Result<crate::batch::BatchCall<crate::types::Domains>> {
+        let url = format!(
+            "/admin/directory/v1/customer/{}/domains/{}",
+            crate::progenitor_support::encode_path(&customer.to_string()),
+            crate::progenitor_support::encode_path(&domain_name.to_string()),
+        );
+
+        Ok(batch.add(reqwest::Method::GET, &url, None))
+    }
+
     /**
      * This function performs a `DELETE` to the `/admin/directory/v1/customer/{customer}/domains/{domainName}` endpoint.
      *
@@ -93,4 +173,31 @@ impl Domains {
 
         self.client.delete(&url, None).await
     }
+
+    /**
+     * Like `delete`, but adds the call to a `Batch` to be sent with others, instead of sending it.
+     *
+     * This function performs a `DELETE` to the `/admin/directory/v1/customer/{customer}/domains/{domainName}` endpoint.
+     *
+     * Deletes a domain of the customer.
+     *
+     * **Parameters:**
+     *
+     * * `customer: &str` -- Immutable ID of the Google Workspace account.
+     * * `domain_name: &str` -- Name of domain to be deleted.
+     */
+    pub fn delete_batch(
+        &self,
+        batch: &mut crate::batch::Batch,
+        customer: &str,
+        domain_name: &str,
+    ) -> Result<crate::batch::BatchCall<()>> {
+        let url = format!(
+            "/admin/directory/v1/customer/{}/domains/{}",
+            crate::progenitor_support::encode_path(&customer.to_string()),
+            crate::progenitor_support::encode_path(&domain_name.to_string()),
+        );
+
+        Ok(batch.add(reqwest::Method::DELETE, &url, None))
+    }
 }
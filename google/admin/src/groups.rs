@@ -155,6 +155,27 @@ impl Groups {
             .await
     }
 
+    /**
+     * Like `insert`, but adds the call to a `Batch` to be sent with others, instead of sending it.
+     *
+     * This function performs a `POST` to the `/admin/directory/v1/groups` endpoint.
+     *
+     * Creates a group.
+     */
+    pub fn insert_batch(
+        &self,
+        batch: &mut crate::batch::Batch,
+        body: &crate::types::Group,
+    ) -> Result<crate::batch::BatchCall<crate::types::Group>> {
+        let url = "/admin/directory/v1/groups".to_string();
+
+        Ok(batch.add(
+            reqwest::Method::POST,
+            &url,
+            Some(serde_json::to_string(body)?),
+        ))
+    }
+
     /**
      * This function performs a `GET` to the `/admin/directory/v1/groups/{groupKey}` endpoint.
      *
@@ -173,6 +194,30 @@ impl Groups {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Like `get`, but adds the call to a `Batch` to be sent with others, instead of sending it.
+     *
+     * This function performs a `GET` to the `/admin/directory/v1/groups/{groupKey}` endpoint.
+     *
+     * Retrieves a group's properties.
+     *
+     * **Parameters:**
+     *
+     * * `group_key: &str` -- Identifies the group in the API request. The value can be the group's email address, group alias, or the unique group ID.
+     */
+    pub fn get_batch(
+        &self,
+        batch: &mut crate::batch::Batch,
+        group_key: &str,
+    ) -> Result<crate::batch::BatchCall<crate::types::Group>> {
+        let url = format!(
+            "/admin/directory/v1/groups/{}",
+            crate::progenitor_support::encode_path(&group_key.to_string()),
+        );
+
+        Ok(batch.add(reqwest::Method::GET, &url, None))
+    }
+
     /**
      * This function performs a `PUT` to the `/admin/directory/v1/groups/{groupKey}` endpoint.
      *
@@ -197,6 +242,35 @@ impl Groups {
             .await
     }
 
+    /**
+     * Like `update`, but adds the call to a `Batch` to be sent with others, instead of sending it.
+     *
+     * This function performs a `PUT` to the `/admin/directory/v1/groups/{groupKey}` endpoint.
+     *
+     * Updates a group's properties.
+     *
+     * **Parameters:**
+     *
+     * * `group_key: &str` -- Identifies the group in the API request. The value can be the group's email address, group alias, or the unique group ID.
+     */
+    pub fn update_batch(
+        &self,
+        batch: &mut crate::batch::Batch,
+        group_key: &str,
+        body: &crate::types::Group,
+    ) -> Result<crate::batch::BatchCall<crate::types::Group>> {
+        let url = format!(
+            "/admin/directory/v1/groups/{}",
+            crate::progenitor_support::encode_path(&group_key.to_string()),
+        );
+
+        Ok(batch.add(
+            reqwest::Method::PUT,
+            &url,
+            Some(serde_json::to_string(body)?),
+        ))
+    }
+
     /**
      * This function performs a `DELETE` to the `/admin/directory/v1/groups/{groupKey}` endpoint.
      *
@@ -215,6 +289,30 @@ impl Groups {
         self.client.delete(&url, None).await
     }
 
+    /**
+     * Like `delete`, but adds the call to a `Batch` to be sent with others, instead of sending it.
+     *
+     * This function performs a `DELETE` to the `/admin/directory/v1/groups/{groupKey}` endpoint.
+     *
+     * Deletes a group.
+     *
+     * **Parameters:**
+     *
+     * * `group_key: &str` -- Identifies the group in the API request. The value can be the group's email address, group alias, or the unique group ID.
+     */
+    pub fn delete_batch(
+        &self,
+        batch: &mut crate::batch::Batch,
+        group_key: &str,
+    ) -> Result<crate::batch::BatchCall<()>> {
+        let url = format!(
+            "/admin/directory/v1/groups/{}",
+            crate::progenitor_support::encode_path(&group_key.to_string()),
+        );
+
+        Ok(batch.add(reqwest::Method::DELETE, &url, None))
+    }
+
     /**
      * This function performs a `PATCH` to the `/admin/directory/v1/groups/{groupKey}` endpoint.
      *
@@ -239,6 +337,35 @@ impl Groups {
             .await
     }
 
+    /**
+     * Like `patch`, but adds the call to a `Batch` to be sent with others, instead of sending it.
+     *
+     * This function performs a `PATCH` to the `/admin/directory/v1/groups/{groupKey}` endpoint.
+     *
+     * Updates a group's properties. This method supports [patch semantics](/admin-sdk/directory/v1/guides/performance#patch).
+     *
+     * **Parameters:**
+     *
+     * * `group_key: &str` -- Identifies the group in the API request. The value can be the group's email address, group alias, or the unique group ID.
+     */
+    pub fn patch_batch(
+        &self,
+        batch: &mut crate::batch::Batch,
+        group_key: &str,
+        body: &crate::types::Group,
+    ) -> Result<crate::batch::BatchCall<crate::types::Group>> {
+        let url = format!(
+            "/admin/directory/v1/groups/{}",
+            crate::progenitor_support::encode_path(&group_key.to_string()),
+        );
+
+        Ok(batch.add(
+            reqwest::Method::PATCH,
+            &url,
+            Some(serde_json::to_string(body)?),
+        ))
+    }
+
     /**
      * This function performs a `GET` to the `/admin/directory/v1/groups/{groupKey}/aliases` endpoint.
      *
@@ -257,6 +384,30 @@ impl Groups {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Like `aliases_list`, but adds the call to a `Batch` to be sent with others, instead of sending it.
+     *
+     * This function performs a `GET` to the `/admin/directory/v1/groups/{groupKey}/aliases` endpoint.
+     *
+     * Lists all aliases for a group.
+     *
+     * **Parameters:**
+     *
+     * * `group_key: &str` -- Identifies the group in the API request. The value can be the group's email address, group alias, or the unique group ID.
+     */
+    pub fn aliases_list_batch(
+        &self,
+        batch: &mut crate::batch::Batch,
+        group_key: &str,
+    ) -> Result<crate::batch::BatchCall<crate::types::Aliases>> {
+        let url = format!(
+            "/admin/directory/v1/groups/{}/aliases",
+            crate::progenitor_support::encode_path(&group_key.to_string()),
+        );
+
+        Ok(batch.add(reqwest::Method::GET, &url, None))
+    }
+
     /**
      * This function performs a `POST` to the `/admin/directory/v1/groups/{groupKey}/aliases` endpoint.
      *
@@ -281,6 +432,35 @@ impl Groups {
             .await
     }
 
+    /**
+     * Like `aliases_insert`, but adds the call to a `Batch` to be sent with others, instead of sending it.
+     *
+     * This function performs a `POST` to the `/admin/directory/v1/groups/{groupKey}/aliases` endpoint.
+     *
+     * Adds an alias for the group.
+     *
+     * **Parameters:**
+     *
+     * * `group_key: &str` -- Identifies the group in the API request. The value can be the group's email address, group alias, or the unique group ID.
+     */
+    pub fn aliases_insert_batch(
+        &self,
+        batch: &mut crate::batch::Batch,
+        group_key: &str,
+        body: &crate::types::Alias,
+    ) -> Result<crate::batch::BatchCall<crate::types::Alias>> {
+        let url = format!(
+            "/admin/directory/v1/groups/{}/aliases",
+            crate::progenitor_support::encode_path(&group_key.to_string()),
+        );
+
+        Ok(batch.add(
+            reqwest::Method::POST,
+            &url,
+            Some(serde_json::to_string(body)?),
+        ))
+    }
+
     /**
      * This function performs a `DELETE` to the `/admin/directory/v1/groups/{groupKey}/aliases/{alias}` endpoint.
      *
@@ -300,4 +480,31 @@ impl Groups {
 
         self.client.delete(&url, None).await
     }
+
+    /**
+     * Like `aliases_delete`, but adds the call to a `Batch` to be sent with others, instead of sending it.
+     *
+     * This function performs a `DELETE` to the `/admin/directory/v1/groups/{groupKey}/aliases/{alias}` endpoint.
+     *
+     * Removes an alias.
+     *
+     * **Parameters:**
+     *
+     * * `group_key: &str` -- Identifies the group in the API request. The value can be the group's email address, group alias, or the unique group ID.
+     * * `alias: &str` -- The alias to be removed.
+     */
+    pub fn aliases_delete_batch(
+        &self,
+        batch: &mut crate::batch::Batch,
+        group_key: &str,
+        alias: &str,
+    ) -> Result<crate::batch::BatchCall<()>> {
+        let url = format!(
+            "/admin/directory/v1/groups/{}/aliases/{}",
+            crate::progenitor_support::encode_path(&group_key.to_string()),
+            crate::progenitor_support::encode_path(&alias.to_string()),
+        );
+
+        Ok(batch.add(reqwest::Method::DELETE, &url, None))
+    }
 }
@@ -36,6 +36,33 @@ impl Members {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Like `has`, but adds the call to a `Batch` to be sent with others, instead of sending it.
+     *
+     * This function performs a `GET` to the `/admin/directory/v1/groups/{groupKey}/hasMember/{memberKey}` endpoint.
+     *
+     * Checks whether the given user is a member of the group. Membership can be direct or nested.
+     *
+     * **Parameters:**
+     *
+     * * `group_key: &str` -- Identifies the group in the API request. The value can be the group's email address, group alias, or the unique group ID.
+     * * `member_key: &str` -- Identifies the user member in the API request. The value can be the user's primary email address, alias, or unique ID.
+     */
+    pub fn has_batch(
+        &self,
+        batch: &mut crate::batch::Batch,
+        group_key: &str,
+        member_key: &str,
+    ) -> Result<crate::batch::BatchCall<crate::types::MembersHasMember>> {
+        let url = format!(
+            "/admin/directory/v1/groups/{}/hasMember/{}",
+            crate::progenitor_support::encode_path(&group_key.to_string()),
+            crate::progenitor_support::encode_path(&member_key.to_string()),
+        );
+
+        Ok(batch.add(reqwest::Method::GET, &url, None))
+    }
+
     /**
      * This function performs a `GET` to the `/admin/directory/v1/groups/{groupKey}/members` endpoint.
      *
@@ -172,6 +199,35 @@ impl Members {
             .await
     }
 
+    /**
+     * Like `insert`, but adds the call to a `Batch` to be sent with others, instead of sending it.
+     *
+     * This function performs a `POST` to the `/admin/directory/v1/groups/{groupKey}/members` endpoint.
+     *
+     * Adds a user to the specified group.
+     *
+     * **Parameters:**
+     *
+     * * `group_key: &str` -- Identifies the group in the API request. The value can be the group's email address, group alias, or the unique group ID.
+     */
+    pub fn insert_batch(
+        &self,
+        batch: &mut crate::batch::Batch,
+        group_key: &str,
+        body: &crate::types::Member,
+    ) -> Result<crate::batch::BatchCall<crate::types::Member>> {
+        let url = format!(
+            "/admin/directory/v1/groups/{}/members",
+            crate::progenitor_support::encode_path(&group_key.to_string()),
+        );
+
+        Ok(batch.add(
+            reqwest::Method::POST,
+            &url,
+            Some(serde_json::to_string(body)?),
+        ))
+    }
+
     /**
      * This function performs a `GET` to the `/admin/directory/v1/groups/{groupKey}/members/{memberKey}` endpoint.
      *
@@ -192,6 +248,33 @@ impl Members {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Like `get`, but adds the call to a `Batch` to be sent with others, instead of sending it.
+     *
+     * This function performs a `GET` to the `/admin/directory/v1/groups/{groupKey}/members/{memberKey}` endpoint.
+     *
+     * Retrieves a group member's properties.
+     *
+     * **Parameters:**
+     *
+     * * `group_key: &str` -- Identifies the group in the API request. The value can be the group's email address, group alias, or the unique group ID.
+     * * `member_key: &str` -- Identifies the group member in the API request. A group member can be a user or another group. The value can be the member's (group or user) primary email address, alias, or unique ID.
+     */
+    pub fn get_batch(
+        &self,
+        batch: &mut crate::batch::Batch,
+        group_key: &str,
+        member_key: &str,
+    ) -> Result<crate::batch::BatchCall<crate::types::Member>> {
+        let url = format!(
+            "/admin/directory/v1/groups/{}/members/{}",
+            crate::progenitor_support::encode_path(&group_key.to_string()),
+            crate::progenitor_support::encode_path(&member_key.to_string()),
+        );
+
+        Ok(batch.add(reqwest::Method::GET, &url, None))
+    }
+
     /**
      * This function performs a `PUT` to the `/admin/directory/v1/groups/{groupKey}/members/{memberKey}` endpoint.
      *
@@ -219,6 +302,38 @@ impl Members {
             .await
     }
 
+    /**
+     * Like `update`, but adds the call to a `Batch` to be sent with others, instead of sending it.
+     *
+     * This function performs a `PUT` to the `/admin/directory/v1/groups/{groupKey}/members/{memberKey}` endpoint.
+     *
+     * Updates the membership of a user in the specified group.
+     *
+     * **Parameters:**
+     *
+     * * `group_key: &str` -- Identifies the group in the API request. The value can be the group's email address, group alias, or the unique group ID.
+     * * `member_key: &str` -- Identifies the group member in the API request. A group member can be a user or another group. The value can be the member's (group or user) primary email address, alias, or unique ID.
+     */
+    pub fn update_batch(
+        &self,
+        batch: &mut crate::batch::Batch,
+        group_key: &str,
+        member_key: &str,
+        body: &crate::types::Member,
+    ) -> Result<crate::batch::BatchCall<crate::types::Member>> {
+        let url = format!(
+            "/admin/directory/v1/groups/{}/members/{}",
+            crate::progenitor_support::encode_path(&group_key.to_string()),
+            crate::progenitor_support::encode_path(&member_key.to_string()),
+        );
+
+        Ok(batch.add(
+            reqwest::Method::PUT,
+            &url,
+            Some(serde_json::to_string(body)?),
+        ))
+    }
+
     /**
      * This function performs a `DELETE` to the `/admin/directory/v1/groups/{groupKey}/members/{memberKey}` endpoint.
      *
@@ -239,6 +354,33 @@ impl Members {
         self.client.delete(&url, None).await
     }
 
+    /**
+     * Like `delete`, but adds the call to a `Batch` to be sent with others, instead of sending it.
+     *
+     * This function performs a `DELETE` to the `/admin/directory/v1/groups/{groupKey}/members/{memberKey}` endpoint.
+     *
+     * Removes a member from a group.
+     *
+     * **Parameters:**
+     *
+     * * `group_key: &str` -- Identifies the group in the API request. The value can be the group's email address, group alias, or the unique group ID.
+     * * `member_key: &str` -- Identifies the group member in the API request. A group member can be a user or another group. The value can be the member's (group or user) primary email address, alias, or unique ID.
+     */
+    pub fn delete_batch(
+        &self,
+        batch: &mut crate::batch::Batch,
+        group_key: &str,
+        member_key: &str,
+    ) -> Result<crate::batch::BatchCall<()>> {
+        let url = format!(
+            "/admin/directory/v1/groups/{}/members/{}",
+            crate::progenitor_support::encode_path(&group_key.to_string()),
+            crate::progenitor_support::encode_path(&member_key.to_string()),
+        );
+
+        Ok(batch.add(reqwest::Method::DELETE, &url, None))
+    }
+
     /**
      * This function performs a `PATCH` to the `/admin/directory/v1/groups/{groupKey}/members/{memberKey}` endpoint.
      *
@@ -265,4 +407,36 @@ impl Members {
             .patch(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
             .await
     }
+
+    /**
+     * Like `patch`, but adds the call to a `Batch` to be sent with others, instead of sending it.
+     *
+     * This function performs a `PATCH` to the `/admin/directory/v1/groups/{groupKey}/members/{memberKey}` endpoint.
+     *
+     * Updates the membership properties of a user in the specified group. This method supports [patch semantics](/admin-sdk/directory/v1/guides/performance#patch).
+     *
+     * **Parameters:**
+     *
+     * * `group_key: &str` -- Identifies the group in the API request. The value can be the group's email address, group alias, or the unique group ID.
+     * * `member_key: &str` -- Identifies the group member in the API request. A group member can be a user or another group. The value can be the member's (group or user) primary email address, alias, or unique ID.
+     */
+    pub fn patch_batch(
+        &self,
+        batch: &mut crate::batch::Batch,
+        group_key: &str,
+        member_key: &str,
+        body: &crate::types::Member,
+    ) -> Result<crate::batch::BatchCall<crate::types::Member>> {
+        let url = format!(
+            "/admin/directory/v1/groups/{}/members/{}",
+            crate::progenitor_support::encode_path(&group_key.to_string()),
+            crate::progenitor_support::encode_path(&member_key.to_string()),
+        );
+
+        Ok(batch.add(
+            reqwest::Method::PATCH,
+            &url,
+            Some(serde_json::to_string(body)?),
+        ))
+    }
 }
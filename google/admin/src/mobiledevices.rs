@@ -168,6 +168,41 @@ impl Mobiledevices {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Like `get`, but adds the call to a `Batch` to be sent with others, instead of sending it.
+     *
+     * This function performs a `GET` to the `/admin/directory/v1/customer/{customerId}/devices/mobile/{resourceId}` endpoint.
+     *
+     * Retrieves a mobile device's properties.
+     *
+     * **Parameters:**
+     *
+     * * `customer_id: &str` -- The unique ID for the customer's Google Workspace account. As an account administrator, you can also use the `my_customer` alias to represent your account's `customerId`. The `customerId` is also returned as part of the [Users resource](/admin-sdk/directory/v1/reference/users).
+     * * `resource_id: &str` -- The unique ID the API service uses to identify the mobile device.
+     * * `projection: crate::types::Projection` -- Restrict information returned to a set of selected fields.
+     */
+    pub fn get_batch(
+        &self,
+        batch: &mut crate::batch::Batch,
+        customer_id: &str,
+        resource_id: &str,
+        projection: crate::types::Projection,
+    ) -> Result<crate::batch::BatchCall<crate::types::MobileDevice>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !projection.to_string().is_empty() {
+            query_args.push(("projection".to_string(), projection.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/admin/directory/v1/customer/{}/devices/mobile/{}?{}",
+            crate::progenitor_support::encode_path(&customer_id.to_string()),
+            crate::progenitor_support::encode_path(&resource_id.to_string()),
+            query_
+        );
+
+        Ok(batch.add(reqwest::Method::GET, &url, None))
+    }
+
     /**
      * This function performs a `DELETE` to the `/admin/directory/v1/customer/{customerId}/devices/mobile/{resourceId}` endpoint.
      *
@@ -188,6 +223,33 @@ impl Mobiledevices {
         self.client.delete(&url, None).await
     }
 
+    /**
+     * Like `delete`, but adds the call to a `Batch` to be sent with others, instead of sending it.
+     *
+     * This function performs a `DELETE` to the `/admin/directory/v1/customer/{customerId}/devices/mobile/{resourceId}` endpoint.
+     *
+     * Removes a mobile device.
+     *
+     * **Parameters:**
+     *
+     * * `customer_id: &str` -- The unique ID for the customer's Google Workspace account. As an account administrator, you can also use the `my_customer` alias to represent your account's `customerId`. The `customerId` is also returned as part of the [Users resource](/admin-sdk/directory/v1/reference/users).
+     * * `resource_id: &str` -- The unique ID the API service uses to identify the mobile device.
+     */
+    pub fn delete_batch(
+        &self,
+        batch: &mut crate::batch::Batch,
+        customer_id: &str,
+        resource_id: &str,
+    ) -> Result<crate::batch::BatchCall<()>> {
+        let url = format!(
+            "/admin/directory/v1/customer/{}/devices/mobile/{}",
+            crate::progenitor_support::encode_path(&customer_id.to_string()),
+            crate::progenitor_support::encode_path(&resource_id.to_string()),
+        );
+
+        Ok(batch.add(reqwest::Method::DELETE, &url, None))
+    }
+
     /**
      * This function performs a `POST` to the `/admin/directory/v1/customer/{customerId}/devices/mobile/{resourceId}/action` endpoint.
      *
@@ -214,4 +276,36 @@ impl Mobiledevices {
             .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
             .await
     }
+
+    /**
+     * Like `action`, but adds the call to a `Batch` to be sent with others, instead of sending it.
+     *
+     * This function performs a `POST` to the `/admin/directory/v1/customer/{customerId}/devices/mobile/{resourceId}/action` endpoint.
+     *
+     * Takes an action that affects a mobile device. For example, remotely wiping a device.
+     *
+     * **Parameters:**
+     *
+     * * `customer_id: &str` -- The unique ID for the customer's Google Workspace account. As an account administrator, you can also use the `my_customer` alias to represent your account's `customerId`. The `customerId` is also returned as part of the [Users resource](/admin-sdk/directory/v1/reference/users).
+     * * `resource_id: &str` -- The unique ID the API service uses to identify the mobile device.
+     */
+    pub fn action_batch(
+        &self,
+        batch: &mut crate::batch::Batch,
+        customer_id: &str,
+        resource_id: &str,
+        body: &crate::types::MobileDeviceAction,
+    ) -> Result<crate::batch::BatchCall<()>> {
+        let url = format!(
+            "/admin/directory/v1/customer/{}/devices/mobile/{}/action",
+            crate::progenitor_support::encode_path(&customer_id.to_string()),
+            crate::progenitor_support::encode_path(&resource_id.to_string()),
+        );
+
+        Ok(batch.add(
+            reqwest::Method::POST,
+            &url,
+            Some(serde_json::to_string(body)?),
+        ))
+    }
 }
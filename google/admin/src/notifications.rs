@@ -0,0 +1,319 @@
+//! Manage push notification channels and parse the notifications sent to them.
+//!
+//! A `ChannelManager` creates channels with random ids and verification tokens,
+//! keeps track of when they expire so they can be renewed, and stops them on
+//! shutdown. The watch call itself is passed in as a closure, since each resource
+//! has its own `watch` function.
+//!
+//! ```no_run
+//! # async fn run(client: gsuite_api::Client) -> anyhow::Result<()> {
+//! use gsuite_api::{notifications::ChannelManager, types::Channel, Client};
+//!
+//! async fn watch(client: Client, channel: Channel) -> anyhow::Result<Channel> {
+//!     // Call the `watch` function for the resource, with `channel` as the body.
+//! #   Ok(channel)
+//! }
+//!
+//! let manager = ChannelManager::new(client, "https://example.com/notifications");
+//! let channel = manager.watch(watch).await?;
+//!
+//! // Call this periodically, e.g. at `manager.next_renewal()`.
+//! manager.renew_expiring().await?;
+//!
+//! // And stop all the channels on shutdown.
+//! manager.stop_all().await?;
+//! # Ok(())
+//! # }
+//! ```
+use std::{
+    collections::HashMap,
+    future::Future,
+    pin::Pin,
+    sync::{Arc, Mutex},
+};
+
+use anyhow::{anyhow, Result};
+
+use crate::{types::Channel, Client};
+
+type WatchFuture = Pin<Box<dyn Future<Output = Result<Channel>> + Send>>;
+type WatchFn = Arc<dyn Fn(Client, Channel) -> WatchFuture + Send + Sync>;
+
+struct WatchedChannel {
+    channel: Channel,
+    expires_at: chrono::DateTime<chrono::Utc>,
+    watch: WatchFn,
+}
+
+/// Creates, renews and stops push notification channels.
+///
+/// Clones share the same set of channels.
+#[derive(Clone)]
+pub struct ChannelManager {
+    client: Client,
+    address: String,
+    ttl: chrono::Duration,
+    renew_before: chrono::Duration,
+    channels: Arc<Mutex<HashMap<String, WatchedChannel>>>,
+}
+
+impl ChannelManager {
+    /// Create a manager for channels that deliver notifications to `address`, which
+    /// must be an HTTPS URL.
+    pub fn new<A>(client: Client, address: A) -> Self
+    where
+        A: ToString,
+    {
+        ChannelManager {
+            client,
+            address: address.to_string(),
+            ttl: chrono::Duration::hours(1),
+            renew_before: chrono::Duration::minutes(10),
+            channels: Default::default(),
+        }
+    }
+
+    /// Set how long channels should live for, which defaults to an hour. The API may
+    /// shorten this to the maximum for the resource.
+    pub fn with_ttl(mut self, ttl: chrono::Duration) -> Self {
+        self.ttl = ttl;
+        self
+    }
+
+    /// Set how long before they expire channels are renewed by `renew_expiring`,
+    /// which defaults to ten minutes.
+    pub fn with_renew_before(mut self, renew_before: chrono::Duration) -> Self {
+        self.renew_before = renew_before;
+        self
+    }
+
+    /// Create a channel with `watch`, which is called with a new channel to pass as
+    /// the body of a `watch` function. It is called again with a new channel when
+    /// the channel is renewed.
+    pub async fn watch<F, Fut>(&self, watch: F) -> Result<Channel>
+    where
+        F: Fn(Client, Channel) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<Channel>> + Send + 'static,
+    {
+        let watch: WatchFn = Arc::new(move |client, channel| Box::pin(watch(client, channel)));
+        self.create(watch).await
+    }
+
+    async fn create(&self, watch: WatchFn) -> Result<Channel> {
+        let requested_expiration = chrono::Utc::now() + self.ttl;
+        let channel: Channel = serde_json::from_value(serde_json::json!({
+            "id": uuid::Uuid::new_v4().to_string(),
+            "token": uuid::Uuid::new_v4().to_simple().to_string(),
+            "type": "web_hook",
+            "address": self.address,
+            "expiration": requested_expiration.timestamp_millis(),
+        }))?;
+
+        let mut created = watch(self.client.clone(), channel.clone()).await?;
+        // The response doesn't echo the token.
+        if created.token.is_empty() {
+            created.token = channel.token.clone();
+        }
+        let expires_at =
+            match chrono::TimeZone::timestamp_millis_opt(&chrono::Utc, created.expiration) {
+                chrono::LocalResult::Single(e) if created.expiration > 0 => e,
+                _ => requested_expiration,
+            };
+
+        self.channels.lock().unwrap().insert(
+            created.id.clone(),
+            WatchedChannel {
+                channel: created.clone(),
+                expires_at,
+                watch,
+            },
+        );
+
+        Ok(created)
+    }
+
+    /// The channels that are being managed.
+    pub fn channels(&self) -> Vec<Channel> {
+        self.channels
+            .lock()
+            .unwrap()
+            .values()
+            .map(|c| c.channel.clone())
+            .collect()
+    }
+
+    /// When the next channel should be renewed, if there are any.
+    pub fn next_renewal(&self) -> Option<chrono::DateTime<chrono::Utc>> {
+        self.channels
+            .lock()
+            .unwrap()
+            .values()
+            .map(|c| c.expires_at - self.renew_before)
+            .min()
+    }
+
+    /// Returns if a notification is for one of our channels and has its token.
+    pub fn verify(&self, notification: &ChannelNotification) -> bool {
+        match self.channels.lock().unwrap().get(&notification.channel_id) {
+            Some(c) => notification.channel_token.as_deref() == Some(c.channel.token.as_str()),
+            None => false,
+        }
+    }
+
+    /// Replace the channels that expire within the renewal window with new
+    /// channels, and stop the old ones. Returns the new channels.
+    pub async fn renew_expiring(&self) -> Result<Vec<Channel>> {
+        let cutoff = chrono::Utc::now() + self.renew_before;
+        let expiring: Vec<(String, WatchFn)> = self
+            .channels
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|(_, c)| c.expires_at <= cutoff)
+            .map(|(id, c)| (id.to_string(), c.watch.clone()))
+            .collect();
+
+        let mut renewed = Vec::with_capacity(expiring.len());
+        for (id, watch) in expiring {
+            // Create the new channel first so no notifications are missed.
+            renewed.push(self.create(watch).await?);
+            if let Err(e) = self.stop(&id).await {
+                // The old channel will expire on its own shortly.
+                log::warn!("failed to stop renewed channel {}: {}", id, e);
+            }
+        }
+
+        Ok(renewed)
+    }
+
+    /// Stop a channel.
+    pub async fn stop(&self, id: &str) -> Result<()> {
+        let channel = self
+            .channels
+            .lock()
+            .unwrap()
+            .remove(id)
+            .ok_or_else(|| anyhow!("no channel with id {}", id))?
+            .channel;
+
+        crate::channels::Channels::new(self.client.clone())
+            .admin_stop(&channel)
+            .await
+    }
+
+    /// Stop all the channels, returning the first error after trying to stop them
+    /// all.
+    pub async fn stop_all(&self) -> Result<()> {
+        let ids: Vec<String> = self.channels.lock().unwrap().keys().cloned().collect();
+
+        let mut result = Ok(());
+        for id in ids {
+            if let Err(e) = self.stop(&id).await {
+                if result.is_ok() {
+                    result = Err(e);
+                }
+            }
+        }
+
+        result
+    }
+}
+
+/// The state of the resource a notification is about.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ResourceState {
+    /// The channel was created; sent once per channel.
+    Sync,
+    Exists,
+    NotExists,
+    Add,
+    Remove,
+    Update,
+    Trash,
+    Untrash,
+    Change,
+    Delete,
+    Undelete,
+    MakeAdmin,
+    Other(String),
+}
+
+impl From<&str> for ResourceState {
+    fn from(s: &str) -> Self {
+        match s {
+            "sync" => ResourceState::Sync,
+            "exists" => ResourceState::Exists,
+            "not_exists" => ResourceState::NotExists,
+            "add" => ResourceState::Add,
+            "remove" => ResourceState::Remove,
+            "update" => ResourceState::Update,
+            "trash" => ResourceState::Trash,
+            "untrash" => ResourceState::Untrash,
+            "change" => ResourceState::Change,
+            "delete" => ResourceState::Delete,
+            "undelete" => ResourceState::Undelete,
+            "makeAdmin" => ResourceState::MakeAdmin,
+            s => ResourceState::Other(s.to_string()),
+        }
+    }
+}
+
+/// A push notification, parsed from the `X-Goog-*` headers of the request sent to
+/// the channel address.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChannelNotification {
+    pub channel_id: String,
+    pub channel_token: Option<String>,
+    pub channel_expiration: Option<chrono::DateTime<chrono::Utc>>,
+    pub message_number: i64,
+    pub resource_id: String,
+    pub resource_uri: String,
+    pub resource_state: ResourceState,
+    /// What changed about the resource, e.g. `content` or `permissions`, for
+    /// `update` notifications.
+    pub changed: Vec<String>,
+}
+
+impl ChannelNotification {
+    /// Parse a notification from request headers, as name and value pairs. Header
+    /// names are matched case insensitively.
+    pub fn from_headers<I, K, V>(headers: I) -> Result<Self>
+    where
+        I: IntoIterator<Item = (K, V)>,
+        K: AsRef<str>,
+        V: AsRef<str>,
+    {
+        let headers: HashMap<String, String> = headers
+            .into_iter()
+            .map(|(k, v)| (k.as_ref().to_lowercase(), v.as_ref().trim().to_string()))
+            .collect();
+        let get = |name: &str| headers.get(name).filter(|v| !v.is_empty()).cloned();
+        let require = |name: &str| get(name).ok_or_else(|| anyhow!("missing {} header", name));
+
+        Ok(ChannelNotification {
+            channel_id: require("x-goog-channel-id")?,
+            channel_token: get("x-goog-channel-token"),
+            channel_expiration: get("x-goog-channel-expiration")
+                .and_then(|e| chrono::DateTime::parse_from_rfc2822(&e).ok())
+                .map(|e| e.with_timezone(&chrono::Utc)),
+            message_number: require("x-goog-message-number")?
+                .parse()
+                .map_err(|e| anyhow!("invalid x-goog-message-number header: {}", e))?,
+            resource_id: require("x-goog-resource-id")?,
+            resource_uri: get("x-goog-resource-uri").unwrap_or_default(),
+            resource_state: ResourceState::from(require("x-goog-resource-state")?.as_str()),
+            changed: get("x-goog-changed")
+                .map(|c| c.split(',').map(|s| s.trim().to_string()).collect())
+                .unwrap_or_default(),
+        })
+    }
+
+    /// Parse a notification from an `http::HeaderMap`.
+    pub fn from_header_map(headers: &http::HeaderMap) -> Result<Self> {
+        Self::from_headers(
+            headers
+                .iter()
+                .filter_map(|(k, v)| v.to_str().ok().map(|v| (k.as_str(), v))),
+        )
+    }
+}
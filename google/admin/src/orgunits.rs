@@ -46,6 +46,43 @@ impl Orgunits {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Like `list`, but adds the call to a `Batch` to be sent with others, instead of sending it.
+     *
+     * This function performs a `GET` to the `/admin/directory/v1/customer/{customerId}/orgunits` endpoint.
+     *
+     * Retrieves a list of all organizational units for an account.
+     *
+     * **Parameters:**
+     *
+     * * `customer_id: &str` -- The unique ID for the customer's Google Workspace account. As an account administrator, you can also use the `my_customer` alias to represent your account's `customerId`. The `customerId` is also returned as part of the [Users resource](/admin-sdk/directory/v1/reference/users).
+     * * `org_unit_path: &str` -- The full path to the organizational unit or its unique ID. Returns the children of the specified organizational unit.
+     * * `type_: crate::types::DirectoryOrgunitsListType` -- Whether to return all sub-organizations or just immediate children.
+     */
+    pub fn list_batch(
+        &self,
+        batch: &mut crate::batch::Batch,
+        customer_id: &str,
+        org_unit_path: &str,
+        type_: crate::types::DirectoryOrgunitsListType,
+    ) -> Result<crate::batch::BatchCall<crate::types::OrgUnits>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !org_unit_path.is_empty() {
+            query_args.push(("orgUnitPath".to_string(), org_unit_path.to_string()));
+        }
+        if !type_.to_string().is_empty() {
+            query_args.push(("type".to_string(), type_.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/admin/directory/v1/customer/{}/orgunits?{}",
+            crate::progenitor_support::encode_path(&customer_id.to_string()),
+            query_
+        );
+
+        Ok(batch.add(reqwest::Method::GET, &url, None))
+    }
+
     /**
      * This function performs a `POST` to the `/admin/directory/v1/customer/{customerId}/orgunits` endpoint.
      *
@@ -70,6 +107,35 @@ impl Orgunits {
             .await
     }
 
+    /**
+     * Like `insert`, but adds the call to a `Batch` to be sent with others, instead of sending it.
+     *
+     * This function performs a `POST` to the `/admin/directory/v1/customer/{customerId}/orgunits` endpoint.
+     *
+     * Adds an organizational unit.
+     *
+     * **Parameters:**
+     *
+     * * `customer_id: &str` -- The unique ID for the customer's Google Workspace account. As an account administrator, you can also use the `my_customer` alias to represent your account's `customerId`. The `customerId` is also returned as part of the [Users resource](/admin-sdk/directory/v1/reference/users).
+     */
+    pub fn insert_batch(
+        &self,
+        batch: &mut crate::batch::Batch,
+        customer_id: &str,
+        body: &crate::types::OrgUnit,
+    ) -> Result<crate::batch::BatchCall<crate::types::OrgUnit>> {
+        let url = format!(
+            "/admin/directory/v1/customer/{}/orgunits",
+            crate::progenitor_support::encode_path(&customer_id.to_string()),
+        );
+
+        Ok(batch.add(
+            reqwest::Method::POST,
+            &url,
+            Some(serde_json::to_string(body)?),
+        ))
+    }
+
     /**
      * This function performs a `GET` to the `/admin/directory/v1/customer/{customerId}/orgunits/{orgUnitPath}` endpoint.
      *
@@ -94,6 +160,33 @@ impl Orgunits {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Like `get`, but adds the call to a `Batch` to be sent with others, instead of sending it.
+     *
+     * This function performs a `GET` to the `/admin/directory/v1/customer/{customerId}/orgunits/{orgUnitPath}` endpoint.
+     *
+     * Retrieves an organizational unit.
+     *
+     * **Parameters:**
+     *
+     * * `customer_id: &str` -- The unique ID for the customer's Google Workspace account. As an account administrator, you can also use the `my_customer` alias to represent your account's `customerId`. The `customerId` is also returned as part of the [Users resource](/admin-sdk/directory/v1/reference/users).
+     * * `org_unit_path: &str` -- The full path of the organizational unit or its unique ID.
+     */
+    pub fn get_batch(
+        &self,
+        batch: &mut crate::batch::Batch,
+        customer_id: &str,
+        org_unit_path: &str,
+    ) -> Result<crate::batch::BatchCall<crate::types::OrgUnit>> {
+        let url = format!(
+            "/admin/directory/v1/customer/{}/orgunits/{}",
+            crate::progenitor_support::encode_path(&customer_id.to_string()),
+            crate::progenitor_support::encode_path(&org_unit_path.to_string()),
+        );
+
+        Ok(batch.add(reqwest::Method::GET, &url, None))
+    }
+
     /**
      * This function performs a `PUT` to the `/admin/directory/v1/customer/{customerId}/orgunits/{orgUnitPath}` endpoint.
      *
@@ -121,6 +214,38 @@ impl Orgunits {
             .await
     }
 
+    /**
+     * Like `update`, but adds the call to a `Batch` to be sent with others, instead of sending it.
+     *
+     * This function performs a `PUT` to the `/admin/directory/v1/customer/{customerId}/orgunits/{orgUnitPath}` endpoint.
+     *
+     * Updates an organizational unit.
+     *
+     * **Parameters:**
+     *
+     * * `customer_id: &str` -- The unique ID for the customer's Google Workspace account. As an account administrator, you can also use the `my_customer` alias to represent your account's `customerId`. The `customerId` is also returned as part of the [Users resource](/admin-sdk/directory/v1/reference/users).
+     * * `org_unit_path: &str` -- The full path of the organizational unit or its unique ID.
+     */
+    pub fn update_batch(
+        &self,
+        batch: &mut crate::batch::Batch,
+        customer_id: &str,
+        org_unit_path: &str,
+        body: &crate::types::OrgUnit,
+    ) -> Result<crate::batch::BatchCall<crate::types::OrgUnit>> {
+        let url = format!(
+            "/admin/directory/v1/customer/{}/orgunits/{}",
+            crate::progenitor_support::encode_path(&customer_id.to_string()),
+            crate::progenitor_support::encode_path(&org_unit_path.to_string()),
+        );
+
+        Ok(batch.add(
+            reqwest::Method::PUT,
+            &url,
+            Some(serde_json::to_string(body)?),
+        ))
+    }
+
     /**
      * This function performs a `DELETE` to the `/admin/directory/v1/customer/{customerId}/orgunits/{orgUnitPath}` endpoint.
      *
@@ -141,6 +266,33 @@ impl Orgunits {
         self.client.delete(&url, None).await
     }
 
+    /**
+     * Like `delete`, but adds the call to a `Batch` to be sent with others, instead of sending it.
+     *
+     * This function performs a `DELETE` to the `/admin/directory/v1/customer/{customerId}/orgunits/{orgUnitPath}` endpoint.
+     *
+     * Removes an organizational unit.
+     *
+     * **Parameters:**
+     *
+     * * `customer_id: &str` -- The unique ID for the customer's Google Workspace account. As an account administrator, you can also use the `my_customer` alias to represent your account's `customerId`. The `customerId` is also returned as part of the [Users resource](/admin-sdk/directory/v1/reference/users).
+     * * `org_unit_path: &str` -- The full path of the organizational unit or its unique ID.
+     */
+    pub fn delete_batch(
+        &self,
+        batch: &mut crate::batch::Batch,
+        customer_id: &str,
+        org_unit_path: &str,
+    ) -> Result<crate::batch::BatchCall<()>> {
+        let url = format!(
+            "/admin/directory/v1/customer/{}/orgunits/{}",
+            crate::progenitor_support::encode_path(&customer_id.to_string()),
+            crate::progenitor_support::encode_path(&org_unit_path.to_string()),
+        );
+
+        Ok(batch.add(reqwest::Method::DELETE, &url, None))
+    }
+
     /**
      * This function performs a `PATCH` to the `/admin/directory/v1/customer/{customerId}/orgunits/{orgUnitPath}` endpoint.
      *
@@ -167,4 +319,36 @@ impl Orgunits {
             .patch(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
             .await
     }
+
+    /**
+     * Like `patch`, but adds the call to a `Batch` to be sent with others, instead of sending it.
+     *
+     * This function performs a `PATCH` to the `/admin/directory/v1/customer/{customerId}/orgunits/{orgUnitPath}` endpoint.
+     *
+     * Updates an organizational unit. This method supports [patch semantics](/admin-sdk/directory/v1/guides/performance#patch)
+     *
+     * **Parameters:**
+     *
+     * * `customer_id: &str` -- The unique ID for the customer's Google Workspace account. As an account administrator, you can also use the `my_customer` alias to represent your account's `customerId`. The `customerId` is also returned as part of the [Users resource](/admin-sdk/directory/v1/reference/users).
+     * * `org_unit_path: &str` -- The full path of the organizational unit or its unique ID.
+     */
+    pub fn patch_batch(
+        &self,
+        batch: &mut crate::batch::Batch,
+        customer_id: &str,
+        org_unit_path: &str,
+        body: &crate::types::OrgUnit,
+    ) -> Result<crate::batch::BatchCall<crate::types::OrgUnit>> {
+        let url = format!(
+            "/admin/directory/v1/customer/{}/orgunits/{}",
+            crate::progenitor_support::encode_path(&customer_id.to_string()),
+            crate::progenitor_support::encode_path(&org_unit_path.to_string()),
+        );
+
+        Ok(batch.add(
+            reqwest::Method::PATCH,
+            &url,
+            Some(serde_json::to_string(body)?),
+        ))
+    }
 }
@@ -29,4 +29,28 @@ impl Privileges {
 
         self.client.get(&url, None).await
     }
+
+    /**
+     * Like `list`, but adds the call to a `Batch` to be sent with others, instead of sending it.
+     *
+     * This function performs a `GET` to the `/admin/directory/v1/customer/{customer}/roles/ALL/privileges` endpoint.
+     *
+     * Retrieves a paginated list of all privileges for a customer.
+     *
+     * **Parameters:**
+     *
+     * * `customer: &str` -- Immutable ID of the Google Workspace account.
+     */
+    pub fn list_batch(
+        &self,
+        batch: &mut crate::batch::Batch,
+        customer: &str,
+    ) -> Result<crate::batch::BatchCall<crate::types::Privileges>> {
+        let url = format!(
+            "/admin/directory/v1/customer/{}/roles/ALL/privileges",
+            crate::progenitor_support::encode_path(&customer.to_string()),
+        );
+
+        Ok(batch.add(reqwest::Method::GET, &url, None))
+    }
 }
@@ -129,6 +129,46 @@ impl Resources {
             .await
     }
 
+    /**
+     * Like `buildings_insert`, but adds the call to a `Batch` to be sent with others, instead of sending it.
+     *
+     * This function performs a `POST` to the `/admin/directory/v1/customer/{customer}/resources/buildings` endpoint.
+     *
+     * Inserts a building.
+     *
+     * **Parameters:**
+     *
+     * * `customer: &str` -- The unique ID for the customer's Google Workspace account. As an account administrator, you can also use the `my_customer` alias to represent your account's customer ID.
+     * * `coordinates_source: crate::types::CoordinatesSource` -- Source from which Building.coordinates are derived.
+     */
+    pub fn buildings_insert_batch(
+        &self,
+        batch: &mut crate::batch::Batch,
+        customer: &str,
+        coordinates_source: crate::types::CoordinatesSource,
+        body: &crate::types::Building,
+    ) -> Result<crate::batch::BatchCall<crate::types::Building>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !coordinates_source.to_string().is_empty() {
+            query_args.push((
+                "coordinatesSource".to_string(),
+                coordinates_source.to_string(),
+            ));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/admin/directory/v1/customer/{}/resources/buildings?{}",
+            crate::progenitor_support::encode_path(&customer.to_string()),
+            query_
+        );
+
+        Ok(batch.add(
+            reqwest::Method::POST,
+            &url,
+            Some(serde_json::to_string(body)?),
+        ))
+    }
+
     /**
      * This function performs a `GET` to the `/admin/directory/v1/customer/{customer}/resources/buildings/{buildingId}` endpoint.
      *
@@ -153,6 +193,33 @@ impl Resources {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Like `buildings_get`, but adds the call to a `Batch` to be sent with others, instead of sending it.
+     *
+     * This function performs a `GET` to the `/admin/directory/v1/customer/{customer}/resources/buildings/{buildingId}` endpoint.
+     *
+     * Retrieves a building.
+     *
+     * **Parameters:**
+     *
+     * * `customer: &str` -- The unique ID for the customer's Google Workspace account. As an account administrator, you can also use the `my_customer` alias to represent your account's customer ID.
+     * * `building_id: &str` -- The unique ID of the building to retrieve.
+     */
+    pub fn buildings_get_batch(
+        &self,
+        batch: &mut crate::batch::Batch,
+        customer: &str,
+        building_id: &str,
+    ) -> Result<crate::batch::BatchCall<crate::types::Building>> {
+        let url = format!(
+            "/admin/directory/v1/customer/{}/resources/buildings/{}",
+            crate::progenitor_support::encode_path(&customer.to_string()),
+            crate::progenitor_support::encode_path(&building_id.to_string()),
+        );
+
+        Ok(batch.add(reqwest::Method::GET, &url, None))
+    }
+
     /**
      * This function performs a `PUT` to the `/admin/directory/v1/customer/{customer}/resources/buildings/{buildingId}` endpoint.
      *
@@ -191,6 +258,49 @@ impl Resources {
             .await
     }
 
+    /**
+     * Like `buildings_update`, but adds the call to a `Batch` to be sent with others, instead of sending it.
+     *
+     * This function performs a `PUT` to the `/admin/directory/v1/customer/{customer}/resources/buildings/{buildingId}` endpoint.
+     *
+     * Updates a building.
+     *
+     * **Parameters:**
+     *
+     * * `customer: &str` -- The unique ID for the customer's Google Workspace account. As an account administrator, you can also use the `my_customer` alias to represent your account's customer ID.
+     * * `building_id: &str` -- The id of the building to update.
+     * * `coordinates_source: crate::types::CoordinatesSource` -- Source from which Building.coordinates are derived.
+     */
+    pub fn buildings_update_batch(
+        &self,
+        batch: &mut crate::batch::Batch,
+        customer: &str,
+        building_id: &str,
+        coordinates_source: crate::types::CoordinatesSource,
+        body: &crate::types::Building,
+    ) -> Result<crate::batch::BatchCall<crate::types::Building>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !coordinates_source.to_string().is_empty() {
+            query_args.push((
+                "coordinatesSource".to_string(),
+                coordinates_source.to_string(),
+            ));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/admin/directory/v1/customer/{}/resources/buildings/{}?{}",
+            crate::progenitor_support::encode_path(&customer.to_string()),
+            crate::progenitor_support::encode_path(&building_id.to_string()),
+            query_
+        );
+
+        Ok(batch.add(
+            reqwest::Method::PUT,
+            &url,
+            Some(serde_json::to_string(body)?),
+        ))
+    }
+
     /**
      * This function performs a `DELETE` to the `/admin/directory/v1/customer/{customer}/resources/buildings/{buildingId}` endpoint.
      *
@@ -211,6 +321,33 @@ impl Resources {
         self.client.delete(&url, None).await
     }
 
+    /**
+     * Like `buildings_delete`, but adds the call to a `Batch` to be sent with others, instead of sending it.
+     *
+     * This function performs a `DELETE` to the `/admin/directory/v1/customer/{customer}/resources/buildings/{buildingId}` endpoint.
+     *
+     * Deletes a building.
+     *
+     * **Parameters:**
+     *
+     * * `customer: &str` -- The unique ID for the customer's Google Workspace account. As an account administrator, you can also use the `my_customer` alias to represent your account's customer ID.
+     * * `building_id: &str` -- The id of the building to delete.
+     */
+    pub fn buildings_delete_batch(
+        &self,
+        batch: &mut crate::batch::Batch,
+        customer: &str,
+        building_id: &str,
+    ) -> Result<crate::batch::BatchCall<()>> {
+        let url = format!(
+            "/admin/directory/v1/customer/{}/resources/buildings/{}",
+            crate::progenitor_support::encode_path(&customer.to_string()),
+            crate::progenitor_support::encode_path(&building_id.to_string()),
+        );
+
+        Ok(batch.add(reqwest::Method::DELETE, &url, None))
+    }
+
     /**
      * This function performs a `PATCH` to the `/admin/directory/v1/customer/{customer}/resources/buildings/{buildingId}` endpoint.
      *
@@ -249,6 +386,49 @@ impl Resources {
             .await
     }
 
+    /**
+     * Like `buildings_patch`, but adds the call to a `Batch` to be sent with others, instead of sending it.
+     *
+     * This function performs a `PATCH` to the `/admin/directory/v1/customer/{customer}/resources/buildings/{buildingId}` endpoint.
+     *
+     * Patches a building.
+     *
+     * **Parameters:**
+     *
+     * * `customer: &str` -- The unique ID for the customer's Google Workspace account. As an account administrator, you can also use the `my_customer` alias to represent your account's customer ID.
+     * * `building_id: &str` -- The id of the building to update.
+     * * `coordinates_source: crate::types::CoordinatesSource` -- Source from which Building.coordinates are derived.
+     */
+    pub fn buildings_patch_batch(
+        &self,
+        batch: &mut crate::batch::Batch,
+        customer: &str,
+        building_id: &str,
+        coordinates_source: crate::types::CoordinatesSource,
+        body: &crate::types::Building,
+    ) -> Result<crate::batch::BatchCall<crate::types::Building>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !coordinates_source.to_string().is_empty() {
+            query_args.push((
+                "coordinatesSource".to_string(),
+                coordinates_source.to_string(),
+            ));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/admin/directory/v1/customer/{}/resources/buildings/{}?{}",
+            crate::progenitor_support::encode_path(&customer.to_string()),
+            crate::progenitor_support::encode_path(&building_id.to_string()),
+            query_
+        );
+
+        Ok(batch.add(
+            reqwest::Method::PATCH,
+            &url,
+            Some(serde_json::to_string(body)?),
+        ))
+    }
+
     /**
      * This function performs a `GET` to the `/admin/directory/v1/customer/{customer}/resources/calendars` endpoint.
      *
@@ -379,6 +559,35 @@ impl Resources {
             .await
     }
 
+    /**
+     * Like `calendars_insert`, but adds the call to a `Batch` to be sent with others, instead of sending it.
+     *
+     * This function performs a `POST` to the `/admin/directory/v1/customer/{customer}/resources/calendars` endpoint.
+     *
+     * Inserts a calendar resource.
+     *
+     * **Parameters:**
+     *
+     * * `customer: &str` -- The unique ID for the customer's Google Workspace account. As an account administrator, you can also use the `my_customer` alias to represent your account's customer ID.
+     */
+    pub fn calendars_insert_batch(
+        &self,
+        batch: &mut crate::batch::Batch,
+        customer: &str,
+        body: &crate::types::CalendarResource,
+    ) -> Result<crate::batch::BatchCall<crate::types::CalendarResource>> {
+        let url = format!(
+            "/admin/directory/v1/customer/{}/resources/calendars",
+            crate::progenitor_support::encode_path(&customer.to_string()),
+        );
+
+        Ok(batch.add(
+            reqwest::Method::POST,
+            &url,
+            Some(serde_json::to_string(body)?),
+        ))
+    }
+
     /**
      * This function performs a `GET` to the `/admin/directory/v1/customer/{customer}/resources/calendars/{calendarResourceId}` endpoint.
      *
@@ -403,6 +612,33 @@ impl Resources {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Like `calendars_get`, but adds the call to a `Batch` to be sent with others, instead of sending it.
+     *
+     * This function performs a `GET` to the `/admin/directory/v1/customer/{customer}/resources/calendars/{calendarResourceId}` endpoint.
+     *
+     * Retrieves a calendar resource.
+     *
+     * **Parameters:**
+     *
+     * * `customer: &str` -- The unique ID for the customer's Google Workspace account. As an account administrator, you can also use the `my_customer` alias to represent your account's customer ID.
+     * * `calendar_resource_id: &str` -- The unique ID of the calendar resource to retrieve.
+     */
+    pub fn calendars_get_batch(
+        &self,
+        batch: &mut crate::batch::Batch,
+        customer: &str,
+        calendar_resource_id: &str,
+    ) -> Result<crate::batch::BatchCall<crate::types::CalendarResource>> {
+        let url = format!(
+            "/admin/directory/v1/customer/{}/resources/calendars/{}",
+            crate::progenitor_support::encode_path(&customer.to_string()),
+            crate::progenitor_support::encode_path(&calendar_resource_id.to_string()),
+        );
+
+        Ok(batch.add(reqwest::Method::GET, &url, None))
+    }
+
     /**
      * This function performs a `PUT` to the `/admin/directory/v1/customer/{customer}/resources/calendars/{calendarResourceId}` endpoint.
      *
@@ -430,6 +666,38 @@ impl Resources {
             .await
     }
 
+    /**
+     * Like `calendars_update`, but adds the call to a `Batch` to be sent with others, instead of sending it.
+     *
+     * This function performs a `PUT` to the `/admin/directory/v1/customer/{customer}/resources/calendars/{calendarResourceId}` endpoint.
+     *
+     * Updates a calendar resource. This method supports patch semantics, meaning you only need to include the fields you wish to update. Fields that are not present in the request will be preserved.
+     *
+     * **Parameters:**
+     *
+     * * `customer: &str` -- The unique ID for the customer's Google Workspace account. As an account administrator, you can also use the `my_customer` alias to represent your account's customer ID.
+     * * `calendar_resource_id: &str` -- The unique ID of the calendar resource to update.
+     */
+    pub fn calendars_update_batch(
+        &self,
+        batch: &mut crate::batch::Batch,
+        customer: &str,
+        calendar_resource_id: &str,
+        body: &crate::types::CalendarResource,
+    ) -> Result<crate::batch::BatchCall<crate::types::CalendarResource>> {
+        let url = format!(
+            "/admin/directory/v1/customer/{}/resources/calendars/{}",
+            crate::progenitor_support::encode_path(&customer.to_string()),
+            crate::progenitor_support::encode_path(&calendar_resource_id.to_string()),
+        );
+
+        Ok(batch.add(
+            reqwest::Method::PUT,
+            &url,
+            Some(serde_json::to_string(body)?),
+        ))
+    }
+
     /**
      * This function performs a `DELETE` to the `/admin/directory/v1/customer/{customer}/resources/calendars/{calendarResourceId}` endpoint.
      *
@@ -450,6 +718,33 @@ impl Resources {
         self.client.delete(&url, None).await
     }
 
+    /**
+     * Like `calendars_delete`, but adds the call to a `Batch` to be sent with others, instead of sending it.
+     *
+     * This function performs a `DELETE` to the `/admin/directory/v1/customer/{customer}/resources/calendars/{calendarResourceId}` endpoint.
+     *
+     * Deletes a calendar resource.
+     *
+     * **Parameters:**
+     *
+     * * `customer: &str` -- The unique ID for the customer's Google Workspace account. As an account administrator, you can also use the `my_customer` alias to represent your account's customer ID.
+     * * `calendar_resource_id: &str` -- The unique ID of the calendar resource to delete.
+     */
+    pub fn calendars_delete_batch(
+        &self,
+        batch: &mut crate::batch::Batch,
+        customer: &str,
+        calendar_resource_id: &str,
+    ) -> Result<crate::batch::BatchCall<()>> {
+        let url = format!(
+            "/admin/directory/v1/customer/{}/resources/calendars/{}",
+            crate::progenitor_support::encode_path(&customer.to_string()),
+            crate::progenitor_support::encode_path(&calendar_resource_id.to_string()),
+        );
+
+        Ok(batch.add(reqwest::Method::DELETE, &url, None))
+    }
+
     /**
      * This function performs a `PATCH` to the `/admin/directory/v1/customer/{customer}/resources/calendars/{calendarResourceId}` endpoint.
      *
@@ -477,6 +772,38 @@ impl Resources {
             .await
     }
 
+    /**
+     * Like `calendars_patch`, but adds the call to a `Batch` to be sent with others, instead of sending it.
+     *
+     * This function performs a `PATCH` to the `/admin/directory/v1/customer/{customer}/resources/calendars/{calendarResourceId}` endpoint.
+     *
+     * Patches a calendar resource.
+     *
+     * **Parameters:**
+     *
+     * * `customer: &str` -- The unique ID for the customer's Google Workspace account. As an account administrator, you can also use the `my_customer` alias to represent your account's customer ID.
+     * * `calendar_resource_id: &str` -- The unique ID of the calendar resource to update.
+     */
+    pub fn calendars_patch_batch(
+        &self,
+        batch: &mut crate::batch::Batch,
+        customer: &str,
+        calendar_resource_id: &str,
+        body: &crate::types::CalendarResource,
+    ) -> Result<crate::batch::BatchCall<crate::types::CalendarResource>> {
+        let url = format!(
+            "/admin/directory/v1/customer/{}/resources/calendars/{}",
+            crate::progenitor_support::encode_path(&customer.to_string()),
+            crate::progenitor_support::encode_path(&calendar_resource_id.to_string()),
+        );
+
+        Ok(batch.add(
+            reqwest::Method::PATCH,
+            &url,
+            Some(serde_json::to_string(body)?),
+        ))
+    }
+
     /**
      * This function performs a `GET` to the `/admin/directory/v1/customer/{customer}/resources/features` endpoint.
      *
@@ -583,6 +910,35 @@ impl Resources {
             .await
     }
 
+    /**
+     * Like `features_insert`, but adds the call to a `Batch` to be sent with others, instead of sending it.
+     *
+     * This function performs a `POST` to the `/admin/directory/v1/customer/{customer}/resources/features` endpoint.
+     *
+     * Inserts a feature.
+     *
+     * **Parameters:**
+     *
+     * * `customer: &str` -- The unique ID for the customer's Google Workspace account. As an account administrator, you can also use the `my_customer` alias to represent your account's customer ID.
+     */
+    pub fn features_insert_batch(
+        &self,
+        batch: &mut crate::batch::Batch,
+        customer: &str,
+        body: &crate::types::Feature,
+    ) -> Result<crate::batch::BatchCall<crate::types::Feature>> {
+        let url = format!(
+            "/admin/directory/v1/customer/{}/resources/features",
+            crate::progenitor_support::encode_path(&customer.to_string()),
+        );
+
+        Ok(batch.add(
+            reqwest::Method::POST,
+            &url,
+            Some(serde_json::to_string(body)?),
+        ))
+    }
+
     /**
      * This function performs a `GET` to the `/admin/directory/v1/customer/{customer}/resources/features/{featureKey}` endpoint.
      *
@@ -607,6 +963,33 @@ impl Resources {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Like `features_get`, but adds the call to a `Batch` to be sent with others, instead of sending it.
+     *
+     * This function performs a `GET` to the `/admin/directory/v1/customer/{customer}/resources/features/{featureKey}` endpoint.
+     *
+     * Retrieves a feature.
+     *
+     * **Parameters:**
+     *
+     * * `customer: &str` -- The unique ID for the customer's Google Workspace account. As an account administrator, you can also use the `my_customer` alias to represent your account's customer ID.
+     * * `feature_key: &str` -- The unique ID of the feature to retrieve.
+     */
+    pub fn features_get_batch(
+        &self,
+        batch: &mut crate::batch::Batch,
+        customer: &str,
+        feature_key: &str,
+    ) -> Result<crate::batch::BatchCall<crate::types::Feature>> {
+        let url = format!(
+            "/admin/directory/v1/customer/{}/resources/features/{}",
+            crate::progenitor_support::encode_path(&customer.to_string()),
+            crate::progenitor_support::encode_path(&feature_key.to_string()),
+        );
+
+        Ok(batch.add(reqwest::Method::GET, &url, None))
+    }
+
     /**
      * This function performs a `PUT` to the `/admin/directory/v1/customer/{customer}/resources/features/{featureKey}` endpoint.
      *
@@ -634,6 +1017,38 @@ impl Resources {
             .await
     }
 
+    /**
+     * Like `features_update`, but adds the call to a `Batch` to be sent with others, instead of sending it.
+     *
+     * This function performs a `PUT` to the `/admin/directory/v1/customer/{customer}/resources/features/{featureKey}` endpoint.
+     *
+     * Updates a feature.
+     *
+     * **Parameters:**
+     *
+     * * `customer: &str` -- The unique ID for the customer's Google Workspace account. As an account administrator, you can also use the `my_customer` alias to represent your account's customer ID.
+     * * `feature_key: &str` -- The unique ID of the feature to update.
+     */
+    pub fn features_update_batch(
+        &self,
+        batch: &mut crate::batch::Batch,
+        customer: &str,
+        feature_key: &str,
+        body: &crate::types::Feature,
+    ) -> Result<crate::batch::BatchCall<crate::types::Feature>> {
+        let url = format!(
+            "/admin/directory/v1/customer/{}/resources/features/{}",
+            crate::progenitor_support::encode_path(&customer.to_string()),
+            crate::progenitor_support::encode_path(&feature_key.to_string()),
+        );
+
+        Ok(batch.add(
+            reqwest::Method::PUT,
+            &url,
+            Some(serde_json::to_string(body)?),
+        ))
+    }
+
     /**
      * This function performs a `DELETE` to the `/admin/directory/v1/customer/{customer}/resources/features/{featureKey}` endpoint.
      *
@@ -654,6 +1069,33 @@ impl Resources {
         self.client.delete(&url, None).await
     }
 
+    /**
+     * Like `features_delete`, but adds the call to a `Batch` to be sent with others, instead of sending it.
+     *
+     * This function performs a `DELETE` to the `/admin/directory/v1/customer/{customer}/resources/features/{featureKey}` endpoint.
+     *
+     * Deletes a feature.
+     *
+     * **Parameters:**
+     *
+     * * `customer: &str` -- The unique ID for the customer's Google Workspace account. As an account administrator, you can also use the `my_customer` alias to represent your account's customer ID.
+     * * `feature_key: &str` -- The unique ID of the feature to delete.
+     */
+    pub fn features_delete_batch(
+        &self,
+        batch: &mut crate::batch::Batch,
+        customer: &str,
+        feature_key: &str,
+    ) -> Result<crate::batch::BatchCall<()>> {
+        let url = format!(
+            "/admin/directory/v1/customer/{}/resources/features/{}",
+            crate::progenitor_support::encode_path(&customer.to_string()),
+            crate::progenitor_support::encode_path(&feature_key.to_string()),
+        );
+
+        Ok(batch.add(reqwest::Method::DELETE, &url, None))
+    }
+
     /**
      * This function performs a `PATCH` to the `/admin/directory/v1/customer/{customer}/resources/features/{featureKey}` endpoint.
      *
@@ -681,6 +1123,38 @@ impl Resources {
             .await
     }
 
+    /**
+     * Like `features_patch`, but adds the call to a `Batch` to be sent with others, instead of sending it.
+     *
+     * This function performs a `PATCH` to the `/admin/directory/v1/customer/{customer}/resources/features/{featureKey}` endpoint.
+     *
+     * Patches a feature.
+     *
+     * **Parameters:**
+     *
+     * * `customer: &str` -- The unique ID for the customer's Google Workspace account. As an account administrator, you can also use the `my_customer` alias to represent your account's customer ID.
+     * * `feature_key: &str` -- The unique ID of the feature to update.
+     */
+    pub fn features_patch_batch(
+        &self,
+        batch: &mut crate::batch::Batch,
+        customer: &str,
+        feature_key: &str,
+        body: &crate::types::Feature,
+    ) -> Result<crate::batch::BatchCall<crate::types::Feature>> {
+        let url = format!(
+            "/admin/directory/v1/customer/{}/resources/features/{}",
+            crate::progenitor_support::encode_path(&customer.to_string()),
+            crate::progenitor_support::encode_path(&feature_key.to_string()),
+        );
+
+        Ok(batch.add(
+            reqwest::Method::PATCH,
+            &url,
+            Some(serde_json::to_string(body)?),
+        ))
+    }
+
     /**
      * This function performs a `POST` to the `/admin/directory/v1/customer/{customer}/resources/features/{oldName}/rename` endpoint.
      *
@@ -707,4 +1181,36 @@ impl Resources {
             .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
             .await
     }
+
+    /**
+     * Like `features_rename`, but adds the call to a `Batch` to be sent with others, instead of sending it.
+     *
+     * This function performs a `POST` to the `/admin/directory/v1/customer/{customer}/resources/features/{oldName}/rename` endpoint.
+     *
+     * Renames a feature.
+     *
+     * **Parameters:**
+     *
+     * * `customer: &str` -- The unique ID for the customer's Google Workspace account. As an account administrator, you can also use the `my_customer` alias to represent your account's customer ID.
+     * * `old_name: &str` -- The unique ID of the feature to rename.
+     */
+    pub fn features_rename_batch(
+        &self,
+        batch: &mut crate::batch::Batch,
+        customer: &str,
+        old_name: &str,
+        body: &crate::types::FeatureRename,
+    ) -> Result<crate::batch::BatchCall<()>> {
+        let url = format!(
+            "/admin/directory/v1/customer/{}/resources/features/{}/rename",
+            crate::progenitor_support::encode_path(&customer.to_string()),
+            crate::progenitor_support::encode_path(&old_name.to_string()),
+        );
+
+        Ok(batch.add(
+            reqwest::Method::POST,
+            &url,
+            Some(serde_json::to_string(body)?),
+        ))
+    }
 }
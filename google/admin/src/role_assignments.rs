@@ -142,6 +142,35 @@ impl RoleAssignments {
             .await
     }
 
+    /**
+     * Like `insert`, but adds the call to a `Batch` to be sent with others, instead of sending it.
+     *
+     * This function performs a `POST` to the `/admin/directory/v1/customer/{customer}/roleassignments` endpoint.
+     *
+     * Creates a role assignment.
+     *
+     * **Parameters:**
+     *
+     * * `customer: &str` -- Immutable ID of the Google Workspace account.
+     */
+    pub fn insert_batch(
+        &self,
+        batch: &mut crate::batch::Batch,
+        customer: &str,
+        body: &crate::types::RoleAssignment,
+    ) -> Result<crate::batch::BatchCall<crate::types::RoleAssignment>> {
+        let url = format!(
+            "/admin/directory/v1/customer/{}/roleassignments",
+            crate::progenitor_support::encode_path(&customer.to_string()),
+        );
+
+        Ok(batch.add(
+            reqwest::Method::POST,
+            &url,
+            Some(serde_json::to_string(body)?),
+        ))
+    }
+
     /**
      * This function performs a `GET` to the `/admin/directory/v1/customer/{customer}/roleassignments/{roleAssignmentId}` endpoint.
      *
@@ -166,6 +195,33 @@ impl RoleAssignments {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Like `get`, but adds the call to a `Batch` to be sent with others, instead of sending it.
+     *
+     * This function performs a `GET` to the `/admin/directory/v1/customer/{customer}/roleassignments/{roleAssignmentId}` endpoint.
+     *
+     * Retrieves a role assignment.
+     *
+     * **Parameters:**
+     *
+     * * `customer: &str` -- Immutable ID of the Google Workspace account.
+     * * `role_assignment_id: &str` -- Immutable ID of the role assignment.
+     */
+    pub fn get_batch(
+        &self,
+        batch: &mut crate::batch::Batch,
+        customer: &str,
+        role_assignment_id: &str,
+    ) -> Result<crate::batch::BatchCall<crate::types::RoleAssignment>> {
+        let url = format!(
+            "/admin/directory/v1/customer/{}/roleassignments/{}",
+            crate::progenitor_support::encode_path(&customer.to_string()),
+            crate::progenitor_support::encode_path(&role_assignment_id.to_string()),
+        );
+
+        Ok(batch.add(reqwest::Method::GET, &url, None))
+    }
+
     /**
      * This function performs a `DELETE` to the `/admin/directory/v1/customer/{customer}/roleassignments/{roleAssignmentId}` endpoint.
      *
@@ -185,4 +241,31 @@ impl RoleAssignments {
 
         self.client.delete(&url, None).await
     }
+
+    /**
+     * Like `delete`, but adds the call to a `Batch` to be sent with others, instead of sending it.
+     *
+     * This function performs a `DELETE` to the `/admin/directory/v1/customer/{customer}/roleassignments/{roleAssignmentId}` endpoint.
+     *
+     * Deletes a role assignment.
+     *
+     * **Parameters:**
+     *
+     * * `customer: &str` -- Immutable ID of the Google Workspace account.
+     * * `role_assignment_id: &str` -- Immutable ID of the role assignment.
+     */
+    pub fn delete_batch(
+        &self,
+        batch: &mut crate::batch::Batch,
+        customer: &str,
+        role_assignment_id: &str,
+    ) -> Result<crate::batch::BatchCall<()>> {
+        let url = format!(
+            "/admin/directory/v1/customer/{}/roleassignments/{}",
+            crate::progenitor_support::encode_path(&customer.to_string()),
+            crate::progenitor_support::encode_path(&role_assignment_id.to_string()),
+        );
+
+        Ok(batch.add(reqwest::Method::DELETE, &url, None))
+    }
 }
@@ -30,6 +30,30 @@ impl Schemas {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Like `list`, but adds the call to a `Batch` to be sent with others, instead of sending it.
+     *
+     * This function performs a `GET` to the `/admin/directory/v1/customer/{customerId}/schemas` endpoint.
+     *
+     * Retrieves all schemas for a customer.
+     *
+     * **Parameters:**
+     *
+     * * `customer_id: &str` -- Immutable ID of the Google Workspace account.
+     */
+    pub fn list_batch(
+        &self,
+        batch: &mut crate::batch::Batch,
+        customer_id: &str,
+    ) -> Result<crate::batch::BatchCall<crate::types::Schemas>> {
+        let url = format!(
+            "/admin/directory/v1/customer/{}/schemas",
+            crate::progenitor_support::encode_path(&customer_id.to_string()),
+        );
+
+        Ok(batch.add(reqwest::Method::GET, &url, None))
+    }
+
     /**
      * This function performs a `POST` to the `/admin/directory/v1/customer/{customerId}/schemas` endpoint.
      *
@@ -54,6 +78,35 @@ impl Schemas {
             .await
     }
 
+    /**
+     * Like `insert`, but adds the call to a `Batch` to be sent with others, instead of sending it.
+     *
+     * This function performs a `POST` to the `/admin/directory/v1/customer/{customerId}/schemas` endpoint.
+     *
+     * Creates a schema.
+     *
+     * **Parameters:**
+     *
+     * * `customer_id: &str` -- Immutable ID of the Google Workspace account.
+     */
+    pub fn insert_batch(
+        &self,
+        batch: &mut crate::batch::Batch,
+        customer_id: &str,
+        body: &crate::types::Schema,
+    ) -> Result<crate::batch::BatchCall<crate::types::Schema>> {
+        let url = format!(
+            "/admin/directory/v1/customer/{}/schemas",
+            crate::progenitor_support::encode_path(&customer_id.to_string()),
+        );
+
+        Ok(batch.add(
+            reqwest::Method::POST,
+            &url,
+            Some(serde_json::to_string(body)?),
+        ))
+    }
+
     /**
      * This function performs a `GET` to the `/admin/directory/v1/customer/{customerId}/schemas/{schemaKey}` endpoint.
      *
@@ -74,6 +127,33 @@ impl Schemas {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Like `get`, but adds the call to a `Batch` to be sent with others, instead of sending it.
+     *
+     * This function performs a `GET` to the `/admin/directory/v1/customer/{customerId}/schemas/{schemaKey}` endpoint.
+     *
+     * Retrieves a schema.
+     *
+     * **Parameters:**
+     *
+     * * `customer_id: &str` -- Immutable ID of the Google Workspace account.
+     * * `schema_key: &str` -- Name or immutable ID of the schema.
+     */
+    pub fn get_batch(
+        &self,
+        batch: &mut crate::batch::Batch,
+        customer_id: &str,
+        schema_key: &str,
+    ) -> Result<crate::batch::BatchCall<crate::types::Schema>> {
+        let url = format!(
+            "/admin/directory/v1/customer/{}/schemas/{}",
+            crate::progenitor_support::encode_path(&customer_id.to_string()),
+            crate::progenitor_support::encode_path(&schema_key.to_string()),
+        );
+
+        Ok(batch.add(reqwest::Method::GET, &url, None))
+    }
+
     /**
      * This function performs a `PUT` to the `/admin/directory/v1/customer/{customerId}/schemas/{schemaKey}` endpoint.
      *
@@ -101,6 +181,38 @@ impl Schemas {
             .await
     }
 
+    /**
+     * Like `update`, but adds the call to a `Batch` to be sent with others, instead of sending it.
+     *
+     * This function performs a `PUT` to the `/admin/directory/v1/customer/{customerId}/schemas/{schemaKey}` endpoint.
+     *
+     * Updates a schema.
+     *
+     * **Parameters:**
+     *
+     * * `customer_id: &str` -- Immutable ID of the Google Workspace account.
+     * * `schema_key: &str` -- Name or immutable ID of the schema.
+     */
+    pub fn update_batch(
+        &self,
+        batch: &mut crate::batch::Batch,
+        customer_id: &str,
+        schema_key: &str,
+        body: &crate::types::Schema,
+    ) -> Result<crate::batch::BatchCall<crate::types::Schema>> {
+        let url = format!(
+            "/admin/directory/v1/customer/{}/schemas/{}",
+            crate::progenitor_support::encode_path(&customer_id.to_string()),
+            crate::progenitor_support::encode_path(&schema_key.to_string()),
+        );
+
+        Ok(batch.add(
+            reqwest::Method::PUT,
+            &url,
+            Some(serde_json::to_string(body)?),
+        ))
+    }
+
     /**
      * This function performs a `DELETE` to the `/admin/directory/v1/customer/{customerId}/schemas/{schemaKey}` endpoint.
      *
@@ -121,6 +233,33 @@ impl Schemas {
         self.client.delete(&url, None).await
     }
 
+    /**
+     * Like `delete`, but adds the call to a `Batch` to be sent with others, instead of sending it.
+     *
+     * This function performs a `DELETE` to the `/admin/directory/v1/customer/{customerId}/schemas/{schemaKey}` endpoint.
+     *
+     * Deletes a schema.
+     *
+     * **Parameters:**
+     *
+     * * `customer_id: &str` -- Immutable ID of the Google Workspace account.
+     * * `schema_key: &str` -- Name or immutable ID of the schema.
+     */
+    pub fn delete_batch(
+        &self,
+        batch: &mut crate::batch::Batch,
+        customer_id: &str,
+        schema_key: &str,
+    ) -> Result<crate::batch::BatchCall<()>> {
+        let url = format!(
+            "/admin/directory/v1/customer/{}/schemas/{}",
+            crate::progenitor_support::encode_path(&customer_id.to_string()),
+            crate::progenitor_support::encode_path(&schema_key.to_string()),
+        );
+
+        Ok(batch.add(reqwest::Method::DELETE, &url, None))
+    }
+
     /**
      * This function performs a `PATCH` to the `/admin/directory/v1/customer/{customerId}/schemas/{schemaKey}` endpoint.
      *
@@ -147,4 +286,36 @@ impl Schemas {
             .patch(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
             .await
     }
+
+    /**
+     * Like `patch`, but adds the call to a `Batch` to be sent with others, instead of sending it.
+     *
+     * This function performs a `PATCH` to the `/admin/directory/v1/customer/{customerId}/schemas/{schemaKey}` endpoint.
+     *
+     * Patches a schema.
+     *
+     * **Parameters:**
+     *
+     * * `customer_id: &str` -- Immutable ID of the Google Workspace account.
+     * * `schema_key: &str` -- Name or immutable ID of the schema.
+     */
+    pub fn patch_batch(
+        &self,
+        batch: &mut crate::batch::Batch,
+        customer_id: &str,
+        schema_key: &str,
+        body: &crate::types::Schema,
+    ) -> Result<crate::batch::BatchCall<crate::types::Schema>> {
+        let url = format!(
+            "/admin/directory/v1/customer/{}/schemas/{}",
+            crate::progenitor_support::encode_path(&customer_id.to_string()),
+            crate::progenitor_support::encode_path(&schema_key.to_string()),
+        );
+
+        Ok(batch.add(
+            reqwest::Method::PATCH,
+            &url,
+            Some(serde_json::to_string(body)?),
+        ))
+    }
 }
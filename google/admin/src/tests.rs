@@ -1 +1,149 @@
+#[test]
+fn test_directory_sync_diff() {
+    use crate::directory_sync::{
+        diff, Change, DesiredGroup, DesiredState, DesiredUser, Directory, MemberRole, SyncOptions,
+    };
 
+    let live = Directory {
+        users: serde_json::from_value(serde_json::json!([
+            {
+                "primaryEmail": "alice@example.com",
+                "name": {"givenName": "Alice", "familyName": "Smith"},
+                "orgUnitPath": "/",
+                "aliases": ["al@example.com"],
+                "customSchemas": {"Employment": {"employeeId": "E1"}},
+            },
+            {
+                "primaryEmail": "bob@example.com",
+                "name": {"givenName": "Bob", "familyName": "Jones"},
+                "orgUnitPath": "/Sales",
+                "aliases": ["rob@example.com"],
+            },
+            {
+                "primaryEmail": "root@example.com",
+                "name": {"givenName": "Root", "familyName": "Admin"},
+                "isAdmin": true,
+            },
+        ]))
+        .unwrap(),
+        groups: serde_json::from_value(serde_json::json!([
+            {"email": "eng@example.com", "name": "Engineering"},
+        ]))
+        .unwrap(),
+        members: vec![(
+            "eng@example.com".to_string(),
+            serde_json::from_value(serde_json::json!([
+                {"email": "al@example.com", "role": "MEMBER", "type": "USER"},
+                {"email": "rob@example.com", "role": "MEMBER", "type": "USER"},
+                {"email": "leads@example.com", "role": "MEMBER", "type": "GROUP"},
+            ]))
+            .unwrap(),
+        )]
+        .into_iter()
+        .collect(),
+    };
+
+    let desired = DesiredState {
+        users: vec![
+            DesiredUser::new("Alice@example.com", "Alice", "Smith")
+                .with_org_unit("/Engineering")
+                .with_alias("asmith@example.com")
+                .with_custom_field("Employment", "employeeId", serde_json::json!("E1"))
+                .with_group("eng@example.com", MemberRole::Owner),
+            DesiredUser::new("carol@example.com", "Carol", "White")
+                .with_group("eng@example.com", MemberRole::Member),
+        ],
+        groups: vec![DesiredGroup::new("eng@example.com", "Engineering")],
+    };
+
+    let plan = diff(&live, &desired, &SyncOptions::default()).unwrap();
+    let lines: Vec<String> = plan.changes.iter().map(|c| c.to_string()).collect();
+    assert_eq!(
+        lines,
+        vec![
+            "~ user Alice@example.com: orgUnitPath",
+            "+ user carol@example.com",
+            "- user bob@example.com (suspend)",
+            "+ alias asmith@example.com of Alice@example.com",
+            "- alias al@example.com of Alice@example.com",
+            "~ member Alice@example.com of eng@example.com as OWNER",
+            "+ member carol@example.com of eng@example.com as MEMBER",
+            "- member bob@example.com of eng@example.com",
+        ]
+    );
+    match &plan.changes[1] {
+        Change::InsertUser { body, .. } => {
+            assert_eq!(body["name"]["givenName"], "Carol");
+            assert!(!body["password"].as_str().unwrap().is_empty());
+        }
+        change => panic!("unexpected change {}", change),
+    }
+
+    let err = diff(
+        &live,
+        &DesiredState {
+            users: vec![DesiredUser::new("dan@example.com", "Dan", "Brown")
+                .with_group("ops@example.com", MemberRole::Member)],
+            groups: Vec::new(),
+        },
+        &SyncOptions::default(),
+    )
+    .unwrap_err();
+    assert!(err.to_string().contains("ops@example.com"));
+}
+
+#[test]
+fn test_directory_sync_rename() {
+    use crate::directory_sync::{
+        diff, Change, DesiredGroup, DesiredState, DesiredUser, Directory, MemberRole, SyncOptions,
+    };
+
+    let live = Directory {
+        users: serde_json::from_value(serde_json::json!([
+            {
+                "primaryEmail": "bob@example.com",
+                "name": {"givenName": "Bob", "familyName": "Jones"},
+                "orgUnitPath": "/",
+                "aliases": ["robert@example.com"],
+            },
+        ]))
+        .unwrap(),
+        groups: serde_json::from_value(serde_json::json!([
+            {"email": "eng@example.com", "name": "Engineering"},
+        ]))
+        .unwrap(),
+        members: Default::default(),
+    };
+
+    // Bob is found by his alias, and renamed to it.
+    let desired = DesiredState {
+        users: vec![DesiredUser::new("robert@example.com", "Bob", "Jones")
+            .with_alias("rjones@example.com")
+            .with_group("eng@example.com", MemberRole::Member)],
+        groups: vec![DesiredGroup::new("eng@example.com", "Engineering")],
+    };
+
+    let plan = diff(&live, &desired, &SyncOptions::default()).unwrap();
+    let lines: Vec<String> = plan.changes.iter().map(|c| c.to_string()).collect();
+    assert_eq!(
+        lines,
+        vec![
+            "~ user robert@example.com (was bob@example.com): primaryEmail",
+            "+ alias rjones@example.com of robert@example.com",
+            "+ member robert@example.com of eng@example.com as MEMBER",
+        ]
+    );
+    match &plan.changes[0] {
+        Change::PatchUser {
+            email,
+            user_key,
+            body,
+            ..
+        } => {
+            assert_eq!(email, "robert@example.com");
+            assert_eq!(user_key, "bob@example.com");
+            assert_eq!(body["primaryEmail"], "robert@example.com");
+        }
+        change => panic!("unexpected change {}", change),
+    }
+}
@@ -30,6 +30,30 @@ impl Tokens {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Like `list`, but adds the call to a `Batch` to be sent with others, instead of sending it.
+     *
+     * This function performs a `GET` to the `/admin/directory/v1/users/{userKey}/tokens` endpoint.
+     *
+     * Returns the set of tokens specified user has issued to 3rd party applications.
+     *
+     * **Parameters:**
+     *
+     * * `user_key: &str` -- Identifies the user in the API request. The value can be the user's primary email address, alias email address, or unique user ID.
+     */
+    pub fn list_batch(
+        &self,
+        batch: &mut crate::batch::Batch,
+        user_key: &str,
+    ) -> Result<crate::batch::BatchCall<crate::types::Tokens>> {
+        let url = format!(
+            "/admin/directory/v1/users/{}/tokens",
+            crate::progenitor_support::encode_path(&user_key.to_string()),
+        );
+
+        Ok(batch.add(reqwest::Method::GET, &url, None))
+    }
+
     /**
      * This function performs a `GET` to the `/admin/directory/v1/users/{userKey}/tokens/{clientId}` endpoint.
      *
@@ -50,6 +74,33 @@ impl Tokens {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Like `get`, but adds the call to a `Batch` to be sent with others, instead of sending it.
+     *
+     * This function performs a `GET` to the `/admin/directory/v1/users/{userKey}/tokens/{clientId}` endpoint.
+     *
+     * Gets information about an access token issued by a user.
+     *
+     * **Parameters:**
+     *
+     * * `user_key: &str` -- Identifies the user in the API request. The value can be the user's primary email address, alias email address, or unique user ID.
+     * * `client_id: &str` -- The Client ID of the application the token is issued to.
+     */
+    pub fn get_batch(
+        &self,
+        batch: &mut crate::batch::Batch,
+        user_key: &str,
+        client_id: &str,
+    ) -> Result<crate::batch::BatchCall<crate::types::Token>> {
+        let url = format!(
+            "/admin/directory/v1/users/{}/tokens/{}",
+            crate::progenitor_support::encode_path(&user_key.to_string()),
+            crate::progenitor_support::encode_path(&client_id.to_string()),
+        );
+
+        Ok(batch.add(reqwest::Method::GET, &url, None))
+    }
+
     /**
      * This function performs a `DELETE` to the `/admin/directory/v1/users/{userKey}/tokens/{clientId}` endpoint.
      *
@@ -69,4 +120,31 @@ impl Tokens {
 
         self.client.delete(&url, None).await
     }
+
+    /**
+     * Like `delete`, but adds the call to a `Batch` to be sent with others, instead of sending it.
+     *
+     * This function performs a `DELETE` to the `/admin/directory/v1/users/{userKey}/tokens/{clientId}` endpoint.
+     *
+     * Deletes all access tokens issued by a user for an application.
+     *
+     * **Parameters:**
+     *
+     * * `user_key: &str` -- Identifies the user in the API request. The value can be the user's primary email address, alias email address, or unique user ID.
+     * * `client_id: &str` -- The Client ID of the application the token is issued to.
+     */
+    pub fn delete_batch(
+        &self,
+        batch: &mut crate::batch::Batch,
+        user_key: &str,
+        client_id: &str,
+    ) -> Result<crate::batch::BatchCall<()>> {
+        let url = format!(
+            "/admin/directory/v1/users/{}/tokens/{}",
+            crate::progenitor_support::encode_path(&user_key.to_string()),
+            crate::progenitor_support::encode_path(&client_id.to_string()),
+        );
+
+        Ok(batch.add(reqwest::Method::DELETE, &url, None))
+    }
 }
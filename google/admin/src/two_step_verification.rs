@@ -29,4 +29,28 @@ impl TwoStepVerification {
 
         self.client.post(&url, None).await
     }
+
+    /**
+     * Like `turn_off`, but adds the call to a `Batch` to be sent with others, instead of sending it.
+     *
+     * This function performs a `POST` to the `/admin/directory/v1/users/{userKey}/twoStepVerification/turnOff` endpoint.
+     *
+     * Turns off 2-Step Verification for user.
+     *
+     * **Parameters:**
+     *
+     * * `user_key: &str` -- Identifies the user in the API request. The value can be the user's primary email address, alias email address, or unique user ID.
+     */
+    pub fn turn_off_batch(
+        &self,
+        batch: &mut crate::batch::Batch,
+        user_key: &str,
+    ) -> Result<crate::batch::BatchCall<()>> {
+        let url = format!(
+            "/admin/directory/v1/users/{}/twoStepVerification/turnOff",
+            crate::progenitor_support::encode_path(&user_key.to_string()),
+        );
+
+        Ok(batch.add(reqwest::Method::POST, &url, None))
+    }
 }
@@ -183,6 +183,27 @@ impl Users {
             .await
     }
 
+    /**
+     * Like `insert`, but adds the call to a `Batch` to be sent with others, instead of sending it.
+     *
+     * This function performs a `POST` to the `/admin/directory/v1/users` endpoint.
+     *
+     * Creates a user.
+     */
+    pub fn insert_batch(
+        &self,
+        batch: &mut crate::batch::Batch,
+        body: &crate::types::User,
+    ) -> Result<crate::batch::BatchCall<crate::types::User>> {
+        let url = "/admin/directory/v1/users".to_string();
+
+        Ok(batch.add(
+            reqwest::Method::POST,
+            &url,
+            Some(serde_json::to_string(body)?),
+        ))
+    }
+
     /**
      * This function performs a `POST` to the `/admin/directory/v1/users/watch` endpoint.
      *
@@ -260,6 +281,88 @@ impl Users {
             .await
     }
 
+    /**
+     * Like `watch`, but adds the call to a `Batch` to be sent with others, instead of sending it.
+     *
+     * This function performs a `POST` to the `/admin/directory/v1/users/watch` endpoint.
+     *
+     * Watches for changes in users list.
+     *
+     * **Parameters:**
+     *
+     * * `custom_field_mask: &str` -- Comma-separated list of schema names. All fields from these schemas are fetched. This should only be set when projection=custom.
+     * * `customer: &str` -- Immutable ID of the Google Workspace account. In case of multi-domain, to fetch all users for a customer, fill this field instead of domain.
+     * * `domain: &str` -- Name of the domain. Fill this field to get users from only this domain. To return all users in a multi-domain fill customer field instead.".
+     * * `event: crate::types::Event` -- Event on which subscription is intended (if subscribing).
+     * * `max_results: i64` -- Maximum number of results to return.
+     * * `order_by: crate::types::DirectoryUsersListOrderBy` -- Property to use for sorting results.
+     * * `page_token: &str` -- Token to specify next page in the list.
+     * * `projection: crate::types::DirectoryUsersListProjection` -- What subset of fields to fetch for this user.
+     * * `query: &str` -- Query string search. Should be of the form "". Complete documentation is at https: //developers.google.com/admin-sdk/directory/v1/guides/search-users.
+     * * `show_deleted: &str` -- If set to true, retrieves the list of deleted users. (Default: false).
+     * * `sort_order: crate::types::SortOrder` -- Whether to return results in ascending or descending order. Must be used with the `orderBy` parameter.
+     * * `view_type: crate::types::ViewType` -- Whether to fetch the administrator-only or domain-wide public view of the user. For more information, see [Retrieve a user as a non-administrator](/admin-sdk/directory/v1/guides/manage-users#retrieve_users_non_admin).
+     */
+    pub fn watch_batch(
+        &self,
+        batch: &mut crate::batch::Batch,
+        customer: &str,
+        domain: &str,
+        event: crate::types::Event,
+        max_results: i64,
+        order_by: crate::types::DirectoryUsersListOrderBy,
+        page_token: &str,
+        projection: crate::types::DirectoryUsersListProjection,
+        query: &str,
+        show_deleted: &str,
+        sort_order: crate::types::SortOrder,
+        view_type: crate::types::ViewType,
+        body: &crate::types::Channel,
+    ) -> Result<crate::batch::BatchCall<crate::types::Channel>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !customer.is_empty() {
+            query_args.push(("customer".to_string(), customer.to_string()));
+        }
+        if !domain.is_empty() {
+            query_args.push(("domain".to_string(), domain.to_string()));
+        }
+        if !event.to_string().is_empty() {
+            query_args.push(("event".to_string(), event.to_string()));
+        }
+        if max_results > 0 {
+            query_args.push(("maxResults".to_string(), max_results.to_string()));
+        }
+        if !order_by.to_string().is_empty() {
+            query_args.push(("orderBy".to_string(), order_by.to_string()));
+        }
+        if !page_token.is_empty() {
+            query_args.push(("pageToken".to_string(), page_token.to_string()));
+        }
+        if !projection.to_string().is_empty() {
+            query_args.push(("projection".to_string(), projection.to_string()));
+        }
+        if !query.is_empty() {
+            query_args.push(("query".to_string(), query.to_string()));
+        }
+        if !show_deleted.is_empty() {
+            query_args.push(("showDeleted".to_string(), show_deleted.to_string()));
+        }
+        if !sort_order.to_string().is_empty() {
+            query_args.push(("sortOrder".to_string(), sort_order.to_string()));
+        }
+        if !view_type.to_string().is_empty() {
+            query_args.push(("viewType".to_string(), view_type.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/admin/directory/v1/users/watch?{}", query_);
+
+        Ok(batch.add(
+            reqwest::Method::POST,
+            &url,
+            Some(serde_json::to_string(body)?),
+        ))
+    }
+
     /**
      * This function performs a `GET` to the `/admin/directory/v1/users/{userKey}` endpoint.
      *
@@ -295,6 +398,44 @@ impl Users {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Like `get`, but adds the call to a `Batch` to be sent with others, instead of sending it.
+     *
+     * This function performs a `GET` to the `/admin/directory/v1/users/{userKey}` endpoint.
+     *
+     * Retrieves a user.
+     *
+     * **Parameters:**
+     *
+     * * `user_key: &str` -- Identifies the user in the API request. The value can be the user's primary email address, alias email address, or unique user ID.
+     * * `custom_field_mask: &str` -- A comma-separated list of schema names. All fields from these schemas are fetched. This should only be set when `projection=custom`.
+     * * `projection: crate::types::DirectoryUsersListProjection` -- What subset of fields to fetch for this user.
+     * * `view_type: crate::types::ViewType` -- Whether to fetch the administrator-only or domain-wide public view of the user. For more information, see [Retrieve a user as a non-administrator](/admin-sdk/directory/v1/guides/manage-users#retrieve_users_non_admin).
+     */
+    pub fn get_batch(
+        &self,
+        batch: &mut crate::batch::Batch,
+        user_key: &str,
+        projection: crate::types::DirectoryUsersListProjection,
+        view_type: crate::types::ViewType,
+    ) -> Result<crate::batch::BatchCall<crate::types::User>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !projection.to_string().is_empty() {
+            query_args.push(("projection".to_string(), projection.to_string()));
+        }
+        if !view_type.to_string().is_empty() {
+            query_args.push(("viewType".to_string(), view_type.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/admin/directory/v1/users/{}?{}",
+            crate::progenitor_support::encode_path(&user_key.to_string()),
+            query_
+        );
+
+        Ok(batch.add(reqwest::Method::GET, &url, None))
+    }
+
     /**
      * This function performs a `PUT` to the `/admin/directory/v1/users/{userKey}` endpoint.
      *
@@ -319,6 +460,35 @@ impl Users {
             .await
     }
 
+    /**
+     * Like `update`, but adds the call to a `Batch` to be sent with others, instead of sending it.
+     *
+     * This function performs a `PUT` to the `/admin/directory/v1/users/{userKey}` endpoint.
+     *
+     * Updates a user. This method supports patch semantics, meaning you only need to include the fields you wish to update. Fields that are not present in the request will be preserved, and fields set to `null` will be cleared.
+     *
+     * **Parameters:**
+     *
+     * * `user_key: &str` -- Identifies the user in the API request. The value can be the user's primary email address, alias email address, or unique user ID.
+     */
+    pub fn update_batch(
+        &self,
+        batch: &mut crate::batch::Batch,
+        user_key: &str,
+        body: &crate::types::User,
+    ) -> Result<crate::batch::BatchCall<crate::types::User>> {
+        let url = format!(
+            "/admin/directory/v1/users/{}",
+            crate::progenitor_support::encode_path(&user_key.to_string()),
+        );
+
+        Ok(batch.add(
+            reqwest::Method::PUT,
+            &url,
+            Some(serde_json::to_string(body)?),
+        ))
+    }
+
     /**
      * This function performs a `DELETE` to the `/admin/directory/v1/users/{userKey}` endpoint.
      *
@@ -337,6 +507,30 @@ impl Users {
         self.client.delete(&url, None).await
     }
 
+    /**
+     * Like `delete`, but adds the call to a `Batch` to be sent with others, instead of sending it.
+     *
+     * This function performs a `DELETE` to the `/admin/directory/v1/users/{userKey}` endpoint.
+     *
+     * Deletes a user.
+     *
+     * **Parameters:**
+     *
+     * * `user_key: &str` -- Identifies the user in the API request. The value can be the user's primary email address, alias email address, or unique user ID.
+     */
+    pub fn delete_batch(
+        &self,
+        batch: &mut crate::batch::Batch,
+        user_key: &str,
+    ) -> Result<crate::batch::BatchCall<()>> {
+        let url = format!(
+            "/admin/directory/v1/users/{}",
+            crate::progenitor_support::encode_path(&user_key.to_string()),
+        );
+
+        Ok(batch.add(reqwest::Method::DELETE, &url, None))
+    }
+
     /**
      * This function performs a `PATCH` to the `/admin/directory/v1/users/{userKey}` endpoint.
      *
@@ -361,6 +555,35 @@ impl Users {
             .await
     }
 
+    /**
+     * Like `patch`, but adds the call to a `Batch` to be sent with others, instead of sending it.
+     *
+     * This function performs a `PATCH` to the `/admin/directory/v1/users/{userKey}` endpoint.
+     *
+     * Updates a user using patch semantics. The update method should be used instead, since it also supports patch semantics and has better performance. This method is unable to clear fields that contain repeated objects (`addresses`, `phones`, etc). Use the update method instead.
+     *
+     * **Parameters:**
+     *
+     * * `user_key: &str` -- Identifies the user in the API request. The value can be the user's primary email address, alias email address, or unique user ID.
+     */
+    pub fn patch_batch(
+        &self,
+        batch: &mut crate::batch::Batch,
+        user_key: &str,
+        body: &crate::types::User,
+    ) -> Result<crate::batch::BatchCall<crate::types::User>> {
+        let url = format!(
+            "/admin/directory/v1/users/{}",
+            crate::progenitor_support::encode_path(&user_key.to_string()),
+        );
+
+        Ok(batch.add(
+            reqwest::Method::PATCH,
+            &url,
+            Some(serde_json::to_string(body)?),
+        ))
+    }
+
     /**
      * This function performs a `GET` to the `/admin/directory/v1/users/{userKey}/aliases` endpoint.
      *
@@ -390,6 +613,38 @@ impl Users {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Like `aliases_list`, but adds the call to a `Batch` to be sent with others, instead of sending it.
+     *
+     * This function performs a `GET` to the `/admin/directory/v1/users/{userKey}/aliases` endpoint.
+     *
+     * Lists all aliases for a user.
+     *
+     * **Parameters:**
+     *
+     * * `user_key: &str` -- Identifies the user in the API request. The value can be the user's primary email address, alias email address, or unique user ID.
+     * * `event: crate::types::DirectoryUsersAliasesListEvent` -- Events to watch for.
+     */
+    pub fn aliases_list_batch(
+        &self,
+        batch: &mut crate::batch::Batch,
+        user_key: &str,
+        event: crate::types::DirectoryUsersAliasesListEvent,
+    ) -> Result<crate::batch::BatchCall<crate::types::Aliases>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !event.to_string().is_empty() {
+            query_args.push(("event".to_string(), event.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/admin/directory/v1/users/{}/aliases?{}",
+            crate::progenitor_support::encode_path(&user_key.to_string()),
+            query_
+        );
+
+        Ok(batch.add(reqwest::Method::GET, &url, None))
+    }
+
     /**
      * This function performs a `POST` to the `/admin/directory/v1/users/{userKey}/aliases` endpoint.
      *
@@ -414,6 +669,35 @@ impl Users {
             .await
     }
 
+    /**
+     * Like `aliases_insert`, but adds the call to a `Batch` to be sent with others, instead of sending it.
+     *
+     * This function performs a `POST` to the `/admin/directory/v1/users/{userKey}/aliases` endpoint.
+     *
+     * Adds an alias.
+     *
+     * **Parameters:**
+     *
+     * * `user_key: &str` -- Identifies the user in the API request. The value can be the user's primary email address, alias email address, or unique user ID.
+     */
+    pub fn aliases_insert_batch(
+        &self,
+        batch: &mut crate::batch::Batch,
+        user_key: &str,
+        body: &crate::types::Alias,
+    ) -> Result<crate::batch::BatchCall<crate::types::Alias>> {
+        let url = format!(
+            "/admin/directory/v1/users/{}/aliases",
+            crate::progenitor_support::encode_path(&user_key.to_string()),
+        );
+
+        Ok(batch.add(
+            reqwest::Method::POST,
+            &url,
+            Some(serde_json::to_string(body)?),
+        ))
+    }
+
     /**
      * This function performs a `POST` to the `/admin/directory/v1/users/{userKey}/aliases/watch` endpoint.
      *
@@ -446,6 +730,43 @@ impl Users {
             .await
     }
 
+    /**
+     * Like `aliases_watch`, but adds the call to a `Batch` to be sent with others, instead of sending it.
+     *
+     * This function performs a `POST` to the `/admin/directory/v1/users/{userKey}/aliases/watch` endpoint.
+     *
+     * Watches for changes in users list.
+     *
+     * **Parameters:**
+     *
+     * * `user_key: &str` -- Email or immutable ID of the user.
+     * * `event: crate::types::DirectoryUsersAliasesListEvent` -- Events to watch for.
+     */
+    pub fn aliases_watch_batch(
+        &self,
+        batch: &mut crate::batch::Batch,
+        user_key: &str,
+        event: crate::types::DirectoryUsersAliasesListEvent,
+        body: &crate::types::Channel,
+    ) -> Result<crate::batch::BatchCall<crate::types::Channel>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !event.to_string().is_empty() {
+            query_args.push(("event".to_string(), event.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/admin/directory/v1/users/{}/aliases/watch?{}",
+            crate::progenitor_support::encode_path(&user_key.to_string()),
+            query_
+        );
+
+        Ok(batch.add(
+            reqwest::Method::POST,
+            &url,
+            Some(serde_json::to_string(body)?),
+        ))
+    }
+
     /**
      * This function performs a `DELETE` to the `/admin/directory/v1/users/{userKey}/aliases/{alias}` endpoint.
      *
@@ -466,6 +787,33 @@ impl Users {
         self.client.delete(&url, None).await
     }
 
+    /**
+     * Like `aliases_delete`, but adds the call to a `Batch` to be sent with others, instead of sending it.
+     *
+     * This function performs a `DELETE` to the `/admin/directory/v1/users/{userKey}/aliases/{alias}` endpoint.
+     *
+     * Removes an alias.
+     *
+     * **Parameters:**
+     *
+     * * `user_key: &str` -- Identifies the user in the API request. The value can be the user's primary email address, alias email address, or unique user ID.
+     * * `alias: &str` -- The alias to be removed.
+     */
+    pub fn aliases_delete_batch(
+        &self,
+        batch: &mut crate::batch::Batch,
+        user_key: &str,
+        alias: &str,
+    ) -> Result<crate::batch::BatchCall<()>> {
+        let url = format!(
+            "/admin/directory/v1/users/{}/aliases/{}",
+            crate::progenitor_support::encode_path(&user_key.to_string()),
+            crate::progenitor_support::encode_path(&alias.to_string()),
+        );
+
+        Ok(batch.add(reqwest::Method::DELETE, &url, None))
+    }
+
     /**
      * This function performs a `POST` to the `/admin/directory/v1/users/{userKey}/makeAdmin` endpoint.
      *
@@ -490,6 +838,35 @@ impl Users {
             .await
     }
 
+    /**
+     * Like `make_admin`, but adds the call to a `Batch` to be sent with others, instead of sending it.
+     *
+     * This function performs a `POST` to the `/admin/directory/v1/users/{userKey}/makeAdmin` endpoint.
+     *
+     * Makes a user a super administrator.
+     *
+     * **Parameters:**
+     *
+     * * `user_key: &str` -- Identifies the user in the API request. The value can be the user's primary email address, alias email address, or unique user ID.
+     */
+    pub fn make_admin_batch(
+        &self,
+        batch: &mut crate::batch::Batch,
+        user_key: &str,
+        body: &crate::types::UserMakeAdmin,
+    ) -> Result<crate::batch::BatchCall<()>> {
+        let url = format!(
+            "/admin/directory/v1/users/{}/makeAdmin",
+            crate::progenitor_support::encode_path(&user_key.to_string()),
+        );
+
+        Ok(batch.add(
+            reqwest::Method::POST,
+            &url,
+            Some(serde_json::to_string(body)?),
+        ))
+    }
+
     /**
      * This function performs a `GET` to the `/admin/directory/v1/users/{userKey}/photos/thumbnail` endpoint.
      *
@@ -508,6 +885,30 @@ impl Users {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Like `photos_get`, but adds the call to a `Batch` to be sent with others, instead of sending it.
+     *
+     * This function performs a `GET` to the `/admin/directory/v1/users/{userKey}/photos/thumbnail` endpoint.
+     *
+     * Retrieves the user's photo.
+     *
+     * **Parameters:**
+     *
+     * * `user_key: &str` -- Identifies the user in the API request. The value can be the user's primary email address, alias email address, or unique user ID.
+     */
+    pub fn photos_get_batch(
+        &self,
+        batch: &mut crate::batch::Batch,
+        user_key: &str,
+    ) -> Result<crate::batch::BatchCall<crate::types::UserPhoto>> {
+        let url = format!(
+            "/admin/directory/v1/users/{}/photos/thumbnail",
+            crate::progenitor_support::encode_path(&user_key.to_string()),
+        );
+
+        Ok(batch.add(reqwest::Method::GET, &url, None))
+    }
+
     /**
      * This function performs a `PUT` to the `/admin/directory/v1/users/{userKey}/photos/thumbnail` endpoint.
      *
@@ -532,6 +933,35 @@ impl Users {
             .await
     }
 
+    /**
+     * Like `photos_update`, but adds the call to a `Batch` to be sent with others, instead of sending it.
+     *
+     * This function performs a `PUT` to the `/admin/directory/v1/users/{userKey}/photos/thumbnail` endpoint.
+     *
+     * Adds a photo for the user.
+     *
+     * **Parameters:**
+     *
+     * * `user_key: &str` -- Identifies the user in the API request. The value can be the user's primary email address, alias email address, or unique user ID.
+     */
+    pub fn photos_update_batch(
+        &self,
+        batch: &mut crate::batch::Batch,
+        user_key: &str,
+        body: &crate::types::UserPhoto,
+    ) -> Result<crate::batch::BatchCall<crate::types::UserPhoto>> {
+        let url = format!(
+            "/admin/directory/v1/users/{}/photos/thumbnail",
+            crate::progenitor_support::encode_path(&user_key.to_string()),
+        );
+
+        Ok(batch.add(
+            reqwest::Method::PUT,
+            &url,
+            Some(serde_json::to_string(body)?),
+        ))
+    }
+
     /**
      * This function performs a `DELETE` to the `/admin/directory/v1/users/{userKey}/photos/thumbnail` endpoint.
      *
@@ -550,6 +980,30 @@ impl Users {
         self.client.delete(&url, None).await
     }
 
+    /**
+     * Like `photos_delete`, but adds the call to a `Batch` to be sent with others, instead of sending it.
+     *
+     * This function performs a `DELETE` to the `/admin/directory/v1/users/{userKey}/photos/thumbnail` endpoint.
+     *
+     * Removes the user's photo.
+     *
+     * **Parameters:**
+     *
+     * * `user_key: &str` -- Identifies the user in the API request. The value can be the user's primary email address, alias email address, or unique user ID.
+     */
+    pub fn photos_delete_batch(
+        &self,
+        batch: &mut crate::batch::Batch,
+        user_key: &str,
+    ) -> Result<crate::batch::BatchCall<()>> {
+        let url = format!(
+            "/admin/directory/v1/users/{}/photos/thumbnail",
+            crate::progenitor_support::encode_path(&user_key.to_string()),
+        );
+
+        Ok(batch.add(reqwest::Method::DELETE, &url, None))
+    }
+
     /**
      * This function performs a `PATCH` to the `/admin/directory/v1/users/{userKey}/photos/thumbnail` endpoint.
      *
@@ -574,6 +1028,35 @@ impl Users {
             .await
     }
 
+    /**
+     * Like `photos_patch`, but adds the call to a `Batch` to be sent with others, instead of sending it.
+     *
+     * This function performs a `PATCH` to the `/admin/directory/v1/users/{userKey}/photos/thumbnail` endpoint.
+     *
+     * Adds a photo for the user. This method supports [patch semantics](/admin-sdk/directory/v1/guides/performance#patch).
+     *
+     * **Parameters:**
+     *
+     * * `user_key: &str` -- Identifies the user in the API request. The value can be the user's primary email address, alias email address, or unique user ID.
+     */
+    pub fn photos_patch_batch(
+        &self,
+        batch: &mut crate::batch::Batch,
+        user_key: &str,
+        body: &crate::types::UserPhoto,
+    ) -> Result<crate::batch::BatchCall<crate::types::UserPhoto>> {
+        let url = format!(
+            "/admin/directory/v1/users/{}/photos/thumbnail",
+            crate::progenitor_support::encode_path(&user_key.to_string()),
+        );
+
+        Ok(batch.add(
+            reqwest::Method::PATCH,
+            &url,
+            Some(serde_json::to_string(body)?),
+        ))
+    }
+
     /**
      * This function performs a `POST` to the `/admin/directory/v1/users/{userKey}/signOut` endpoint.
      *
@@ -592,6 +1075,30 @@ impl Users {
         self.client.post(&url, None).await
     }
 
+    /**
+     * Like `sign_out`, but adds the call to a `Batch` to be sent with others, instead of sending it.
+     *
+     * This function performs a `POST` to the `/admin/directory/v1/users/{userKey}/signOut` endpoint.
+     *
+     * Signs a user out of all web and device sessions and reset their sign-in cookies. User will have to sign in by authenticating again.
+     *
+     * **Parameters:**
+     *
+     * * `user_key: &str` -- Identifies the target user in the API request. The value can be the user's primary email address, alias email address, or unique user ID.
+     */
+    pub fn sign_out_batch(
+        &self,
+        batch: &mut crate::batch::Batch,
+        user_key: &str,
+    ) -> Result<crate::batch::BatchCall<()>> {
+        let url = format!(
+            "/admin/directory/v1/users/{}/signOut",
+            crate::progenitor_support::encode_path(&user_key.to_string()),
+        );
+
+        Ok(batch.add(reqwest::Method::POST, &url, None))
+    }
+
     /**
      * This function performs a `POST` to the `/admin/directory/v1/users/{userKey}/undelete` endpoint.
      *
@@ -611,4 +1118,33 @@ impl Users {
             .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
             .await
     }
+
+    /**
+     * Like `undelete`, but adds the call to a `Batch` to be sent with others, instead of sending it.
+     *
+     * This function performs a `POST` to the `/admin/directory/v1/users/{userKey}/undelete` endpoint.
+     *
+     * Undeletes a deleted user.
+     *
+     * **Parameters:**
+     *
+     * * `user_key: &str` -- The immutable id of the user.
+     */
+    pub fn undelete_batch(
+        &self,
+        batch: &mut crate::batch::Batch,
+        user_key: &str,
+        body: &crate::types::UserUndelete,
+    ) -> Result<crate::batch::BatchCall<()>> {
+        let url = format!(
+            "/admin/directory/v1/users/{}/undelete",
+            crate::progenitor_support::encode_path(&user_key.to_string()),
+        );
+
+        Ok(batch.add(
+            reqwest::Method::POST,
+            &url,
+            Some(serde_json::to_string(body)?),
+        ))
+    }
 }
@@ -30,6 +30,30 @@ impl VerificationCodes {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Like `list`, but adds the call to a `Batch` to be sent with others, instead of sending it.
+     *
+     * This function performs a `GET` to the `/admin/directory/v1/users/{userKey}/verificationCodes` endpoint.
+     *
+     * Returns the current set of valid backup verification codes for the specified user.
+     *
+     * **Parameters:**
+     *
+     * * `user_key: &str` -- Identifies the user in the API request. The value can be the user's primary email address, alias email address, or unique user ID.
+     */
+    pub fn list_batch(
+        &self,
+        batch: &mut crate::batch::Batch,
+        user_key: &str,
+    ) -> Result<crate::batch::BatchCall<crate::types::VerificationCodes>> {
+        let url = format!(
+            "/admin/directory/v1/users/{}/verificationCodes",
+            crate::progenitor_support::encode_path(&user_key.to_string()),
+        );
+
+        Ok(batch.add(reqwest::Method::GET, &url, None))
+    }
+
     /**
      * This function performs a `POST` to the `/admin/directory/v1/users/{userKey}/verificationCodes/generate` endpoint.
      *
@@ -48,6 +72,30 @@ impl VerificationCodes {
         self.client.post(&url, None).await
     }
 
+    /**
+     * Like `generate`, but adds the call to a `Batch` to be sent with others, instead of sending it.
+     *
+     * This function performs a `POST` to the `/admin/directory/v1/users/{userKey}/verificationCodes/generate` endpoint.
+     *
+     * Generates new backup verification codes for the user.
+     *
+     * **Parameters:**
+     *
+     * * `user_key: &str` -- Email or immutable ID of the user.
+     */
+    pub fn generate_batch(
+        &self,
+        batch: &mut crate::batch::Batch,
+        user_key: &str,
+    ) -> Result<crate::batch::BatchCall<()>> {
+        let url = format!(
+            "/admin/directory/v1/users/{}/verificationCodes/generate",
+            crate::progenitor_support::encode_path(&user_key.to_string()),
+        );
+
+        Ok(batch.add(reqwest::Method::POST, &url, None))
+    }
+
     /**
      * This function performs a `POST` to the `/admin/directory/v1/users/{userKey}/verificationCodes/invalidate` endpoint.
      *
@@ -65,4 +113,28 @@ impl VerificationCodes {
 
         self.client.post(&url, None).await
     }
+
+    /**
+     * Like `invalidate`, but adds the call to a `Batch` to be sent with others, instead of sending it.
+     *
+     * This function performs a `POST` to the `/admin/directory/v1/users/{userKey}/verificationCodes/invalidate` endpoint.
+     *
+     * Invalidates the current backup verification codes for the user.
+     *
+     * **Parameters:**
+     *
+     * * `user_key: &str` -- Email or immutable ID of the user.
+     */
+    pub fn invalidate_batch(
+        &self,
+        batch: &mut crate::batch::Batch,
+        user_key: &str,
+    ) -> Result<crate::batch::BatchCall<()>> {
+        let url = format!(
+            "/admin/directory/v1/users/{}/verificationCodes/invalidate",
+            crate::progenitor_support::encode_path(&user_key.to_string()),
+        );
+
+        Ok(batch.add(reqwest::Method::POST, &url, None))
+    }
 }
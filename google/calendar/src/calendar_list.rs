@@ -147,6 +147,37 @@ impl CalendarList {
             .await
     }
 
+    /**
+     * Like `list_insert`, but adds the call to a `Batch` to be sent with others, instead of sending it.
+     *
+     * This function performs a `POST` to the `/users/me/calendarList` endpoint.
+     *
+     * Inserts an existing calendar into the user's calendar list.
+     *
+     * **Parameters:**
+     *
+     * * `color_rgb_format: bool` -- Whether to use the foregroundColor and backgroundColor fields to write the calendar colors (RGB). If this feature is used, the index-based colorId field will be set to the best matching option automatically. Optional. The default is False.
+     */
+    pub fn list_insert_batch(
+        &self,
+        batch: &mut crate::batch::Batch,
+        color_rgb_format: bool,
+        body: &crate::types::CalendarListEntry,
+    ) -> Result<crate::batch::BatchCall<crate::types::CalendarListEntry>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if color_rgb_format {
+            query_args.push(("colorRgbFormat".to_string(), color_rgb_format.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/users/me/calendarList?{}", query_);
+
+        Ok(batch.add(
+            reqwest::Method::POST,
+            &url,
+            Some(serde_json::to_string(body)?),
+        ))
+    }
+
     /**
      * This function performs a `POST` to the `/users/me/calendarList/watch` endpoint.
      *
@@ -198,6 +229,62 @@ impl CalendarList {
             .await
     }
 
+    /**
+     * Like `list_watch`, but adds the call to a `Batch` to be sent with others, instead of sending it.
+     *
+     * This function performs a `POST` to the `/users/me/calendarList/watch` endpoint.
+     *
+     * Watch for changes to CalendarList resources.
+     *
+     * **Parameters:**
+     *
+     * * `max_results: i64` -- Maximum number of entries returned on one result page. By default the value is 100 entries. The page size can never be larger than 250 entries. Optional.
+     * * `min_access_role: crate::types::MinAccessRole` -- The minimum access role for the user in the returned entries. Optional. The default is no restriction.
+     * * `page_token: &str` -- Token specifying which result page to return. Optional.
+     * * `show_deleted: bool` -- Whether this calendar list entry has been deleted from the calendar list. Read-only. Optional. The default is False.
+     * * `show_hidden: bool` -- Whether this calendar list entry has been deleted from the calendar list. Read-only. Optional. The default is False.
+     * * `sync_token: &str` -- Token obtained from the nextSyncToken field returned on the last page of results from the previous list request. It makes the result of this list request contain only entries that have changed since then. If only read-only fields such as calendar properties or ACLs have changed, the entry won't be returned. All entries deleted and hidden since the previous list request will always be in the result set and it is not allowed to set showDeleted neither showHidden to False.
+     *   To ensure client state consistency minAccessRole query parameter cannot be specified together with nextSyncToken.
+     *   If the syncToken expires, the server will respond with a 410 GONE response code and the client should clear its storage and perform a full synchronization without any syncToken.
+     *   Learn more about incremental synchronization.
+     *   Optional. The default is to return all entries.
+     */
+    pub fn list_watch_batch(
+        &self,
+        batch: &mut crate::batch::Batch,
+        max_results: i64,
+        min_access_role: crate::types::MinAccessRole,
+        page_token: &str,
+        show_deleted: bool,
+        show_hidden: bool,
+        body: &crate::types::Channel,
+    ) -> Result<crate::batch::BatchCall<crate::types::Channel>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if max_results > 0 {
+            query_args.push(("maxResults".to_string(), max_results.to_string()));
+        }
+        if !min_access_role.to_string().is_empty() {
+            query_args.push(("minAccessRole".to_string(), min_access_role.to_string()));
+        }
+        if !page_token.is_empty() {
+            query_args.push(("pageToken".to_string(), page_token.to_string()));
+        }
+        if show_deleted {
+            query_args.push(("showDeleted".to_string(), show_deleted.to_string()));
+        }
+        if show_hidden {
+            query_args.push(("showHidden".to_string(), show_hidden.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/users/me/calendarList/watch?{}", query_);
+
+        Ok(batch.add(
+            reqwest::Method::POST,
+            &url,
+            Some(serde_json::to_string(body)?),
+        ))
+    }
+
     /**
      * This function performs a `GET` to the `/users/me/calendarList/{calendarId}` endpoint.
      *
@@ -216,6 +303,30 @@ impl CalendarList {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Like `list_get`, but adds the call to a `Batch` to be sent with others, instead of sending it.
+     *
+     * This function performs a `GET` to the `/users/me/calendarList/{calendarId}` endpoint.
+     *
+     * Returns a calendar from the user's calendar list.
+     *
+     * **Parameters:**
+     *
+     * * `calendar_id: &str` -- Calendar identifier. To retrieve calendar IDs call the calendarList.list method. If you want to access the primary calendar of the currently logged in user, use the "primary" keyword.
+     */
+    pub fn list_get_batch(
+        &self,
+        batch: &mut crate::batch::Batch,
+        calendar_id: &str,
+    ) -> Result<crate::batch::BatchCall<crate::types::CalendarListEntry>> {
+        let url = format!(
+            "/users/me/calendarList/{}",
+            crate::progenitor_support::encode_path(&calendar_id.to_string()),
+        );
+
+        Ok(batch.add(reqwest::Method::GET, &url, None))
+    }
+
     /**
      * This function performs a `PUT` to the `/users/me/calendarList/{calendarId}` endpoint.
      *
@@ -248,6 +359,43 @@ impl CalendarList {
             .await
     }
 
+    /**
+     * Like `list_update`, but adds the call to a `Batch` to be sent with others, instead of sending it.
+     *
+     * This function performs a `PUT` to the `/users/me/calendarList/{calendarId}` endpoint.
+     *
+     * Updates an existing calendar on the user's calendar list.
+     *
+     * **Parameters:**
+     *
+     * * `calendar_id: &str` -- Calendar identifier. To retrieve calendar IDs call the calendarList.list method. If you want to access the primary calendar of the currently logged in user, use the "primary" keyword.
+     * * `color_rgb_format: bool` -- Whether to use the foregroundColor and backgroundColor fields to write the calendar colors (RGB). If this feature is used, the index-based colorId field will be set to the best matching option automatically. Optional. The default is False.
+     */
+    pub fn list_update_batch(
+        &self,
+        batch: &mut crate::batch::Batch,
+        calendar_id: &str,
+        color_rgb_format: bool,
+        body: &crate::types::CalendarListEntry,
+    ) -> Result<crate::batch::BatchCall<crate::types::CalendarListEntry>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if color_rgb_format {
+            query_args.push(("colorRgbFormat".to_string(), color_rgb_format.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/users/me/calendarList/{}?{}",
+            crate::progenitor_support::encode_path(&calendar_id.to_string()),
+            query_
+        );
+
+        Ok(batch.add(
+            reqwest::Method::PUT,
+            &url,
+            Some(serde_json::to_string(body)?),
+        ))
+    }
+
     /**
      * This function performs a `DELETE` to the `/users/me/calendarList/{calendarId}` endpoint.
      *
@@ -266,6 +414,30 @@ impl CalendarList {
         self.client.delete(&url, None).await
     }
 
+    /**
+     * Like `list_delete`, but adds the call to a `Batch` to be sent with others, instead of sending it.
+     *
+     * This function performs a `DELETE` to the `/users/me/calendarList/{calendarId}` endpoint.
+     *
+     * Removes a calendar from the user's calendar list.
+     *
+     * **Parameters:**
+     *
+     * * `calendar_id: &str` -- Calendar identifier. To retrieve calendar IDs call the calendarList.list method. If you want to access the primary calendar of the currently logged in user, use the "primary" keyword.
+     */
+    pub fn list_delete_batch(
+        &self,
+        batch: &mut crate::batch::Batch,
+        calendar_id: &str,
+    ) -> Result<crate::batch::BatchCall<()>> {
+        let url = format!(
+            "/users/me/calendarList/{}",
+            crate::progenitor_support::encode_path(&calendar_id.to_string()),
+        );
+
+        Ok(batch.add(reqwest::Method::DELETE, &url, None))
+    }
+
     /**
      * This function performs a `PATCH` to the `/users/me/calendarList/{calendarId}` endpoint.
      *
@@ -297,4 +469,41 @@ impl CalendarList {
             .patch(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
             .await
     }
+
+    /**
+     * Like `list_patch`, but adds the call to a `Batch` to be sent with others, instead of sending it.
+     *
+     * This function performs a `PATCH` to the `/users/me/calendarList/{calendarId}` endpoint.
+     *
+     * Updates an existing calendar on the user's calendar list. This method supports patch semantics.
+     *
+     * **Parameters:**
+     *
+     * * `calendar_id: &str` -- Calendar identifier. To retrieve calendar IDs call the calendarList.list method. If you want to access the primary calendar of the currently logged in user, use the "primary" keyword.
+     * * `color_rgb_format: bool` -- Whether to use the foregroundColor and backgroundColor fields to write the calendar colors (RGB). If this feature is used, the index-based colorId field will be set to the best matching option automatically. Optional. The default is False.
+     */
+    pub fn list_patch_batch(
+        &self,
+        batch: &mut crate::batch::Batch,
+        calendar_id: &str,
+        color_rgb_format: bool,
+        body: &crate::types::CalendarListEntry,
+    ) -> Result<crate::batch::BatchCall<crate::types::CalendarListEntry>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if color_rgb_format {
+            query_args.push(("colorRgbFormat".to_string(), color_rgb_format.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/users/me/calendarList/{}?{}",
+            crate::progenitor_support::encode_path(&calendar_id.to_string()),
+            query_
+        );
+
+        Ok(batch.add(
+            reqwest::Method::PATCH,
+            &url,
+            Some(serde_json::to_string(body)?),
+        ))
+    }
 }
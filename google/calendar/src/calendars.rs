@@ -24,6 +24,27 @@ impl Calendars {
             .await
     }
 
+    /**
+     * Like `insert`, but adds the call to a `Batch` to be sent with others, instead of sending it.
+     *
+     * This function performs a `POST` to the `/calendars` endpoint.
+     *
+     * Creates a secondary calendar.
+     */
+    pub fn insert_batch(
+        &self,
+        batch: &mut crate::batch::Batch,
+        body: &crate::types::Calendar,
+    ) -> Result<crate::batch::BatchCall<crate::types::Calendar>> {
+        let url = "/calendars".to_string();
+
+        Ok(batch.add(
+            reqwest::Method::POST,
+            &url,
+            Some(serde_json::to_string(body)?),
+        ))
+    }
+
     /**
      * This function performs a `GET` to the `/calendars/{calendarId}` endpoint.
      *
@@ -42,6 +63,30 @@ impl Calendars {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Like `get`, but adds the call to a `Batch` to be sent with others, instead of sending it.
+     *
+     * This function performs a `GET` to the `/calendars/{calendarId}` endpoint.
+     *
+     * Returns metadata for a calendar.
+     *
+     * **Parameters:**
+     *
+     * * `calendar_id: &str` -- Calendar identifier. To retrieve calendar IDs call the calendarList.list method. If you want to access the primary calendar of the currently logged in user, use the "primary" keyword.
+     */
+    pub fn get_batch(
+        &self,
+        batch: &mut crate::batch::Batch,
+        calendar_id: &str,
+    ) -> Result<crate::batch::BatchCall<crate::types::Calendar>> {
+        let url = format!(
+            "/calendars/{}",
+            crate::progenitor_support::encode_path(&calendar_id.to_string()),
+        );
+
+        Ok(batch.add(reqwest::Method::GET, &url, None))
+    }
+
     /**
      * This function performs a `PUT` to the `/calendars/{calendarId}` endpoint.
      *
@@ -66,6 +111,35 @@ impl Calendars {
             .await
     }
 
+    /**
+     * Like `update`, but adds the call to a `Batch` to be sent with others, instead of sending it.
+     *
+     * This function performs a `PUT` to the `/calendars/{calendarId}` endpoint.
+     *
+     * Updates metadata for a calendar.
+     *
+     * **Parameters:**
+     *
+     * * `calendar_id: &str` -- Calendar identifier. To retrieve calendar IDs call the calendarList.list method. If you want to access the primary calendar of the currently logged in user, use the "primary" keyword.
+     */
+    pub fn update_batch(
+        &self,
+        batch: &mut crate::batch::Batch,
+        calendar_id: &str,
+        body: &crate::types::Calendar,
+    ) -> Result<crate::batch::BatchCall<crate::types::Calendar>> {
+        let url = format!(
+            "/calendars/{}",
+            crate::progenitor_support::encode_path(&calendar_id.to_string()),
+        );
+
+        Ok(batch.add(
+            reqwest::Method::PUT,
+            &url,
+            Some(serde_json::to_string(body)?),
+        ))
+    }
+
     /**
      * This function performs a `DELETE` to the `/calendars/{calendarId}` endpoint.
      *
@@ -84,6 +158,30 @@ impl Calendars {
         self.client.delete(&url, None).await
     }
 
+    /**
+     * Like `delete`, but adds the call to a `Batch` to be sent with others, instead of sending it.
+     *
+     * This function performs a `DELETE` to the `/calendars/{calendarId}` endpoint.
+     *
+     * Deletes a secondary calendar. Use calendars.clear for clearing all events on primary calendars.
+     *
+     * **Parameters:**
+     *
+     * * `calendar_id: &str` -- Calendar identifier. To retrieve calendar IDs call the calendarList.list method. If you want to access the primary calendar of the currently logged in user, use the "primary" keyword.
+     */
+    pub fn delete_batch(
+        &self,
+        batch: &mut crate::batch::Batch,
+        calendar_id: &str,
+    ) -> Result<crate::batch::BatchCall<()>> {
+        let url = format!(
+            "/calendars/{}",
+            crate::progenitor_support::encode_path(&calendar_id.to_string()),
+        );
+
+        Ok(batch.add(reqwest::Method::DELETE, &url, None))
+    }
+
     /**
      * This function performs a `PATCH` to the `/calendars/{calendarId}` endpoint.
      *
@@ -108,6 +206,35 @@ impl Calendars {
             .await
     }
 
+    /**
+     * Like `patch`, but adds the call to a `Batch` to be sent with others, instead of sending it.
+     *
+     * This function performs a `PATCH` to the `/calendars/{calendarId}` endpoint.
+     *
+     * Updates metadata for a calendar. This method supports patch semantics.
+     *
+     * **Parameters:**
+     *
+     * * `calendar_id: &str` -- Calendar identifier. To retrieve calendar IDs call the calendarList.list method. If you want to access the primary calendar of the currently logged in user, use the "primary" keyword.
+     */
+    pub fn patch_batch(
+        &self,
+        batch: &mut crate::batch::Batch,
+        calendar_id: &str,
+        body: &crate::types::Calendar,
+    ) -> Result<crate::batch::BatchCall<crate::types::Calendar>> {
+        let url = format!(
+            "/calendars/{}",
+            crate::progenitor_support::encode_path(&calendar_id.to_string()),
+        );
+
+        Ok(batch.add(
+            reqwest::Method::PATCH,
+            &url,
+            Some(serde_json::to_string(body)?),
+        ))
+    }
+
     /**
      * This function performs a `POST` to the `/calendars/{calendarId}/clear` endpoint.
      *
@@ -125,4 +252,28 @@ impl Calendars {
 
         self.client.post(&url, None).await
     }
+
+    /**
+     * Like `clear`, but adds the call to a `Batch` to be sent with others, instead of sending it.
+     *
+     * This function performs a `POST` to the `/calendars/{calendarId}/clear` endpoint.
+     *
+     * Clears a primary calendar. This operation deletes all events associated with the primary calendar of an account.
+     *
+     * **Parameters:**
+     *
+     * * `calendar_id: &str` -- Calendar identifier. To retrieve calendar IDs call the calendarList.list method. If you want to access the primary calendar of the currently logged in user, use the "primary" keyword.
+     */
+    pub fn clear_batch(
+        &self,
+        batch: &mut crate::batch::Batch,
+        calendar_id: &str,
+    ) -> Result<crate::batch::BatchCall<()>> {
+        let url = format!(
+            "/calendars/{}/clear",
+            crate::progenitor_support::encode_path(&calendar_id.to_string()),
+        );
+
+        Ok(batch.add(reqwest::Method::POST, &url, None))
+    }
 }
@@ -23,4 +23,25 @@ impl Channels {
             .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
             .await
     }
+
+    /**
+     * Like `stop`, but adds the call to a `Batch` to be sent with others, instead of sending it.
+     *
+     * This function performs a `POST` to the `/channels/stop` endpoint.
+     *
+     * Stop watching resources through this channel
+     */
+    pub fn stop_batch(
+        &self,
+        batch: &mut crate::batch::Batch,
+        body: &crate::types::Channel,
+    ) -> Result<crate::batch::BatchCall<()>> {
+        let url = "/channels/stop".to_string();
+
+        Ok(batch.add(
+            reqwest::Method::POST,
+            &url,
+            Some(serde_json::to_string(body)?),
+        ))
+    }
 }
@@ -21,4 +21,20 @@ impl Colors {
         let url = "/colors".to_string();
         self.client.get(&url, None).await
     }
+
+    /**
+     * Like `get`, but adds the call to a `Batch` to be sent with others, instead of sending it.
+     *
+     * This function performs a `GET` to the `/colors` endpoint.
+     *
+     * Returns the color definitions for calendars and events.
+     */
+    pub fn get_batch(
+        &self,
+        batch: &mut crate::batch::Batch,
+    ) -> Result<crate::batch::BatchCall<crate::types::Colors>> {
+        let url = "/colors".to_string();
+
+        Ok(batch.add(reqwest::Method::GET, &url, None))
+    }
 }
@@ -26,4 +26,25 @@ impl Freebusy {
             .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
             .await
     }
+
+    /**
+     * Like `query`, but adds the call to a `Batch` to be sent with others, instead of sending it.
+     *
+     * This function performs a `POST` to the `/freeBusy` endpoint.
+     *
+     * Returns free/busy information for a set of calendars.
+     */
+    pub fn query_batch(
+        &self,
+        batch: &mut crate::batch::Batch,
+        body: &crate::types::FreeBusyRequest,
+    ) -> Result<crate::batch::BatchCall<crate::types::FreeBusyResponse>> {
+        let url = "/freeBusy".to_string();
+
+        Ok(batch.add(
+            reqwest::Method::POST,
+            &url,
+            Some(serde_json::to_string(body)?),
+        ))
+    }
 }
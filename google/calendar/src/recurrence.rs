@@ -0,0 +1,1043 @@
+//! Parse, serialize and expand RFC 5545 recurrence rules.
+//!
+//! `Event.recurrence` holds the raw `RRULE`, `RDATE` and `EXDATE` lines of a
+//! recurring event. `Recurrence` parses them into typed rules and dates, and can
+//! expand the occurrences of an event within a window locally, in the time zone of
+//! the event.
+//!
+//! ```no_run
+//! # fn run(event: google_calendar::types::Event) -> anyhow::Result<()> {
+//! use google_calendar::recurrence::expand_event;
+//!
+//! let time_min = chrono::Utc::now();
+//! let time_max = time_min + chrono::Duration::weeks(4);
+//! for occurrence in expand_event(&event, chrono_tz::America::New_York, time_min, time_max)? {
+//!     println!("{} - {}", occurrence.start, occurrence.end);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+use std::{convert::TryFrom, fmt, str::FromStr};
+
+use anyhow::{anyhow, bail, Result};
+use chrono::{
+    DateTime, Datelike, Duration, NaiveDate, NaiveDateTime, NaiveTime, Offset, TimeZone, Timelike,
+    Utc, Weekday,
+};
+use chrono_tz::Tz;
+
+use crate::types::{Event, EventDateTime};
+
+const DATE_FORMAT: &str = "%Y%m%d";
+const DATE_TIME_FORMAT: &str = "%Y%m%dT%H%M%S";
+
+/// How often a rule repeats.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Frequency {
+    Secondly,
+    Minutely,
+    Hourly,
+    Daily,
+    Weekly,
+    Monthly,
+    Yearly,
+}
+
+impl fmt::Display for Frequency {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let s = match self {
+            Frequency::Secondly => "SECONDLY",
+            Frequency::Minutely => "MINUTELY",
+            Frequency::Hourly => "HOURLY",
+            Frequency::Daily => "DAILY",
+            Frequency::Weekly => "WEEKLY",
+            Frequency::Monthly => "MONTHLY",
+            Frequency::Yearly => "YEARLY",
+        };
+        write!(f, "{}", s)
+    }
+}
+
+impl FromStr for Frequency {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Ok(match s.to_uppercase().as_str() {
+            "SECONDLY" => Frequency::Secondly,
+            "MINUTELY" => Frequency::Minutely,
+            "HOURLY" => Frequency::Hourly,
+            "DAILY" => Frequency::Daily,
+            "WEEKLY" => Frequency::Weekly,
+            "MONTHLY" => Frequency::Monthly,
+            "YEARLY" => Frequency::Yearly,
+            _ => bail!("invalid FREQ: {}", s),
+        })
+    }
+}
+
+/// A day of the week in a `BYDAY` rule part, like `MO`, `2TU` or `-1FR`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WeekdayNum {
+    /// Which occurrence of the day within the month or year, counting from the end
+    /// if negative. Zero means every occurrence.
+    pub n: i32,
+    pub weekday: Weekday,
+}
+
+impl WeekdayNum {
+    /// Every occurrence of a day of the week.
+    pub fn every(weekday: Weekday) -> Self {
+        WeekdayNum { n: 0, weekday }
+    }
+
+    /// The `n`th occurrence of a day of the week, counting from the end if negative.
+    pub fn nth(n: i32, weekday: Weekday) -> Self {
+        WeekdayNum { n, weekday }
+    }
+}
+
+impl fmt::Display for WeekdayNum {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.n != 0 {
+            write!(f, "{}", self.n)?;
+        }
+        write!(f, "{}", weekday_str(self.weekday))
+    }
+}
+
+impl FromStr for WeekdayNum {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        if s.len() < 2 || !s.is_char_boundary(s.len() - 2) {
+            bail!("invalid BYDAY value: {}", s);
+        }
+        let (n, day) = s.split_at(s.len() - 2);
+        let n = match n.trim_start_matches('+') {
+            "" => 0,
+            n => match n.parse::<i32>() {
+                Ok(n) if n != 0 && (-53..=53).contains(&n) => n,
+                _ => bail!("invalid BYDAY value: {}", s),
+            },
+        };
+
+        Ok(WeekdayNum {
+            n,
+            weekday: parse_weekday(day)?,
+        })
+    }
+}
+
+fn weekday_str(weekday: Weekday) -> &'static str {
+    match weekday {
+        Weekday::Mon => "MO",
+        Weekday::Tue => "TU",
+        Weekday::Wed => "WE",
+        Weekday::Thu => "TH",
+        Weekday::Fri => "FR",
+        Weekday::Sat => "SA",
+        Weekday::Sun => "SU",
+    }
+}
+
+fn parse_weekday(s: &str) -> Result<Weekday> {
+    Ok(match s.to_uppercase().as_str() {
+        "MO" => Weekday::Mon,
+        "TU" => Weekday::Tue,
+        "WE" => Weekday::Wed,
+        "TH" => Weekday::Thu,
+        "FR" => Weekday::Fri,
+        "SA" => Weekday::Sat,
+        "SU" => Weekday::Sun,
+        _ => bail!("invalid weekday: {}", s),
+    })
+}
+
+/// A date or date and time in an `UNTIL`, `RDATE` or `EXDATE`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RecurrenceDate {
+    /// A date, for all-day events.
+    Date(NaiveDate),
+    /// A local date and time in the zone from its `TZID`, or in the time zone of the
+    /// event if `None`.
+    Local(NaiveDateTime, Option<Tz>),
+    /// A date and time in UTC.
+    Utc(DateTime<Utc>),
+}
+
+impl RecurrenceDate {
+    fn parse(value: &str, tz: Option<Tz>) -> Result<Self> {
+        let value = value.trim();
+        if let Some(utc) = value.strip_suffix('Z') {
+            let date_time = NaiveDateTime::parse_from_str(utc, DATE_TIME_FORMAT)
+                .map_err(|e| anyhow!("invalid date-time {}: {}", value, e))?;
+            Ok(RecurrenceDate::Utc(Utc.from_utc_datetime(&date_time)))
+        } else if value.contains('T') {
+            let date_time = NaiveDateTime::parse_from_str(value, DATE_TIME_FORMAT)
+                .map_err(|e| anyhow!("invalid date-time {}: {}", value, e))?;
+            Ok(RecurrenceDate::Local(date_time, tz))
+        } else {
+            let date = NaiveDate::parse_from_str(value, DATE_FORMAT)
+                .map_err(|e| anyhow!("invalid date {}: {}", value, e))?;
+            Ok(RecurrenceDate::Date(date))
+        }
+    }
+
+    /// The value without any parameters, as it is written in a property.
+    fn value(&self) -> String {
+        match self {
+            RecurrenceDate::Date(d) => d.format(DATE_FORMAT).to_string(),
+            RecurrenceDate::Local(d, _) => d.format(DATE_TIME_FORMAT).to_string(),
+            RecurrenceDate::Utc(d) => format!("{}Z", d.format(DATE_TIME_FORMAT)),
+        }
+    }
+
+    /// The parameters of a property with this value.
+    fn params(&self) -> String {
+        match self {
+            RecurrenceDate::Date(_) => ";VALUE=DATE".to_string(),
+            RecurrenceDate::Local(_, Some(tz)) => format!(";TZID={}", tz.name()),
+            _ => "".to_string(),
+        }
+    }
+
+    /// The local date and time in `tz`. Dates are at `time`.
+    fn local_in(&self, tz: Tz, time: NaiveTime) -> NaiveDateTime {
+        match self {
+            RecurrenceDate::Date(d) => d.and_time(time),
+            RecurrenceDate::Local(d, None) => *d,
+            RecurrenceDate::Local(d, Some(zone)) => {
+                localize(*zone, *d).with_timezone(&tz).naive_local()
+            }
+            RecurrenceDate::Utc(d) => d.with_timezone(&tz).naive_local(),
+        }
+    }
+}
+
+impl fmt::Display for RecurrenceDate {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.value())
+    }
+}
+
+/// A recurrence rule, as in an `RRULE` line.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RRule {
+    pub frequency: Frequency,
+    pub interval: u32,
+    pub count: Option<u32>,
+    pub until: Option<RecurrenceDate>,
+    pub by_second: Vec<u32>,
+    pub by_minute: Vec<u32>,
+    pub by_hour: Vec<u32>,
+    pub by_day: Vec<WeekdayNum>,
+    pub by_month_day: Vec<i32>,
+    pub by_year_day: Vec<i32>,
+    pub by_week_no: Vec<i32>,
+    pub by_month: Vec<u32>,
+    pub by_set_pos: Vec<i32>,
+    pub week_start: Weekday,
+}
+
+impl RRule {
+    /// A rule that repeats forever at `frequency`, with every other part unset.
+    pub fn new(frequency: Frequency) -> Self {
+        RRule {
+            frequency,
+            interval: 1,
+            count: None,
+            until: None,
+            by_second: Vec::new(),
+            by_minute: Vec::new(),
+            by_hour: Vec::new(),
+            by_day: Vec::new(),
+            by_month_day: Vec::new(),
+            by_year_day: Vec::new(),
+            by_week_no: Vec::new(),
+            by_month: Vec::new(),
+            by_set_pos: Vec::new(),
+            week_start: Weekday::Mon,
+        }
+    }
+
+    /// Expand the rule from `start`, in local time in `tz`, up to the first period
+    /// that starts after `end`. `start` is always the first occurrence.
+    fn expand(&self, start: NaiveDateTime, tz: Tz, end: DateTime<Utc>) -> Vec<NaiveDateTime> {
+        let rule = self.with_defaults(start);
+        let interval = rule.interval.max(1) as i64;
+        let week_start_offset = days_since(start.date().weekday(), rule.week_start);
+
+        let mut occurrences = vec![start];
+        if rule.count == Some(1) {
+            return occurrences;
+        }
+
+        for k in 0.. {
+            let (dates, base) = match rule.period(start, k * interval, week_start_offset) {
+                Some(period) => period,
+                None => break,
+            };
+            if localize(tz, base) > end {
+                break;
+            }
+
+            let times = rule.times(base);
+            let mut candidates: Vec<NaiveDateTime> = dates
+                .iter()
+                .filter(|d| rule.date_matches(**d))
+                .flat_map(|d| times.iter().map(move |t| d.and_time(*t)))
+                .collect();
+            candidates.sort();
+            if !rule.by_set_pos.is_empty() {
+                candidates = select_positions(&candidates, &rule.by_set_pos);
+            }
+
+            for candidate in candidates {
+                if candidate <= start {
+                    continue;
+                }
+                if !rule.before_until(candidate, tz) {
+                    return occurrences;
+                }
+                occurrences.push(candidate);
+                if let Some(count) = rule.count {
+                    if occurrences.len() >= count as usize {
+                        return occurrences;
+                    }
+                }
+            }
+        }
+
+        occurrences
+    }
+
+    /// Fill in the parts that are implied by the start, per RFC 5545.
+    fn with_defaults(&self, start: NaiveDateTime) -> RRule {
+        let mut rule = self.clone();
+        let no_days = rule.by_day.is_empty()
+            && rule.by_month_day.is_empty()
+            && rule.by_year_day.is_empty()
+            && rule.by_week_no.is_empty();
+        match rule.frequency {
+            Frequency::Yearly if no_days => {
+                if rule.by_month.is_empty() {
+                    rule.by_month = vec![start.month()];
+                }
+                rule.by_month_day = vec![start.day() as i32];
+            }
+            Frequency::Yearly
+                if !rule.by_week_no.is_empty()
+                    && rule.by_day.is_empty()
+                    && rule.by_month_day.is_empty()
+                    && rule.by_year_day.is_empty() =>
+            {
+                rule.by_day = vec![WeekdayNum::every(start.weekday())];
+            }
+            Frequency::Monthly
+                if rule.by_day.is_empty()
+                    && rule.by_month_day.is_empty()
+                    && rule.by_year_day.is_empty() =>
+            {
+                rule.by_month_day = vec![start.day() as i32];
+            }
+            Frequency::Weekly if rule.by_day.is_empty() => {
+                rule.by_day = vec![WeekdayNum::every(start.weekday())];
+            }
+            _ => {}
+        }
+
+        if rule.frequency > Frequency::Hourly && rule.by_hour.is_empty() {
+            rule.by_hour = vec![start.hour()];
+        }
+        if rule.frequency > Frequency::Minutely && rule.by_minute.is_empty() {
+            rule.by_minute = vec![start.minute()];
+        }
+        if rule.frequency > Frequency::Secondly && rule.by_second.is_empty() {
+            rule.by_second = vec![start.second()];
+        }
+
+        rule
+    }
+
+    /// The dates in the `k`th period after the start, and the start of the period
+    /// for sub-daily frequencies.
+    fn period(
+        &self,
+        start: NaiveDateTime,
+        k: i64,
+        week_start_offset: i64,
+    ) -> Option<(Vec<NaiveDate>, NaiveDateTime)> {
+        let date = start.date();
+        let (first, days) = match self.frequency {
+            Frequency::Yearly => {
+                let year = date.year().checked_add(i32::try_from(k).ok()?)?;
+                let first = NaiveDate::from_ymd_opt(year, 1, 1)?;
+                (first, days_in_year(year))
+            }
+            Frequency::Monthly => {
+                let month = (date.year() as i64) * 12 + date.month0() as i64 + k;
+                let year = i32::try_from(month.div_euclid(12)).ok()?;
+                let month = month.rem_euclid(12) as u32 + 1;
+                let first = NaiveDate::from_ymd_opt(year, month, 1)?;
+                (first, days_in_month(year, month))
+            }
+            Frequency::Weekly => {
+                let first = date.checked_sub_signed(Duration::days(week_start_offset))?;
+                (first.checked_add_signed(Duration::weeks(k))?, 7)
+            }
+            Frequency::Daily => (date.checked_add_signed(Duration::days(k))?, 1),
+            Frequency::Hourly | Frequency::Minutely | Frequency::Secondly => {
+                let (truncated, step) = match self.frequency {
+                    Frequency::Hourly => (
+                        start.date().and_hms_opt(start.hour(), 0, 0)?,
+                        Duration::hours(k),
+                    ),
+                    Frequency::Minutely => (
+                        start.date().and_hms_opt(start.hour(), start.minute(), 0)?,
+                        Duration::minutes(k),
+                    ),
+                    _ => (start, Duration::seconds(k)),
+                };
+                let base = truncated.checked_add_signed(step)?;
+                return Some((vec![base.date()], base));
+            }
+        };
+
+        let dates = (0..days)
+            .filter_map(|d| first.checked_add_signed(Duration::days(d as i64)))
+            .collect::<Vec<_>>();
+        if dates.is_empty() {
+            return None;
+        }
+        Some((dates, midnight(first)))
+    }
+
+    /// The times of day in a period, which for sub-daily frequencies is limited to
+    /// the hour, minute or second starting at `base`.
+    fn times(&self, base: NaiveDateTime) -> Vec<NaiveTime> {
+        let part = |frequency: Frequency, values: &[u32], base: u32| -> Vec<u32> {
+            if self.frequency > frequency {
+                values.to_vec()
+            } else if values.is_empty() || values.contains(&base) {
+                vec![base]
+            } else {
+                Vec::new()
+            }
+        };
+        let hours = part(Frequency::Hourly, &self.by_hour, base.hour());
+        let minutes = part(Frequency::Minutely, &self.by_minute, base.minute());
+        let seconds = part(Frequency::Secondly, &self.by_second, base.second());
+
+        let mut times = Vec::new();
+        for h in &hours {
+            for m in &minutes {
+                for s in &seconds {
+                    if let Some(t) = NaiveTime::from_hms_opt(*h, *m, *s) {
+                        times.push(t);
+                    }
+                }
+            }
+        }
+        times.sort();
+        times.dedup();
+        times
+    }
+
+    /// Returns if a date in a period matches the `BYxxx` parts of the rule.
+    fn date_matches(&self, date: NaiveDate) -> bool {
+        let year_days = days_in_year(date.year()) as i32;
+        let month_days = days_in_month(date.year(), date.month()) as i32;
+
+        if !self.by_month.is_empty() && !self.by_month.contains(&date.month()) {
+            return false;
+        }
+        if !self.by_week_no.is_empty() && self.frequency == Frequency::Yearly {
+            let (week, weeks) = week_number(date, self.week_start);
+            if !self
+                .by_week_no
+                .iter()
+                .any(|w| *w == week || *w == week - weeks - 1)
+            {
+                return false;
+            }
+        }
+        if !self.by_year_day.is_empty() {
+            let day = date.ordinal() as i32;
+            if !self
+                .by_year_day
+                .iter()
+                .any(|d| *d == day || *d == day - year_days - 1)
+            {
+                return false;
+            }
+        }
+        if !self.by_month_day.is_empty() {
+            let day = date.day() as i32;
+            if !self
+                .by_month_day
+                .iter()
+                .any(|d| *d == day || *d == day - month_days - 1)
+            {
+                return false;
+            }
+        }
+        if !self.by_day.is_empty() {
+            // Numbered days are within the month for monthly rules and yearly rules
+            // with months, within the year for other yearly rules, and are otherwise
+            // meaningless.
+            let (day, days) = match self.frequency {
+                Frequency::Monthly => (date.day() as i32, month_days),
+                Frequency::Yearly if !self.by_month.is_empty() => (date.day() as i32, month_days),
+                Frequency::Yearly if self.by_week_no.is_empty() => {
+                    (date.ordinal() as i32, year_days)
+                }
+                _ => (0, 0),
+            };
+            if !self.by_day.iter().any(|wd| {
+                wd.weekday == date.weekday()
+                    && (wd.n == 0
+                        || days == 0
+                        || wd.n == (day - 1) / 7 + 1
+                        || wd.n == -((days - day) / 7 + 1))
+            }) {
+                return false;
+            }
+        }
+
+        true
+    }
+
+    fn before_until(&self, candidate: NaiveDateTime, tz: Tz) -> bool {
+        match &self.until {
+            None => true,
+            Some(RecurrenceDate::Date(d)) => candidate.date() <= *d,
+            Some(RecurrenceDate::Local(d, None)) => candidate <= *d,
+            Some(RecurrenceDate::Local(d, Some(zone))) => {
+                localize(tz, candidate) <= localize(*zone, *d)
+            }
+            Some(RecurrenceDate::Utc(d)) => localize(tz, candidate).with_timezone(&Utc) <= *d,
+        }
+    }
+}
+
+impl fmt::Display for RRule {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fn join<T: ToString>(values: &[T]) -> String {
+            values
+                .iter()
+                .map(|v| v.to_string())
+                .collect::<Vec<_>>()
+                .join(",")
+        }
+
+        write!(f, "FREQ={}", self.frequency)?;
+        if let Some(until) = &self.until {
+            write!(f, ";UNTIL={}", until)?;
+        }
+        if let Some(count) = self.count {
+            write!(f, ";COUNT={}", count)?;
+        }
+        if self.interval > 1 {
+            write!(f, ";INTERVAL={}", self.interval)?;
+        }
+        let parts: [(&str, String); 9] = [
+            ("BYSECOND", join(&self.by_second)),
+            ("BYMINUTE", join(&self.by_minute)),
+            ("BYHOUR", join(&self.by_hour)),
+            ("BYDAY", join(&self.by_day)),
+            ("BYMONTHDAY", join(&self.by_month_day)),
+            ("BYYEARDAY", join(&self.by_year_day)),
+            ("BYWEEKNO", join(&self.by_week_no)),
+            ("BYMONTH", join(&self.by_month)),
+            ("BYSETPOS", join(&self.by_set_pos)),
+        ];
+        for (name, value) in parts.iter() {
+            if !value.is_empty() {
+                write!(f, ";{}={}", name, value)?;
+            }
+        }
+        if self.week_start != Weekday::Mon {
+            write!(f, ";WKST={}", weekday_str(self.week_start))?;
+        }
+
+        Ok(())
+    }
+}
+
+impl FromStr for RRule {
+    type Err = anyhow::Error;
+
+    /// Parse a rule, with or without the `RRULE:` prefix.
+    fn from_str(s: &str) -> Result<Self> {
+        let s = s.trim();
+        let s = if s.len() > 6 && s[..6].eq_ignore_ascii_case("RRULE:") {
+            &s[6..]
+        } else {
+            s
+        };
+
+        let mut frequency = None;
+        let mut rule = RRule::new(Frequency::Daily);
+        for part in s.split(';').filter(|p| !p.is_empty()) {
+            let (name, value) = part
+                .split_once('=')
+                .ok_or_else(|| anyhow!("invalid RRULE part: {}", part))?;
+            match name.to_uppercase().as_str() {
+                "FREQ" => frequency = Some(value.parse()?),
+                "INTERVAL" => {
+                    rule.interval = value
+                        .parse()
+                        .ok()
+                        .filter(|i| *i > 0)
+                        .ok_or_else(|| anyhow!("invalid INTERVAL: {}", value))?
+                }
+                "COUNT" => {
+                    rule.count = Some(
+                        value
+                            .parse()
+                            .map_err(|_| anyhow!("invalid COUNT: {}", value))?,
+                    )
+                }
+                "UNTIL" => rule.until = Some(RecurrenceDate::parse(value, None)?),
+                "BYSECOND" => rule.by_second = parse_list(name, value, 0, 60)?,
+                "BYMINUTE" => rule.by_minute = parse_list(name, value, 0, 59)?,
+                "BYHOUR" => rule.by_hour = parse_list(name, value, 0, 23)?,
+                "BYDAY" => {
+                    rule.by_day = value
+                        .split(',')
+                        .map(|d| d.trim().parse())
+                        .collect::<Result<_>>()?
+                }
+                "BYMONTHDAY" => rule.by_month_day = parse_signed_list(name, value, 31)?,
+                "BYYEARDAY" => rule.by_year_day = parse_signed_list(name, value, 366)?,
+                "BYWEEKNO" => rule.by_week_no = parse_signed_list(name, value, 53)?,
+                "BYMONTH" => rule.by_month = parse_list(name, value, 1, 12)?,
+                "BYSETPOS" => rule.by_set_pos = parse_signed_list(name, value, 366)?,
+                "WKST" => rule.week_start = parse_weekday(value)?,
+                _ => bail!("unsupported RRULE part: {}", part),
+            }
+        }
+
+        rule.frequency = frequency.ok_or_else(|| anyhow!("RRULE has no FREQ: {}", s))?;
+        if rule.count.is_some() && rule.until.is_some() {
+            bail!("RRULE has both COUNT and UNTIL: {}", s);
+        }
+
+        Ok(rule)
+    }
+}
+
+fn parse_list(name: &str, value: &str, min: u32, max: u32) -> Result<Vec<u32>> {
+    value
+        .split(',')
+        .map(|v| match v.trim().parse::<u32>() {
+            Ok(n) if (min..=max).contains(&n) => Ok(n),
+            _ => Err(anyhow!("invalid {} value: {}", name, v)),
+        })
+        .collect()
+}
+
+fn parse_signed_list(name: &str, value: &str, max: i32) -> Result<Vec<i32>> {
+    value
+        .split(',')
+        .map(|v| match v.trim().trim_start_matches('+').parse::<i32>() {
+            Ok(n) if n != 0 && (-max..=max).contains(&n) => Ok(n),
+            _ => Err(anyhow!("invalid {} value: {}", name, v)),
+        })
+        .collect()
+}
+
+/// The recurrence of an event: its rules, and the dates added to and excluded from
+/// them.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Recurrence {
+    pub rules: Vec<RRule>,
+    /// Extra occurrences, from `RDATE` lines.
+    pub dates: Vec<RecurrenceDate>,
+    /// Excluded occurrences, from `EXDATE` lines.
+    pub exceptions: Vec<RecurrenceDate>,
+}
+
+impl Recurrence {
+    /// Parse the lines of `Event.recurrence`.
+    pub fn parse<S>(lines: &[S]) -> Result<Self>
+    where
+        S: AsRef<str>,
+    {
+        let mut recurrence = Recurrence::default();
+        for line in lines {
+            let line = line.as_ref().trim();
+            if line.is_empty() {
+                continue;
+            }
+            let (property, value) = line
+                .split_once(':')
+                .ok_or_else(|| anyhow!("invalid recurrence line: {}", line))?;
+            let mut params = property.split(';');
+            let name = params.next().unwrap_or_default().to_uppercase();
+
+            let mut tz = None;
+            for param in params {
+                let (param_name, param_value) = param
+                    .split_once('=')
+                    .ok_or_else(|| anyhow!("invalid parameter in {}", line))?;
+                match param_name.to_uppercase().as_str() {
+                    "TZID" => tz = Some(parse_time_zone(param_value.trim_matches('"'))?),
+                    "VALUE" if param_value.eq_ignore_ascii_case("PERIOD") => {
+                        bail!("RDATE periods are not supported: {}", line)
+                    }
+                    _ => {}
+                }
+            }
+
+            match name.as_str() {
+                "RRULE" => recurrence.rules.push(value.parse()?),
+                "RDATE" | "EXDATE" => {
+                    let dates = value
+                        .split(',')
+                        .map(|v| RecurrenceDate::parse(v, tz))
+                        .collect::<Result<Vec<_>>>()?;
+                    if name == "RDATE" {
+                        recurrence.dates.extend(dates);
+                    } else {
+                        recurrence.exceptions.extend(dates);
+                    }
+                }
+                _ => bail!("unsupported recurrence property: {}", line),
+            }
+        }
+
+        Ok(recurrence)
+    }
+
+    /// Serialize to the lines of `Event.recurrence`.
+    pub fn to_lines(&self) -> Vec<String> {
+        let mut lines: Vec<String> = self.rules.iter().map(|r| format!("RRULE:{}", r)).collect();
+        for (name, dates) in [("RDATE", &self.dates), ("EXDATE", &self.exceptions)].iter() {
+            // Consecutive dates with the same parameters share a line.
+            let mut current: Option<(String, Vec<String>)> = None;
+            for date in dates.iter() {
+                let params = date.params();
+                match &mut current {
+                    Some((p, values)) if *p == params => values.push(date.value()),
+                    _ => {
+                        if let Some((p, values)) = current.take() {
+                            lines.push(format!("{}{}:{}", name, p, values.join(",")));
+                        }
+                        current = Some((params, vec![date.value()]));
+                    }
+                }
+            }
+            if let Some((p, values)) = current {
+                lines.push(format!("{}{}:{}", name, p, values.join(",")));
+            }
+        }
+
+        lines
+    }
+
+    /// Expand the occurrences of an event that starts at `start` and ends at `end`
+    /// and overlap `time_min..time_max`.
+    ///
+    /// Occurrences are in the time zone of `start`, or `default_tz` (the time zone of
+    /// the calendar) for all-day events and events without one.
+    pub fn expand(
+        &self,
+        start: &EventDateTime,
+        end: &EventDateTime,
+        default_tz: Tz,
+        time_min: DateTime<Utc>,
+        time_max: DateTime<Utc>,
+    ) -> Result<Vec<Occurrence>> {
+        let (first, tz, all_day) = local_start(start, default_tz)?;
+        let (last, _, _) = local_start(end, tz)?;
+        let duration = last - first;
+
+        let mut starts = if self.rules.is_empty() {
+            vec![first]
+        } else {
+            self.rules
+                .iter()
+                .flat_map(|r| r.expand(first, tz, time_max))
+                .collect()
+        };
+        starts.extend(self.dates.iter().map(|d| d.local_in(tz, first.time())));
+        starts.sort();
+        starts.dedup();
+
+        let excluded = |s: &NaiveDateTime| {
+            self.exceptions.iter().any(|e| match e {
+                RecurrenceDate::Date(d) => s.date() == *d,
+                e => e.local_in(tz, first.time()) == *s,
+            })
+        };
+
+        Ok(starts
+            .into_iter()
+            .filter(|s| !excluded(s))
+            .map(|s| Occurrence {
+                start: localize(tz, s),
+                // All-day events span whole days, whatever the length of the days.
+                end: if all_day {
+                    localize(tz, s + duration)
+                } else {
+                    localize(tz, s) + duration
+                },
+                all_day,
+            })
+            .filter(|o| {
+                o.start.with_timezone(&Utc) < time_max && o.end.with_timezone(&Utc) > time_min
+            })
+            .collect())
+    }
+}
+
+/// An occurrence of an event.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Occurrence {
+    /// The start the recurrence generated, which is the `originalStartTime` of the
+    /// instance. All-day occurrences start at midnight.
+    pub start: DateTime<Tz>,
+    pub end: DateTime<Tz>,
+    pub all_day: bool,
+}
+
+impl Occurrence {
+    /// Returns if two occurrences overlap in time.
+    pub fn overlaps(&self, other: &Occurrence) -> bool {
+        self.start < other.end && other.start < self.end
+    }
+}
+
+/// Expand the occurrences of an event that overlap `time_min..time_max`. Events
+/// that don't recur have a single occurrence.
+///
+/// `default_tz` is the time zone of the calendar, used for all-day events and
+/// events without a time zone.
+pub fn expand_event(
+    event: &Event,
+    default_tz: Tz,
+    time_min: DateTime<Utc>,
+    time_max: DateTime<Utc>,
+) -> Result<Vec<Occurrence>> {
+    let start = event
+        .start
+        .as_ref()
+        .ok_or_else(|| anyhow!("event {} has no start", event.id))?;
+    let end = event.end.as_ref().unwrap_or(start);
+
+    Recurrence::parse(&event.recurrence)?.expand(start, end, default_tz, time_min, time_max)
+}
+
+/// Parse an IANA time zone name, like `America/New_York`.
+pub fn parse_time_zone(name: &str) -> Result<Tz> {
+    name.parse()
+        .map_err(|e| anyhow!("invalid time zone {}: {}", name, e))
+}
+
+/// The differences between a local expansion and the instances from the API.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ExpansionDiff {
+    /// Instances the API returned that weren't expanded locally.
+    pub missing: Vec<DateTime<Utc>>,
+    /// Occurrences that were expanded locally but the API didn't return.
+    pub unexpected: Vec<DateTime<Utc>>,
+}
+
+impl ExpansionDiff {
+    /// Returns if the expansion agrees with the API.
+    pub fn is_empty(&self) -> bool {
+        self.missing.is_empty() && self.unexpected.is_empty()
+    }
+}
+
+#[async_trait::async_trait]
+pub trait RecurrenceOps {
+    /// Compare the local expansion of a recurring event with the instances the API
+    /// returns between `time_min` and `time_max`.
+    ///
+    /// Instances are matched by their original start, so moved and cancelled
+    /// instances still match. Instances moved across either end of the window may
+    /// show up as differences.
+    async fn check_expansion(
+        &self,
+        calendar_id: &str,
+        event: &Event,
+        default_tz: Tz,
+        time_min: DateTime<Utc>,
+        time_max: DateTime<Utc>,
+    ) -> Result<ExpansionDiff>;
+}
+
+#[async_trait::async_trait]
+impl RecurrenceOps for crate::events::Events {
+    /// Compare the local expansion of a recurring event with the instances the API
+    /// returns between `time_min` and `time_max`.
+    ///
+    /// Instances are matched by their original start, so moved and cancelled
+    /// instances still match. Instances moved across either end of the window may
+    /// show up as differences.
+    async fn check_expansion(
+        &self,
+        calendar_id: &str,
+        event: &Event,
+        default_tz: Tz,
+        time_min: DateTime<Utc>,
+        time_max: DateTime<Utc>,
+    ) -> Result<ExpansionDiff> {
+        let mut expected: Vec<DateTime<Utc>> = expand_event(event, default_tz, time_min, time_max)?
+            .into_iter()
+            .map(|o| o.start.with_timezone(&Utc))
+            .collect();
+
+        let instances = self
+            .get_all_instances(
+                calendar_id,
+                &event.id,
+                0,
+                "",
+                true, // show deleted
+                &time_max.to_rfc3339(),
+                &time_min.to_rfc3339(),
+                "",
+            )
+            .await?;
+        let mut actual = Vec::with_capacity(instances.len());
+        for instance in instances {
+            let start = instance
+                .original_start_time
+                .as_ref()
+                .or(instance.start.as_ref())
+                .ok_or_else(|| anyhow!("instance {} has no start", instance.id))?;
+            let (local, tz, _) = local_start(start, default_tz)?;
+            actual.push(localize(tz, local).with_timezone(&Utc));
+        }
+
+        expected.sort();
+        actual.sort();
+        Ok(ExpansionDiff {
+            missing: actual
+                .iter()
+                .filter(|a| expected.binary_search(a).is_err())
+                .cloned()
+                .collect(),
+            unexpected: expected
+                .iter()
+                .filter(|e| actual.binary_search(e).is_err())
+                .cloned()
+                .collect(),
+        })
+    }
+}
+
+/// The local start of an event, its time zone and if it is all-day.
+fn local_start(start: &EventDateTime, default_tz: Tz) -> Result<(NaiveDateTime, Tz, bool)> {
+    let tz = if start.time_zone.is_empty() {
+        default_tz
+    } else {
+        parse_time_zone(&start.time_zone)?
+    };
+
+    match (&start.date_time, &start.date) {
+        (Some(date_time), _) => Ok((date_time.with_timezone(&tz).naive_local(), tz, false)),
+        (None, Some(date)) => Ok((midnight(*date), tz, true)),
+        (None, None) => bail!("event time has neither a date nor a date-time"),
+    }
+}
+
+/// Resolve a local time in a zone. Ambiguous times resolve to the earlier time, and
+/// times skipped by a transition are moved forward by its length, as RFC 5545
+/// specifies.
+fn localize(tz: Tz, local: NaiveDateTime) -> DateTime<Tz> {
+    match tz.from_local_datetime(&local) {
+        chrono::LocalResult::Single(d) => d,
+        chrono::LocalResult::Ambiguous(earliest, _) => earliest,
+        chrono::LocalResult::None => {
+            // Interpret the time with the offset from before the transition.
+            let before = tz.offset_from_utc_datetime(&(local - Duration::days(1)));
+            tz.from_utc_datetime(
+                &(local - Duration::seconds(before.fix().local_minus_utc() as i64)),
+            )
+        }
+    }
+}
+
+fn midnight(date: NaiveDate) -> NaiveDateTime {
+    date.and_hms_opt(0, 0, 0).unwrap()
+}
+
+/// The number of days from `week_start` to `weekday`.
+fn days_since(weekday: Weekday, week_start: Weekday) -> i64 {
+    (weekday.num_days_from_monday() as i64 - week_start.num_days_from_monday() as i64).rem_euclid(7)
+}
+
+fn select_positions(candidates: &[NaiveDateTime], positions: &[i32]) -> Vec<NaiveDateTime> {
+    let len = candidates.len() as i32;
+    let mut selected: Vec<NaiveDateTime> = positions
+        .iter()
+        .filter_map(|p| {
+            let index = if *p > 0 { p - 1 } else { len + p };
+            if (0..len).contains(&index) {
+                Some(candidates[index as usize])
+            } else {
+                None
+            }
+        })
+        .collect();
+    selected.sort();
+    selected.dedup();
+    selected
+}
+
+fn days_in_year(year: i32) -> u32 {
+    if NaiveDate::from_ymd_opt(year, 2, 29).is_some() {
+        366
+    } else {
+        365
+    }
+}
+
+fn days_in_month(year: i32, month: u32) -> u32 {
+    let (next_year, next_month) = if month == 12 {
+        (year + 1, 1)
+    } else {
+        (year, month + 1)
+    };
+    NaiveDate::from_ymd_opt(next_year, next_month, 1)
+        .and_then(|d| d.pred_opt())
+        .map(|d| d.day())
+        .unwrap_or(31)
+}
+
+/// The week number of a date within its year, with weeks starting on `week_start`
+/// and week 1 being the first with at least four days in the year, along with the
+/// number of weeks in the year.
+fn week_number(date: NaiveDate, week_start: Weekday) -> (i32, i32) {
+    let first_week = |year: i32| {
+        let jan1 = NaiveDate::from_ymd_opt(year, 1, 1).unwrap();
+        let offset = days_since(jan1.weekday(), week_start);
+        let week = jan1 - Duration::days(offset);
+        // Week 1 is the week containing January 4th.
+        if offset >= 4 {
+            week + Duration::weeks(1)
+        } else {
+            week
+        }
+    };
+
+    let year = date.year();
+    let start = first_week(year);
+    let next = first_week(year + 1);
+    let weeks = ((next - start).num_days() / 7) as i32;
+    if date < start {
+        // The last week of the previous year.
+        let previous = first_week(year - 1);
+        let weeks = ((start - previous).num_days() / 7) as i32;
+        return (weeks, weeks);
+    }
+    if date >= next {
+        return (1, weeks);
+    }
+
+    (((date - start).num_days() / 7) as i32 + 1, weeks)
+}
@@ -0,0 +1,392 @@
+//! Find free meeting slots from free/busy information.
+//!
+//! `FreebusyOps::query_all` queries any number of calendars and groups, splitting
+//! them into requests of at most 50 and querying the members of groups the API
+//! couldn't expand in full. `find_slots` then finds the times when every attendee
+//! is free and within their working hours.
+//!
+//! ```no_run
+//! # async fn run(client: google_calendar::Client) -> anyhow::Result<()> {
+//! use google_calendar::scheduling::{find_slots, Attendee, FreebusyOps, SlotRequest};
+//!
+//! let attendees = vec![
+//!     Attendee::new("alice@example.com", chrono_tz::America::New_York),
+//!     Attendee::new("team@example.com", chrono_tz::Europe::London),
+//! ];
+//! let time_min = chrono::Utc::now();
+//! let time_max = time_min + chrono::Duration::weeks(1);
+//!
+//! let ids: Vec<String> = attendees.iter().map(|a| a.id.clone()).collect();
+//! let free_busy = client.freebusy().query_all(&ids, time_min, time_max).await?;
+//!
+//! let request = SlotRequest::new(chrono::Duration::minutes(30), attendees)
+//!     .with_buffer(chrono::Duration::minutes(10));
+//! let slots = find_slots(&free_busy, &request, time_min, time_max);
+//! # Ok(())
+//! # }
+//! ```
+use std::collections::{HashMap, HashSet};
+
+use anyhow::Result;
+use chrono::{DateTime, Datelike, Duration, NaiveTime, TimeZone, Utc, Weekday};
+use chrono_tz::Tz;
+use serde::Deserialize;
+
+/// The maximum number of calendars and groups in a single free/busy request.
+pub const MAX_ITEMS_PER_QUERY: usize = 50;
+
+/// The maximum number of members the API expands a group into.
+const GROUP_EXPANSION_MAX: i64 = 100;
+
+/// A period of time, from `start` up to `end`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Interval {
+    pub start: DateTime<Utc>,
+    pub end: DateTime<Utc>,
+}
+
+impl Interval {
+    pub fn new(start: DateTime<Utc>, end: DateTime<Utc>) -> Self {
+        Interval { start, end }
+    }
+
+    /// Returns if two intervals overlap.
+    pub fn overlaps(&self, other: &Interval) -> bool {
+        self.start < other.end && other.start < self.end
+    }
+}
+
+/// Sort intervals and merge the ones that overlap or touch.
+pub fn merge_intervals(mut intervals: Vec<Interval>) -> Vec<Interval> {
+    intervals.sort();
+
+    let mut merged: Vec<Interval> = Vec::with_capacity(intervals.len());
+    for interval in intervals {
+        match merged.last_mut() {
+            Some(last) if interval.start <= last.end => {
+                last.end = last.end.max(interval.end);
+            }
+            _ => merged.push(interval),
+        }
+    }
+
+    merged
+}
+
+/// The free/busy information for a set of calendars and groups.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct FreeBusy {
+    /// The merged busy intervals of each calendar.
+    pub busy: HashMap<String, Vec<Interval>>,
+    /// The calendars each group was expanded into.
+    pub groups: HashMap<String, Vec<String>>,
+    /// The reasons the calendars and groups that couldn't be queried failed, like
+    /// `notFound` or `groupTooBig`.
+    pub errors: HashMap<String, Vec<String>>,
+}
+
+impl FreeBusy {
+    /// The calendars of a calendar or group id: the members of a group, or the
+    /// calendar itself.
+    pub fn calendars(&self, id: &str) -> Vec<String> {
+        match self.groups.get(id) {
+            Some(members) => members.clone(),
+            None => vec![id.to_string()],
+        }
+    }
+
+    /// The merged busy intervals of a set of calendars and groups.
+    pub fn busy_for<S>(&self, ids: &[S]) -> Vec<Interval>
+    where
+        S: AsRef<str>,
+    {
+        merge_intervals(
+            ids.iter()
+                .flat_map(|id| self.calendars(id.as_ref()))
+                .flat_map(|c| self.busy.get(&c).cloned().unwrap_or_default())
+                .collect(),
+        )
+    }
+}
+
+#[derive(Deserialize)]
+struct QueryResponse {
+    #[serde(default)]
+    calendars: HashMap<String, crate::types::FreeBusyCalendar>,
+    #[serde(default)]
+    groups: HashMap<String, crate::types::FreeBusyGroup>,
+}
+
+#[async_trait::async_trait]
+pub trait FreebusyOps {
+    /// Query the free/busy information of any number of calendars and groups
+    /// between `time_min` and `time_max`.
+    ///
+    /// Ids are split into requests of at most 50, and the members of groups that
+    /// weren't included in a response are queried in later requests.
+    async fn query_all(
+        &self,
+        ids: &[String],
+        time_min: DateTime<Utc>,
+        time_max: DateTime<Utc>,
+    ) -> Result<FreeBusy>;
+}
+
+#[async_trait::async_trait]
+impl FreebusyOps for crate::freebusy::Freebusy {
+    /// Query the free/busy information of any number of calendars and groups
+    /// between `time_min` and `time_max`.
+    ///
+    /// Ids are split into requests of at most 50, and the members of groups that
+    /// weren't included in a response are queried in later requests.
+    async fn query_all(
+        &self,
+        ids: &[String],
+        time_min: DateTime<Utc>,
+        time_max: DateTime<Utc>,
+    ) -> Result<FreeBusy> {
+        let mut free_busy = FreeBusy::default();
+        let mut queried: HashSet<String> = HashSet::new();
+        let mut pending: Vec<String> = Vec::new();
+        for id in ids {
+            if queried.insert(id.to_string()) {
+                pending.push(id.to_string());
+            }
+        }
+
+        while !pending.is_empty() {
+            let mut next = Vec::new();
+            for chunk in pending.chunks(MAX_ITEMS_PER_QUERY) {
+                let body = crate::types::FreeBusyRequest {
+                    calendar_expansion_max: MAX_ITEMS_PER_QUERY as i64,
+                    group_expansion_max: GROUP_EXPANSION_MAX,
+                    items: chunk
+                        .iter()
+                        .map(|id| crate::types::FreeBusyRequestItem { id: id.to_string() })
+                        .collect(),
+                    time_max: Some(time_max),
+                    time_min: Some(time_min),
+                    time_zone: "UTC".to_string(),
+                };
+                // The generated response type has a single calendar and group, but the
+                // API returns maps keyed by their ids.
+                let resp: QueryResponse = self
+                    .client
+                    .post(
+                        "/freeBusy",
+                        Some(reqwest::Body::from(serde_json::to_vec(&body)?)),
+                    )
+                    .await?;
+
+                for (id, group) in resp.groups {
+                    if !group.errors.is_empty() {
+                        free_busy
+                            .errors
+                            .entry(id.to_string())
+                            .or_default()
+                            .extend(group.errors.iter().map(|e| e.reason.to_string()));
+                    }
+                    for member in &group.calendars {
+                        if queried.insert(member.to_string()) {
+                            next.push(member.to_string());
+                        }
+                    }
+                    free_busy
+                        .groups
+                        .entry(id)
+                        .or_default()
+                        .extend(group.calendars);
+                }
+
+                for (id, calendar) in resp.calendars {
+                    queried.insert(id.to_string());
+                    next.retain(|n| *n != id);
+                    if !calendar.errors.is_empty() {
+                        free_busy
+                            .errors
+                            .entry(id.to_string())
+                            .or_default()
+                            .extend(calendar.errors.into_iter().map(|e| e.reason));
+                    }
+                    let busy = free_busy.busy.entry(id).or_default();
+                    busy.extend(
+                        calendar
+                            .busy
+                            .into_iter()
+                            .filter_map(|p| match (p.start, p.end) {
+                                (Some(start), Some(end)) if start < end => {
+                                    Some(Interval { start, end })
+                                }
+                                _ => None,
+                            }),
+                    );
+                    *busy = merge_intervals(std::mem::take(busy));
+                }
+            }
+            pending = next;
+        }
+
+        Ok(free_busy)
+    }
+}
+
+/// The hours someone works, in their own time zone.
+#[derive(Debug, Clone, PartialEq)]
+pub struct WorkingHours {
+    pub days: Vec<Weekday>,
+    pub start: NaiveTime,
+    pub end: NaiveTime,
+}
+
+impl Default for WorkingHours {
+    /// Nine to five, Monday to Friday.
+    fn default() -> Self {
+        WorkingHours {
+            days: vec![
+                Weekday::Mon,
+                Weekday::Tue,
+                Weekday::Wed,
+                Weekday::Thu,
+                Weekday::Fri,
+            ],
+            start: NaiveTime::from_hms_opt(9, 0, 0).unwrap(),
+            end: NaiveTime::from_hms_opt(17, 0, 0).unwrap(),
+        }
+    }
+}
+
+impl WorkingHours {
+    /// Returns if a period is within the working hours of a single day in `tz`.
+    fn contains(&self, tz: Tz, interval: &Interval) -> bool {
+        let start = interval.start.with_timezone(&tz);
+        let end = interval.end.with_timezone(&tz);
+
+        start.naive_local().date() == end.naive_local().date()
+            && self.days.contains(&start.weekday())
+            && start.time() >= self.start
+            && end.time() <= self.end
+    }
+}
+
+/// Someone to find a meeting slot for, by calendar or group id. The members of a
+/// group share its time zone and working hours.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Attendee {
+    pub id: String,
+    pub time_zone: Tz,
+    pub working_hours: WorkingHours,
+}
+
+impl Attendee {
+    /// An attendee with the default working hours.
+    pub fn new<S>(id: S, time_zone: Tz) -> Self
+    where
+        S: ToString,
+    {
+        Attendee {
+            id: id.to_string(),
+            time_zone,
+            working_hours: Default::default(),
+        }
+    }
+
+    pub fn with_working_hours(mut self, working_hours: WorkingHours) -> Self {
+        self.working_hours = working_hours;
+        self
+    }
+}
+
+/// What kind of meeting slot to find, and for whom.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SlotRequest {
+    pub duration: Duration,
+    pub attendees: Vec<Attendee>,
+    /// Free time required between a busy period and the start of a slot.
+    pub buffer_before: Duration,
+    /// Free time required between the end of a slot and a busy period.
+    pub buffer_after: Duration,
+    /// The granularity of slot start times, which defaults to 15 minutes.
+    pub step: Duration,
+}
+
+impl SlotRequest {
+    pub fn new(duration: Duration, attendees: Vec<Attendee>) -> Self {
+        SlotRequest {
+            duration,
+            attendees,
+            buffer_before: Duration::zero(),
+            buffer_after: Duration::zero(),
+            step: Duration::minutes(15),
+        }
+    }
+
+    /// Require `buffer` of free time both before and after a slot.
+    pub fn with_buffer(mut self, buffer: Duration) -> Self {
+        self.buffer_before = buffer;
+        self.buffer_after = buffer;
+        self
+    }
+
+    pub fn with_step(mut self, step: Duration) -> Self {
+        self.step = step;
+        self
+    }
+}
+
+/// Find the slots between `time_min` and `time_max` when every attendee is free and
+/// within their working hours, with the buffers around them also free.
+///
+/// Slots start at multiples of the step, and every slot is returned, so slots
+/// overlap when the step is shorter than the duration. Slots don't span midnight
+/// in any attendee's time zone. Calendars that couldn't be queried are treated as
+/// always free; check `FreeBusy::errors` for them.
+pub fn find_slots(
+    free_busy: &FreeBusy,
+    request: &SlotRequest,
+    time_min: DateTime<Utc>,
+    time_max: DateTime<Utc>,
+) -> Vec<Interval> {
+    if request.duration <= Duration::zero() || request.step <= Duration::zero() {
+        return Vec::new();
+    }
+
+    let people: Vec<(&Attendee, Vec<Interval>)> = request
+        .attendees
+        .iter()
+        .map(|a| (a, free_busy.busy_for(&[a.id.as_str()])))
+        .collect();
+
+    // Align slots to the step, counting from the epoch.
+    let step = request.step.num_seconds().max(1);
+    let first = time_min.timestamp().div_euclid(step) * step
+        + if time_min.timestamp().rem_euclid(step) == 0 {
+            0
+        } else {
+            step
+        };
+
+    let mut slots = Vec::new();
+    let mut start = Utc.timestamp_opt(first, 0).single();
+    while let Some(s) = start {
+        let slot = Interval::new(s, s + request.duration);
+        if slot.end > time_max {
+            break;
+        }
+
+        let padded = Interval::new(
+            slot.start - request.buffer_before,
+            slot.end + request.buffer_after,
+        );
+        let available = people.iter().all(|(attendee, busy)| {
+            attendee.working_hours.contains(attendee.time_zone, &slot)
+                && !busy.iter().any(|b| b.overlaps(&padded))
+        });
+        if available {
+            slots.push(slot);
+        }
+
+        start = s.checked_add_signed(request.step);
+    }
+
+    slots
+}
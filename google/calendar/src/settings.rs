@@ -123,6 +123,46 @@ impl Settings {
             .await
     }
 
+    /**
+     * Like `watch`, but adds the call to a `Batch` to be sent with others, instead of sending it.
+     *
+     * This function performs a `POST` to the `/users/me/settings/watch` endpoint.
+     *
+     * Watch for changes to Settings resources.
+     *
+     * **Parameters:**
+     *
+     * * `max_results: i64` -- Maximum number of entries returned on one result page. By default the value is 100 entries. The page size can never be larger than 250 entries. Optional.
+     * * `page_token: &str` -- Token specifying which result page to return. Optional.
+     * * `sync_token: &str` -- Token obtained from the nextSyncToken field returned on the last page of results from the previous list request. It makes the result of this list request contain only entries that have changed since then.
+     *   If the syncToken expires, the server will respond with a 410 GONE response code and the client should clear its storage and perform a full synchronization without any syncToken.
+     *   Learn more about incremental synchronization.
+     *   Optional. The default is to return all entries.
+     */
+    pub fn watch_batch(
+        &self,
+        batch: &mut crate::batch::Batch,
+        max_results: i64,
+        page_token: &str,
+        body: &crate::types::Channel,
+    ) -> Result<crate::batch::BatchCall<crate::types::Channel>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if max_results > 0 {
+            query_args.push(("maxResults".to_string(), max_results.to_string()));
+        }
+        if !page_token.is_empty() {
+            query_args.push(("pageToken".to_string(), page_token.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/users/me/settings/watch?{}", query_);
+
+        Ok(batch.add(
+            reqwest::Method::POST,
+            &url,
+            Some(serde_json::to_string(body)?),
+        ))
+    }
+
     /**
      * This function performs a `GET` to the `/users/me/settings/{setting}` endpoint.
      *
@@ -140,4 +180,28 @@ impl Settings {
 
         self.client.get(&url, None).await
     }
+
+    /**
+     * Like `get`, but adds the call to a `Batch` to be sent with others, instead of sending it.
+     *
+     * This function performs a `GET` to the `/users/me/settings/{setting}` endpoint.
+     *
+     * Returns a single user setting.
+     *
+     * **Parameters:**
+     *
+     * * `setting: &str` -- The id of the user setting.
+     */
+    pub fn get_batch(
+        &self,
+        batch: &mut crate::batch::Batch,
+        setting: &str,
+    ) -> Result<crate::batch::BatchCall<crate::types::Setting>> {
+        let url = format!(
+            "/users/me/settings/{}",
+            crate::progenitor_support::encode_path(&setting.to_string()),
+        );
+
+        Ok(batch.add(reqwest::Method::GET, &url, None))
+    }
 }
@@ -0,0 +1,97 @@
+//! Incrementally sync the events of a calendar with sync tokens.
+//!
+//! The first `sync`, with an empty token, lists every event. Every later `sync`
+//! with the token it returned lists only the events that changed since, including
+//! deleted events with a status of `cancelled`.
+//!
+//! ```no_run
+//! # async fn run(client: google_calendar::Client, stored_token: String) -> anyhow::Result<()> {
+//! use google_calendar::sync::{EventsSync, SyncResult};
+//!
+//! match client.events().sync("primary", &stored_token).await? {
+//!     SyncResult::Changes { items, next_token } => {
+//!         // Apply the changes, and store `next_token` along with them.
+//!     }
+//!     SyncResult::Reset => {
+//!         // Clear the stored events, and sync again with an empty token.
+//!     }
+//! }
+//! # Ok(())
+//! # }
+//! ```
+use anyhow::Result;
+use http::StatusCode;
+
+use crate::{types::Event, utils::HttpError};
+
+/// The largest page size the events API accepts.
+const MAX_PAGE_SIZE: i64 = 2500;
+
+/// The result of an incremental sync.
+#[derive(Debug, Clone, PartialEq)]
+pub enum SyncResult<T> {
+    /// Everything that changed since the token, and the token to pass next time.
+    Changes { items: Vec<T>, next_token: String },
+    /// The token is no longer valid, so a full sync is required before starting
+    /// again with a new token.
+    Reset,
+}
+
+#[async_trait::async_trait]
+pub trait EventsSync {
+    /// List all the events that changed since `sync_token`, following every page,
+    /// along with the token to pass next time.
+    ///
+    /// An empty `sync_token` lists every event on the calendar.
+    async fn sync(&self, calendar_id: &str, sync_token: &str) -> Result<SyncResult<Event>>;
+}
+
+#[async_trait::async_trait]
+impl EventsSync for crate::events::Events {
+    /// List all the events that changed since `sync_token`, following every page,
+    /// along with the token to pass next time.
+    ///
+    /// An empty `sync_token` lists every event on the calendar.
+    async fn sync(&self, calendar_id: &str, sync_token: &str) -> Result<SyncResult<Event>> {
+        let mut items = Vec::new();
+        let mut page_token = String::new();
+        loop {
+            let mut query_args: Vec<(String, String)> =
+                vec![("maxResults".to_string(), MAX_PAGE_SIZE.to_string())];
+            if !sync_token.is_empty() {
+                query_args.push(("syncToken".to_string(), sync_token.to_string()));
+            }
+            if !page_token.is_empty() {
+                query_args.push(("pageToken".to_string(), page_token));
+            }
+            let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+            let url = format!(
+                "/calendars/{}/events?{}",
+                crate::progenitor_support::encode_path(calendar_id),
+                query_
+            );
+
+            let resp: crate::types::Events = match self.client.get(&url, None).await {
+                Ok(resp) => resp,
+                // The API responds with `410 Gone` when the token has expired and a
+                // full sync is required.
+                Err(e) if HttpError::status_of(&e) == Some(StatusCode::GONE) => {
+                    return Ok(SyncResult::Reset)
+                }
+                Err(e) => return Err(e),
+            };
+            items.extend(resp.items);
+
+            if !resp.next_sync_token.is_empty() {
+                return Ok(SyncResult::Changes {
+                    items,
+                    next_token: resp.next_sync_token,
+                });
+            }
+            if resp.next_page_token.is_empty() {
+                anyhow::bail!("events response has neither a next page nor a sync token");
+            }
+            page_token = resp.next_page_token;
+        }
+    }
+}
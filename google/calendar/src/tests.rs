@@ -99,3 +99,150 @@ fn test_deserialize() {
     let deserialized: crate::types::Event = serde_json::from_str(EVENT).unwrap();
     println!("event = {:?}", deserialized);
 }
+
+#[test]
+fn test_expand_event_across_daylight_saving() {
+    use chrono::TimeZone;
+
+    let event: crate::types::Event = serde_json::from_str(EVENT).unwrap();
+    let occurrences = crate::recurrence::expand_event(
+        &event,
+        chrono_tz::UTC,
+        chrono::Utc.ymd(2021, 10, 29).and_hms(0, 0, 0),
+        chrono::Utc.ymd(2021, 11, 13).and_hms(0, 0, 0),
+    )
+    .unwrap();
+
+    // The meeting stays at 10am local time when daylight saving time ends.
+    let starts: Vec<String> = occurrences
+        .iter()
+        .map(|o| o.start.with_timezone(&chrono::Utc).to_rfc3339())
+        .collect();
+    assert_eq!(
+        starts,
+        vec![
+            "2021-10-29T17:00:00+00:00",
+            "2021-11-05T17:00:00+00:00",
+            "2021-11-12T18:00:00+00:00",
+        ]
+    );
+    assert_eq!(
+        occurrences[2].end - occurrences[2].start,
+        chrono::Duration::hours(1)
+    );
+}
+
+#[test]
+fn test_recurrence_parse_and_expand() {
+    use chrono::TimeZone;
+
+    use crate::recurrence::{Frequency, Recurrence, WeekdayNum};
+
+    let lines = vec![
+        "RRULE:FREQ=MONTHLY;COUNT=4;BYDAY=-1FR".to_string(),
+        "EXDATE;TZID=Europe/London:20210924T090000".to_string(),
+    ];
+    let recurrence = Recurrence::parse(&lines).unwrap();
+    assert_eq!(recurrence.rules[0].frequency, Frequency::Monthly);
+    assert_eq!(
+        recurrence.rules[0].by_day,
+        vec![WeekdayNum::nth(-1, chrono::Weekday::Fri)]
+    );
+    assert_eq!(recurrence.to_lines(), lines);
+
+    let start: crate::types::EventDateTime = serde_json::from_str(
+        r#"{"dateTime": "2021-08-27T09:00:00+01:00", "timeZone": "Europe/London"}"#,
+    )
+    .unwrap();
+    let end: crate::types::EventDateTime = serde_json::from_str(
+        r#"{"dateTime": "2021-08-27T09:30:00+01:00", "timeZone": "Europe/London"}"#,
+    )
+    .unwrap();
+    let occurrences = recurrence
+        .expand(
+            &start,
+            &end,
+            chrono_tz::UTC,
+            chrono::Utc.ymd(2021, 1, 1).and_hms(0, 0, 0),
+            chrono::Utc.ymd(2022, 1, 1).and_hms(0, 0, 0),
+        )
+        .unwrap();
+
+    // The last Fridays of August to November, without the excluded September one.
+    let dates: Vec<String> = occurrences
+        .iter()
+        .map(|o| o.start.format("%F %R").to_string())
+        .collect();
+    assert_eq!(
+        dates,
+        vec!["2021-08-27 09:00", "2021-10-29 09:00", "2021-11-26 09:00"]
+    );
+}
+
+#[test]
+fn test_find_slots() {
+    use chrono::TimeZone;
+
+    use crate::scheduling::{find_slots, Attendee, FreeBusy, Interval, SlotRequest};
+
+    let at = |h: u32, m: u32| chrono::Utc.ymd(2021, 9, 6).and_hms(h, m, 0);
+    let mut free_busy = FreeBusy::default();
+    free_busy.busy.insert(
+        "alice@example.com".to_string(),
+        vec![Interval::new(at(13, 0), at(14, 0))],
+    );
+    free_busy.busy.insert(
+        "bob@example.com".to_string(),
+        vec![Interval::new(at(15, 30), at(16, 0))],
+    );
+    free_busy.groups.insert(
+        "team@example.com".to_string(),
+        vec![
+            "bob@example.com".to_string(),
+            "carol@example.com".to_string(),
+        ],
+    );
+
+    // Working hours overlap from 1pm to 4pm UTC.
+    let request = SlotRequest::new(
+        chrono::Duration::minutes(30),
+        vec![
+            Attendee::new("alice@example.com", chrono_tz::America::New_York),
+            Attendee::new("team@example.com", chrono_tz::Europe::London),
+        ],
+    )
+    .with_buffer(chrono::Duration::minutes(15))
+    .with_step(chrono::Duration::minutes(30));
+
+    let slots = find_slots(&free_busy, &request, at(0, 0), at(23, 59));
+    assert_eq!(slots, vec![Interval::new(at(14, 30), at(15, 0))]);
+}
+
+#[tokio::test]
+async fn test_sync_reset_on_gone() {
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+    use crate::sync::{EventsSync, SyncResult};
+
+    // Respond to every request like the API does to an expired sync token.
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    tokio::spawn(async move {
+        while let Ok((mut socket, _)) = listener.accept().await {
+            let mut request = vec![0; 4096];
+            let n = socket.read(&mut request).await.unwrap();
+            assert!(n > 0);
+            let body = r#"{"error": {"code": 410, "message": "Sync token is no longer valid, a full sync is required."}}"#;
+            let response = format!(
+                "HTTP/1.1 410 Gone\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{}",
+                body.len(),
+                body
+            );
+            socket.write_all(response.as_bytes()).await.unwrap();
+        }
+    });
+
+    let client = crate::Client::new("", "", "", "token", "").with_host(format!("http://{}", addr));
+    let result = client.events().sync("primary", "expired").await.unwrap();
+    assert_eq!(result, SyncResult::Reset);
+}
@@ -109,6 +109,11 @@
 #![cfg_attr(docsrs, feature(doc_cfg))]
 
 pub mod folders;
+#[cfg(feature = "httpcache")]
+#[cfg_attr(docsrs, doc(cfg(feature = "httpcache")))]
+pub mod http_cache;
+/// Wait for long-running operations to finish.
+pub mod long_running;
 pub mod operations;
 #[cfg(test)]
 mod tests;
@@ -160,6 +165,8 @@ pub struct Client {
     redirect_uri: String,
 
     client: reqwest::Client,
+    #[cfg(feature = "httpcache")]
+    http_cache: crate::http_cache::BoxedAsyncHttpCache,
 }
 
 use schemars::JsonSchema;
@@ -235,6 +242,8 @@ impl Client {
                     refresh_token: refresh_token.to_string(),
 
                     client: c,
+                    #[cfg(feature = "httpcache")]
+                    http_cache: <dyn crate::http_cache::AsyncHttpCache>::noop(),
                 }
             }
             Err(e) => panic!("creating reqwest client failed: {:?}", e),
@@ -251,6 +260,15 @@ impl Client {
         c
     }
 
+    /// Use a cache for conditional requests with the etags returned by the API.
+    #[cfg(feature = "httpcache")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "httpcache")))]
+    pub fn with_http_cache(&self, http_cache: crate::http_cache::BoxedAsyncHttpCache) -> Self {
+        let mut c = self.clone();
+        c.http_cache = http_cache;
+        c
+    }
+
     /// Create a new Client struct from environment variables. It
     /// takes a type that can convert into
     /// an &str (`String` or `Vec<u8>` for example). As long as the function is
@@ -294,6 +312,8 @@ impl Client {
                     refresh_token: refresh_token.to_string(),
 
                     client: c,
+                    #[cfg(feature = "httpcache")]
+                    http_cache: <dyn crate::http_cache::AsyncHttpCache>::noop(),
                 }
             }
             Err(e) => panic!("creating reqwest client failed: {:?}", e),
@@ -398,13 +418,13 @@ impl Client {
         parsed_url.map(|u| (u, Some(auth))).map_err(Error::from)
     }
 
-    async fn request_raw(
+    async fn build_request(
         &self,
         method: reqwest::Method,
         uri: &str,
         body: Option<reqwest::Body>,
-    ) -> Result<reqwest::Response> {
-        let u = if uri.starts_with("https://") {
+    ) -> Result<reqwest::RequestBuilder> {
+        let u = if uri.starts_with("https://") || uri.starts_with("http://") {
             uri.to_string()
         } else {
             (self.host.clone() + uri).to_string()
@@ -437,24 +457,130 @@ impl Client {
             req = req.body(body);
         }
         log::debug!("request: {:?}", &req);
+        Ok(req)
+    }
+
+    async fn request_raw(
+        &self,
+        method: reqwest::Method,
+        uri: &str,
+        body: Option<reqwest::Body>,
+    ) -> Result<reqwest::Response> {
+        let req = self.build_request(method, uri, body).await?;
         Ok(req.send().await?)
     }
 
-    async fn request<Out>(
+    /// Send a request and return the status, link header and body of the response.
+    ///
+    /// With the `httpcache` feature, GET requests are made conditional on the etag of
+    /// the cached response, if there is one, and the cached body is returned if the
+    /// response has not been modified.
+    async fn request_body(
         &self,
         method: reqwest::Method,
         uri: &str,
         body: Option<reqwest::Body>,
-    ) -> Result<Out>
-    where
-        Out: serde::de::DeserializeOwned + 'static + Send,
-    {
+    ) -> Result<(
+        reqwest::StatusCode,
+        Option<hyperx::header::Link>,
+        bytes::Bytes,
+    )> {
+        #[cfg(not(feature = "httpcache"))]
         let response = self.request_raw(method, uri, body).await?;
 
+        #[cfg(feature = "httpcache")]
+        let u = if uri.starts_with("https://") || uri.starts_with("http://") {
+            uri.to_string()
+        } else {
+            (self.host.clone() + uri).to_string()
+        };
+        #[cfg(feature = "httpcache")]
+        let etag = if method == reqwest::Method::GET {
+            self.http_cache.lookup_etag(&u).await.ok()
+        } else {
+            None
+        };
+        #[cfg(feature = "httpcache")]
+        let response = match etag {
+            Some(etag) => {
+                let req = self.build_request(method, uri, body).await?;
+                // Keep a copy of the request without the etag, so we can fall back to it if
+                // the cached body has gone missing by the time we get a 304.
+                let unconditional = req.try_clone();
+                let response = req.header(http::header::IF_NONE_MATCH, etag).send().await?;
+                if response.status() != http::StatusCode::NOT_MODIFIED {
+                    response
+                } else {
+                    match self.http_cache.lookup_body(&u).await {
+                        Ok(body) => {
+                            let link = self
+                                .http_cache
+                                .lookup_next_link(&u)
+                                .await
+                                .unwrap_or_default()
+                                .map(|next| {
+                                    let next = hyperx::header::LinkValue::new(next)
+                                        .push_rel(hyperx::header::RelationType::Next);
+                                    hyperx::header::Link::new(vec![next])
+                                });
+                            return Ok((http::StatusCode::OK, link, body.into()));
+                        }
+                        Err(e) => match unconditional {
+                            Some(req) => {
+                                log::info!("failed to look up cached body, refetching: {}", e);
+                                req.send().await?
+                            }
+                            None => return Err(e),
+                        },
+                    }
+                }
+            }
+            None => self.request_raw(method, uri, body).await?,
+        };
+
         let status = response.status();
+        let link = response
+            .headers()
+            .get(http::header::LINK)
+            .and_then(|l| l.to_str().ok())
+            .and_then(|l| l.parse().ok());
+        #[cfg(feature = "httpcache")]
+        let etag = response
+            .headers()
+            .get(http::header::ETAG)
+            .map(|etag| etag.as_bytes().to_vec());
 
         let response_body = response.bytes().await?;
 
+        #[cfg(feature = "httpcache")]
+        if status.is_success() {
+            if let Some(etag) = etag {
+                let next_link = link.as_ref().and_then(|l| crate::utils::next_link(l));
+                if let Err(e) = self
+                    .http_cache
+                    .cache_response(&u, &response_body, &etag, &next_link)
+                    .await
+                {
+                    // failing to cache isn't fatal, so just log & swallow the error
+                    log::info!("failed to cache body & etag: {}", e);
+                }
+            }
+        }
+
+        Ok((status, link, response_body))
+    }
+
+    async fn request<Out>(
+        &self,
+        method: reqwest::Method,
+        uri: &str,
+        body: Option<reqwest::Body>,
+    ) -> Result<Out>
+    where
+        Out: serde::de::DeserializeOwned + 'static + Send,
+    {
+        let (status, _, response_body) = self.request_body(method, uri, body).await?;
+
         if status.is_success() {
             log::debug!(
                 "response payload {}",
@@ -469,15 +595,7 @@ impl Client {
             };
             parsed_response.map_err(Error::from)
         } else {
-            let error = if response_body.is_empty() {
-                anyhow!("code: {}, empty response", status)
-            } else {
-                anyhow!(
-                    "code: {}, error: {:?}",
-                    status,
-                    String::from_utf8_lossy(&response_body),
-                )
-            };
+            let error = anyhow::Error::from(crate::utils::HttpError::new(status, &response_body));
 
             Err(error)
         }
@@ -492,16 +610,7 @@ impl Client {
     where
         Out: serde::de::DeserializeOwned + 'static + Send,
     {
-        let response = self.request_raw(method, uri, body).await?;
-
-        let status = response.status();
-        let link = response
-            .headers()
-            .get(http::header::LINK)
-            .and_then(|l| l.to_str().ok())
-            .and_then(|l| l.parse().ok());
-
-        let response_body = response.bytes().await?;
+        let (status, link, response_body) = self.request_body(method, uri, body).await?;
 
         if status.is_success() {
             log::debug!(
@@ -518,15 +627,7 @@ impl Client {
             };
             parsed_response.map(|out| (link, out)).map_err(Error::from)
         } else {
-            let error = if response_body.is_empty() {
-                anyhow!("code: {}, empty response", status)
-            } else {
-                anyhow!(
-                    "code: {}, error: {:?}",
-                    status,
-                    String::from_utf8_lossy(&response_body),
-                )
-            };
+            let error = anyhow::Error::from(crate::utils::HttpError::new(status, &response_body));
             Err(error)
         }
     }
@@ -537,7 +638,7 @@ impl Client {
     where
         Out: serde::de::DeserializeOwned + 'static + Send,
     {
-        let u = if uri.starts_with("https://") {
+        let u = if uri.starts_with("https://") || uri.starts_with("http://") {
             uri.to_string()
         } else {
             (self.host.clone() + uri).to_string()
@@ -591,15 +692,7 @@ impl Client {
             };
             parsed_response.map_err(Error::from)
         } else {
-            let error = if response_body.is_empty() {
-                anyhow!("code: {}, empty response", status)
-            } else {
-                anyhow!(
-                    "code: {}, error: {:?}",
-                    status,
-                    String::from_utf8_lossy(&response_body),
-                )
-            };
+            let error = anyhow::Error::from(crate::utils::HttpError::new(status, &response_body));
 
             Err(error)
         }
@@ -616,7 +709,7 @@ impl Client {
     where
         Out: serde::de::DeserializeOwned + 'static + Send,
     {
-        let u = if uri.starts_with("https://") {
+        let u = if uri.starts_with("https://") || uri.starts_with("http://") {
             uri.to_string()
         } else {
             (self.host.clone() + uri).to_string()
@@ -663,15 +756,7 @@ impl Client {
             };
             parsed_response.map_err(Error::from)
         } else {
-            let error = if response_body.is_empty() {
-                anyhow!("code: {}, empty response", status)
-            } else {
-                anyhow!(
-                    "code: {}, error: {:?}",
-                    status,
-                    String::from_utf8_lossy(&response_body),
-                )
-            };
+            let error = anyhow::Error::from(crate::utils::HttpError::new(status, &response_body));
 
             Err(error)
         }
@@ -689,7 +774,7 @@ impl Client {
     where
         Out: serde::de::DeserializeOwned + 'static + Send,
     {
-        let u = if uri.starts_with("https://") {
+        let u = if uri.starts_with("https://") || uri.starts_with("http://") {
             uri.to_string()
         } else {
             (self.host.clone() + uri).to_string()
@@ -751,15 +836,7 @@ impl Client {
             };
             parsed_response.map_err(Error::from)
         } else {
-            let error = if response_body.is_empty() {
-                anyhow!("code: {}, empty response", status)
-            } else {
-                anyhow!(
-                    "code: {}, error: {:?}",
-                    status,
-                    String::from_utf8_lossy(&response_body),
-                )
-            };
+            let error = anyhow::Error::from(crate::utils::HttpError::new(status, &response_body));
 
             Err(error)
         }
@@ -0,0 +1,233 @@
+//! Wait for long-running operations to finish.
+//!
+//! Calls that start a long-running operation return an `Operation`, which
+//! `OperationOps::wait` polls until it is done. The
+//! `response` of a successful operation is then decoded into the type the call
+//! documents, and the `error` of a failed one returned as an `OperationError`.
+//!
+//! ```no_run
+//! # async fn run(client: google_cloud_resource_manager::Client) -> anyhow::Result<()> {
+//! use google_cloud_resource_manager::long_running::{OperationError, OperationOps, PollOptions};
+//!
+//! let body: google_cloud_resource_manager::types::Folder = serde_json::from_value(
+//!     serde_json::json!({"displayName": "Engineering"}),
+//! )?;
+//! let operation = client.folders().create("organizations/123", &body).await?;
+//!
+//! let options = PollOptions::default().with_timeout(std::time::Duration::from_secs(120));
+//! match client
+//!     .operations()
+//!     .wait::<google_cloud_resource_manager::types::Folder>(operation, &options)
+//!     .await
+//! {
+//!     Ok(folder) => println!("created {}", folder.name),
+//!     Err(e) => match e.downcast_ref::<OperationError>() {
+//!         Some(failed) => println!("failed with code {}", failed.status.code),
+//!         None => return Err(e),
+//!     },
+//! }
+//! # Ok(())
+//! # }
+//! ```
+use std::time::{Duration, Instant};
+
+use anyhow::{anyhow, Result};
+use serde::de::DeserializeOwned;
+
+use crate::types::{Operation, Status};
+
+/// How often to poll an operation, and for how long.
+///
+/// The delay between polls starts at `initial_delay` and is multiplied by
+/// `multiplier` after each poll, up to `max_delay`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PollOptions {
+    pub initial_delay: Duration,
+    pub max_delay: Duration,
+    pub multiplier: f64,
+    /// How long to wait for the operation to finish, or forever if `None`.
+    pub timeout: Option<Duration>,
+}
+
+impl Default for PollOptions {
+    /// Poll after one second, backing off to every 30 seconds, for up to ten minutes.
+    fn default() -> Self {
+        PollOptions {
+            initial_delay: Duration::from_secs(1),
+            max_delay: Duration::from_secs(30),
+            multiplier: 1.5,
+            timeout: Some(Duration::from_secs(600)),
+        }
+    }
+}
+
+impl PollOptions {
+    pub fn with_backoff(
+        mut self,
+        initial_delay: Duration,
+        max_delay: Duration,
+        multiplier: f64,
+    ) -> Self {
+        self.initial_delay = initial_delay;
+        self.max_delay = max_delay;
+        self.multiplier = multiplier;
+        self
+    }
+
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Wait for the operation to finish, however long it takes.
+    pub fn without_timeout(mut self) -> Self {
+        self.timeout = None;
+        self
+    }
+
+    /// The delay before the poll after one that waited `delay`.
+    fn next_delay(&self, delay: Duration) -> Duration {
+        let next = delay.as_secs_f64() * self.multiplier.max(1.0);
+        Duration::from_secs_f64(next.min(self.max_delay.as_secs_f64()))
+    }
+}
+
+/// The error of a failed operation.
+#[derive(Debug, Clone, PartialEq)]
+pub struct OperationError {
+    /// The name of the operation.
+    pub name: String,
+    pub status: Status,
+}
+
+impl std::fmt::Display for OperationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "operation {} failed with code {}: {}",
+            self.name, self.status.code, self.status.message
+        )
+    }
+}
+
+impl std::error::Error for OperationError {}
+
+/// The error when an operation didn't finish before the timeout.
+#[derive(Debug, Clone, PartialEq)]
+pub struct OperationTimeout {
+    /// The last state of the operation, whose name can be used to keep polling it.
+    pub operation: Operation,
+    pub elapsed: Duration,
+}
+
+impl std::fmt::Display for OperationTimeout {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "operation {} did not finish after {:?}",
+            self.operation.name, self.elapsed
+        )
+    }
+}
+
+impl std::error::Error for OperationTimeout {}
+
+/// The result of a finished operation: its `response` decoded as `T`, or its
+/// `error` as an `OperationError`. Returns `None` if the operation isn't done.
+///
+/// Operations without a response, like deletes, can be decoded as `()` or
+/// `serde_json::Value`.
+pub fn operation_result<T>(operation: &Operation) -> Option<Result<T>>
+where
+    T: DeserializeOwned,
+{
+    if !operation.done {
+        return None;
+    }
+
+    if let Some(status) = &operation.error {
+        return Some(Err(OperationError {
+            name: operation.name.to_string(),
+            status: status.clone(),
+        }
+        .into()));
+    }
+
+    let response = operation
+        .response
+        .clone()
+        .unwrap_or(serde_json::Value::Null);
+    Some(serde_json::from_value(response).map_err(|e| {
+        anyhow!(
+            "decoding the response of operation {}: {}",
+            operation.name,
+            e
+        )
+    }))
+}
+
+#[async_trait::async_trait]
+pub trait OperationOps {
+    /// Poll an operation until it is done, and return its decoded response.
+    ///
+    /// A failed operation returns an `OperationError`, and one that doesn't finish
+    /// in time an `OperationTimeout`; get them with `anyhow::Error::downcast_ref`.
+    async fn wait<T>(&self, operation: Operation, options: &PollOptions) -> Result<T>
+    where
+        T: DeserializeOwned + Send;
+}
+
+#[async_trait::async_trait]
+impl OperationOps for crate::operations::Operations {
+    /// Poll an operation until it is done, and return its decoded response.
+    ///
+    /// A failed operation returns an `OperationError`, and one that doesn't finish
+    /// in time an `OperationTimeout`; get them with `anyhow::Error::downcast_ref`.
+    async fn wait<T>(&self, operation: Operation, options: &PollOptions) -> Result<T>
+    where
+        T: DeserializeOwned + Send,
+    {
+        let started = Instant::now();
+        let mut operation = operation;
+        let mut delay = options.initial_delay;
+        loop {
+            if let Some(result) = operation_result(&operation) {
+                return result;
+            }
+
+            let elapsed = started.elapsed();
+            if let Some(timeout) = options.timeout {
+                if elapsed >= timeout {
+                    return Err(OperationTimeout { operation, elapsed }.into());
+                }
+                // Don't sleep past the timeout, but poll once more at it.
+                tokio::time::sleep(delay.min(timeout - elapsed)).await;
+            } else {
+                tokio::time::sleep(delay).await;
+            }
+
+            operation = self
+                .client
+                .request_entity(
+                    http::Method::GET,
+                    &operation_url(&self.client.host, &operation.name)?,
+                    None,
+                )
+                .await?;
+            delay = options.next_delay(delay);
+        }
+    }
+}
+
+/// The URL to poll an operation at.
+///
+/// The path of `Operations::get` has its own version, so it is put on the origin
+/// of the host rather than on the host, which ends with the version of the API.
+fn operation_url(host: &str, name: &str) -> Result<String> {
+    let host: reqwest::Url = host.parse()?;
+    Ok(format!(
+        "{}/v1/{}",
+        host.origin().ascii_serialization(),
+        crate::progenitor_support::encode_path(name)
+    ))
+}
@@ -1 +1,97 @@
+#[test]
+fn test_operation_result() {
+    use crate::{
+        long_running::{operation_result, OperationError},
+        types::{Folder, Operation},
+    };
 
+    let pending: Operation =
+        serde_json::from_value(serde_json::json!({"name": "operations/fc.1"})).unwrap();
+    assert!(operation_result::<Folder>(&pending).is_none());
+
+    let done: Operation = serde_json::from_value(serde_json::json!({
+        "name": "operations/fc.1",
+        "done": true,
+        "response": {
+            "@type": "type.googleapis.com/google.cloud.resourcemanager.v2.Folder",
+            "name": "folders/123",
+            "displayName": "Engineering",
+        },
+    }))
+    .unwrap();
+    let folder = operation_result::<Folder>(&done).unwrap().unwrap();
+    assert_eq!(folder.name, "folders/123");
+    assert_eq!(folder.display_name, "Engineering");
+
+    let failed: Operation = serde_json::from_value(serde_json::json!({
+        "name": "operations/fc.2",
+        "done": true,
+        "error": {"code": 9, "message": "Folder height constraint violated."},
+    }))
+    .unwrap();
+    let err = operation_result::<Folder>(&failed).unwrap().unwrap_err();
+    let err = err.downcast_ref::<OperationError>().unwrap();
+    assert_eq!(err.status.code, 9);
+    assert_eq!(err.name, "operations/fc.2");
+}
+
+#[tokio::test]
+async fn test_wait_polls_operation() {
+    use std::{
+        sync::{Arc, Mutex},
+        time::Duration,
+    };
+
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+    use crate::{
+        long_running::{OperationOps, PollOptions},
+        types::{Folder, Operation},
+    };
+
+    // Answer the first poll with a pending operation and the second with a done one.
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    let paths = Arc::new(Mutex::new(Vec::new()));
+    let seen = paths.clone();
+    tokio::spawn(async move {
+        while let Ok((mut socket, _)) = listener.accept().await {
+            let mut request = vec![0; 4096];
+            let n = socket.read(&mut request).await.unwrap();
+            let request = String::from_utf8_lossy(&request[..n]).to_string();
+            let path = request.split_whitespace().nth(1).unwrap().to_string();
+            let polls = {
+                let mut seen = seen.lock().unwrap();
+                seen.push(path);
+                seen.len()
+            };
+            let body = if polls < 2 {
+                r#"{"name": "operations/fc.1"}"#
+            } else {
+                r#"{"name": "operations/fc.1", "done": true, "response": {"name": "folders/123"}}"#
+            };
+            let response = format!(
+                "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{}",
+                body.len(),
+                body
+            );
+            socket.write_all(response.as_bytes()).await.unwrap();
+        }
+    });
+
+    let client =
+        crate::Client::new("", "", "", "token", "").with_host(format!("http://{}/v2", addr));
+    let pending: Operation =
+        serde_json::from_value(serde_json::json!({"name": "operations/fc.1"})).unwrap();
+    let options = PollOptions::default().with_backoff(
+        Duration::from_millis(1),
+        Duration::from_millis(1),
+        1.0,
+    );
+    let folder: Folder = client.operations().wait(pending, &options).await.unwrap();
+    assert_eq!(folder.name, "folders/123");
+    assert_eq!(
+        *paths.lock().unwrap(),
+        vec!["/v1/operations/fc.1", "/v1/operations/fc.1"]
+    );
+}
@@ -21,4 +21,20 @@ impl About {
         let url = "/about".to_string();
         self.client.get(&url, None).await
     }
+
+    /**
+     * Like `get`, but adds the call to a `Batch` to be sent with others, instead of sending it.
+     *
+     * This function performs a `GET` to the `/about` endpoint.
+     *
+     * Gets information about the user, the user's Drive, and system capabilities.
+     */
+    pub fn get_batch(
+        &self,
+        batch: &mut crate::batch::Batch,
+    ) -> Result<crate::batch::BatchCall<crate::types::About>> {
+        let url = "/about".to_string();
+
+        Ok(batch.add(reqwest::Method::GET, &url, None))
+    }
 }
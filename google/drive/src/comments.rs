@@ -148,6 +148,35 @@ impl Comments {
             .await
     }
 
+    /**
+     * Like `create`, but adds the call to a `Batch` to be sent with others, instead of sending it.
+     *
+     * This function performs a `POST` to the `/files/{fileId}/comments` endpoint.
+     *
+     * Creates a new comment on a file.
+     *
+     * **Parameters:**
+     *
+     * * `file_id: &str` -- A link to this theme's background image.
+     */
+    pub fn create_batch(
+        &self,
+        batch: &mut crate::batch::Batch,
+        file_id: &str,
+        body: &crate::types::Comment,
+    ) -> Result<crate::batch::BatchCall<crate::types::Comment>> {
+        let url = format!(
+            "/files/{}/comments",
+            crate::progenitor_support::encode_path(&file_id.to_string()),
+        );
+
+        Ok(batch.add(
+            reqwest::Method::POST,
+            &url,
+            Some(serde_json::to_string(body)?),
+        ))
+    }
+
     /**
      * This function performs a `GET` to the `/files/{fileId}/comments/{commentId}` endpoint.
      *
@@ -180,6 +209,41 @@ impl Comments {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Like `get`, but adds the call to a `Batch` to be sent with others, instead of sending it.
+     *
+     * This function performs a `GET` to the `/files/{fileId}/comments/{commentId}` endpoint.
+     *
+     * Gets a comment by ID.
+     *
+     * **Parameters:**
+     *
+     * * `file_id: &str` -- A link to this theme's background image.
+     * * `comment_id: &str` -- A link to this theme's background image.
+     * * `include_deleted: bool` -- Whether to return deleted comments. Deleted comments will not include their original content.
+     */
+    pub fn get_batch(
+        &self,
+        batch: &mut crate::batch::Batch,
+        file_id: &str,
+        comment_id: &str,
+        include_deleted: bool,
+    ) -> Result<crate::batch::BatchCall<crate::types::Comment>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if include_deleted {
+            query_args.push(("includeDeleted".to_string(), include_deleted.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/files/{}/comments/{}?{}",
+            crate::progenitor_support::encode_path(&file_id.to_string()),
+            crate::progenitor_support::encode_path(&comment_id.to_string()),
+            query_
+        );
+
+        Ok(batch.add(reqwest::Method::GET, &url, None))
+    }
+
     /**
      * This function performs a `DELETE` to the `/files/{fileId}/comments/{commentId}` endpoint.
      *
@@ -200,6 +264,33 @@ impl Comments {
         self.client.delete(&url, None).await
     }
 
+    /**
+     * Like `delete`, but adds the call to a `Batch` to be sent with others, instead of sending it.
+     *
+     * This function performs a `DELETE` to the `/files/{fileId}/comments/{commentId}` endpoint.
+     *
+     * Deletes a comment.
+     *
+     * **Parameters:**
+     *
+     * * `file_id: &str` -- A link to this theme's background image.
+     * * `comment_id: &str` -- A link to this theme's background image.
+     */
+    pub fn delete_batch(
+        &self,
+        batch: &mut crate::batch::Batch,
+        file_id: &str,
+        comment_id: &str,
+    ) -> Result<crate::batch::BatchCall<()>> {
+        let url = format!(
+            "/files/{}/comments/{}",
+            crate::progenitor_support::encode_path(&file_id.to_string()),
+            crate::progenitor_support::encode_path(&comment_id.to_string()),
+        );
+
+        Ok(batch.add(reqwest::Method::DELETE, &url, None))
+    }
+
     /**
      * This function performs a `PATCH` to the `/files/{fileId}/comments/{commentId}` endpoint.
      *
@@ -226,4 +317,36 @@ impl Comments {
             .patch(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
             .await
     }
+
+    /**
+     * Like `update`, but adds the call to a `Batch` to be sent with others, instead of sending it.
+     *
+     * This function performs a `PATCH` to the `/files/{fileId}/comments/{commentId}` endpoint.
+     *
+     * Updates a comment with patch semantics.
+     *
+     * **Parameters:**
+     *
+     * * `file_id: &str` -- A link to this theme's background image.
+     * * `comment_id: &str` -- A link to this theme's background image.
+     */
+    pub fn update_batch(
+        &self,
+        batch: &mut crate::batch::Batch,
+        file_id: &str,
+        comment_id: &str,
+        body: &crate::types::Comment,
+    ) -> Result<crate::batch::BatchCall<crate::types::Comment>> {
+        let url = format!(
+            "/files/{}/comments/{}",
+            crate::progenitor_support::encode_path(&file_id.to_string()),
+            crate::progenitor_support::encode_path(&comment_id.to_string()),
+        );
+
+        Ok(batch.add(
+            reqwest::Method::PATCH,
+            &url,
+            Some(serde_json::to_string(body)?),
+        ))
+    }
 }
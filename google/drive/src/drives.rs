@@ -139,6 +139,37 @@ impl Drives {
             .await
     }
 
+    /**
+     * Like `create`, but adds the call to a `Batch` to be sent with others, instead of sending it.
+     *
+     * This function performs a `POST` to the `/drives` endpoint.
+     *
+     * Creates a new shared drive.
+     *
+     * **Parameters:**
+     *
+     * * `request_id: &str` -- An ID, such as a random UUID, which uniquely identifies this user's request for idempotent creation of a shared drive. A repeated request by the same user and with the same request ID will avoid creating duplicates by attempting to create the same shared drive. If the shared drive already exists a 409 error will be returned.
+     */
+    pub fn create_batch(
+        &self,
+        batch: &mut crate::batch::Batch,
+        request_id: &str,
+        body: &crate::types::Drive,
+    ) -> Result<crate::batch::BatchCall<crate::types::Drive>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !request_id.is_empty() {
+            query_args.push(("requestId".to_string(), request_id.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/drives?{}", query_);
+
+        Ok(batch.add(
+            reqwest::Method::POST,
+            &url,
+            Some(serde_json::to_string(body)?),
+        ))
+    }
+
     /**
      * This function performs a `GET` to the `/drives/{driveId}` endpoint.
      *
@@ -171,6 +202,41 @@ impl Drives {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Like `get`, but adds the call to a `Batch` to be sent with others, instead of sending it.
+     *
+     * This function performs a `GET` to the `/drives/{driveId}` endpoint.
+     *
+     * Gets a shared drive's metadata by ID.
+     *
+     * **Parameters:**
+     *
+     * * `drive_id: &str` -- A link to this theme's background image.
+     * * `use_domain_admin_access: bool` -- Issue the request as a domain administrator; if set to true, then the requester will be granted access if they are an administrator of the domain to which the shared drive belongs.
+     */
+    pub fn get_batch(
+        &self,
+        batch: &mut crate::batch::Batch,
+        drive_id: &str,
+        use_domain_admin_access: bool,
+    ) -> Result<crate::batch::BatchCall<crate::types::Drive>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if use_domain_admin_access {
+            query_args.push((
+                "useDomainAdminAccess".to_string(),
+                use_domain_admin_access.to_string(),
+            ));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/drives/{}?{}",
+            crate::progenitor_support::encode_path(&drive_id.to_string()),
+            query_
+        );
+
+        Ok(batch.add(reqwest::Method::GET, &url, None))
+    }
+
     /**
      * This function performs a `DELETE` to the `/drives/{driveId}` endpoint.
      *
@@ -189,6 +255,30 @@ impl Drives {
         self.client.delete(&url, None).await
     }
 
+    /**
+     * Like `delete`, but adds the call to a `Batch` to be sent with others, instead of sending it.
+     *
+     * This function performs a `DELETE` to the `/drives/{driveId}` endpoint.
+     *
+     * Permanently deletes a shared drive for which the user is an organizer. The shared drive cannot contain any untrashed items.
+     *
+     * **Parameters:**
+     *
+     * * `drive_id: &str` -- A link to this theme's background image.
+     */
+    pub fn delete_batch(
+        &self,
+        batch: &mut crate::batch::Batch,
+        drive_id: &str,
+    ) -> Result<crate::batch::BatchCall<()>> {
+        let url = format!(
+            "/drives/{}",
+            crate::progenitor_support::encode_path(&drive_id.to_string()),
+        );
+
+        Ok(batch.add(reqwest::Method::DELETE, &url, None))
+    }
+
     /**
      * This function performs a `PATCH` to the `/drives/{driveId}` endpoint.
      *
@@ -224,6 +314,46 @@ impl Drives {
             .await
     }
 
+    /**
+     * Like `update`, but adds the call to a `Batch` to be sent with others, instead of sending it.
+     *
+     * This function performs a `PATCH` to the `/drives/{driveId}` endpoint.
+     *
+     * Updates the metadate for a shared drive.
+     *
+     * **Parameters:**
+     *
+     * * `drive_id: &str` -- A link to this theme's background image.
+     * * `use_domain_admin_access: bool` -- Issue the request as a domain administrator; if set to true, then the requester will be granted access if they are an administrator of the domain to which the shared drive belongs.
+     */
+    pub fn update_batch(
+        &self,
+        batch: &mut crate::batch::Batch,
+        drive_id: &str,
+        use_domain_admin_access: bool,
+        body: &crate::types::Drive,
+    ) -> Result<crate::batch::BatchCall<crate::types::Drive>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if use_domain_admin_access {
+            query_args.push((
+                "useDomainAdminAccess".to_string(),
+                use_domain_admin_access.to_string(),
+            ));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/drives/{}?{}",
+            crate::progenitor_support::encode_path(&drive_id.to_string()),
+            query_
+        );
+
+        Ok(batch.add(
+            reqwest::Method::PATCH,
+            &url,
+            Some(serde_json::to_string(body)?),
+        ))
+    }
+
     /**
      * This function performs a `POST` to the `/drives/{driveId}/hide` endpoint.
      *
@@ -242,6 +372,30 @@ impl Drives {
         self.client.post(&url, None).await
     }
 
+    /**
+     * Like `hide`, but adds the call to a `Batch` to be sent with others, instead of sending it.
+     *
+     * This function performs a `POST` to the `/drives/{driveId}/hide` endpoint.
+     *
+     * Hides a shared drive from the default view.
+     *
+     * **Parameters:**
+     *
+     * * `drive_id: &str` -- A link to this theme's background image.
+     */
+    pub fn hide_batch(
+        &self,
+        batch: &mut crate::batch::Batch,
+        drive_id: &str,
+    ) -> Result<crate::batch::BatchCall<crate::types::Drive>> {
+        let url = format!(
+            "/drives/{}/hide",
+            crate::progenitor_support::encode_path(&drive_id.to_string()),
+        );
+
+        Ok(batch.add(reqwest::Method::POST, &url, None))
+    }
+
     /**
      * This function performs a `POST` to the `/drives/{driveId}/unhide` endpoint.
      *
@@ -259,4 +413,28 @@ impl Drives {
 
         self.client.post(&url, None).await
     }
+
+    /**
+     * Like `unhide`, but adds the call to a `Batch` to be sent with others, instead of sending it.
+     *
+     * This function performs a `POST` to the `/drives/{driveId}/unhide` endpoint.
+     *
+     * Restores a shared drive to the default view.
+     *
+     * **Parameters:**
+     *
+     * * `drive_id: &str` -- A link to this theme's background image.
+     */
+    pub fn unhide_batch(
+        &self,
+        batch: &mut crate::batch::Batch,
+        drive_id: &str,
+    ) -> Result<crate::batch::BatchCall<crate::types::Drive>> {
+        let url = format!(
+            "/drives/{}/unhide",
+            crate::progenitor_support::encode_path(&drive_id.to_string()),
+        );
+
+        Ok(batch.add(reqwest::Method::POST, &url, None))
+    }
 }
@@ -294,6 +294,86 @@ impl Files {
             .await
     }
 
+    /**
+     * Like `create`, but adds the call to a `Batch` to be sent with others, instead of sending it.
+     *
+     * This function performs a `POST` to the `/files` endpoint.
+     *
+     * Creates a new file.
+     *
+     * **Parameters:**
+     *
+     * * `enforce_single_parent: bool` -- Deprecated. Creating files in multiple folders is no longer supported.
+     * * `ignore_default_visibility: bool` -- Whether to ignore the domain's default visibility settings for the created file. Domain administrators can choose to make all uploaded files visible to the domain by default; this parameter bypasses that behavior for the request. Permissions are still inherited from parent folders.
+     * * `include_permissions_for_view: &str` -- Specifies which additional view's permissions to include in the response. Only 'published' is supported.
+     * * `keep_revision_forever: bool` -- Whether to set the 'keepForever' field in the new head revision. This is only applicable to files with binary content in Google Drive. Only 200 revisions for the file can be kept forever. If the limit is reached, try deleting pinned revisions.
+     * * `ocr_language: &str` -- A language hint for OCR processing during image import (ISO 639-1 code).
+     * * `supports_all_drives: bool` -- Whether the requesting application supports both My Drives and shared drives.
+     * * `supports_team_drives: bool` -- Whether the user has installed the requesting app.
+     * * `use_content_as_indexable_text: bool` -- Whether to use the uploaded content as indexable text.
+     */
+    pub fn create_batch(
+        &self,
+        batch: &mut crate::batch::Batch,
+        ignore_default_visibility: bool,
+        include_permissions_for_view: &str,
+        keep_revision_forever: bool,
+        ocr_language: &str,
+        supports_all_drives: bool,
+        supports_team_drives: bool,
+        use_content_as_indexable_text: bool,
+        body: &crate::types::File,
+    ) -> Result<crate::batch::BatchCall<crate::types::File>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if ignore_default_visibility {
+            query_args.push((
+                "ignoreDefaultVisibility".to_string(),
+                ignore_default_visibility.to_string(),
+            ));
+        }
+        if !include_permissions_for_view.is_empty() {
+            query_args.push((
+                "includePermissionsForView".to_string(),
+                include_permissions_for_view.to_string(),
+            ));
+        }
+        if keep_revision_forever {
+            query_args.push((
+                "keepRevisionForever".to_string(),
+                keep_revision_forever.to_string(),
+            ));
+        }
+        if !ocr_language.is_empty() {
+            query_args.push(("ocrLanguage".to_string(), ocr_language.to_string()));
+        }
+        if supports_all_drives {
+            query_args.push((
+                "supportsAllDrives".to_string(),
+                supports_all_drives.to_string(),
+            ));
+        }
+        if supports_team_drives {
+            query_args.push((
+                "supportsTeamDrives".to_string(),
+                supports_team_drives.to_string(),
+            ));
+        }
+        if use_content_as_indexable_text {
+            query_args.push((
+                "useContentAsIndexableText".to_string(),
+                use_content_as_indexable_text.to_string(),
+            ));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/files?{}", query_);
+
+        Ok(batch.add(
+            reqwest::Method::POST,
+            &url,
+            Some(serde_json::to_string(body)?),
+        ))
+    }
+
     /**
      * This function performs a `GET` to the `/files/generateIds` endpoint.
      *
@@ -327,6 +407,42 @@ impl Files {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Like `generate_id`, but adds the call to a `Batch` to be sent with others, instead of sending it.
+     *
+     * This function performs a `GET` to the `/files/generateIds` endpoint.
+     *
+     * Generates a set of file IDs which can be provided in create or copy requests.
+     *
+     * **Parameters:**
+     *
+     * * `count: i64` -- A map of maximum import sizes by MIME type, in bytes.
+     * * `space: &str` -- The space in which the IDs can be used to create new files. Supported values are 'drive' and 'appDataFolder'. (Default: 'drive').
+     * * `type_: &str` -- The type of items which the IDs can be used for. Supported values are 'files' and 'shortcuts'. Note that 'shortcuts' are only supported in the drive 'space'. (Default: 'files').
+     */
+    pub fn generate_id_batch(
+        &self,
+        batch: &mut crate::batch::Batch,
+        count: i64,
+        space: &str,
+        type_: &str,
+    ) -> Result<crate::batch::BatchCall<crate::types::GeneratedIds>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if count > 0 {
+            query_args.push(("count".to_string(), count.to_string()));
+        }
+        if !space.is_empty() {
+            query_args.push(("space".to_string(), space.to_string()));
+        }
+        if !type_.is_empty() {
+            query_args.push(("type".to_string(), type_.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/files/generateIds?{}", query_);
+
+        Ok(batch.add(reqwest::Method::GET, &url, None))
+    }
+
     /**
      * This function performs a `DELETE` to the `/files/trash` endpoint.
      *
@@ -341,6 +457,26 @@ impl Files {
         self.client.delete(&url, None).await
     }
 
+    /**
+     * Like `empty_trash`, but adds the call to a `Batch` to be sent with others, instead of sending it.
+     *
+     * This function performs a `DELETE` to the `/files/trash` endpoint.
+     *
+     * Permanently deletes all of the user's trashed files.
+     *
+     * **Parameters:**
+     *
+     * * `enforce_single_parent: bool` -- Deprecated. If an item is not in a shared drive and its last parent is deleted but the item itself is not, the item will be placed under its owner's root.
+     */
+    pub fn empty_trash_batch(
+        &self,
+        batch: &mut crate::batch::Batch,
+    ) -> Result<crate::batch::BatchCall<()>> {
+        let url = "/files/trash".to_string();
+
+        Ok(batch.add(reqwest::Method::DELETE, &url, None))
+    }
+
     /**
      * This function performs a `GET` to the `/files/{fileId}` endpoint.
      *
@@ -397,6 +533,65 @@ impl Files {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Like `get`, but adds the call to a `Batch` to be sent with others, instead of sending it.
+     *
+     * This function performs a `GET` to the `/files/{fileId}` endpoint.
+     *
+     * Gets a file's metadata or content by ID.
+     *
+     * **Parameters:**
+     *
+     * * `file_id: &str` -- A link to this theme's background image.
+     * * `acknowledge_abuse: bool` -- Whether the user is acknowledging the risk of downloading known malware or other abusive files. This is only applicable when alt=media.
+     * * `include_permissions_for_view: &str` -- Specifies which additional view's permissions to include in the response. Only 'published' is supported.
+     * * `supports_all_drives: bool` -- Whether the requesting application supports both My Drives and shared drives.
+     * * `supports_team_drives: bool` -- Whether the user has installed the requesting app.
+     */
+    pub fn get_batch(
+        &self,
+        batch: &mut crate::batch::Batch,
+        file_id: &str,
+        acknowledge_abuse: bool,
+        include_permissions_for_view: &str,
+        supports_all_drives: bool,
+        supports_team_drives: bool,
+    ) -> Result<crate::batch::BatchCall<crate::types::File>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if acknowledge_abuse {
+            query_args.push((
+                "acknowledgeAbuse".to_string(),
+                acknowledge_abuse.to_string(),
+            ));
+        }
+        if !include_permissions_for_view.is_empty() {
+            query_args.push((
+                "includePermissionsForView".to_string(),
+                include_permissions_for_view.to_string(),
+            ));
+        }
+        if supports_all_drives {
+            query_args.push((
+                "supportsAllDrives".to_string(),
+                supports_all_drives.to_string(),
+            ));
+        }
+        if supports_team_drives {
+            query_args.push((
+                "supportsTeamDrives".to_string(),
+                supports_team_drives.to_string(),
+            ));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/files/{}?{}",
+            crate::progenitor_support::encode_path(&file_id.to_string()),
+            query_
+        );
+
+        Ok(batch.add(reqwest::Method::GET, &url, None))
+    }
+
     /**
      * This function performs a `DELETE` to the `/files/{fileId}` endpoint.
      *
@@ -438,6 +633,50 @@ impl Files {
         self.client.delete(&url, None).await
     }
 
+    /**
+     * Like `delete`, but adds the call to a `Batch` to be sent with others, instead of sending it.
+     *
+     * This function performs a `DELETE` to the `/files/{fileId}` endpoint.
+     *
+     * Permanently deletes a file owned by the user without moving it to the trash. If the file belongs to a shared drive the user must be an organizer on the parent. If the target is a folder, all descendants owned by the user are also deleted.
+     *
+     * **Parameters:**
+     *
+     * * `file_id: &str` -- A link to this theme's background image.
+     * * `enforce_single_parent: bool` -- Deprecated. If an item is not in a shared drive and its last parent is deleted but the item itself is not, the item will be placed under its owner's root.
+     * * `supports_all_drives: bool` -- Whether the requesting application supports both My Drives and shared drives.
+     * * `supports_team_drives: bool` -- Whether the user has installed the requesting app.
+     */
+    pub fn delete_batch(
+        &self,
+        batch: &mut crate::batch::Batch,
+        file_id: &str,
+        supports_all_drives: bool,
+        supports_team_drives: bool,
+    ) -> Result<crate::batch::BatchCall<()>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if supports_all_drives {
+            query_args.push((
+                "supportsAllDrives".to_string(),
+                supports_all_drives.to_string(),
+            ));
+        }
+        if supports_team_drives {
+            query_args.push((
+                "supportsTeamDrives".to_string(),
+                supports_team_drives.to_string(),
+            ));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/files/{}?{}",
+            crate::progenitor_support::encode_path(&file_id.to_string()),
+            query_
+        );
+
+        Ok(batch.add(reqwest::Method::DELETE, &url, None))
+    }
+
     /**
      * This function performs a `PATCH` to the `/files/{fileId}` endpoint.
      *
@@ -521,6 +760,94 @@ impl Files {
             .await
     }
 
+    /**
+     * Like `update`, but adds the call to a `Batch` to be sent with others, instead of sending it.
+     *
+     * This function performs a `PATCH` to the `/files/{fileId}` endpoint.
+     *
+     * Updates a file's metadata and/or content. This method supports patch semantics.
+     *
+     * **Parameters:**
+     *
+     * * `file_id: &str` -- A link to this theme's background image.
+     * * `add_parents: &str` -- A comma-separated list of parent IDs to add.
+     * * `enforce_single_parent: bool` -- Deprecated. Adding files to multiple folders is no longer supported. Use shortcuts instead.
+     * * `include_permissions_for_view: &str` -- Specifies which additional view's permissions to include in the response. Only 'published' is supported.
+     * * `keep_revision_forever: bool` -- Whether to set the 'keepForever' field in the new head revision. This is only applicable to files with binary content in Google Drive. Only 200 revisions for the file can be kept forever. If the limit is reached, try deleting pinned revisions.
+     * * `ocr_language: &str` -- A language hint for OCR processing during image import (ISO 639-1 code).
+     * * `remove_parents: &str` -- A comma-separated list of parent IDs to remove.
+     * * `supports_all_drives: bool` -- Whether the requesting application supports both My Drives and shared drives.
+     * * `supports_team_drives: bool` -- Whether the user has installed the requesting app.
+     * * `use_content_as_indexable_text: bool` -- Whether to use the uploaded content as indexable text.
+     */
+    pub fn update_batch(
+        &self,
+        batch: &mut crate::batch::Batch,
+        file_id: &str,
+        add_parents: &str,
+        include_permissions_for_view: &str,
+        keep_revision_forever: bool,
+        ocr_language: &str,
+        remove_parents: &str,
+        supports_all_drives: bool,
+        supports_team_drives: bool,
+        use_content_as_indexable_text: bool,
+        body: &crate::types::File,
+    ) -> Result<crate::batch::BatchCall<crate::types::File>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !add_parents.is_empty() {
+            query_args.push(("addParents".to_string(), add_parents.to_string()));
+        }
+        if !include_permissions_for_view.is_empty() {
+            query_args.push((
+                "includePermissionsForView".to_string(),
+                include_permissions_for_view.to_string(),
+            ));
+        }
+        if keep_revision_forever {
+            query_args.push((
+                "keepRevisionForever".to_string(),
+                keep_revision_forever.to_string(),
+            ));
+        }
+        if !ocr_language.is_empty() {
+            query_args.push(("ocrLanguage".to_string(), ocr_language.to_string()));
+        }
+        if !remove_parents.is_empty() {
+            query_args.push(("removeParents".to_string(), remove_parents.to_string()));
+        }
+        if supports_all_drives {
+            query_args.push((
+                "supportsAllDrives".to_string(),
+                supports_all_drives.to_string(),
+            ));
+        }
+        if supports_team_drives {
+            query_args.push((
+                "supportsTeamDrives".to_string(),
+                supports_team_drives.to_string(),
+            ));
+        }
+        if use_content_as_indexable_text {
+            query_args.push((
+                "useContentAsIndexableText".to_string(),
+                use_content_as_indexable_text.to_string(),
+            ));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/files/{}?{}",
+            crate::progenitor_support::encode_path(&file_id.to_string()),
+            query_
+        );
+
+        Ok(batch.add(
+            reqwest::Method::PATCH,
+            &url,
+            Some(serde_json::to_string(body)?),
+        ))
+    }
+
     /**
      * This function performs a `POST` to the `/files/{fileId}/copy` endpoint.
      *
@@ -594,6 +921,84 @@ impl Files {
             .await
     }
 
+    /**
+     * Like `copy`, but adds the call to a `Batch` to be sent with others, instead of sending it.
+     *
+     * This function performs a `POST` to the `/files/{fileId}/copy` endpoint.
+     *
+     * Creates a copy of a file and applies any requested updates with patch semantics. Folders cannot be copied.
+     *
+     * **Parameters:**
+     *
+     * * `file_id: &str` -- A link to this theme's background image.
+     * * `enforce_single_parent: bool` -- Deprecated. Copying files into multiple folders is no longer supported. Use shortcuts instead.
+     * * `ignore_default_visibility: bool` -- Whether to ignore the domain's default visibility settings for the created file. Domain administrators can choose to make all uploaded files visible to the domain by default; this parameter bypasses that behavior for the request. Permissions are still inherited from parent folders.
+     * * `include_permissions_for_view: &str` -- Specifies which additional view's permissions to include in the response. Only 'published' is supported.
+     * * `keep_revision_forever: bool` -- Whether to set the 'keepForever' field in the new head revision. This is only applicable to files with binary content in Google Drive. Only 200 revisions for the file can be kept forever. If the limit is reached, try deleting pinned revisions.
+     * * `ocr_language: &str` -- A language hint for OCR processing during image import (ISO 639-1 code).
+     * * `supports_all_drives: bool` -- Whether the requesting application supports both My Drives and shared drives.
+     * * `supports_team_drives: bool` -- Whether the user has installed the requesting app.
+     */
+    pub fn copy_batch(
+        &self,
+        batch: &mut crate::batch::Batch,
+        file_id: &str,
+        ignore_default_visibility: bool,
+        include_permissions_for_view: &str,
+        keep_revision_forever: bool,
+        ocr_language: &str,
+        supports_all_drives: bool,
+        supports_team_drives: bool,
+        body: &crate::types::File,
+    ) -> Result<crate::batch::BatchCall<crate::types::File>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if ignore_default_visibility {
+            query_args.push((
+                "ignoreDefaultVisibility".to_string(),
+                ignore_default_visibility.to_string(),
+            ));
+        }
+        if !include_permissions_for_view.is_empty() {
+            query_args.push((
+                "includePermissionsForView".to_string(),
+                include_permissions_for_view.to_string(),
+            ));
+        }
+        if keep_revision_forever {
+            query_args.push((
+                "keepRevisionForever".to_string(),
+                keep_revision_forever.to_string(),
+            ));
+        }
+        if !ocr_language.is_empty() {
+            query_args.push(("ocrLanguage".to_string(), ocr_language.to_string()));
+        }
+        if supports_all_drives {
+            query_args.push((
+                "supportsAllDrives".to_string(),
+                supports_all_drives.to_string(),
+            ));
+        }
+        if supports_team_drives {
+            query_args.push((
+                "supportsTeamDrives".to_string(),
+                supports_team_drives.to_string(),
+            ));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/files/{}/copy?{}",
+            crate::progenitor_support::encode_path(&file_id.to_string()),
+            query_
+        );
+
+        Ok(batch.add(
+            reqwest::Method::POST,
+            &url,
+            Some(serde_json::to_string(body)?),
+        ))
+    }
+
     /**
      * This function performs a `GET` to the `/files/{fileId}/export` endpoint.
      *
@@ -619,6 +1024,38 @@ impl Files {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Like `export`, but adds the call to a `Batch` to be sent with others, instead of sending it.
+     *
+     * This function performs a `GET` to the `/files/{fileId}/export` endpoint.
+     *
+     * Exports a Google Doc to the requested MIME type and returns the exported content. Please note that the exported content is limited to 10MB.
+     *
+     * **Parameters:**
+     *
+     * * `file_id: &str` -- A link to this theme's background image.
+     * * `mime_type: &str` -- The MIME type of the format requested for this export.
+     */
+    pub fn export_batch(
+        &self,
+        batch: &mut crate::batch::Batch,
+        file_id: &str,
+        mime_type: &str,
+    ) -> Result<crate::batch::BatchCall<()>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !mime_type.is_empty() {
+            query_args.push(("mimeType".to_string(), mime_type.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/files/{}/export?{}",
+            crate::progenitor_support::encode_path(&file_id.to_string()),
+            query_
+        );
+
+        Ok(batch.add(reqwest::Method::GET, &url, None))
+    }
+
     /**
      * This function performs a `POST` to the `/files/{fileId}/watch` endpoint.
      *
@@ -677,4 +1114,68 @@ impl Files {
             .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
             .await
     }
+
+    /**
+     * Like `watch`, but adds the call to a `Batch` to be sent with others, instead of sending it.
+     *
+     * This function performs a `POST` to the `/files/{fileId}/watch` endpoint.
+     *
+     * Subscribes to changes to a file
+     *
+     * **Parameters:**
+     *
+     * * `file_id: &str` -- A link to this theme's background image.
+     * * `acknowledge_abuse: bool` -- Whether the user is acknowledging the risk of downloading known malware or other abusive files. This is only applicable when alt=media.
+     * * `include_permissions_for_view: &str` -- Specifies which additional view's permissions to include in the response. Only 'published' is supported.
+     * * `supports_all_drives: bool` -- Whether the requesting application supports both My Drives and shared drives.
+     * * `supports_team_drives: bool` -- Whether the user has installed the requesting app.
+     */
+    pub fn watch_batch(
+        &self,
+        batch: &mut crate::batch::Batch,
+        file_id: &str,
+        acknowledge_abuse: bool,
+        include_permissions_for_view: &str,
+        supports_all_drives: bool,
+        supports_team_drives: bool,
+        body: &crate::types::Channel,
+    ) -> Result<crate::batch::BatchCall<crate::types::Channel>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if acknowledge_abuse {
+            query_args.push((
+                "acknowledgeAbuse".to_string(),
+                acknowledge_abuse.to_string(),
+            ));
+        }
+        if !include_permissions_for_view.is_empty() {
+            query_args.push((
+                "includePermissionsForView".to_string(),
+                include_permissions_for_view.to_string(),
+            ));
+        }
+        if supports_all_drives {
+            query_args.push((
+                "supportsAllDrives".to_string(),
+                supports_all_drives.to_string(),
+            ));
+        }
+        if supports_team_drives {
+            query_args.push((
+                "supportsTeamDrives".to_string(),
+                supports_team_drives.to_string(),
+            ));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/files/{}/watch?{}",
+            crate::progenitor_support::encode_path(&file_id.to_string()),
+            query_
+        );
+
+        Ok(batch.add(
+            reqwest::Method::POST,
+            &url,
+            Some(serde_json::to_string(body)?),
+        ))
+    }
 }
@@ -239,6 +239,90 @@ impl Permissions {
             .await
     }
 
+    /**
+     * Like `create`, but adds the call to a `Batch` to be sent with others, instead of sending it.
+     *
+     * This function performs a `POST` to the `/files/{fileId}/permissions` endpoint.
+     *
+     * Creates a permission for a file or shared drive.
+     *
+     * **Parameters:**
+     *
+     * * `file_id: &str` -- A link to this theme's background image.
+     * * `email_message: &str` -- A plain text custom message to include in the notification email.
+     * * `enforce_single_parent: bool` -- Whether the user has installed the requesting app.
+     * * `move_to_new_owners_root: bool` -- This parameter will only take effect if the item is not in a shared drive and the request is attempting to transfer the ownership of the item. If set to true, the item will be moved to the new owner's My Drive root folder and all prior parents removed. If set to false, parents are not changed.
+     * * `send_notification_email: bool` -- Whether to send a notification email when sharing to users or groups. This defaults to true for users and groups, and is not allowed for other requests. It must not be disabled for ownership transfers.
+     * * `supports_all_drives: bool` -- Whether the requesting application supports both My Drives and shared drives.
+     * * `supports_team_drives: bool` -- Whether the user has installed the requesting app.
+     * * `transfer_ownership: bool` -- Whether to transfer ownership to the specified user and downgrade the current owner to a writer. This parameter is required as an acknowledgement of the side effect. File owners can only transfer ownership of files existing on My Drive. Files existing in a shared drive are owned by the organization that owns that shared drive. Ownership transfers are not supported for files and folders in shared drives. Organizers of a shared drive can move items from that shared drive into their My Drive which transfers the ownership to them.
+     * * `use_domain_admin_access: bool` -- Issue the request as a domain administrator; if set to true, then the requester will be granted access if the file ID parameter refers to a shared drive and the requester is an administrator of the domain to which the shared drive belongs.
+     */
+    pub fn create_batch(
+        &self,
+        batch: &mut crate::batch::Batch,
+        file_id: &str,
+        email_message: &str,
+        move_to_new_owners_root: bool,
+        send_notification_email: bool,
+        supports_all_drives: bool,
+        supports_team_drives: bool,
+        transfer_ownership: bool,
+        use_domain_admin_access: bool,
+        body: &crate::types::Permission,
+    ) -> Result<crate::batch::BatchCall<crate::types::Permission>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !email_message.is_empty() {
+            query_args.push(("emailMessage".to_string(), email_message.to_string()));
+        }
+        if move_to_new_owners_root {
+            query_args.push((
+                "moveToNewOwnersRoot".to_string(),
+                move_to_new_owners_root.to_string(),
+            ));
+        }
+        query_args.push((
+            "sendNotificationEmail".to_string(),
+            send_notification_email.to_string(),
+        ));
+        if supports_all_drives {
+            query_args.push((
+                "supportsAllDrives".to_string(),
+                supports_all_drives.to_string(),
+            ));
+        }
+        if supports_team_drives {
+            query_args.push((
+                "supportsTeamDrives".to_string(),
+                supports_team_drives.to_string(),
+            ));
+        }
+        if transfer_ownership {
+            query_args.push((
+                "transferOwnership".to_string(),
+                transfer_ownership.to_string(),
+            ));
+        }
+        if use_domain_admin_access {
+            query_args.push((
+                "useDomainAdminAccess".to_string(),
+                use_domain_admin_access.to_string(),
+            ));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/files/{}/permissions?{}",
+            crate::progenitor_support::encode_path(&file_id.to_string()),
+            query_
+        );
+
+        Ok(batch.add(
+            reqwest::Method::POST,
+            &url,
+            Some(serde_json::to_string(body)?),
+        ))
+    }
+
     /**
      * This function performs a `GET` to the `/files/{fileId}/permissions/{permissionId}` endpoint.
      *
@@ -290,6 +374,60 @@ impl Permissions {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Like `get`, but adds the call to a `Batch` to be sent with others, instead of sending it.
+     *
+     * This function performs a `GET` to the `/files/{fileId}/permissions/{permissionId}` endpoint.
+     *
+     * Gets a permission by ID.
+     *
+     * **Parameters:**
+     *
+     * * `file_id: &str` -- A link to this theme's background image.
+     * * `permission_id: &str` -- A link to this theme's background image.
+     * * `supports_all_drives: bool` -- Whether the requesting application supports both My Drives and shared drives.
+     * * `supports_team_drives: bool` -- Whether the user has installed the requesting app.
+     * * `use_domain_admin_access: bool` -- Issue the request as a domain administrator; if set to true, then the requester will be granted access if the file ID parameter refers to a shared drive and the requester is an administrator of the domain to which the shared drive belongs.
+     */
+    pub fn get_batch(
+        &self,
+        batch: &mut crate::batch::Batch,
+        file_id: &str,
+        permission_id: &str,
+        supports_all_drives: bool,
+        supports_team_drives: bool,
+        use_domain_admin_access: bool,
+    ) -> Result<crate::batch::BatchCall<crate::types::Permission>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if supports_all_drives {
+            query_args.push((
+                "supportsAllDrives".to_string(),
+                supports_all_drives.to_string(),
+            ));
+        }
+        if supports_team_drives {
+            query_args.push((
+                "supportsTeamDrives".to_string(),
+                supports_team_drives.to_string(),
+            ));
+        }
+        if use_domain_admin_access {
+            query_args.push((
+                "useDomainAdminAccess".to_string(),
+                use_domain_admin_access.to_string(),
+            ));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/files/{}/permissions/{}?{}",
+            crate::progenitor_support::encode_path(&file_id.to_string()),
+            crate::progenitor_support::encode_path(&permission_id.to_string()),
+            query_
+        );
+
+        Ok(batch.add(reqwest::Method::GET, &url, None))
+    }
+
     /**
      * This function performs a `DELETE` to the `/files/{fileId}/permissions/{permissionId}` endpoint.
      *
@@ -341,6 +479,60 @@ impl Permissions {
         self.client.delete(&url, None).await
     }
 
+    /**
+     * Like `delete`, but adds the call to a `Batch` to be sent with others, instead of sending it.
+     *
+     * This function performs a `DELETE` to the `/files/{fileId}/permissions/{permissionId}` endpoint.
+     *
+     * Deletes a permission.
+     *
+     * **Parameters:**
+     *
+     * * `file_id: &str` -- A link to this theme's background image.
+     * * `permission_id: &str` -- A link to this theme's background image.
+     * * `supports_all_drives: bool` -- Whether the requesting application supports both My Drives and shared drives.
+     * * `supports_team_drives: bool` -- Whether the user has installed the requesting app.
+     * * `use_domain_admin_access: bool` -- Issue the request as a domain administrator; if set to true, then the requester will be granted access if the file ID parameter refers to a shared drive and the requester is an administrator of the domain to which the shared drive belongs.
+     */
+    pub fn delete_batch(
+        &self,
+        batch: &mut crate::batch::Batch,
+        file_id: &str,
+        permission_id: &str,
+        supports_all_drives: bool,
+        supports_team_drives: bool,
+        use_domain_admin_access: bool,
+    ) -> Result<crate::batch::BatchCall<()>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if supports_all_drives {
+            query_args.push((
+                "supportsAllDrives".to_string(),
+                supports_all_drives.to_string(),
+            ));
+        }
+        if supports_team_drives {
+            query_args.push((
+                "supportsTeamDrives".to_string(),
+                supports_team_drives.to_string(),
+            ));
+        }
+        if use_domain_admin_access {
+            query_args.push((
+                "useDomainAdminAccess".to_string(),
+                use_domain_admin_access.to_string(),
+            ));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/files/{}/permissions/{}?{}",
+            crate::progenitor_support::encode_path(&file_id.to_string()),
+            crate::progenitor_support::encode_path(&permission_id.to_string()),
+            query_
+        );
+
+        Ok(batch.add(reqwest::Method::DELETE, &url, None))
+    }
+
     /**
      * This function performs a `PATCH` to the `/files/{fileId}/permissions/{permissionId}` endpoint.
      *
@@ -410,4 +602,79 @@ impl Permissions {
             .patch(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
             .await
     }
+
+    /**
+     * Like `update`, but adds the call to a `Batch` to be sent with others, instead of sending it.
+     *
+     * This function performs a `PATCH` to the `/files/{fileId}/permissions/{permissionId}` endpoint.
+     *
+     * Updates a permission with patch semantics.
+     *
+     * **Parameters:**
+     *
+     * * `file_id: &str` -- A link to this theme's background image.
+     * * `permission_id: &str` -- A link to this theme's background image.
+     * * `remove_expiration: bool` -- Whether the user has installed the requesting app.
+     * * `supports_all_drives: bool` -- Whether the requesting application supports both My Drives and shared drives.
+     * * `supports_team_drives: bool` -- Whether the user has installed the requesting app.
+     * * `transfer_ownership: bool` -- Whether to transfer ownership to the specified user and downgrade the current owner to a writer. This parameter is required as an acknowledgement of the side effect. File owners can only transfer ownership of files existing on My Drive. Files existing in a shared drive are owned by the organization that owns that shared drive. Ownership transfers are not supported for files and folders in shared drives. Organizers of a shared drive can move items from that shared drive into their My Drive which transfers the ownership to them.
+     * * `use_domain_admin_access: bool` -- Issue the request as a domain administrator; if set to true, then the requester will be granted access if the file ID parameter refers to a shared drive and the requester is an administrator of the domain to which the shared drive belongs.
+     */
+    pub fn update_batch(
+        &self,
+        batch: &mut crate::batch::Batch,
+        file_id: &str,
+        permission_id: &str,
+        remove_expiration: bool,
+        supports_all_drives: bool,
+        supports_team_drives: bool,
+        transfer_ownership: bool,
+        use_domain_admin_access: bool,
+        body: &crate::types::Permission,
+    ) -> Result<crate::batch::BatchCall<crate::types::Permission>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if remove_expiration {
+            query_args.push((
+                "removeExpiration".to_string(),
+                remove_expiration.to_string(),
+            ));
+        }
+        if supports_all_drives {
+            query_args.push((
+                "supportsAllDrives".to_string(),
+                supports_all_drives.to_string(),
+            ));
+        }
+        if supports_team_drives {
+            query_args.push((
+                "supportsTeamDrives".to_string(),
+                supports_team_drives.to_string(),
+            ));
+        }
+        if transfer_ownership {
+            query_args.push((
+                "transferOwnership".to_string(),
+                transfer_ownership.to_string(),
+            ));
+        }
+        if use_domain_admin_access {
+            query_args.push((
+                "useDomainAdminAccess".to_string(),
+                use_domain_admin_access.to_string(),
+            ));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/files/{}/permissions/{}?{}",
+            crate::progenitor_support::encode_path(&file_id.to_string()),
+            crate::progenitor_support::encode_path(&permission_id.to_string()),
+            query_
+        );
+
+        Ok(batch.add(
+            reqwest::Method::PATCH,
+            &url,
+            Some(serde_json::to_string(body)?),
+        ))
+    }
 }
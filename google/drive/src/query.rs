@@ -0,0 +1,200 @@
+//! Build search queries for files, with their values escaped.
+//!
+//! Queries format as the `q` parameter of `Files::list_all`, following the
+//! [search syntax](https://developers.google.com/drive/api/v3/search-files).
+//!
+//! ```
+//! use google_drive::query::Query;
+//!
+//! let q = Query::name_is("Bob's report")
+//!     .and(Query::in_parents("folder-id"))
+//!     .and(Query::mime_type_is("application/pdf").or(Query::folders()))
+//!     .and(!Query::trashed(true));
+//! assert_eq!(
+//!     q.to_string(),
+//!     "name = 'Bob\\'s report' and 'folder-id' in parents and (mimeType = \
+//!      'application/pdf' or mimeType = 'application/vnd.google-apps.folder') and not \
+//!      trashed = true"
+//! );
+//! ```
+use chrono::{DateTime, SecondsFormat, Utc};
+
+/// The MIME type of folders.
+pub const FOLDER_MIME_TYPE: &str = "application/vnd.google-apps.folder";
+
+/// A search query for files.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Query(Expr);
+
+#[derive(Debug, Clone, PartialEq)]
+enum Expr {
+    Term(String),
+    And(Vec<Expr>),
+    Or(Vec<Expr>),
+    Not(Box<Expr>),
+}
+
+impl Query {
+    fn term(term: String) -> Self {
+        Query(Expr::Term(term))
+    }
+
+    /// Files with exactly this name.
+    pub fn name_is(name: &str) -> Self {
+        Query::term(format!("name = {}", quote(name)))
+    }
+
+    /// Files with a word in their name that starts with `prefix`.
+    pub fn name_contains(prefix: &str) -> Self {
+        Query::term(format!("name contains {}", quote(prefix)))
+    }
+
+    /// Files whose name, description, content or indexable text contains `text`.
+    pub fn full_text_contains(text: &str) -> Self {
+        Query::term(format!("fullText contains {}", quote(text)))
+    }
+
+    pub fn mime_type_is(mime_type: &str) -> Self {
+        Query::term(format!("mimeType = {}", quote(mime_type)))
+    }
+
+    /// Folders.
+    pub fn folders() -> Self {
+        Query::mime_type_is(FOLDER_MIME_TYPE)
+    }
+
+    /// Files modified after `time`.
+    pub fn modified_after(time: DateTime<Utc>) -> Self {
+        Query::term(format!("modifiedTime > {}", quote_time(time)))
+    }
+
+    /// Files modified before `time`.
+    pub fn modified_before(time: DateTime<Utc>) -> Self {
+        Query::term(format!("modifiedTime < {}", quote_time(time)))
+    }
+
+    /// Files in the folder or shared drive with id `parent_id`.
+    pub fn in_parents(parent_id: &str) -> Self {
+        Query::term(format!("{} in parents", quote(parent_id)))
+    }
+
+    /// Files owned by the user with this email.
+    pub fn owned_by(email: &str) -> Self {
+        Query::term(format!("{} in owners", quote(email)))
+    }
+
+    pub fn trashed(trashed: bool) -> Self {
+        Query::term(format!("trashed = {}", trashed))
+    }
+
+    pub fn starred(starred: bool) -> Self {
+        Query::term(format!("starred = {}", starred))
+    }
+
+    /// Files with a public custom property.
+    pub fn has_property(key: &str, value: &str) -> Self {
+        Query::term(format!(
+            "properties has {{ key={} and value={} }}",
+            quote(key),
+            quote(value)
+        ))
+    }
+
+    /// Files with a custom property private to the requesting app.
+    pub fn has_app_property(key: &str, value: &str) -> Self {
+        Query::term(format!(
+            "appProperties has {{ key={} and value={} }}",
+            quote(key),
+            quote(value)
+        ))
+    }
+
+    /// Files that match both queries.
+    pub fn and(self, other: Query) -> Self {
+        Query(match (self.0, other.0) {
+            (Expr::And(mut a), Expr::And(b)) => {
+                a.extend(b);
+                Expr::And(a)
+            }
+            (Expr::And(mut a), b) => {
+                a.push(b);
+                Expr::And(a)
+            }
+            (a, Expr::And(mut b)) => {
+                b.insert(0, a);
+                Expr::And(b)
+            }
+            (a, b) => Expr::And(vec![a, b]),
+        })
+    }
+
+    /// Files that match either query.
+    pub fn or(self, other: Query) -> Self {
+        Query(match (self.0, other.0) {
+            (Expr::Or(mut a), Expr::Or(b)) => {
+                a.extend(b);
+                Expr::Or(a)
+            }
+            (Expr::Or(mut a), b) => {
+                a.push(b);
+                Expr::Or(a)
+            }
+            (a, Expr::Or(mut b)) => {
+                b.insert(0, a);
+                Expr::Or(b)
+            }
+            (a, b) => Expr::Or(vec![a, b]),
+        })
+    }
+}
+
+impl std::ops::Not for Query {
+    type Output = Query;
+
+    /// Files that don't match the query.
+    fn not(self) -> Query {
+        Query(match self.0 {
+            Expr::Not(e) => *e,
+            e => Expr::Not(Box::new(e)),
+        })
+    }
+}
+
+impl std::fmt::Display for Query {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl std::fmt::Display for Expr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // Operands are grouped with parentheses, unless they're single terms.
+        let operand = |e: &Expr| match e {
+            Expr::Term(_) | Expr::Not(_) => e.to_string(),
+            _ => format!("({})", e),
+        };
+        match self {
+            Expr::Term(term) => write!(f, "{}", term),
+            Expr::And(exprs) => write!(
+                f,
+                "{}",
+                exprs.iter().map(operand).collect::<Vec<_>>().join(" and ")
+            ),
+            Expr::Or(exprs) => write!(
+                f,
+                "{}",
+                exprs.iter().map(operand).collect::<Vec<_>>().join(" or ")
+            ),
+            Expr::Not(e) => write!(f, "not {}", operand(e)),
+        }
+    }
+}
+
+/// Quote a value, escaping backslashes and single quotes.
+fn quote(value: &str) -> String {
+    format!("'{}'", value.replace('\\', "\\\\").replace('\'', "\\'"))
+}
+
+fn quote_time(time: DateTime<Utc>) -> String {
+    quote(&time.to_rfc3339_opts(SecondsFormat::Secs, true))
+}
@@ -141,6 +141,38 @@ impl Replies {
             .await
     }
 
+    /**
+     * Like `create`, but adds the call to a `Batch` to be sent with others, instead of sending it.
+     *
+     * This function performs a `POST` to the `/files/{fileId}/comments/{commentId}/replies` endpoint.
+     *
+     * Creates a new reply to a comment.
+     *
+     * **Parameters:**
+     *
+     * * `file_id: &str` -- A link to this theme's background image.
+     * * `comment_id: &str` -- A link to this theme's background image.
+     */
+    pub fn create_batch(
+        &self,
+        batch: &mut crate::batch::Batch,
+        file_id: &str,
+        comment_id: &str,
+        body: &crate::types::Reply,
+    ) -> Result<crate::batch::BatchCall<crate::types::Reply>> {
+        let url = format!(
+            "/files/{}/comments/{}/replies",
+            crate::progenitor_support::encode_path(&file_id.to_string()),
+            crate::progenitor_support::encode_path(&comment_id.to_string()),
+        );
+
+        Ok(batch.add(
+            reqwest::Method::POST,
+            &url,
+            Some(serde_json::to_string(body)?),
+        ))
+    }
+
     /**
      * This function performs a `GET` to the `/files/{fileId}/comments/{commentId}/replies/{replyId}` endpoint.
      *
@@ -176,6 +208,44 @@ impl Replies {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Like `get`, but adds the call to a `Batch` to be sent with others, instead of sending it.
+     *
+     * This function performs a `GET` to the `/files/{fileId}/comments/{commentId}/replies/{replyId}` endpoint.
+     *
+     * Gets a reply by ID.
+     *
+     * **Parameters:**
+     *
+     * * `file_id: &str` -- A link to this theme's background image.
+     * * `comment_id: &str` -- A link to this theme's background image.
+     * * `reply_id: &str` -- A link to this theme's background image.
+     * * `include_deleted: bool` -- Whether to return deleted replies. Deleted replies will not include their original content.
+     */
+    pub fn get_batch(
+        &self,
+        batch: &mut crate::batch::Batch,
+        file_id: &str,
+        comment_id: &str,
+        reply_id: &str,
+        include_deleted: bool,
+    ) -> Result<crate::batch::BatchCall<crate::types::Reply>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if include_deleted {
+            query_args.push(("includeDeleted".to_string(), include_deleted.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/files/{}/comments/{}/replies/{}?{}",
+            crate::progenitor_support::encode_path(&file_id.to_string()),
+            crate::progenitor_support::encode_path(&comment_id.to_string()),
+            crate::progenitor_support::encode_path(&reply_id.to_string()),
+            query_
+        );
+
+        Ok(batch.add(reqwest::Method::GET, &url, None))
+    }
+
     /**
      * This function performs a `DELETE` to the `/files/{fileId}/comments/{commentId}/replies/{replyId}` endpoint.
      *
@@ -198,6 +268,36 @@ impl Replies {
         self.client.delete(&url, None).await
     }
 
+    /**
+     * Like `delete`, but adds the call to a `Batch` to be sent with others, instead of sending it.
+     *
+     * This function performs a `DELETE` to the `/files/{fileId}/comments/{commentId}/replies/{replyId}` endpoint.
+     *
+     * Deletes a reply.
+     *
+     * **Parameters:**
+     *
+     * * `file_id: &str` -- A link to this theme's background image.
+     * * `comment_id: &str` -- A link to this theme's background image.
+     * * `reply_id: &str` -- A link to this theme's background image.
+     */
+    pub fn delete_batch(
+        &self,
+        batch: &mut crate::batch::Batch,
+        file_id: &str,
+        comment_id: &str,
+        reply_id: &str,
+    ) -> Result<crate::batch::BatchCall<()>> {
+        let url = format!(
+            "/files/{}/comments/{}/replies/{}",
+            crate::progenitor_support::encode_path(&file_id.to_string()),
+            crate::progenitor_support::encode_path(&comment_id.to_string()),
+            crate::progenitor_support::encode_path(&reply_id.to_string()),
+        );
+
+        Ok(batch.add(reqwest::Method::DELETE, &url, None))
+    }
+
     /**
      * This function performs a `PATCH` to the `/files/{fileId}/comments/{commentId}/replies/{replyId}` endpoint.
      *
@@ -227,4 +327,39 @@ impl Replies {
             .patch(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
             .await
     }
+
+    /**
+     * Like `update`, but adds the call to a `Batch` to be sent with others, instead of sending it.
+     *
+     * This function performs a `PATCH` to the `/files/{fileId}/comments/{commentId}/replies/{replyId}` endpoint.
+     *
+     * Updates a reply with patch semantics.
+     *
+     * **Parameters:**
+     *
+     * * `file_id: &str` -- A link to this theme's background image.
+     * * `comment_id: &str` -- A link to this theme's background image.
+     * * `reply_id: &str` -- A link to this theme's background image.
+     */
+    pub fn update_batch(
+        &self,
+        batch: &mut crate::batch::Batch,
+        file_id: &str,
+        comment_id: &str,
+        reply_id: &str,
+        body: &crate::types::Reply,
+    ) -> Result<crate::batch::BatchCall<crate::types::Reply>> {
+        let url = format!(
+            "/files/{}/comments/{}/replies/{}",
+            crate::progenitor_support::encode_path(&file_id.to_string()),
+            crate::progenitor_support::encode_path(&comment_id.to_string()),
+            crate::progenitor_support::encode_path(&reply_id.to_string()),
+        );
+
+        Ok(batch.add(
+            reqwest::Method::PATCH,
+            &url,
+            Some(serde_json::to_string(body)?),
+        ))
+    }
 }
@@ -129,6 +129,44 @@ impl Revisions {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Like `get`, but adds the call to a `Batch` to be sent with others, instead of sending it.
+     *
+     * This function performs a `GET` to the `/files/{fileId}/revisions/{revisionId}` endpoint.
+     *
+     * Gets a revision's metadata or content by ID.
+     *
+     * **Parameters:**
+     *
+     * * `file_id: &str` -- A link to this theme's background image.
+     * * `revision_id: &str` -- A link to this theme's background image.
+     * * `acknowledge_abuse: bool` -- Whether the user is acknowledging the risk of downloading known malware or other abusive files. This is only applicable when alt=media.
+     */
+    pub fn get_batch(
+        &self,
+        batch: &mut crate::batch::Batch,
+        file_id: &str,
+        revision_id: &str,
+        acknowledge_abuse: bool,
+    ) -> Result<crate::batch::BatchCall<crate::types::Revision>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if acknowledge_abuse {
+            query_args.push((
+                "acknowledgeAbuse".to_string(),
+                acknowledge_abuse.to_string(),
+            ));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/files/{}/revisions/{}?{}",
+            crate::progenitor_support::encode_path(&file_id.to_string()),
+            crate::progenitor_support::encode_path(&revision_id.to_string()),
+            query_
+        );
+
+        Ok(batch.add(reqwest::Method::GET, &url, None))
+    }
+
     /**
      * This function performs a `DELETE` to the `/files/{fileId}/revisions/{revisionId}` endpoint.
      *
@@ -149,6 +187,33 @@ impl Revisions {
         self.client.delete(&url, None).await
     }
 
+    /**
+     * Like `delete`, but adds the call to a `Batch` to be sent with others, instead of sending it.
+     *
+     * This function performs a `DELETE` to the `/files/{fileId}/revisions/{revisionId}` endpoint.
+     *
+     * Permanently deletes a file version. You can only delete revisions for files with binary content in Google Drive, like images or videos. Revisions for other files, like Google Docs or Sheets, and the last remaining file version can't be deleted.
+     *
+     * **Parameters:**
+     *
+     * * `file_id: &str` -- A link to this theme's background image.
+     * * `revision_id: &str` -- A link to this theme's background image.
+     */
+    pub fn delete_batch(
+        &self,
+        batch: &mut crate::batch::Batch,
+        file_id: &str,
+        revision_id: &str,
+    ) -> Result<crate::batch::BatchCall<()>> {
+        let url = format!(
+            "/files/{}/revisions/{}",
+            crate::progenitor_support::encode_path(&file_id.to_string()),
+            crate::progenitor_support::encode_path(&revision_id.to_string()),
+        );
+
+        Ok(batch.add(reqwest::Method::DELETE, &url, None))
+    }
+
     /**
      * This function performs a `PATCH` to the `/files/{fileId}/revisions/{revisionId}` endpoint.
      *
@@ -175,4 +240,36 @@ impl Revisions {
             .patch(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
             .await
     }
+
+    /**
+     * Like `update`, but adds the call to a `Batch` to be sent with others, instead of sending it.
+     *
+     * This function performs a `PATCH` to the `/files/{fileId}/revisions/{revisionId}` endpoint.
+     *
+     * Updates a revision with patch semantics.
+     *
+     * **Parameters:**
+     *
+     * * `file_id: &str` -- A link to this theme's background image.
+     * * `revision_id: &str` -- A link to this theme's background image.
+     */
+    pub fn update_batch(
+        &self,
+        batch: &mut crate::batch::Batch,
+        file_id: &str,
+        revision_id: &str,
+        body: &crate::types::Revision,
+    ) -> Result<crate::batch::BatchCall<crate::types::Revision>> {
+        let url = format!(
+            "/files/{}/revisions/{}",
+            crate::progenitor_support::encode_path(&file_id.to_string()),
+            crate::progenitor_support::encode_path(&revision_id.to_string()),
+        );
+
+        Ok(batch.add(
+            reqwest::Method::PATCH,
+            &url,
+            Some(serde_json::to_string(body)?),
+        ))
+    }
 }
@@ -0,0 +1,116 @@
+//! Incrementally sync changes to files and shared drives with page tokens.
+//!
+//! Get a token with `start_token`, then call `sync` with it to list everything that
+//! changed since and the token to store for the next sync.
+//!
+//! ```no_run
+//! # async fn run(client: google_drive::Client, stored_token: String) -> anyhow::Result<()> {
+//! use google_drive::sync::{ChangesSync, SyncResult};
+//!
+//! let changes = client.changes();
+//! let token = if stored_token.is_empty() {
+//!     changes.start_token("").await?
+//! } else {
+//!     stored_token
+//! };
+//!
+//! match changes.sync("", &token).await? {
+//!     SyncResult::Changes { items, next_token } => {
+//!         // Apply the changes, and store `next_token` along with them.
+//!     }
+//!     SyncResult::Reset => {
+//!         // Mirror everything again, and start over with a new token.
+//!     }
+//! }
+//! # Ok(())
+//! # }
+//! ```
+use anyhow::Result;
+use http::StatusCode;
+
+use crate::{types::Change, utils::HttpError};
+
+/// The largest page size the changes API accepts.
+const MAX_PAGE_SIZE: i64 = 1000;
+
+/// The result of an incremental sync.
+#[derive(Debug, Clone, PartialEq)]
+pub enum SyncResult<T> {
+    /// Everything that changed since the token, and the token to pass next time.
+    Changes { items: Vec<T>, next_token: String },
+    /// The token is no longer valid, so a full sync is required before starting
+    /// again with a new token.
+    Reset,
+}
+
+#[async_trait::async_trait]
+pub trait ChangesSync {
+    /// Get a token for the changes made from now on.
+    ///
+    /// Pass an empty `drive_id` for the changes to the user's files, or the id of a
+    /// shared drive for the changes to that drive.
+    async fn start_token(&self, drive_id: &str) -> Result<String>;
+
+    /// List all the changes since `token`, following every page, along with the
+    /// token to pass next time.
+    ///
+    /// `drive_id` must be the same as the one the token was started with.
+    async fn sync(&self, drive_id: &str, token: &str) -> Result<SyncResult<Change>>;
+}
+
+#[async_trait::async_trait]
+impl ChangesSync for crate::changes::Changes {
+    /// Get a token for the changes made from now on.
+    ///
+    /// Pass an empty `drive_id` for the changes to the user's files, or the id of a
+    /// shared drive for the changes to that drive.
+    async fn start_token(&self, drive_id: &str) -> Result<String> {
+        let token = self.get_start_page_token(drive_id, true, false, "").await?;
+
+        Ok(token.start_page_token)
+    }
+
+    /// List all the changes since `token`, following every page, along with the
+    /// token to pass next time.
+    ///
+    /// `drive_id` must be the same as the one the token was started with.
+    async fn sync(&self, drive_id: &str, token: &str) -> Result<SyncResult<Change>> {
+        let mut items = Vec::new();
+        let mut page_token = token.to_string();
+        loop {
+            let mut query_args: Vec<(String, String)> = vec![
+                ("pageToken".to_string(), page_token),
+                ("pageSize".to_string(), MAX_PAGE_SIZE.to_string()),
+                ("includeItemsFromAllDrives".to_string(), "true".to_string()),
+                ("supportsAllDrives".to_string(), "true".to_string()),
+            ];
+            if !drive_id.is_empty() {
+                query_args.push(("driveId".to_string(), drive_id.to_string()));
+            }
+            let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+            let url = format!("/changes?{}", query_);
+
+            let resp: crate::types::ChangeList = match self.client.get(&url, None).await {
+                Ok(resp) => resp,
+                // The API responds with `410 Gone` when the token has expired and a
+                // full sync is required.
+                Err(e) if HttpError::status_of(&e) == Some(StatusCode::GONE) => {
+                    return Ok(SyncResult::Reset)
+                }
+                Err(e) => return Err(e),
+            };
+            items.extend(resp.changes);
+
+            if !resp.new_start_page_token.is_empty() {
+                return Ok(SyncResult::Changes {
+                    items,
+                    next_token: resp.new_start_page_token,
+                });
+            }
+            if resp.next_page_token.is_empty() {
+                anyhow::bail!("changes response has neither a next page nor a new start token");
+            }
+            page_token = resp.next_page_token;
+        }
+    }
+}
@@ -139,6 +139,37 @@ impl Teamdrives {
             .await
     }
 
+    /**
+     * Like `create`, but adds the call to a `Batch` to be sent with others, instead of sending it.
+     *
+     * This function performs a `POST` to the `/teamdrives` endpoint.
+     *
+     * Deprecated use drives.create instead.
+     *
+     * **Parameters:**
+     *
+     * * `request_id: &str` -- An ID, such as a random UUID, which uniquely identifies this user's request for idempotent creation of a Team Drive. A repeated request by the same user and with the same request ID will avoid creating duplicates by attempting to create the same Team Drive. If the Team Drive already exists a 409 error will be returned.
+     */
+    pub fn create_batch(
+        &self,
+        batch: &mut crate::batch::Batch,
+        request_id: &str,
+        body: &crate::types::TeamDrive,
+    ) -> Result<crate::batch::BatchCall<crate::types::TeamDrive>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !request_id.is_empty() {
+            query_args.push(("requestId".to_string(), request_id.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/teamdrives?{}", query_);
+
+        Ok(batch.add(
+            reqwest::Method::POST,
+            &url,
+            Some(serde_json::to_string(body)?),
+        ))
+    }
+
     /**
      * This function performs a `GET` to the `/teamdrives/{teamDriveId}` endpoint.
      *
@@ -171,6 +202,41 @@ impl Teamdrives {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Like `get`, but adds the call to a `Batch` to be sent with others, instead of sending it.
+     *
+     * This function performs a `GET` to the `/teamdrives/{teamDriveId}` endpoint.
+     *
+     * Deprecated use drives.get instead.
+     *
+     * **Parameters:**
+     *
+     * * `team_drive_id: &str` -- A link to this theme's background image.
+     * * `use_domain_admin_access: bool` -- Issue the request as a domain administrator; if set to true, then the requester will be granted access if they are an administrator of the domain to which the Team Drive belongs.
+     */
+    pub fn get_batch(
+        &self,
+        batch: &mut crate::batch::Batch,
+        team_drive_id: &str,
+        use_domain_admin_access: bool,
+    ) -> Result<crate::batch::BatchCall<crate::types::TeamDrive>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if use_domain_admin_access {
+            query_args.push((
+                "useDomainAdminAccess".to_string(),
+                use_domain_admin_access.to_string(),
+            ));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/teamdrives/{}?{}",
+            crate::progenitor_support::encode_path(&team_drive_id.to_string()),
+            query_
+        );
+
+        Ok(batch.add(reqwest::Method::GET, &url, None))
+    }
+
     /**
      * This function performs a `DELETE` to the `/teamdrives/{teamDriveId}` endpoint.
      *
@@ -189,6 +255,30 @@ impl Teamdrives {
         self.client.delete(&url, None).await
     }
 
+    /**
+     * Like `delete`, but adds the call to a `Batch` to be sent with others, instead of sending it.
+     *
+     * This function performs a `DELETE` to the `/teamdrives/{teamDriveId}` endpoint.
+     *
+     * Deprecated use drives.delete instead.
+     *
+     * **Parameters:**
+     *
+     * * `team_drive_id: &str` -- A link to this theme's background image.
+     */
+    pub fn delete_batch(
+        &self,
+        batch: &mut crate::batch::Batch,
+        team_drive_id: &str,
+    ) -> Result<crate::batch::BatchCall<()>> {
+        let url = format!(
+            "/teamdrives/{}",
+            crate::progenitor_support::encode_path(&team_drive_id.to_string()),
+        );
+
+        Ok(batch.add(reqwest::Method::DELETE, &url, None))
+    }
+
     /**
      * This function performs a `PATCH` to the `/teamdrives/{teamDriveId}` endpoint.
      *
@@ -223,4 +313,44 @@ impl Teamdrives {
             .patch(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
             .await
     }
+
+    /**
+     * Like `update`, but adds the call to a `Batch` to be sent with others, instead of sending it.
+     *
+     * This function performs a `PATCH` to the `/teamdrives/{teamDriveId}` endpoint.
+     *
+     * Deprecated use drives.update instead
+     *
+     * **Parameters:**
+     *
+     * * `team_drive_id: &str` -- A link to this theme's background image.
+     * * `use_domain_admin_access: bool` -- Issue the request as a domain administrator; if set to true, then the requester will be granted access if they are an administrator of the domain to which the Team Drive belongs.
+     */
+    pub fn update_batch(
+        &self,
+        batch: &mut crate::batch::Batch,
+        team_drive_id: &str,
+        use_domain_admin_access: bool,
+        body: &crate::types::TeamDrive,
+    ) -> Result<crate::batch::BatchCall<crate::types::TeamDrive>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if use_domain_admin_access {
+            query_args.push((
+                "useDomainAdminAccess".to_string(),
+                use_domain_admin_access.to_string(),
+            ));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/teamdrives/{}?{}",
+            crate::progenitor_support::encode_path(&team_drive_id.to_string()),
+            query_
+        );
+
+        Ok(batch.add(
+            reqwest::Method::PATCH,
+            &url,
+            Some(serde_json::to_string(body)?),
+        ))
+    }
 }
@@ -1 +1,111 @@
+#[test]
+fn test_decode_batch() {
+    let body = "--batch_abc\r\nContent-Type: application/http\r\nContent-ID: <response-1>\r\n\r\nHTTP/1.1 404 Not Found\r\nContent-Type: application/json\r\n\r\n{\"error\": {}}\r\n--batch_abc\r\nContent-Type: application/http\r\nContent-ID: <response-0>\r\n\r\nHTTP/1.1 200 OK\r\nContent-Type: application/json\r\n\r\n{\"id\": \"1\"}\r\n--batch_abc--\r\n";
 
+    let parts = crate::batch::decode_batch("batch_abc", body).unwrap();
+    assert_eq!(parts.len(), 2);
+    assert_eq!(parts[0].0.as_deref(), Some("1"));
+    assert_eq!(parts[0].1.status, http::StatusCode::NOT_FOUND);
+    assert_eq!(parts[1].0.as_deref(), Some("0"));
+    assert_eq!(parts[1].1.body, "{\"id\": \"1\"}");
+}
+
+#[test]
+fn test_match_batch_responses() {
+    let body = "--batch_abc\r\nContent-ID: <response-999>\r\n\r\nHTTP/1.1 200 OK\r\n\r\n{}\r\n--batch_abc\r\nContent-ID: <response-3>\r\n\r\nHTTP/1.1 200 OK\r\n\r\n{}\r\n--batch_abc\r\nContent-ID: <response-101>\r\n\r\nHTTP/1.1 204 No Content\r\n\r\n\r\n--batch_abc\r\nContent-Type: application/http\r\n\r\nHTTP/1.1 404 Not Found\r\n\r\n{}\r\n--batch_abc--\r\n";
+
+    // A second chunk of calls, so response 3 belongs to the first one.
+    let parts = crate::batch::decode_batch("batch_abc", body).unwrap();
+    let responses = crate::batch::match_responses(&[100, 101, 102, 103], parts);
+    let matched: Vec<(usize, http::StatusCode)> = responses
+        .iter()
+        .map(|(i, part)| (*i, part.status))
+        .collect();
+    assert_eq!(
+        matched,
+        vec![
+            (101, http::StatusCode::NO_CONTENT),
+            (103, http::StatusCode::NOT_FOUND),
+        ]
+    );
+}
+
+#[test]
+fn test_channel_notification_from_headers() {
+    use crate::notifications::{ChannelNotification, ResourceState};
+
+    let notification = ChannelNotification::from_headers(vec![
+        ("X-Goog-Channel-ID", "4ba78bf0-6a47-11e2-bcfd-0800200c9a66"),
+        ("X-Goog-Channel-Token", "398348u3tu83ut8uu38"),
+        ("X-Goog-Channel-Expiration", "Tue, 19 Nov 2013 01:13:52 GMT"),
+        ("X-Goog-Resource-ID", "ret08u3rv24htgh289g"),
+        (
+            "X-Goog-Resource-URI",
+            "https://www.googleapis.com/drive/v3/files/ret08u3rv24htgh289g",
+        ),
+        ("X-Goog-Resource-State", "update"),
+        ("X-Goog-Changed", "content,properties"),
+        ("X-Goog-Message-Number", "10"),
+    ])
+    .unwrap();
+
+    assert_eq!(
+        notification.channel_id,
+        "4ba78bf0-6a47-11e2-bcfd-0800200c9a66"
+    );
+    assert_eq!(
+        notification.channel_token.as_deref(),
+        Some("398348u3tu83ut8uu38")
+    );
+    assert_eq!(
+        notification.channel_expiration.unwrap().to_rfc3339(),
+        "2013-11-19T01:13:52+00:00"
+    );
+    assert_eq!(notification.resource_state, ResourceState::Update);
+    assert_eq!(notification.changed, vec!["content", "properties"]);
+    assert_eq!(notification.message_number, 10);
+
+    assert!(ChannelNotification::from_headers(vec![("x-goog-channel-id", "1")]).is_err());
+}
+
+#[test]
+fn test_http_error_status() {
+    use crate::utils::HttpError;
+
+    let e: anyhow::Error = HttpError::new(
+        http::StatusCode::GONE,
+        b"{\"error\": {\"code\": 410, \"message\": \"Sync token is no longer valid, a full sync is required.\"}}",
+    )
+    .into();
+    assert_eq!(HttpError::status_of(&e), Some(http::StatusCode::GONE));
+    assert!(e.to_string().starts_with("code: 410 Gone, error: "));
+
+    let e: anyhow::Error = HttpError::new(http::StatusCode::NOT_FOUND, b"").into();
+    assert_eq!(HttpError::status_of(&e), Some(http::StatusCode::NOT_FOUND));
+    assert_eq!(e.to_string(), "code: 404 Not Found, empty response");
+
+    assert_eq!(HttpError::status_of(&anyhow::anyhow!("410 Gone")), None);
+}
+
+#[test]
+fn test_query_escaping() {
+    use crate::query::Query;
+
+    assert_eq!(
+        Query::name_is(r"it's a \ test").to_string(),
+        r"name = 'it\'s a \\ test'"
+    );
+
+    let modified = chrono::DateTime::parse_from_rfc3339("2021-06-01T12:00:00Z")
+        .unwrap()
+        .with_timezone(&chrono::Utc);
+    let q = Query::has_app_property("owner's", "ci")
+        .or(Query::owned_by("a@example.com"))
+        .and(!(Query::starred(true).and(Query::modified_after(modified))));
+    assert_eq!(
+        q.to_string(),
+        "(appProperties has { key='owner\\'s' and value='ci' } or 'a@example.com' in owners) \
+         and not (starred = true and modifiedTime > '2021-06-01T12:00:00Z')"
+    );
+    assert_eq!((!!Query::trashed(false)).to_string(), "trashed = false");
+}
@@ -115,6 +115,19 @@ impl PermissionOps for crate::permissions::Permissions {
 
 #[async_trait::async_trait]
 pub trait FileOps {
+    /// List the files in a shared drive that match a query.
+    async fn list_by_query(
+        &self,
+        drive_id: &str,
+        query: &crate::query::Query,
+    ) -> Result<Vec<crate::types::File>>;
+
+    /// List the files in My Drive and every shared drive that match a query.
+    async fn list_all_by_query(
+        &self,
+        query: &crate::query::Query,
+    ) -> Result<Vec<crate::types::File>>;
+
     /// Get a file by it's name.
     async fn get_by_name(
         &self,
@@ -150,6 +163,49 @@ pub trait FileOps {
 
 #[async_trait::async_trait]
 impl FileOps for crate::files::Files {
+    /// List the files in a shared drive that match a query.
+    async fn list_by_query(
+        &self,
+        drive_id: &str,
+        query: &crate::query::Query,
+    ) -> Result<Vec<crate::types::File>> {
+        self.list_all(
+            "drive",            // corpora
+            drive_id,           // drive id
+            true,               // include_items_from_all_drives
+            "",                 // include_permissions_for_view
+            false,              // include_team_drive_items
+            "",                 // order_by
+            &query.to_string(), // query
+            "",                 // spaces
+            true,               // supports_all_drives
+            false,              // supports_team_drives
+            "",                 // team_drive_id
+        )
+        .await
+    }
+
+    /// List the files in My Drive and every shared drive that match a query.
+    async fn list_all_by_query(
+        &self,
+        query: &crate::query::Query,
+    ) -> Result<Vec<crate::types::File>> {
+        self.list_all(
+            "allDrives",        // corpora
+            "",                 // drive id
+            true,               // include_items_from_all_drives
+            "",                 // include_permissions_for_view
+            false,              // include_team_drive_items
+            "",                 // order_by
+            &query.to_string(), // query
+            "",                 // spaces
+            true,               // supports_all_drives
+            false,              // supports_team_drives
+            "",                 // team_drive_id
+        )
+        .await
+    }
+
     /// Get a file by it's name.
     async fn get_by_name(
         &self,
@@ -157,25 +213,12 @@ impl FileOps for crate::files::Files {
         parent_id: &str,
         name: &str,
     ) -> Result<Vec<crate::types::File>> {
-        let mut query = format!("name = '{}'", name);
+        let mut query = crate::query::Query::name_is(name);
         if !parent_id.is_empty() {
-            query = format!("{} and '{}' in parents", query, parent_id);
+            query = query.and(crate::query::Query::in_parents(parent_id));
         }
 
-        self.list_all(
-            "drive",  // corpora
-            drive_id, // drive id
-            true,     // include_items_from_all_drives
-            "",       // include_permissions_for_view
-            false,    // include_team_drive_items
-            "",       // order_by
-            &query,   // query
-            "",       // spaces
-            true,     // supports_all_drives
-            false,    // supports_team_drives
-            "",       // team_drive_id
-        )
-        .await
+        self.list_by_query(drive_id, &query).await
     }
 
     /// Create or update a file in a drive.
@@ -266,40 +309,24 @@ impl FileOps for crate::files::Files {
 
     /// Create a folder, if it doesn't exist, returns the ID of the folder.
     async fn create_folder(&self, drive_id: &str, parent_id: &str, name: &str) -> Result<String> {
-        let folder_mime_type = "application/vnd.google-apps.folder";
         let mut file: crate::types::File = Default::default();
         // Set the name,
         file.name = name.to_string();
-        file.mime_type = folder_mime_type.to_string();
+        file.mime_type = crate::query::FOLDER_MIME_TYPE.to_string();
         if !parent_id.is_empty() {
             file.parents = vec![parent_id.to_string()];
         } else {
             file.parents = vec![drive_id.to_string()];
         }
 
-        let mut query = format!(
-            "name = '{}' and mimeType = 'application/vnd.google-apps.folder'",
-            name
-        );
+        let mut query = crate::query::Query::name_is(name).and(crate::query::Query::folders());
         if !parent_id.is_empty() {
-            query = format!("{} and '{}' in parents", query, parent_id);
+            query = query.and(crate::query::Query::in_parents(parent_id));
         }
 
         // Check if the folder exists.
         let folders = self
-            .list_all(
-                "drive",  // corpora
-                drive_id, // drive id
-                true,     // include_items_from_all_drives
-                "",       // include_permissions_for_view
-                false,    // include_team_drive_items
-                "",       // order_by
-                &query,   // query
-                "",       // spaces
-                true,     // supports_all_drives
-                false,    // supports_team_drives
-                "",       // team_drive_id
-            )
+            .list_by_query(drive_id, &query)
             .await
             .unwrap_or_default();
         if !folders.is_empty() {
@@ -0,0 +1,362 @@
+//! Send many API calls in one HTTP request.
+//!
+//! Every operation that sends a JSON body, or none, has a `_batch` variant, like
+//! `Groups::patch_batch`, which adds the call to a `Batch` and returns a typed
+//! `BatchCall` for its response. The calls are sent as a `multipart/mixed`
+//! request to the batch endpoint, and batches over the limit of 100 calls are
+//! split into several requests.
+//!
+//! ```no_run
+//! # async fn run(
+//! #     groups_settings: google_groups_settings::Client,
+//! #     settings: google_groups_settings::types::Groups,
+//! #     group_emails: &[&str],
+//! # ) -> anyhow::Result<()> {
+//! use google_groups_settings::{batch::Batch, types::Alt};
+//!
+//! let mut batch = Batch::new(groups_settings.clone());
+//! let mut calls = Vec::new();
+//! for group_email in group_emails {
+//!     calls.push(groups_settings.groups().patch_batch(
+//!         &mut batch,
+//!         Alt::Json,
+//!         group_email,
+//!         &settings,
+//!     )?);
+//! }
+//!
+//! let responses = batch.send().await?;
+//! for call in &calls {
+//!     responses.get(call)?;
+//! }
+//! # Ok(())
+//! # }
+//! ```
+use std::marker::PhantomData;
+
+use anyhow::{anyhow, Result};
+
+use crate::Client;
+
+/// The path of the batch endpoint on the API host.
+const BATCH_PATH: &str = "/batch/groupssettings/v1";
+
+/// The maximum number of calls in a single batch request.
+pub const MAX_BATCH_SIZE: usize = 100;
+
+/// A call that has been added to a `Batch`, used to get its response.
+#[derive(Debug)]
+pub struct BatchCall<T> {
+    index: usize,
+    response: PhantomData<fn() -> T>,
+}
+
+impl<T> Clone for BatchCall<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for BatchCall<T> {}
+
+#[derive(Debug, Clone)]
+struct Call {
+    method: http::Method,
+    // Calls with an invalid url are never sent, and the error is their response.
+    url: std::result::Result<reqwest::Url, String>,
+    body: Option<String>,
+}
+
+/// A set of calls to send as batch requests.
+pub struct Batch {
+    client: Client,
+    calls: Vec<Call>,
+}
+
+impl Batch {
+    pub fn new(client: Client) -> Self {
+        Batch {
+            client,
+            calls: Vec::new(),
+        }
+    }
+
+    /// The number of calls in the batch.
+    pub fn len(&self) -> usize {
+        self.calls.len()
+    }
+
+    /// Returns if no calls have been added to the batch.
+    pub fn is_empty(&self) -> bool {
+        self.calls.is_empty()
+    }
+
+    /// Add a call with an optional JSON body, and return the handle to its response.
+    /// `uri` is relative to the host of the client, like the paths used by the
+    /// generated functions, which call this from their `_batch` variants.
+    pub fn add<T>(
+        &mut self,
+        method: http::Method,
+        uri: &str,
+        body: Option<String>,
+    ) -> BatchCall<T> {
+        let index = self.calls.len();
+        let u = if uri.starts_with("https://") || uri.starts_with("http://") {
+            uri.to_string()
+        } else {
+            self.client.host.clone() + uri
+        };
+        let url = u.parse().map_err(|e| format!("invalid url {}: {}", u, e));
+        self.calls.push(Call { method, url, body });
+
+        BatchCall {
+            index,
+            response: PhantomData,
+        }
+    }
+
+    /// Send the calls, in batches of at most `MAX_BATCH_SIZE`, and return their
+    /// responses.
+    ///
+    /// An error is only returned if a batch request itself fails; the results of
+    /// the individual calls are returned by `BatchResponses::get`.
+    pub async fn send(self) -> Result<BatchResponses> {
+        let mut responses: Vec<Option<BatchPart>> = vec![None; self.calls.len()];
+        let mut pending = Vec::new();
+        for (index, call) in self.calls.iter().enumerate() {
+            match &call.url {
+                Ok(url) => pending.push((index, call.method.clone(), url, &call.body)),
+                Err(e) => {
+                    responses[index] = Some(BatchPart {
+                        status: http::StatusCode::BAD_REQUEST,
+                        body: e.to_string(),
+                    })
+                }
+            }
+        }
+
+        for chunk in pending.chunks(MAX_BATCH_SIZE) {
+            let parts = self.send_batch(chunk).await?;
+            for (index, part) in parts {
+                responses[index] = Some(part);
+            }
+        }
+
+        Ok(BatchResponses {
+            parts: responses
+                .into_iter()
+                .map(|part| {
+                    part.unwrap_or(BatchPart {
+                        status: http::StatusCode::INTERNAL_SERVER_ERROR,
+                        body: "no response for call in batch".to_string(),
+                    })
+                })
+                .collect(),
+        })
+    }
+
+    async fn send_batch(&self, calls: &[PendingCall<'_>]) -> Result<Vec<(usize, BatchPart)>> {
+        let host: reqwest::Url = self.client.host.parse()?;
+        let batch_url = format!(
+            "{}://{}{}",
+            host.scheme(),
+            host.host_str().unwrap_or_default(),
+            BATCH_PATH
+        );
+        let (url, auth) = self.client.url_and_auth(&batch_url).await?;
+
+        let boundary = format!("batch_{}", uuid::Uuid::new_v4().to_simple());
+        let body = encode_batch(&boundary, calls);
+
+        let mut req = self.client.client.post(url);
+        req = req.header(
+            reqwest::header::CONTENT_TYPE,
+            format!("multipart/mixed; boundary={}", boundary),
+        );
+        if let Some(auth_str) = auth {
+            req = req.header(http::header::AUTHORIZATION, &*auth_str);
+        }
+        log::debug!("request: {:?}", &req);
+
+        let response = req.body(body).send().await?;
+        let status = response.status();
+        let content_type = response
+            .headers()
+            .get(reqwest::header::CONTENT_TYPE)
+            .and_then(|c| c.to_str().ok())
+            .unwrap_or_default()
+            .to_string();
+        let response_body = response.bytes().await?;
+
+        if !status.is_success() {
+            return Err(crate::utils::HttpError::new(status, &response_body).into());
+        }
+
+        let boundary = content_type
+            .split(';')
+            .filter_map(|p| p.trim().strip_prefix("boundary="))
+            .next()
+            .map(|b| b.trim_matches('"').to_string())
+            .ok_or_else(|| anyhow!("batch response has no boundary: {}", content_type))?;
+
+        let parts = decode_batch(&boundary, &String::from_utf8_lossy(&response_body))?;
+        let indices: Vec<usize> = calls.iter().map(|c| c.0).collect();
+        Ok(match_responses(&indices, parts))
+    }
+}
+
+/// Match the responses of a batch to the indices of its calls.
+///
+/// Responses echo the Content-ID of their call, but fall back to their position in
+/// the response if it is missing. Responses for calls that aren't in the batch are
+/// ignored, so those calls get no response.
+pub(crate) fn match_responses(
+    indices: &[usize],
+    parts: Vec<(Option<String>, BatchPart)>,
+) -> Vec<(usize, BatchPart)> {
+    let mut responses = Vec::with_capacity(parts.len());
+    for (position, (content_id, part)) in parts.into_iter().enumerate() {
+        let index = match content_id.as_deref().map(str::parse::<usize>) {
+            Some(Ok(index)) => Some(index).filter(|i| indices.contains(i)),
+            _ => indices.get(position).copied(),
+        };
+        match index {
+            Some(index) => responses.push((index, part)),
+            None => log::warn!(
+                "ignoring response for a call that isn't in the batch: {:?}",
+                content_id
+            ),
+        }
+    }
+
+    responses
+}
+
+type PendingCall<'a> = (usize, http::Method, &'a reqwest::Url, &'a Option<String>);
+
+/// The response to a single call in a batch.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BatchPart {
+    pub status: http::StatusCode,
+    pub body: String,
+}
+
+/// The responses to the calls in a `Batch`.
+#[derive(Debug, Clone)]
+pub struct BatchResponses {
+    parts: Vec<BatchPart>,
+}
+
+impl BatchResponses {
+    /// The raw response to a call.
+    pub fn part<T>(&self, call: &BatchCall<T>) -> Option<&BatchPart> {
+        self.parts.get(call.index)
+    }
+
+    /// Parse the response to a call, or return its error.
+    pub fn get<T>(&self, call: &BatchCall<T>) -> Result<T>
+    where
+        T: serde::de::DeserializeOwned + 'static,
+    {
+        let part = self
+            .part(call)
+            .ok_or_else(|| anyhow!("call is not part of this batch"))?;
+
+        if part.status.is_success() {
+            let parsed_response = if part.status == http::StatusCode::NO_CONTENT
+                || part.body.trim().is_empty()
+                || std::any::TypeId::of::<T>() == std::any::TypeId::of::<()>()
+            {
+                serde_json::from_str("null")
+            } else {
+                serde_json::from_str::<T>(&part.body)
+            };
+            parsed_response.map_err(anyhow::Error::from)
+        } else {
+            Err(crate::utils::HttpError {
+                status: part.status,
+                body: part.body.clone(),
+            }
+            .into())
+        }
+    }
+}
+
+fn encode_batch(boundary: &str, calls: &[PendingCall<'_>]) -> String {
+    let mut body = String::new();
+    for (index, method, url, call_body) in calls {
+        let path = match url.query() {
+            Some(query) => format!("{}?{}", url.path(), query),
+            None => url.path().to_string(),
+        };
+
+        body.push_str(&format!(
+            "--{}\r\nContent-Type: application/http\r\nContent-ID: <{}>\r\n\r\n{} {} HTTP/1.1\r\n",
+            boundary, index, method, path
+        ));
+        match call_body {
+            Some(b) => body.push_str(&format!(
+                "Content-Type: application/json\r\nContent-Length: {}\r\n\r\n{}\r\n",
+                b.len(),
+                b
+            )),
+            None => body.push_str("\r\n"),
+        }
+    }
+    body.push_str(&format!("--{}--\r\n", boundary));
+
+    body
+}
+
+/// Split a `multipart/mixed` batch response into the `Content-ID` and response
+/// of each part.
+pub(crate) fn decode_batch(boundary: &str, body: &str) -> Result<Vec<(Option<String>, BatchPart)>> {
+    let delimiter = format!("--{}", boundary);
+
+    let mut parts = Vec::new();
+    for part in body.split(delimiter.as_str()).skip(1) {
+        if part.starts_with("--") {
+            break;
+        }
+
+        let (headers, response) = split_headers(part.trim_start_matches(&['\r', '\n'][..]));
+        let content_id = headers.lines().find_map(|l| {
+            let (name, value) = l.split_once(':')?;
+            if !name.trim().eq_ignore_ascii_case("content-id") {
+                return None;
+            }
+            let value = value.trim().trim_start_matches('<').trim_end_matches('>');
+            Some(value.trim_start_matches("response-").to_string())
+        });
+
+        let (status_line, response_body) = split_headers(response);
+        let status = status_line
+            .lines()
+            .next()
+            .and_then(|l| l.split_whitespace().nth(1))
+            .and_then(|s| s.parse::<http::StatusCode>().ok())
+            .ok_or_else(|| anyhow!("invalid response in batch: {:?}", response))?;
+
+        parts.push((
+            content_id,
+            BatchPart {
+                status,
+                body: response_body
+                    .trim_end_matches(&['\r', '\n'][..])
+                    .to_string(),
+            },
+        ));
+    }
+
+    Ok(parts)
+}
+
+/// Split a message at the blank line between its headers and body.
+fn split_headers(message: &str) -> (&str, &str) {
+    match (message.find("\r\n\r\n"), message.find("\n\n")) {
+        (Some(crlf), Some(lf)) if lf < crlf => (&message[..lf], &message[lf + 2..]),
+        (Some(crlf), _) => (&message[..crlf], &message[crlf + 4..]),
+        (None, Some(lf)) => (&message[..lf], &message[lf + 2..]),
+        (None, None) => (message, ""),
+    }
+}
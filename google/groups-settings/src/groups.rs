@@ -42,6 +42,39 @@ impl Groups {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Like `get`, but adds the call to a `Batch` to be sent with others, instead of sending it.
+     *
+     * This function performs a `GET` to the `/{groupUniqueId}` endpoint.
+     *
+     * Gets one resource by id.
+     *
+     * **Parameters:**
+     *
+     * * `group_unique_id: &str` -- Identifies whether members external to your organization can join the group. Possible values are:  
+     *  - true: G Suite users external to your organization can become members of this group.
+     *  - false: Users not belonging to the organization are not allowed to become members of this group.
+     */
+    pub fn get_batch(
+        &self,
+        batch: &mut crate::batch::Batch,
+        alt: crate::types::Alt,
+        group_unique_id: &str,
+    ) -> Result<crate::batch::BatchCall<crate::types::Groups>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !alt.to_string().is_empty() {
+            query_args.push(("alt".to_string(), alt.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/{}?{}",
+            crate::progenitor_support::encode_path(&group_unique_id.to_string()),
+            query_
+        );
+
+        Ok(batch.add(reqwest::Method::GET, &url, None))
+    }
+
     /**
      * This function performs a `PUT` to the `/{groupUniqueId}` endpoint.
      *
@@ -75,6 +108,44 @@ impl Groups {
             .await
     }
 
+    /**
+     * Like `update`, but adds the call to a `Batch` to be sent with others, instead of sending it.
+     *
+     * This function performs a `PUT` to the `/{groupUniqueId}` endpoint.
+     *
+     * Updates an existing resource.
+     *
+     * **Parameters:**
+     *
+     * * `group_unique_id: &str` -- Identifies whether members external to your organization can join the group. Possible values are:  
+     *  - true: G Suite users external to your organization can become members of this group.
+     *  - false: Users not belonging to the organization are not allowed to become members of this group.
+     */
+    pub fn update_batch(
+        &self,
+        batch: &mut crate::batch::Batch,
+        alt: crate::types::Alt,
+        group_unique_id: &str,
+        body: &crate::types::Groups,
+    ) -> Result<crate::batch::BatchCall<crate::types::Groups>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !alt.to_string().is_empty() {
+            query_args.push(("alt".to_string(), alt.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/{}?{}",
+            crate::progenitor_support::encode_path(&group_unique_id.to_string()),
+            query_
+        );
+
+        Ok(batch.add(
+            reqwest::Method::PUT,
+            &url,
+            Some(serde_json::to_string(body)?),
+        ))
+    }
+
     /**
      * This function performs a `PATCH` to the `/{groupUniqueId}` endpoint.
      *
@@ -107,4 +178,42 @@ impl Groups {
             .patch(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
             .await
     }
+
+    /**
+     * Like `patch`, but adds the call to a `Batch` to be sent with others, instead of sending it.
+     *
+     * This function performs a `PATCH` to the `/{groupUniqueId}` endpoint.
+     *
+     * Updates an existing resource. This method supports patch semantics.
+     *
+     * **Parameters:**
+     *
+     * * `group_unique_id: &str` -- Identifies whether members external to your organization can join the group. Possible values are:  
+     *  - true: G Suite users external to your organization can become members of this group.
+     *  - false: Users not belonging to the organization are not allowed to become members of this group.
+     */
+    pub fn patch_batch(
+        &self,
+        batch: &mut crate::batch::Batch,
+        alt: crate::types::Alt,
+        group_unique_id: &str,
+        body: &crate::types::Groups,
+    ) -> Result<crate::batch::BatchCall<crate::types::Groups>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !alt.to_string().is_empty() {
+            query_args.push(("alt".to_string(), alt.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/{}?{}",
+            crate::progenitor_support::encode_path(&group_unique_id.to_string()),
+            query_
+        );
+
+        Ok(batch.add(
+            reqwest::Method::PATCH,
+            &url,
+            Some(serde_json::to_string(body)?),
+        ))
+    }
 }
@@ -0,0 +1,383 @@
+//! Build `batchUpdate` requests for formatting and structure edits.
+//!
+//! Each method of `BatchUpdate` adds a single `Request`, with the field mask of
+//! update requests computed from the fields that are set. Requests can be named,
+//! to get their replies from the response by name.
+//!
+//! ```no_run
+//! # async fn run(client: sheets::Client) -> anyhow::Result<()> {
+//! use sheets::{
+//!     batch_update::BatchUpdate,
+//!     range::A1Range,
+//!     types::{BooleanConditionType, CellFormat},
+//! };
+//!
+//! let bold: CellFormat = serde_json::from_value(serde_json::json!({
+//!     "textFormat": {"bold": true},
+//! }))?;
+//! let red: CellFormat = serde_json::from_value(serde_json::json!({
+//!     "textFormat": {"foregroundColor": {"red": 1.0}},
+//! }))?;
+//!
+//! let replies = BatchUpdate::new()
+//!     .add_sheet_with_id("Config", 42)
+//!     .named("config")
+//!     .freeze(42, 1, 0)
+//!     .format_cells(42, &"A1:Z1".parse::<A1Range>()?, &bold)?
+//!     .set_column_widths(42, 0, 3, 200)
+//!     .add_conditional_format(
+//!         42,
+//!         &"C2:C".parse::<A1Range>()?,
+//!         BooleanConditionType::NumberLess,
+//!         &["0"],
+//!         &red,
+//!     )?
+//!     .send(&client.spreadsheets(), "spreadsheet-id")
+//!     .await?;
+//!
+//! let sheet = replies.get("config")?.add_sheet.as_ref();
+//! # Ok(())
+//! # }
+//! ```
+use std::collections::HashMap;
+
+use anyhow::{anyhow, Result};
+use serde_json::{json, Value};
+
+use crate::{
+    range::A1Range,
+    types::{
+        BooleanConditionType, CellData, CellFormat, MergeType, Request, Response, SheetProperties,
+    },
+};
+
+/// A set of requests to apply to a spreadsheet atomically.
+#[derive(Debug, Clone, Default)]
+pub struct BatchUpdate {
+    requests: Vec<Request>,
+    names: HashMap<String, usize>,
+}
+
+impl BatchUpdate {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// The number of requests.
+    pub fn len(&self) -> usize {
+        self.requests.len()
+    }
+
+    /// Returns if no requests have been added.
+    pub fn is_empty(&self) -> bool {
+        self.requests.is_empty()
+    }
+
+    /// Name the last request added, to get its reply with `BatchUpdateReplies::get`.
+    ///
+    /// Does nothing if no request has been added yet, so `get` with the name
+    /// returns an error.
+    pub fn named(mut self, name: &str) -> Self {
+        if let Some(index) = self.requests.len().checked_sub(1) {
+            self.names.insert(name.to_string(), index);
+        }
+        self
+    }
+
+    /// Add a request of any kind.
+    pub fn request(mut self, request: &Request) -> Self {
+        self.requests.push(request.clone());
+        self
+    }
+
+    /// Add a request built as JSON, which is easier to write than the generated
+    /// types with every field spelled out.
+    fn push(self, request: Value) -> Self {
+        let request: Request = serde_json::from_value(request)
+            .expect("requests built from typed arguments are valid");
+        self.request(&request)
+    }
+
+    /// Add a sheet at the end of the spreadsheet, with an id chosen by the API.
+    pub fn add_sheet(self, title: &str) -> Self {
+        self.push(json!({"addSheet": {"properties": {"title": title}}}))
+    }
+
+    /// Add a sheet with a known id, so later requests in the same batch can use it.
+    pub fn add_sheet_with_id(self, title: &str, sheet_id: i64) -> Self {
+        self.push(json!({
+            "addSheet": {"properties": {"title": title, "sheetId": sheet_id}}
+        }))
+    }
+
+    pub fn delete_sheet(self, sheet_id: i64) -> Self {
+        self.push(json!({"deleteSheet": {"sheetId": sheet_id}}))
+    }
+
+    /// Update the properties of a sheet. Only the fields that are set are updated,
+    /// as `field_mask` finds them, and `properties.sheet_id` is ignored.
+    pub fn update_sheet_properties(
+        self,
+        sheet_id: i64,
+        properties: &SheetProperties,
+    ) -> Result<Self> {
+        let mut properties = serde_json::to_value(properties)?;
+        if let Some(p) = properties.as_object_mut() {
+            p.remove("sheetId");
+        }
+        let fields = field_mask(&properties);
+        properties["sheetId"] = json!(sheet_id);
+        Ok(self.push(json!({
+            "updateSheetProperties": {"properties": properties, "fields": fields}
+        })))
+    }
+
+    pub fn rename_sheet(self, sheet_id: i64, title: &str) -> Self {
+        self.push(json!({
+            "updateSheetProperties": {
+                "properties": {"sheetId": sheet_id, "title": title},
+                "fields": "title",
+            }
+        }))
+    }
+
+    /// Freeze the first rows and columns of a sheet. Zero unfreezes them.
+    pub fn freeze(self, sheet_id: i64, rows: i64, columns: i64) -> Self {
+        self.push(json!({
+            "updateSheetProperties": {
+                "properties": {
+                    "sheetId": sheet_id,
+                    "gridProperties": {"frozenRowCount": rows, "frozenColumnCount": columns},
+                },
+                "fields": "gridProperties.frozenRowCount,gridProperties.frozenColumnCount",
+            }
+        }))
+    }
+
+    /// Set the width in pixels of the columns from `start` up to `end`, zero-based.
+    pub fn set_column_widths(self, sheet_id: i64, start: i64, end: i64, pixels: i64) -> Self {
+        self.set_dimension_size(sheet_id, "COLUMNS", start, end, pixels)
+    }
+
+    /// Set the height in pixels of the rows from `start` up to `end`, zero-based.
+    pub fn set_row_heights(self, sheet_id: i64, start: i64, end: i64, pixels: i64) -> Self {
+        self.set_dimension_size(sheet_id, "ROWS", start, end, pixels)
+    }
+
+    fn set_dimension_size(
+        self,
+        sheet_id: i64,
+        dimension: &str,
+        start: i64,
+        end: i64,
+        pixels: i64,
+    ) -> Self {
+        self.push(json!({
+            "updateDimensionProperties": {
+                "range": dimension_range(sheet_id, dimension, start, end),
+                "properties": {"pixelSize": pixels},
+                "fields": "pixelSize",
+            }
+        }))
+    }
+
+    /// Fit the width of the columns from `start` up to `end` to their contents.
+    pub fn auto_resize_columns(self, sheet_id: i64, start: i64, end: i64) -> Self {
+        self.push(json!({
+            "autoResizeDimensions": {
+                "dimensions": dimension_range(sheet_id, "COLUMNS", start, end),
+            }
+        }))
+    }
+
+    /// Fit the height of the rows from `start` up to `end` to their contents.
+    pub fn auto_resize_rows(self, sheet_id: i64, start: i64, end: i64) -> Self {
+        self.push(json!({
+            "autoResizeDimensions": {
+                "dimensions": dimension_range(sheet_id, "ROWS", start, end),
+            }
+        }))
+    }
+
+    /// Set the format of every cell in a range. Only the fields that are set are
+    /// changed, as `field_mask` finds them.
+    pub fn format_cells(self, sheet_id: i64, range: &A1Range, format: &CellFormat) -> Result<Self> {
+        let cell = json!({ "userEnteredFormat": serde_json::to_value(format)? });
+        Ok(self.push_repeat_cell(sheet_id, range, cell))
+    }
+
+    /// Set every cell in a range to `cell`. Only the fields that are set are
+    /// changed, as `field_mask` finds them.
+    pub fn repeat_cell(self, sheet_id: i64, range: &A1Range, cell: &CellData) -> Result<Self> {
+        let cell = serde_json::to_value(cell)?;
+        Ok(self.push_repeat_cell(sheet_id, range, cell))
+    }
+
+    fn push_repeat_cell(self, sheet_id: i64, range: &A1Range, cell: Value) -> Self {
+        let fields = field_mask(&cell);
+        self.push(json!({
+            "repeatCell": {
+                "range": range.to_grid_range(sheet_id),
+                "cell": cell,
+                "fields": fields,
+            }
+        }))
+    }
+
+    pub fn merge_cells(self, sheet_id: i64, range: &A1Range, merge_type: MergeType) -> Self {
+        self.push(json!({
+            "mergeCells": {"range": range.to_grid_range(sheet_id), "mergeType": merge_type}
+        }))
+    }
+
+    pub fn unmerge_cells(self, sheet_id: i64, range: &A1Range) -> Self {
+        self.push(json!({"unmergeCells": {"range": range.to_grid_range(sheet_id)}}))
+    }
+
+    /// Format the cells in a range that meet a condition. The rule is added before
+    /// the existing ones.
+    pub fn add_conditional_format(
+        self,
+        sheet_id: i64,
+        range: &A1Range,
+        condition: BooleanConditionType,
+        values: &[&str],
+        format: &CellFormat,
+    ) -> Result<Self> {
+        let values: Vec<Value> = values
+            .iter()
+            .map(|v| json!({ "userEnteredValue": v }))
+            .collect();
+        Ok(self.push(json!({
+            "addConditionalFormatRule": {
+                "index": 0,
+                "rule": {
+                    "ranges": [range.to_grid_range(sheet_id)],
+                    "booleanRule": {
+                        "condition": {"type": condition, "values": values},
+                        "format": serde_json::to_value(format)?,
+                    },
+                },
+            }
+        })))
+    }
+
+    pub fn add_named_range(self, name: &str, sheet_id: i64, range: &A1Range) -> Self {
+        self.push(json!({
+            "addNamedRange": {
+                "namedRange": {"name": name, "range": range.to_grid_range(sheet_id)}
+            }
+        }))
+    }
+
+    /// The requests, to send with `Spreadsheets::batch_update` directly.
+    pub fn requests(&self) -> &[Request] {
+        &self.requests
+    }
+
+    /// Apply the requests to a spreadsheet.
+    pub async fn send(
+        self,
+        spreadsheets: &crate::spreadsheets::Spreadsheets,
+        spreadsheet_id: &str,
+    ) -> Result<BatchUpdateReplies> {
+        let response = spreadsheets
+            .batch_update(
+                spreadsheet_id,
+                &crate::types::BatchUpdateSpreadsheetRequest {
+                    include_spreadsheet_in_response: None,
+                    requests: self.requests,
+                    response_include_grid_data: None,
+                    response_ranges: Default::default(),
+                },
+            )
+            .await?;
+
+        Ok(BatchUpdateReplies {
+            replies: response.replies,
+            names: self.names,
+        })
+    }
+}
+
+/// The replies to a `BatchUpdate`, one for each request in order.
+#[derive(Debug, Clone)]
+pub struct BatchUpdateReplies {
+    pub replies: Vec<Response>,
+    names: HashMap<String, usize>,
+}
+
+impl BatchUpdateReplies {
+    /// The reply to a named request.
+    pub fn get(&self, name: &str) -> Result<&Response> {
+        let index = self
+            .names
+            .get(name)
+            .ok_or_else(|| anyhow!("no request is named {:?}", name))?;
+        self.replies
+            .get(*index)
+            .ok_or_else(|| anyhow!("no reply to request {:?}", name))
+    }
+}
+
+impl A1Range {
+    /// The range as a `GridRange` on the sheet with id `sheet_id`. Open ends of the
+    /// range are left unbounded.
+    pub fn to_grid_range(&self, sheet_id: i64) -> crate::types::GridRange {
+        let end = self.end.unwrap_or(self.start);
+        crate::types::GridRange {
+            sheet_id,
+            start_column_index: self.start.column.unwrap_or(0) as i64,
+            start_row_index: self.start.row.unwrap_or(0) as i64,
+            // Zero isn't serialized, which leaves the end unbounded.
+            end_column_index: end.column.map(|c| c as i64 + 1).unwrap_or(0),
+            end_row_index: end.row.map(|r| r as i64 + 1).unwrap_or(0),
+        }
+    }
+}
+
+fn dimension_range(sheet_id: i64, dimension: &str, start: i64, end: i64) -> Value {
+    json!({
+        "sheetId": sheet_id,
+        "dimension": dimension,
+        "startIndex": start,
+        "endIndex": end,
+    })
+}
+
+/// The field mask for the fields set in a serialized object: the paths to each of
+/// its leaves, like `textFormat.bold,backgroundColor`.
+///
+/// The generated types leave out zeros and empty strings, but always serialize
+/// their booleans, so `false` leaves are left out too. Send a `Request` with its own
+/// mask to clear a field.
+pub fn field_mask(value: &Value) -> String {
+    fn leaves(value: &Value, prefix: &str, paths: &mut Vec<String>) {
+        match value.as_object() {
+            Some(object) if !object.is_empty() => {
+                for (key, value) in object {
+                    if value == &Value::Bool(false) {
+                        continue;
+                    }
+                    let path = if prefix.is_empty() {
+                        key.to_string()
+                    } else {
+                        format!("{}.{}", prefix, key)
+                    };
+                    // Colors are set as a whole.
+                    if key.ends_with("Color") || key.ends_with("ColorStyle") {
+                        paths.push(path);
+                    } else {
+                        leaves(value, &path, paths);
+                    }
+                }
+            }
+            _ if !prefix.is_empty() => paths.push(prefix.to_string()),
+            _ => {}
+        }
+    }
+
+    let mut paths = Vec::new();
+    leaves(value, "", &mut paths);
+    paths.join(",")
+}
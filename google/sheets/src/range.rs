@@ -0,0 +1,414 @@
+//! Typed A1 and R1C1 ranges.
+//!
+//! The values functions take ranges as strings like `'My Sheet'!A2:D`. `A1Range`
+//! builds and parses them, quoting sheet names and converting between column
+//! letters and indexes.
+//!
+//! ```
+//! use sheets::range::A1Range;
+//!
+//! let range: A1Range = "'Q1 Budget'!B2:D".parse().unwrap();
+//! assert_eq!(range.sheet.as_deref(), Some("Q1 Budget"));
+//! assert_eq!(range.start.column, Some(1));
+//! assert_eq!(range.end.unwrap().row, None);
+//!
+//! let range = A1Range::cells("Config", 0, 0, 3, 9);
+//! assert_eq!(range.to_string(), "Config!A1:D10");
+//! ```
+use std::{fmt, str::FromStr};
+
+use anyhow::{anyhow, bail, Result};
+
+/// The zero-based index of the last column a sheet can have, `ZZZ`.
+pub const LAST_COLUMN: u32 = 18277;
+
+/// A corner of a range. Indexes are zero-based; a `None` index leaves the range
+/// open in that direction, like the row of `A:A` or the column of `1:1`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CellRef {
+    pub column: Option<u32>,
+    pub row: Option<u32>,
+}
+
+impl CellRef {
+    /// A single cell.
+    pub fn new(column: u32, row: u32) -> Self {
+        CellRef {
+            column: Some(column),
+            row: Some(row),
+        }
+    }
+
+    fn parse_a1(s: &str) -> Result<Self> {
+        let s = s.replace('$', "");
+        let letters = s.chars().take_while(|c| c.is_ascii_alphabetic()).count();
+        let (column, row) = s.split_at(letters);
+        if column.is_empty() && row.is_empty() {
+            bail!("invalid cell reference: {:?}", s);
+        }
+
+        Ok(CellRef {
+            column: if column.is_empty() {
+                None
+            } else {
+                Some(column_index(column)?)
+            },
+            row: if row.is_empty() {
+                None
+            } else {
+                match row.parse::<u32>() {
+                    Ok(r) if r > 0 => Some(r - 1),
+                    _ => bail!("invalid cell reference: {:?}", s),
+                }
+            },
+        })
+    }
+
+    fn parse_r1c1(s: &str) -> Result<Self> {
+        let upper = s.to_uppercase();
+        let number = |part: &str| -> Result<u32> {
+            match part.parse::<u32>() {
+                Ok(n) if n > 0 => Ok(n - 1),
+                _ => bail!("invalid R1C1 reference: {:?}", s),
+            }
+        };
+
+        let (row, column) = match (upper.strip_prefix('R'), upper.find('C')) {
+            (Some(_), Some(c)) => (Some(number(&upper[1..c])?), Some(number(&upper[c + 1..])?)),
+            (Some(rest), None) => (Some(number(rest)?), None),
+            (None, Some(0)) => (None, Some(number(&upper[1..])?)),
+            _ => bail!("invalid R1C1 reference: {:?}", s),
+        };
+
+        Ok(CellRef { column, row })
+    }
+}
+
+impl fmt::Display for CellRef {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if let Some(column) = self.column {
+            write!(f, "{}", column_name(column))?;
+        }
+        if let Some(row) = self.row {
+            write!(f, "{}", row + 1)?;
+        }
+        Ok(())
+    }
+}
+
+/// A range of cells, optionally on a named sheet.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct A1Range {
+    pub sheet: Option<String>,
+    /// The first cell; both indexes are `None` for a whole sheet.
+    pub start: CellRef,
+    /// The last cell, which is the same as the first if `None`.
+    pub end: Option<CellRef>,
+}
+
+impl A1Range {
+    /// All the cells of a sheet.
+    pub fn sheet<S>(sheet: S) -> Self
+    where
+        S: ToString,
+    {
+        A1Range {
+            sheet: Some(sheet.to_string()),
+            ..Default::default()
+        }
+    }
+
+    /// A rectangle of cells, from the zero-based column and row of its top left
+    /// corner to those of its bottom right corner.
+    pub fn cells<S>(
+        sheet: S,
+        start_column: u32,
+        start_row: u32,
+        end_column: u32,
+        end_row: u32,
+    ) -> Self
+    where
+        S: ToString,
+    {
+        A1Range {
+            sheet: Some(sheet.to_string()),
+            start: CellRef::new(start_column, start_row),
+            end: Some(CellRef::new(end_column, end_row)),
+        }
+    }
+
+    /// The columns from `start_column`, starting at `start_row` and open-ended
+    /// downwards, like `A2:D`.
+    pub fn columns_from<S>(sheet: S, start_column: u32, end_column: u32, start_row: u32) -> Self
+    where
+        S: ToString,
+    {
+        A1Range {
+            sheet: Some(sheet.to_string()),
+            start: CellRef::new(start_column, start_row),
+            end: Some(CellRef {
+                column: Some(end_column),
+                row: None,
+            }),
+        }
+    }
+
+    /// The same cells on another sheet.
+    pub fn with_sheet<S>(mut self, sheet: S) -> Self
+    where
+        S: ToString,
+    {
+        self.sheet = Some(sheet.to_string());
+        self
+    }
+
+    /// Returns if the range is a whole sheet.
+    pub fn is_whole_sheet(&self) -> bool {
+        self.start == CellRef::default() && self.end.is_none()
+    }
+
+    /// The number of columns, if the range is bounded horizontally.
+    pub fn width(&self) -> Option<u32> {
+        let end = self.end.unwrap_or(self.start);
+        Some(end.column?.checked_sub(self.start.column?)? + 1)
+    }
+
+    /// The number of rows, if the range is bounded vertically.
+    pub fn height(&self) -> Option<u32> {
+        let end = self.end.unwrap_or(self.start);
+        Some(end.row?.checked_sub(self.start.row?)? + 1)
+    }
+
+    /// The first row of the range, like the header row of a table.
+    pub fn first_row(&self) -> A1Range {
+        let row = self.start.row.unwrap_or(0);
+        let end = self.end.unwrap_or(self.start);
+        A1Range {
+            sheet: self.sheet.clone(),
+            start: CellRef {
+                column: self.start.column,
+                row: Some(row),
+            },
+            end: Some(CellRef {
+                column: end.column,
+                row: Some(row),
+            }),
+        }
+    }
+
+    /// The range without its first row, like the data rows of a table.
+    pub fn without_first_row(&self) -> A1Range {
+        let end = self.end.unwrap_or(self.start);
+        // Rows open downwards need columns, since `2:` is not a range; a range
+        // open in both directions, like a whole sheet, becomes `A2:ZZZ`.
+        let (start_column, end_column) =
+            if self.start.column.is_none() && end.column.is_none() && end.row.is_none() {
+                (Some(0), Some(LAST_COLUMN))
+            } else {
+                (self.start.column, end.column)
+            };
+
+        A1Range {
+            sheet: self.sheet.clone(),
+            start: CellRef {
+                column: start_column,
+                row: Some(self.start.row.unwrap_or(0) + 1),
+            },
+            end: Some(CellRef {
+                column: end_column,
+                row: end.row,
+            }),
+        }
+    }
+
+    /// Parse a range in R1C1 notation, like `Sheet1!R1C1:R10C4`. Only absolute
+    /// references are supported.
+    pub fn parse_r1c1(s: &str) -> Result<Self> {
+        let parse_cells = |cells: &str| -> Result<(CellRef, Option<CellRef>)> {
+            match cells.split_once(':') {
+                Some((start, end)) => {
+                    Ok((CellRef::parse_r1c1(start)?, Some(CellRef::parse_r1c1(end)?)))
+                }
+                None => Ok((CellRef::parse_r1c1(cells)?, None)),
+            }
+        };
+
+        let (sheet, cells) = split_sheet(s)?;
+        let (sheet, (start, end)) = match (sheet, cells) {
+            (sheet, Some(cells)) => (sheet, parse_cells(cells)?),
+            // Cells without a sheet look like a sheet name to `split_sheet`.
+            (Some(sheet), None) if !s.trim().starts_with('\'') => match parse_cells(&sheet) {
+                Ok(cells) => (None, cells),
+                Err(_) => (Some(sheet), (CellRef::default(), None)),
+            },
+            (sheet, None) => (sheet, (CellRef::default(), None)),
+        };
+
+        Ok(A1Range { sheet, start, end })
+    }
+
+    /// Format the range in R1C1 notation.
+    pub fn to_r1c1(&self) -> String {
+        let cell = |c: &CellRef| {
+            let mut s = String::new();
+            if let Some(row) = c.row {
+                s.push_str(&format!("R{}", row + 1));
+            }
+            if let Some(column) = c.column {
+                s.push_str(&format!("C{}", column + 1));
+            }
+            s
+        };
+
+        let mut s = self
+            .sheet
+            .as_deref()
+            .map(quote_sheet_name)
+            .unwrap_or_default();
+        if !self.is_whole_sheet() {
+            if self.sheet.is_some() {
+                s.push('!');
+            }
+            s.push_str(&cell(&self.start));
+            if let Some(end) = &self.end {
+                s.push(':');
+                s.push_str(&cell(end));
+            }
+        }
+        s
+    }
+}
+
+impl fmt::Display for A1Range {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if let Some(sheet) = &self.sheet {
+            write!(f, "{}", quote_sheet_name(sheet))?;
+            if self.is_whole_sheet() {
+                return Ok(());
+            }
+            write!(f, "!")?;
+        }
+        write!(f, "{}", self.start)?;
+        if let Some(end) = &self.end {
+            write!(f, ":{}", end)?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for A1Range {
+    type Err = anyhow::Error;
+
+    /// Parse a range in A1 notation, like `Sheet1!A1:B2`, `'My Sheet'!A:A`, `2:5`,
+    /// `A5:C` or just a sheet name.
+    fn from_str(s: &str) -> Result<Self> {
+        let (sheet, cells) = split_sheet(s)?;
+        let (start, end) = match cells {
+            None => (CellRef::default(), None),
+            Some(cells) => match cells.split_once(':') {
+                Some((start, end)) => (CellRef::parse_a1(start)?, Some(CellRef::parse_a1(end)?)),
+                None => (CellRef::parse_a1(cells)?, None),
+            },
+        };
+
+        Ok(A1Range { sheet, start, end })
+    }
+}
+
+/// Split a range into its sheet name and cells. A range without a `!` is taken to
+/// be cells if it looks like them, and a sheet name otherwise.
+fn split_sheet(s: &str) -> Result<(Option<String>, Option<&str>)> {
+    let s = s.trim();
+    if let Some(quoted) = s.strip_prefix('\'') {
+        // Quotes in quoted names are escaped by doubling them.
+        let mut name = String::new();
+        let mut chars = quoted.char_indices().peekable();
+        while let Some((i, c)) = chars.next() {
+            if c != '\'' {
+                name.push(c);
+                continue;
+            }
+            if let Some((_, '\'')) = chars.peek() {
+                chars.next();
+                name.push('\'');
+                continue;
+            }
+
+            let rest = &quoted[i + 1..];
+            return match rest.strip_prefix('!') {
+                Some(cells) => Ok((Some(name), Some(cells))),
+                None if rest.is_empty() => Ok((Some(name), None)),
+                None => Err(anyhow!("invalid range: {:?}", s)),
+            };
+        }
+        bail!("unterminated sheet name in range: {:?}", s);
+    }
+
+    match s.rsplit_once('!') {
+        Some((sheet, cells)) => Ok((Some(sheet.to_string()), Some(cells))),
+        None if looks_like_cells(s) => Ok((None, Some(s))),
+        None => Ok((Some(s.to_string()), None)),
+    }
+}
+
+fn looks_like_cells(s: &str) -> bool {
+    let parts: Vec<String> = s.split(':').map(|p| p.replace('$', "")).collect();
+    parts.len() <= 2
+        && parts.iter().all(|part| {
+            let letters = part.chars().take_while(|c| c.is_ascii_alphabetic()).count();
+            let digits = part.len() - letters;
+            // A single cell needs both a column and a row, but either end of a range
+            // can leave one out, like `A:A` or `1:1`.
+            letters <= 3
+                && part[letters..].chars().all(|c| c.is_ascii_digit())
+                && if parts.len() == 1 {
+                    letters > 0 && digits > 0
+                } else {
+                    !part.is_empty()
+                }
+        })
+}
+
+/// Quote a sheet name if it needs it, escaping any quotes.
+pub fn quote_sheet_name(name: &str) -> String {
+    let plain = !name.is_empty()
+        && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+        && !name.starts_with(|c: char| c.is_ascii_digit())
+        && !looks_like_cells(name)
+        && CellRef::parse_r1c1(name).is_err();
+    if plain {
+        name.to_string()
+    } else {
+        format!("'{}'", name.replace('\'', "''"))
+    }
+}
+
+/// The zero-based index of a column from its letters, so `A` is 0 and `AA` is 26.
+pub fn column_index(letters: &str) -> Result<u32> {
+    if letters.is_empty() || !letters.chars().all(|c| c.is_ascii_alphabetic()) {
+        bail!("invalid column: {:?}", letters);
+    }
+
+    let mut index: u32 = 0;
+    for c in letters.to_ascii_uppercase().bytes() {
+        index = index
+            .checked_mul(26)
+            .and_then(|i| i.checked_add((c - b'A') as u32 + 1))
+            .ok_or_else(|| anyhow!("invalid column: {:?}", letters))?;
+    }
+
+    Ok(index - 1)
+}
+
+/// The letters of a column from its zero-based index, so 0 is `A` and 26 is `AA`.
+pub fn column_name(index: u32) -> String {
+    let mut n = index as u64 + 1;
+    let mut letters = Vec::new();
+    while n > 0 {
+        let rem = ((n - 1) % 26) as u8;
+        letters.push((b'A' + rem) as char);
+        n = (n - 1) / 26;
+    }
+
+    letters.iter().rev().collect()
+}
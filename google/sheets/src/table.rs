@@ -0,0 +1,286 @@
+//! Map the rows of a table to and from structs.
+//!
+//! A table is a header row followed by a row per record. Each header cell names the
+//! struct field its column maps to, so `#[serde(rename = "...")]` can be used for
+//! headers that aren't valid field names. Cells are strings, and are parsed into
+//! numbers, booleans and `Option`s (empty cells are `None`) as the fields need.
+//!
+//! ```
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Debug, PartialEq, Deserialize, Serialize)]
+//! struct Flag {
+//!     name: String,
+//!     enabled: bool,
+//!     #[serde(rename = "Rollout %")]
+//!     rollout: Option<u8>,
+//! }
+//!
+//! let values = vec![
+//!     vec!["name".to_string(), "enabled".to_string(), "Rollout %".to_string()],
+//!     vec!["dark-mode".to_string(), "TRUE".to_string(), "".to_string()],
+//! ];
+//! let flags: Vec<Flag> = sheets::table::from_values(&values).unwrap();
+//! assert_eq!(flags[0].rollout, None);
+//!
+//! let header = sheets::table::header(&flags).unwrap();
+//! assert_eq!(sheets::table::to_rows(&flags, &header).unwrap()[0], values[1]);
+//! ```
+use anyhow::{anyhow, bail, Result};
+use serde::{
+    de::{self, value::MapDeserializer, IntoDeserializer},
+    forward_to_deserialize_any, Deserialize, Serialize,
+};
+
+/// Deserialize the rows of a table, where the first row is the header, skipping
+/// empty rows and columns with an empty header.
+pub fn from_values<T>(values: &[Vec<String>]) -> Result<Vec<T>>
+where
+    T: de::DeserializeOwned,
+{
+    let (header, rows) = match values.split_first() {
+        Some(split) => split,
+        None => return Ok(Vec::new()),
+    };
+
+    let mut records = Vec::with_capacity(rows.len());
+    for (i, row) in rows.iter().enumerate() {
+        if row.iter().all(|c| c.trim().is_empty()) {
+            continue;
+        }
+
+        // Trailing empty cells are left out of rows, so fill them in.
+        let cells = header
+            .iter()
+            .enumerate()
+            .filter(|(_, name)| !name.trim().is_empty())
+            .map(|(j, name)| {
+                (
+                    name.trim().to_string(),
+                    Cell(row.get(j).cloned().unwrap_or_default()),
+                )
+            });
+        let record = T::deserialize(MapDeserializer::<_, de::value::Error>::new(cells))
+            // Rows are numbered like the spreadsheet, after the header.
+            .map_err(|e| anyhow!("invalid table row {}: {}", i + 2, e))?;
+        records.push(record);
+    }
+
+    Ok(records)
+}
+
+/// The header of a table of `records`: the names of their fields, in order.
+///
+/// Records can leave fields out, like those skipped when `None`, so the header has
+/// the fields of every record. A field missing from the records before it goes
+/// after the field it follows.
+pub fn header<T>(records: &[T]) -> Result<Vec<String>>
+where
+    T: Serialize,
+{
+    let mut header: Vec<String> = Vec::new();
+    for record in records {
+        let mut next = 0;
+        for (name, _) in fields(record)? {
+            next = match header.iter().position(|h| *h == name) {
+                Some(i) => i + 1,
+                None => {
+                    header.insert(next, name);
+                    next + 1
+                }
+            };
+        }
+    }
+
+    Ok(header)
+}
+
+/// Serialize records into rows, with their fields in the order of the columns in
+/// `header`. Columns without a field are left empty.
+pub fn to_rows<T>(records: &[T], header: &[String]) -> Result<Vec<Vec<String>>>
+where
+    T: Serialize,
+{
+    let mut rows = Vec::with_capacity(records.len());
+    for record in records {
+        let mut row = vec![String::new(); header.len()];
+        for (name, value) in fields(record)? {
+            let column = header
+                .iter()
+                .position(|h| h.trim() == name)
+                .ok_or_else(|| anyhow!("field {:?} is not a column of the table", name))?;
+            row[column] = cell_value(value);
+        }
+        rows.push(row);
+    }
+
+    Ok(rows)
+}
+
+/// The fields of a serialized record in order, which a `serde_json::Value` would
+/// sort.
+fn fields<T>(record: &T) -> Result<Vec<(String, serde_json::Value)>>
+where
+    T: Serialize,
+{
+    struct Fields(Vec<(String, serde_json::Value)>);
+
+    impl<'de> Deserialize<'de> for Fields {
+        fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+        where
+            D: de::Deserializer<'de>,
+        {
+            struct FieldsVisitor;
+
+            impl<'de> de::Visitor<'de> for FieldsVisitor {
+                type Value = Fields;
+
+                fn expecting(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                    write!(f, "a struct or map")
+                }
+
+                fn visit_map<A>(self, mut map: A) -> std::result::Result<Fields, A::Error>
+                where
+                    A: de::MapAccess<'de>,
+                {
+                    let mut fields = Vec::new();
+                    while let Some(field) = map.next_entry()? {
+                        fields.push(field);
+                    }
+                    Ok(Fields(fields))
+                }
+            }
+
+            deserializer.deserialize_map(FieldsVisitor)
+        }
+    }
+
+    let json = serde_json::to_string(record)?;
+    match serde_json::from_str::<Fields>(&json) {
+        Ok(fields) => Ok(fields.0),
+        Err(_) => bail!("table records must serialize to a struct or map: {}", json),
+    }
+}
+
+fn cell_value(value: serde_json::Value) -> String {
+    match value {
+        serde_json::Value::Null => String::new(),
+        serde_json::Value::Bool(true) => "TRUE".to_string(),
+        serde_json::Value::Bool(false) => "FALSE".to_string(),
+        serde_json::Value::Number(n) => n.to_string(),
+        serde_json::Value::String(s) => s,
+        v => v.to_string(),
+    }
+}
+
+/// A cell, deserialized as whatever type the field needs.
+struct Cell(String);
+
+impl<'de> IntoDeserializer<'de, de::value::Error> for Cell {
+    type Deserializer = Self;
+
+    fn into_deserializer(self) -> Self {
+        self
+    }
+}
+
+macro_rules! deserialize_parsed {
+    ($($method:ident => $visit:ident,)*) => {
+        $(
+            fn $method<V>(self, visitor: V) -> std::result::Result<V::Value, Self::Error>
+            where
+                V: de::Visitor<'de>,
+            {
+                let s = self.0.trim();
+                match s.parse() {
+                    Ok(v) => visitor.$visit(v),
+                    Err(_) => Err(de::Error::invalid_value(de::Unexpected::Str(s), &visitor)),
+                }
+            }
+        )*
+    };
+}
+
+impl<'de> de::Deserializer<'de> for Cell {
+    type Error = de::value::Error;
+
+    fn deserialize_any<V>(self, visitor: V) -> std::result::Result<V::Value, Self::Error>
+    where
+        V: de::Visitor<'de>,
+    {
+        visitor.visit_string(self.0)
+    }
+
+    fn deserialize_bool<V>(self, visitor: V) -> std::result::Result<V::Value, Self::Error>
+    where
+        V: de::Visitor<'de>,
+    {
+        match self.0.trim().to_lowercase().as_str() {
+            "true" => visitor.visit_bool(true),
+            "false" => visitor.visit_bool(false),
+            _ => Err(de::Error::invalid_value(
+                de::Unexpected::Str(&self.0),
+                &visitor,
+            )),
+        }
+    }
+
+    deserialize_parsed! {
+        deserialize_i8 => visit_i8,
+        deserialize_i16 => visit_i16,
+        deserialize_i32 => visit_i32,
+        deserialize_i64 => visit_i64,
+        deserialize_u8 => visit_u8,
+        deserialize_u16 => visit_u16,
+        deserialize_u32 => visit_u32,
+        deserialize_u64 => visit_u64,
+        deserialize_f32 => visit_f32,
+        deserialize_f64 => visit_f64,
+    }
+
+    fn deserialize_option<V>(self, visitor: V) -> std::result::Result<V::Value, Self::Error>
+    where
+        V: de::Visitor<'de>,
+    {
+        if self.0.trim().is_empty() {
+            visitor.visit_none()
+        } else {
+            visitor.visit_some(self)
+        }
+    }
+
+    fn deserialize_unit<V>(self, visitor: V) -> std::result::Result<V::Value, Self::Error>
+    where
+        V: de::Visitor<'de>,
+    {
+        visitor.visit_unit()
+    }
+
+    fn deserialize_newtype_struct<V>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> std::result::Result<V::Value, Self::Error>
+    where
+        V: de::Visitor<'de>,
+    {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_enum<V>(
+        self,
+        _name: &'static str,
+        _variants: &'static [&'static str],
+        visitor: V,
+    ) -> std::result::Result<V::Value, Self::Error>
+    where
+        V: de::Visitor<'de>,
+    {
+        visitor.visit_enum(self.0.trim().to_string().into_deserializer())
+    }
+
+    forward_to_deserialize_any! {
+        char str string bytes byte_buf unit_struct seq tuple tuple_struct map struct
+        identifier ignored_any
+    }
+}
@@ -1 +1,214 @@
+#[test]
+fn test_a1_range() {
+    use crate::range::{column_index, column_name, A1Range, CellRef};
 
+    assert_eq!(column_index("A").unwrap(), 0);
+    assert_eq!(column_index("az").unwrap(), 51);
+    assert_eq!(column_name(702), "AAA");
+
+    let range: A1Range = "'Bob''s Sheet'!B2:D".parse().unwrap();
+    assert_eq!(range.sheet.as_deref(), Some("Bob's Sheet"));
+    assert_eq!(range.start, CellRef::new(1, 1));
+    assert_eq!(
+        range.end,
+        Some(CellRef {
+            column: Some(3),
+            row: None
+        })
+    );
+    assert_eq!(range.to_string(), "'Bob''s Sheet'!B2:D");
+    assert_eq!(range.first_row().to_string(), "'Bob''s Sheet'!B2:D2");
+    assert_eq!(range.without_first_row().to_string(), "'Bob''s Sheet'!B3:D");
+    assert_eq!(
+        A1Range::sheet("Config").without_first_row().to_string(),
+        "Config!A2:ZZZ"
+    );
+
+    assert_eq!(
+        "Config".parse::<A1Range>().unwrap(),
+        A1Range::sheet("Config")
+    );
+    assert_eq!(A1Range::sheet("A1").to_string(), "'A1'");
+    assert_eq!("2:5".parse::<A1Range>().unwrap().height(), Some(4));
+
+    let range = A1Range::parse_r1c1("R1C1:R10C4").unwrap();
+    assert_eq!(range.sheet, None);
+    assert_eq!(range.end, Some(CellRef::new(3, 9)));
+    assert_eq!(range.to_string(), "A1:D10");
+    assert_eq!(
+        A1Range::cells("Data", 0, 0, 3, 9).to_r1c1(),
+        "Data!R1C1:R10C4"
+    );
+}
+
+#[test]
+fn test_table_rows() {
+    #[derive(Debug, PartialEq, serde::Deserialize, serde::Serialize)]
+    struct Host {
+        name: String,
+        port: u16,
+        #[serde(rename = "TLS")]
+        tls: Option<bool>,
+    }
+
+    let values: Vec<Vec<String>> = vec![
+        vec!["name", "TLS", "port", "notes"],
+        vec!["db", "TRUE", "5432"],
+        vec![],
+        vec!["cache", "", "6379", "local only"],
+    ]
+    .into_iter()
+    .map(|r| r.into_iter().map(|c| c.to_string()).collect())
+    .collect();
+
+    let hosts: Vec<Host> = crate::table::from_values(&values).unwrap();
+    assert_eq!(
+        hosts,
+        vec![
+            Host {
+                name: "db".to_string(),
+                port: 5432,
+                tls: Some(true),
+            },
+            Host {
+                name: "cache".to_string(),
+                port: 6379,
+                tls: None,
+            },
+        ]
+    );
+
+    assert_eq!(
+        crate::table::header(&hosts).unwrap(),
+        vec!["name", "port", "TLS"]
+    );
+    assert_eq!(
+        crate::table::to_rows(&hosts, &values[0]).unwrap(),
+        vec![
+            vec!["db", "TRUE", "5432", ""],
+            vec!["cache", "", "6379", ""]
+        ]
+    );
+
+    let err = crate::table::from_values::<Host>(&[
+        values[0].clone(),
+        vec!["web".to_string(), "".to_string(), "http".to_string()],
+    ])
+    .unwrap_err();
+    assert!(err.to_string().contains("row 2"));
+}
+
+#[test]
+fn test_table_header_union() {
+    #[derive(serde::Serialize)]
+    struct Host {
+        name: String,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        region: Option<String>,
+        port: u16,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        notes: Option<String>,
+    }
+
+    let hosts = vec![
+        Host {
+            name: "db".to_string(),
+            region: None,
+            port: 5432,
+            notes: None,
+        },
+        Host {
+            name: "cache".to_string(),
+            region: None,
+            port: 6379,
+            notes: Some("local only".to_string()),
+        },
+        Host {
+            name: "web".to_string(),
+            region: Some("us-east".to_string()),
+            port: 443,
+            notes: None,
+        },
+    ];
+
+    let header = crate::table::header(&hosts).unwrap();
+    assert_eq!(header, vec!["name", "region", "port", "notes"]);
+    assert_eq!(
+        crate::table::to_rows(&hosts, &header).unwrap(),
+        vec![
+            vec!["db", "", "5432", ""],
+            vec!["cache", "", "6379", "local only"],
+            vec!["web", "us-east", "443", ""],
+        ]
+    );
+    assert!(crate::table::header::<Host>(&[]).unwrap().is_empty());
+}
+
+#[test]
+fn test_batch_update_requests() {
+    use crate::{
+        batch_update::BatchUpdate,
+        range::A1Range,
+        types::{CellData, CellFormat, MergeType, Request, SheetProperties},
+    };
+
+    assert_eq!(
+        crate::batch_update::field_mask(&serde_json::json!({
+            "textFormat": {"bold": true, "italic": false, "fontSize": 12},
+            "backgroundColor": {"red": 1.0},
+            "wrapStrategy": "WRAP",
+        })),
+        "backgroundColor,textFormat.bold,textFormat.fontSize,wrapStrategy"
+    );
+
+    let bold: CellFormat =
+        serde_json::from_value(serde_json::json!({"textFormat": {"bold": true}})).unwrap();
+    let note: CellData = serde_json::from_value(serde_json::json!({"note": "total"})).unwrap();
+    let hidden: SheetProperties = serde_json::from_value(serde_json::json!({
+        "hidden": true,
+        "sheetId": 3,
+    }))
+    .unwrap();
+    let update = BatchUpdate::new()
+        .add_sheet("Summary")
+        .named("summary")
+        .format_cells(7, &"A1:C1".parse::<A1Range>().unwrap(), &bold)
+        .unwrap()
+        .merge_cells(7, &"B2:B".parse::<A1Range>().unwrap(), MergeType::MergeAll)
+        .update_sheet_properties(7, &hidden)
+        .unwrap()
+        .repeat_cell(7, &"D4".parse::<A1Range>().unwrap(), &note)
+        .unwrap();
+    assert_eq!(update.len(), 5);
+
+    let requests = serde_json::to_value(update.requests()).unwrap();
+    // Unset booleans are sent as false, so the mask limits the update to `bold`.
+    let repeat_cell = &requests[1]["repeatCell"];
+    assert_eq!(repeat_cell["fields"], "userEnteredFormat.textFormat.bold");
+    assert_eq!(
+        repeat_cell["cell"]["userEnteredFormat"]["textFormat"]["bold"],
+        true
+    );
+    assert_eq!(
+        repeat_cell["range"],
+        serde_json::json!({"sheetId": 7, "endColumnIndex": 3, "endRowIndex": 1})
+    );
+    assert_eq!(
+        requests[2]["mergeCells"]["range"],
+        serde_json::json!({
+            "sheetId": 7,
+            "startColumnIndex": 1,
+            "endColumnIndex": 2,
+            "startRowIndex": 1,
+        })
+    );
+    let update_sheet = &requests[3]["updateSheetProperties"];
+    assert_eq!(update_sheet["fields"], "hidden");
+    assert_eq!(update_sheet["properties"]["sheetId"], 7);
+    assert_eq!(requests[4]["repeatCell"]["fields"], "note");
+
+    let delete: Request =
+        serde_json::from_value(serde_json::json!({"deleteSheet": {"sheetId": 7}})).unwrap();
+    let update = BatchUpdate::new().request(&delete);
+    assert_eq!(update.requests(), &[delete]);
+}
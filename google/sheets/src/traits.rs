@@ -34,3 +34,178 @@ impl SpreadsheetOps for crate::spreadsheets::Spreadsheets {
         Ok(String::new())
     }
 }
+
+#[async_trait::async_trait]
+pub trait TableOps {
+    /// Read the table in `range`, whose first row is a header, deserializing each
+    /// following row into a `T` by matching the header to its field names.
+    ///
+    /// Cells are read as they are formatted in the spreadsheet.
+    async fn read_table<T>(
+        &self,
+        spreadsheet_id: &str,
+        range: &crate::range::A1Range,
+    ) -> Result<Vec<T>>
+    where
+        T: serde::de::DeserializeOwned + Send;
+
+    /// Replace the table in `range` with a header row and a row for each record,
+    /// clearing whatever was there.
+    ///
+    /// With no records, only the rows after the header are cleared.
+    async fn write_table<T>(
+        &self,
+        spreadsheet_id: &str,
+        range: &crate::range::A1Range,
+        records: &[T],
+        value_input_option: crate::types::ValueInputOption,
+    ) -> Result<()>
+    where
+        T: serde::Serialize + Sync;
+
+    /// Append a row for each record to the table in `range`, in the order of the
+    /// columns of its header row. The header is written first if the table is empty.
+    async fn append_table<T>(
+        &self,
+        spreadsheet_id: &str,
+        range: &crate::range::A1Range,
+        records: &[T],
+        value_input_option: crate::types::ValueInputOption,
+    ) -> Result<()>
+    where
+        T: serde::Serialize + Sync;
+}
+
+#[async_trait::async_trait]
+impl TableOps for crate::spreadsheets::Spreadsheets {
+    /// Read the table in `range`, whose first row is a header, deserializing each
+    /// following row into a `T` by matching the header to its field names.
+    ///
+    /// Cells are read as they are formatted in the spreadsheet.
+    async fn read_table<T>(
+        &self,
+        spreadsheet_id: &str,
+        range: &crate::range::A1Range,
+    ) -> Result<Vec<T>>
+    where
+        T: serde::de::DeserializeOwned + Send,
+    {
+        let values = self
+            .values_get(
+                spreadsheet_id,
+                &range.to_string(),
+                crate::types::DateTimeRenderOption::FormattedString,
+                crate::types::Dimension::Rows,
+                crate::types::ValueRenderOption::FormattedValue,
+            )
+            .await?;
+
+        crate::table::from_values(&values.values)
+    }
+
+    /// Replace the table in `range` with a header row and a row for each record,
+    /// clearing whatever was there.
+    ///
+    /// With no records, only the rows after the header are cleared.
+    async fn write_table<T>(
+        &self,
+        spreadsheet_id: &str,
+        range: &crate::range::A1Range,
+        records: &[T],
+        value_input_option: crate::types::ValueInputOption,
+    ) -> Result<()>
+    where
+        T: serde::Serialize + Sync,
+    {
+        if records.is_empty() {
+            self.values_clear(
+                spreadsheet_id,
+                &range.without_first_row().to_string(),
+                &crate::types::ClearValuesRequest {},
+            )
+            .await?;
+            return Ok(());
+        }
+
+        let header = crate::table::header(records)?;
+        let mut values = vec![header.clone()];
+        values.extend(crate::table::to_rows(records, &header)?);
+
+        self.values_clear(
+            spreadsheet_id,
+            &range.to_string(),
+            &crate::types::ClearValuesRequest {},
+        )
+        .await?;
+        self.values_update(
+            spreadsheet_id,
+            &range.to_string(),
+            false,
+            crate::types::DateTimeRenderOption::Noop,
+            crate::types::ValueRenderOption::Noop,
+            value_input_option,
+            &crate::types::ValueRange {
+                major_dimension: Some(crate::types::Dimension::Rows),
+                range: String::new(),
+                values,
+            },
+        )
+        .await?;
+
+        Ok(())
+    }
+
+    /// Append a row for each record to the table in `range`, in the order of the
+    /// columns of its header row. The header is written first if the table is empty.
+    async fn append_table<T>(
+        &self,
+        spreadsheet_id: &str,
+        range: &crate::range::A1Range,
+        records: &[T],
+        value_input_option: crate::types::ValueInputOption,
+    ) -> Result<()>
+    where
+        T: serde::Serialize + Sync,
+    {
+        if records.is_empty() {
+            return Ok(());
+        }
+
+        let header = self
+            .values_get(
+                spreadsheet_id,
+                &range.first_row().to_string(),
+                crate::types::DateTimeRenderOption::FormattedString,
+                crate::types::Dimension::Rows,
+                crate::types::ValueRenderOption::FormattedValue,
+            )
+            .await?
+            .values
+            .into_iter()
+            .next()
+            .unwrap_or_default();
+        if header.iter().all(|h| h.trim().is_empty()) {
+            return self
+                .write_table(spreadsheet_id, range, records, value_input_option)
+                .await;
+        }
+
+        self.values_append(
+            spreadsheet_id,
+            &range.to_string(),
+            false,
+            crate::types::InsertDataOption::InsertRows,
+            crate::types::DateTimeRenderOption::Noop,
+            crate::types::ValueRenderOption::Noop,
+            value_input_option,
+            &crate::types::ValueRange {
+                major_dimension: Some(crate::types::Dimension::Rows),
+                range: String::new(),
+                values: crate::table::to_rows(records, &header)?,
+            },
+        )
+        .await?;
+
+        Ok(())
+    }
+}
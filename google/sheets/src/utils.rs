@@ -638,3 +638,39 @@ pub mod deserialize_null_vector {
         Ok(Default::default())
     }
 }
+
+/// An error response from the API.
+///
+/// Requests return it as their `anyhow::Error`, so the status of a failed request
+/// can be matched with `HttpError::status_of`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct HttpError {
+    pub status: http::StatusCode,
+    pub body: String,
+}
+
+impl HttpError {
+    pub fn new(status: http::StatusCode, body: &[u8]) -> Self {
+        HttpError {
+            status,
+            body: String::from_utf8_lossy(body).to_string(),
+        }
+    }
+
+    /// The status of the response an error is for, if it is an error response.
+    pub fn status_of(e: &anyhow::Error) -> Option<http::StatusCode> {
+        e.downcast_ref::<HttpError>().map(|e| e.status)
+    }
+}
+
+impl fmt::Display for HttpError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.body.is_empty() {
+            write!(f, "code: {}, empty response", self.status)
+        } else {
+            write!(f, "code: {}, error: {:?}", self.status, self.body)
+        }
+    }
+}
+
+impl std::error::Error for HttpError {}
@@ -48,7 +48,7 @@ pub async fn put_company_or_federal_tax_details(
 &self,
 company_id_or_uuid: &str,
 body: &crate::types::PutCompanyFederalTaxDetailsRequest
-) -> Result<crate::types::GetCompanyFederalTaxDetailsResponse> {
+) -> Result<crate::types::PutCompanyFederalTaxDetailsResponse> {
 let url =
 format!("/v1/companies/{}/federal_tax_details",
 crate::progenitor_support::encode_path(&company_id_or_uuid.to_string()),);
@@ -5978,3 +5978,5 @@ pub struct PutCompanyFederalTaxDetailsRequest {
     )]
     pub version: String,
 }
+
+pub type PutCompanyFederalTaxDetailsResponse = GetCompanyFederalTaxDetailsResponse;
@@ -178,7 +178,7 @@ impl Cards {
     pub async fn post_resources_physical(
         &self,
         body: &crate::types::PostResourcesCardPhysicalRequest,
-    ) -> Result<crate::types::TaskResponse> {
+    ) -> Result<crate::types::PostResourcesCardPhysicalResponse> {
         let url = "/cards/deferred/physical".to_string();
         self.client
             .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
@@ -199,7 +199,7 @@ impl Cards {
     pub async fn post_resources_virtual(
         &self,
         body: &crate::types::PostResourcesCardVirtualRequest,
-    ) -> Result<crate::types::TaskResponse> {
+    ) -> Result<crate::types::PostResourcesCardVirtualResponse> {
         let url = "/cards/deferred/virtual".to_string();
         self.client
             .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
@@ -217,7 +217,7 @@ impl Cards {
         &self,
         id: &str,
         body: &crate::types::PostResourcesCardsCardSuspensionRequest,
-    ) -> Result<crate::types::TaskResponse> {
+    ) -> Result<crate::types::PostResourcesCardsCardTerminationResponse> {
         let url = format!(
             "/cards/{}/deferred/termination",
             crate::progenitor_support::encode_path(&id.to_string()),
@@ -239,7 +239,7 @@ impl Cards {
         &self,
         id: &str,
         body: &crate::types::PostResourcesCardsCardSuspensionRequest,
-    ) -> Result<crate::types::TaskResponse> {
+    ) -> Result<crate::types::PostResourcesCardsCardSuspensionResponse> {
         let url = format!(
             "/cards/{}/deferred/suspension",
             crate::progenitor_support::encode_path(&id.to_string()),
@@ -261,7 +261,7 @@ impl Cards {
         &self,
         id: &str,
         body: &crate::types::PostResourcesCardsCardSuspensionRequest,
-    ) -> Result<crate::types::TaskResponse> {
+    ) -> Result<crate::types::PostResourcesCardsCardUnsuspensionResponse> {
         let url = format!(
             "/cards/{}/deferred/unsuspension",
             crate::progenitor_support::encode_path(&id.to_string()),
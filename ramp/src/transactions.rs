@@ -261,7 +261,10 @@ impl Transactions {
      *
      * * `authorization: &str` -- The OAuth2 token header.
      */
-    pub async fn get_resource(&self, id: &str) -> Result<crate::types::Data> {
+    pub async fn get_resource(
+        &self,
+        id: &str,
+    ) -> Result<crate::types::GetResourcesTransactionsTransactionResponse> {
         let url = format!(
             "/transactions/{}",
             crate::progenitor_support::encode_path(&id.to_string()),
@@ -1190,6 +1190,8 @@ pub struct Business {
     pub website: String,
 }
 
+pub type GetResourcesTransactionsTransactionResponse = Data;
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PostResourcesCardPhysicalRequest {
     /**
@@ -1240,6 +1242,8 @@ pub struct PostResourcesCardPhysicalRequest {
     pub user_id: String,
 }
 
+pub type PostResourcesCardPhysicalResponse = TaskResponse;
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PostResourcesCardVirtualRequest {
     /**
@@ -1285,6 +1289,8 @@ pub struct PostResourcesCardVirtualRequest {
     pub user_id: String,
 }
 
+pub type PostResourcesCardVirtualResponse = TaskResponse;
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub enum TokenType {
     #[serde(rename = "Bearer")]
@@ -1432,6 +1438,12 @@ pub struct PostResourcesCardsCardSuspensionRequest {
     pub idempotency_key: String,
 }
 
+pub type PostResourcesCardsCardTerminationResponse = TaskResponse;
+
+pub type PostResourcesCardsCardSuspensionResponse = TaskResponse;
+
+pub type PostResourcesCardsCardUnsuspensionResponse = TaskResponse;
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct GetEntityTypeCustomRampResponse {
     /**
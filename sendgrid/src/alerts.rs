@@ -128,7 +128,7 @@ impl Alerts {
      *
      * * `on_behalf_of: &str` -- The license key provided with your New Relic account.
      */
-    pub async fn delete(&self, alert_id: i64) -> Result<crate::types::Help> {
+    pub async fn delete(&self, alert_id: i64) -> Result<crate::types::DeleteAlertsAlertResponse> {
         let url = format!(
             "/alerts/{}",
             crate::progenitor_support::encode_path(&alert_id.to_string()),
@@ -158,7 +158,7 @@ impl Alerts {
         &self,
         alert_id: i64,
         body: &crate::types::PatchAlertsAlertRequest,
-    ) -> Result<crate::types::GetAlertsAlertResponse> {
+    ) -> Result<crate::types::PatchAlertsAlertResponse> {
         let url = format!(
             "/alerts/{}",
             crate::progenitor_support::encode_path(&alert_id.to_string()),
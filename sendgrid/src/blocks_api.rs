@@ -103,7 +103,7 @@ impl BlocksApi {
     pub async fn delete_suppression_blocks(
         &self,
         body: &crate::types::DeleteSuppressionBlocksRequest,
-    ) -> Result<crate::types::Help> {
+    ) -> Result<crate::types::DeleteSuppressionBlocksResponse> {
         let url = "/suppression/blocks".to_string();
         self.client
             .delete(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
@@ -165,7 +165,10 @@ impl BlocksApi {
      *
      * * `on_behalf_of: &str` -- The license key provided with your New Relic account.
      */
-    pub async fn delete_suppression_blocks_email(&self, email: &str) -> Result<crate::types::Help> {
+    pub async fn delete_suppression_blocks_email(
+        &self,
+        email: &str,
+    ) -> Result<crate::types::DeleteSuppressionBlocksEmailResponse> {
         let url = format!(
             "/suppression/blocks/{}",
             crate::progenitor_support::encode_path(&email.to_string()),
@@ -158,7 +158,7 @@ impl BouncesApi {
         email: &str,
         email_address: &str,
         body: &serde_json::Value,
-    ) -> Result<crate::types::Help> {
+    ) -> Result<crate::types::DeleteSuppressionBouncesEmailResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !email_address.is_empty() {
             query_args.push(("email_address".to_string(), email_address.to_string()));
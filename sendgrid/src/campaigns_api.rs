@@ -180,7 +180,7 @@ impl CampaignsApi {
     pub async fn get_campaigns_campaign_schedule(
         &self,
         campaign_id: i64,
-    ) -> Result<crate::types::ScheduleACampaignRequest> {
+    ) -> Result<crate::types::GetCampaignsCampaignSchedulesResponse> {
         let url = format!(
             "/campaigns/{}/schedules",
             crate::progenitor_support::encode_path(&campaign_id.to_string()),
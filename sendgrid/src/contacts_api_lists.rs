@@ -171,7 +171,7 @@ impl ContactsApiLists {
         list_id: i64,
         page: i64,
         page_size: i64,
-    ) -> Result<crate::types::GetContactdbRecipientsSearchResponse> {
+    ) -> Result<crate::types::GetContactdbListsListRecipientsResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if page > 0 {
             query_args.push(("page".to_string(), page.to_string()));
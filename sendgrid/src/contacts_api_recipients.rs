@@ -84,7 +84,10 @@ impl ContactsApiRecipients {
      *
      * * `on_behalf_of: &str` -- The license key provided with your New Relic account.
      */
-    pub async fn delete_contactdb_recipients(&self, body: &[String]) -> Result<crate::types::Help> {
+    pub async fn delete_contactdb_recipients(
+        &self,
+        body: &[String],
+    ) -> Result<crate::types::DeleteContactdbRecipientsResponse> {
         let url = "/contactdb/recipients".to_string();
         self.client
             .delete(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
@@ -129,7 +132,7 @@ impl ContactsApiRecipients {
      */
     pub async fn get_contactdb_statu(
         &self,
-    ) -> Result<crate::types::GetContactdbStatusResponseData> {
+    ) -> Result<crate::types::GetContactdbStatusResponseDataType> {
         let url = "/contactdb/status".to_string();
         self.client.get(&url, None).await
     }
@@ -173,7 +176,7 @@ impl ContactsApiRecipients {
     pub async fn delete_contactdb_recipients_recipient(
         &self,
         recipient_id: &str,
-    ) -> Result<crate::types::Help> {
+    ) -> Result<crate::types::DeleteContactdbRecipientsRecipientResponse> {
         let url = format!(
             "/contactdb/recipients/{}",
             crate::progenitor_support::encode_path(&recipient_id.to_string()),
@@ -332,7 +335,7 @@ impl ContactsApiRecipients {
     pub async fn post_contactdb_recipients_search(
         &self,
         body: &crate::types::PostContactdbRecipientsSearchRequest,
-    ) -> Result<crate::types::PostContactdbRecipientsSearchResponseData> {
+    ) -> Result<crate::types::PostContactdbRecipientsSearchResponseDataType> {
         let url = "/contactdb/recipients/search".to_string();
         self.client
             .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
@@ -42,7 +42,7 @@ impl CustomFields {
     pub async fn post_mc_field_definition(
         &self,
         body: &crate::types::PostMcFieldDefinitionsRequest,
-    ) -> Result<crate::types::PostMcFieldDefinitionsResponseAllOf> {
+    ) -> Result<crate::types::PostMcFieldDefinitionsResponseData> {
         let url = "/marketing/field_definitions".to_string();
         self.client
             .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
@@ -80,7 +80,7 @@ impl CustomFields {
         &self,
         custom_field_id: &str,
         body: &crate::types::IpPool,
-    ) -> Result<crate::types::PostMcFieldDefinitionsResponseAllOf> {
+    ) -> Result<crate::types::PatchMcFieldDefinitionsCustomResponse> {
         let url = format!(
             "/marketing/field_definitions/{}",
             crate::progenitor_support::encode_path(&custom_field_id.to_string()),
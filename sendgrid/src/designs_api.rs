@@ -70,7 +70,7 @@ impl DesignsApi {
      *
      * Be sure to check the ID of the design you intend to delete before making this request; deleting a design is a permanent action.
      */
-    pub async fn delete_design(&self, id: &str) -> Result<crate::types::Help> {
+    pub async fn delete_design(&self, id: &str) -> Result<crate::types::DeleteDesignResponse> {
         let url = format!(
             "/designs/{}",
             crate::progenitor_support::encode_path(&id.to_string()),
@@ -247,7 +247,7 @@ impl DesignsApi {
         page_size: u64,
         page_token: &str,
         summary: bool,
-    ) -> Result<crate::types::ListDesignsResponse> {
+    ) -> Result<crate::types::ListSendgridPreBuiltDesignsResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !page_size.to_string().is_empty() {
             query_args.push(("page_size".to_string(), page_size.to_string()));
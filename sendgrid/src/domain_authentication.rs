@@ -155,7 +155,7 @@ impl DomainAuthentication {
     pub async fn delete_whitelabel_domains_domain(
         &self,
         domain_id: &str,
-    ) -> Result<crate::types::Help> {
+    ) -> Result<crate::types::DeleteWhitelabelDomainsDomainResponse> {
         let url = format!(
             "/whitelabel/domains/{}",
             crate::progenitor_support::encode_path(&domain_id.to_string()),
@@ -364,7 +364,7 @@ impl DomainAuthentication {
     pub async fn delete_whitelabel_domains_subuser(
         &self,
         username: &str,
-    ) -> Result<crate::types::Help> {
+    ) -> Result<crate::types::DeleteWhitelabelDomainsSubuserResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !username.is_empty() {
             query_args.push(("username".to_string(), username.to_string()));
@@ -103,7 +103,7 @@ impl InvalidEmailsApi {
     pub async fn delete_suppression_invalid_emails(
         &self,
         body: &crate::types::DeleteSuppressionBlocksRequest,
-    ) -> Result<crate::types::Help> {
+    ) -> Result<crate::types::DeleteSuppressionInvalidEmailsResponse> {
         let url = "/suppression/invalid_emails".to_string();
         self.client
             .delete(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
@@ -168,7 +168,7 @@ impl InvalidEmailsApi {
     pub async fn delete_suppression_invalid_emails_email(
         &self,
         email: &str,
-    ) -> Result<crate::types::Help> {
+    ) -> Result<crate::types::DeleteSuppressionInvalidEmailsEmailResponse> {
         let url = format!(
             "/suppression/invalid_emails/{}",
             crate::progenitor_support::encode_path(&email.to_string()),
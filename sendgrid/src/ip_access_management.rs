@@ -71,7 +71,7 @@ impl IpAccessManagement {
     pub async fn delete_access_settings_whitelist(
         &self,
         body: &crate::types::DeleteAccessSettingsWhitelistRequest,
-    ) -> Result<crate::types::Help> {
+    ) -> Result<crate::types::DeleteAccessSettingsWhitelistResponse> {
         let url = "/access_settings/whitelist".to_string();
         self.client
             .delete(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
@@ -145,7 +145,7 @@ impl IpAccessManagement {
     pub async fn delete_access_settings_whitelist_rule(
         &self,
         rule_id: &str,
-    ) -> Result<crate::types::Help> {
+    ) -> Result<crate::types::DeleteAccessSettingsWhitelistRuleResponse> {
         let url = format!(
             "/access_settings/whitelist/{}",
             crate::progenitor_support::encode_path(&rule_id.to_string()),
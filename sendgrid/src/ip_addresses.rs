@@ -131,7 +131,7 @@ impl IpAddresses {
     pub async fn post_ip(
         &self,
         body: &crate::types::PostIpsRequest,
-    ) -> Result<crate::types::PostIpsResponseData> {
+    ) -> Result<crate::types::PostIpsResponseDataType> {
         let url = "/ips".to_string();
         self.client
             .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
@@ -86,7 +86,7 @@ impl IpPools {
         &self,
         pool_name: &str,
         body: &crate::types::PostIpsWarmupRequest,
-    ) -> Result<crate::types::GetIpsAssignedResponse> {
+    ) -> Result<crate::types::PostIpsPoolsPoolNameResponse> {
         let url = format!(
             "/ips/pools/{}/ips",
             crate::progenitor_support::encode_path(&pool_name.to_string()),
@@ -145,7 +145,10 @@ impl IpPools {
      *
      * **This endpoint allows you to delete an IP pool.**
      */
-    pub async fn delete_ips_pools_pool_name(&self, pool_name: &str) -> Result<crate::types::Help> {
+    pub async fn delete_ips_pools_pool_name(
+        &self,
+        pool_name: &str,
+    ) -> Result<crate::types::DeleteIpsPoolsPoolNameResponse> {
         let url = format!(
             "/ips/pools/{}",
             crate::progenitor_support::encode_path(&pool_name.to_string()),
@@ -165,7 +168,7 @@ impl IpPools {
         &self,
         pool_name: &str,
         ip: &str,
-    ) -> Result<crate::types::Help> {
+    ) -> Result<crate::types::DeleteIpsPoolsPoolNameIpResponse> {
         let url = format!(
             "/ips/pools/{}/ips/{}",
             crate::progenitor_support::encode_path(&pool_name.to_string()),
@@ -111,7 +111,7 @@ impl IpWarmup {
     pub async fn delete_ips_warmup_ip_address(
         &self,
         ip_address: &str,
-    ) -> Result<crate::types::Help> {
+    ) -> Result<crate::types::DeleteIpsWarmupIpAddressResponse> {
         let url = format!(
             "/ips/warmup/{}",
             crate::progenitor_support::encode_path(&ip_address.to_string()),
@@ -172,7 +172,10 @@ impl LinkBranding {
      *
      * * `on_behalf_of: &str` -- The license key provided with your New Relic account.
      */
-    pub async fn delete_whitelabel_links(&self, id: i64) -> Result<crate::types::Help> {
+    pub async fn delete_whitelabel_links(
+        &self,
+        id: i64,
+    ) -> Result<crate::types::DeleteWhitelabelLinksResponse> {
         let url = format!(
             "/whitelabel/links/{}",
             crate::progenitor_support::encode_path(&id.to_string()),
@@ -288,7 +291,7 @@ impl LinkBranding {
     pub async fn delete_whitelabel_links_subuser(
         &self,
         username: &str,
-    ) -> Result<crate::types::Help> {
+    ) -> Result<crate::types::DeleteWhitelabelLinksSubuserResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !username.is_empty() {
             query_args.push(("username".to_string(), username.to_string()));
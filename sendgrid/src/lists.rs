@@ -98,7 +98,7 @@ impl Lists {
         &self,
         id: &str,
         contact_sample: bool,
-    ) -> Result<crate::types::GetMcListsResponseAllOf> {
+    ) -> Result<crate::types::GetMcListsResponseDataType> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if contact_sample {
             query_args.push(("contact_sample".to_string(), contact_sample.to_string()));
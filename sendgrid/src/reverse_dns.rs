@@ -174,7 +174,10 @@ impl ReverseDns {
      *
      * * `on_behalf_of: &str` -- The license key provided with your New Relic account.
      */
-    pub async fn delete_whitelabel_ips(&self, id: &str) -> Result<crate::types::Help> {
+    pub async fn delete_whitelabel_ips(
+        &self,
+        id: &str,
+    ) -> Result<crate::types::DeleteWhitelabelIpsResponse> {
         let url = format!(
             "/whitelabel/ips/{}",
             crate::progenitor_support::encode_path(&id.to_string()),
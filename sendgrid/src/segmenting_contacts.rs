@@ -113,7 +113,7 @@ impl SegmentingContacts {
     pub async fn delete_marketing_segments_segment(
         &self,
         segment_id: &str,
-    ) -> Result<crate::types::Help> {
+    ) -> Result<crate::types::DeleteMarketingSegmentsSegmentResponse> {
         let url = format!(
             "/marketing/segments/{}",
             crate::progenitor_support::encode_path(&segment_id.to_string()),
@@ -30,7 +30,7 @@ impl SendTestEmail {
     pub async fn post_marketing_test_send_email(
         &self,
         body: &crate::types::PostMarketingTestSendEmailRequest,
-    ) -> Result<crate::types::Help> {
+    ) -> Result<crate::types::PostMarketingTestSendEmailResponse> {
         let url = "/marketing/test/send_email".to_string();
         self.client
             .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
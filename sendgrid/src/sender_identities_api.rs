@@ -82,7 +82,10 @@ impl SenderIdentitiesApi {
      *
      * * `on_behalf_of: &str` -- The license key provided with your New Relic account.
      */
-    pub async fn delete_senders_sender(&self, sender_id: i64) -> Result<crate::types::Help> {
+    pub async fn delete_senders_sender(
+        &self,
+        sender_id: i64,
+    ) -> Result<crate::types::DeleteSendersSenderResponse> {
         let url = format!(
             "/senders/{}",
             crate::progenitor_support::encode_path(&sender_id.to_string()),
@@ -135,7 +138,7 @@ impl SenderIdentitiesApi {
     pub async fn post_senders_sender_resend_verification(
         &self,
         sender_id: i64,
-    ) -> Result<crate::types::Help> {
+    ) -> Result<crate::types::PostSendersSenderResendVerificationResponse> {
         let url = format!(
             "/senders/{}/resend_verification",
             crate::progenitor_support::encode_path(&sender_id.to_string()),
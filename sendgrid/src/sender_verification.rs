@@ -143,7 +143,10 @@ impl SenderVerification {
      *
      * You can retrieve the IDs associated with Sender Identities using the "Get All Verified Senders" endpoint.
      */
-    pub async fn delete_verified_senders(&self, id: &str) -> Result<crate::types::Help> {
+    pub async fn delete_verified_senders(
+        &self,
+        id: &str,
+    ) -> Result<crate::types::DeleteVerifiedSendersResponse> {
         let url = format!(
             "/verified_senders/{}",
             crate::progenitor_support::encode_path(&id.to_string()),
@@ -191,7 +194,10 @@ impl SenderVerification {
      *
      * You can retrieve the IDs associated with Sender Identities by passing a "Get All Verified Senders" endpoint.
      */
-    pub async fn post_verified_senders_resend(&self, id: &str) -> Result<crate::types::Help> {
+    pub async fn post_verified_senders_resend(
+        &self,
+        id: &str,
+    ) -> Result<crate::types::PostVerifiedSendersResendResponse> {
         let url = format!(
             "/verified_senders/resend/{}",
             crate::progenitor_support::encode_path(&id.to_string()),
@@ -82,7 +82,7 @@ impl SettingsInboundParse {
     pub async fn delete_user_webhooks_parse_settings_hostname(
         &self,
         hostname: &str,
-    ) -> Result<crate::types::Help> {
+    ) -> Result<crate::types::DeleteUserWebhooksParseSettingsHostnameResponse> {
         let url = format!(
             "/user/webhooks/parse/settings/{}",
             crate::progenitor_support::encode_path(&hostname.to_string()),
@@ -165,7 +165,7 @@ impl SettingsTracking {
     pub async fn patch_tracking_settings_open(
         &self,
         body: &crate::types::PatchTrackingSettingsOpenRequest,
-    ) -> Result<crate::types::GetTrackingSettingsOpenResponse> {
+    ) -> Result<crate::types::PatchTrackingSettingsOpenResponse> {
         let url = "/tracking_settings/open".to_string();
         self.client
             .patch(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
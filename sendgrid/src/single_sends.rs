@@ -213,7 +213,7 @@ impl SingleSends {
         page_size: i64,
         page_token: &str,
         body: &crate::types::SinglesendSearch,
-    ) -> Result<crate::types::GetMarketingSinglesendsResponse> {
+    ) -> Result<crate::types::PostMarketingSinglesendsSearchResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if page_size > 0 {
             query_args.push(("page_size".to_string(), page_size.to_string()));
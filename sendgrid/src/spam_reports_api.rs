@@ -105,7 +105,7 @@ impl SpamReportsApi {
     pub async fn delete_suppression_spam_reports(
         &self,
         body: &crate::types::DeleteSuppressionBlocksRequest,
-    ) -> Result<crate::types::Help> {
+    ) -> Result<crate::types::DeleteSuppressionSpamReportsResponse> {
         let url = "/suppression/spam_reports".to_string();
         self.client
             .delete(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
@@ -172,7 +172,7 @@ impl SpamReportsApi {
     pub async fn delete_suppression_spam_reports_email(
         &self,
         email: &str,
-    ) -> Result<crate::types::Help> {
+    ) -> Result<crate::types::DeleteSuppressionSpamReportsEmailResponse> {
         let url = format!(
             "/suppression/spam_reports/{}",
             crate::progenitor_support::encode_path(&email.to_string()),
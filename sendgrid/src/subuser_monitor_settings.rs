@@ -77,7 +77,7 @@ impl SubuserMonitorSettings {
     pub async fn delete_subusers_subuser_name_monitor(
         &self,
         subuser_name: &str,
-    ) -> Result<crate::types::Help> {
+    ) -> Result<crate::types::DeleteSubusersSubuserNameMonitorResponse> {
         let url = format!(
             "/subusers/{}/monitor",
             crate::progenitor_support::encode_path(&subuser_name.to_string()),
@@ -107,7 +107,7 @@ impl SubusersApi {
     pub async fn delete_subusers_subuser_name(
         &self,
         subuser_name: &str,
-    ) -> Result<crate::types::Help> {
+    ) -> Result<crate::types::DeleteSubusersSubuserNameResponse> {
         let url = format!(
             "/subusers/{}",
             crate::progenitor_support::encode_path(&subuser_name.to_string()),
@@ -127,7 +127,7 @@ impl SubusersApi {
         &self,
         subuser_name: &str,
         body: &crate::types::PatchSubusersSubuserNameRequest,
-    ) -> Result<crate::types::Help> {
+    ) -> Result<crate::types::PatchSubusersSubuserNameResponse> {
         let url = format!(
             "/subusers/{}",
             crate::progenitor_support::encode_path(&subuser_name.to_string()),
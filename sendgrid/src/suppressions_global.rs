@@ -26,7 +26,7 @@ impl SuppressionsGlobal {
     pub async fn post_asm(
         &self,
         body: &crate::types::SuppressionsRequestBody,
-    ) -> Result<crate::types::SuppressionsRequestBody> {
+    ) -> Result<crate::types::PostAsmSuppressionsGlobalResponse> {
         let url = "/asm/suppressions/global".to_string();
         self.client
             .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
@@ -143,7 +143,10 @@ impl SuppressionsGlobal {
      *
      * * `on_behalf_of: &str` -- The license key provided with your New Relic account.
      */
-    pub async fn delete_asm_email(&self, email: &str) -> Result<crate::types::Help> {
+    pub async fn delete_asm_email(
+        &self,
+        email: &str,
+    ) -> Result<crate::types::DeleteAsmSuppressionsGlobalEmailResponse> {
         let url = format!(
             "/asm/suppressions/global/{}",
             crate::progenitor_support::encode_path(&email.to_string()),
@@ -96,7 +96,7 @@ impl SuppressionsUnsubscribeGroups {
     pub async fn get_asm_groups_group(
         &self,
         group_id: &str,
-    ) -> Result<crate::types::GetAsmGroupsGroupResponseAllOf> {
+    ) -> Result<crate::types::GetAsmGroupsGroupResponseData> {
         let url = format!(
             "/asm/groups/{}",
             crate::progenitor_support::encode_path(&group_id.to_string()),
@@ -120,7 +120,10 @@ impl SuppressionsUnsubscribeGroups {
      *
      * * `on_behalf_of: &str` -- The license key provided with your New Relic account.
      */
-    pub async fn delete_asm_groups_group(&self, group_id: &str) -> Result<crate::types::Help> {
+    pub async fn delete_asm_groups_group(
+        &self,
+        group_id: &str,
+    ) -> Result<crate::types::DeleteAsmGroupsGroupResponse> {
         let url = format!(
             "/asm/groups/{}",
             crate::progenitor_support::encode_path(&group_id.to_string()),
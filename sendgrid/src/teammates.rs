@@ -82,7 +82,7 @@ impl Teammates {
     pub async fn post_pending_token_resend(
         &self,
         token: &str,
-    ) -> Result<crate::types::PostTeammatesResponse> {
+    ) -> Result<crate::types::PostTeammatesPendingTokenResendResponse> {
         let url = format!(
             "/teammates/pending/{}/resend",
             crate::progenitor_support::encode_path(&token.to_string()),
@@ -207,7 +207,7 @@ impl Teammates {
     pub async fn delete_username(
         &self,
         username: &str,
-    ) -> Result<crate::types::PostSendersResponse> {
+    ) -> Result<crate::types::DeleteTeammatesUsernameResponse> {
         let url = format!(
             "/teammates/{}",
             crate::progenitor_support::encode_path(&username.to_string()),
@@ -237,7 +237,7 @@ impl Teammates {
         &self,
         username: &str,
         body: &crate::types::PatchTeammatesUsernameRequest,
-    ) -> Result<crate::types::GetTeammatesUsernameResponse> {
+    ) -> Result<crate::types::PatchTeammatesUsernameResponse> {
         let url = format!(
             "/teammates/{}",
             crate::progenitor_support::encode_path(&username.to_string()),
@@ -129,7 +129,10 @@ impl TransactionalTemplates {
      *
      * * `on_behalf_of: &str` -- The license key provided with your New Relic account.
      */
-    pub async fn delete_templates_template(&self, template_id: &str) -> Result<crate::types::Help> {
+    pub async fn delete_templates_template(
+        &self,
+        template_id: &str,
+    ) -> Result<crate::types::DeleteTemplatesTemplateResponse> {
         let url = format!(
             "/templates/{}",
             crate::progenitor_support::encode_path(&template_id.to_string()),
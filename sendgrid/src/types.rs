@@ -7958,6 +7958,8 @@ pub struct GetMessagesMsgResponse {
     pub errors: Vec<TraitGlobalErrors500Response>,
 }
 
+pub type TraitGlobalErrors500ResponseDataType = GetMessagesMsgResponse;
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct TraitCancelScheduledSendsErrors400Response {
     /**
@@ -8004,6 +8006,9 @@ pub struct TraitCancelScheduledSendsErrors400ResponseData {
     pub id: String,
 }
 
+pub type TraitCancelScheduledSendsErrors400ResponseDataType =
+    TraitCancelScheduledSendsErrors400ResponseData;
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct TraitMakoErrorResponse400Errors {
     /**
@@ -8054,6 +8059,14 @@ pub struct TraitMakoErrorResponse400 {
     pub errors: Vec<TraitMakoErrorResponse400Errors>,
 }
 
+pub type TraitMakoErrorResponse401 = TraitMakoErrorResponse400;
+
+pub type TraitMakoErrorResponse403 = TraitMakoErrorResponse400;
+
+pub type TraitMakoErrorResponse404 = TraitMakoErrorResponse400;
+
+pub type TraitMakoErrorResponse500 = TraitMakoErrorResponse400;
+
 #[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Personalizations {
     /**
@@ -8803,6 +8816,8 @@ pub struct DeleteAccessSettingsWhitelistRequest {
     pub ids: Vec<i64>,
 }
 
+pub type DeleteAccessSettingsWhitelistResponse = Help;
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct GetAccessSettingsActivityResponseResult {
     /**
@@ -8867,6 +8882,8 @@ pub struct GetAccessSettingsActivityResponse {
     pub result: Vec<GetAccessSettingsActivityResponseResult>,
 }
 
+pub type DeleteAccessSettingsWhitelistRuleResponse = Help;
+
 ///
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PostSsoCertificatesRequest {
@@ -9295,6 +9312,8 @@ pub struct PostSendersResponse {
     pub errors: Vec<PostTeammatesResponseErrors>,
 }
 
+pub type PostTeammatesPendingTokenResendResponse = PostTeammatesResponse;
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct GetScopesRequestsResponse {
     /**
@@ -9540,6 +9559,8 @@ pub struct GetTeammatesUsernameResponse {
     pub zip: String,
 }
 
+pub type DeleteTeammatesUsernameResponse = PostSendersResponse;
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PatchTeammatesUsernameRequest {
     /**
@@ -9558,6 +9579,8 @@ pub struct PatchTeammatesUsernameRequest {
     pub scopes: Vec<String>,
 }
 
+pub type PatchTeammatesUsernameResponse = GetTeammatesUsernameResponse;
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PatchScopesRequestsApproveResponse {
     /**
@@ -9847,6 +9870,8 @@ pub struct GetAlertsAlertResponse {
     pub updated_at: i64,
 }
 
+pub type DeleteAlertsAlertResponse = Help;
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PatchAlertsAlertRequest {
     #[serde(
@@ -9869,6 +9894,8 @@ pub struct PatchAlertsAlertRequest {
     pub percentage: i64,
 }
 
+pub type PatchAlertsAlertResponse = GetAlertsAlertResponse;
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct GetUserProfileResponse {
     /**
@@ -10053,6 +10080,10 @@ pub struct PutUserEmailRequest {
     pub email: String,
 }
 
+pub type PutUserEmailResponse = GetUserEmailResponse;
+
+pub type GetUserUsernameResponse = Users;
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PutUserUsernameRequest {
     #[serde(
@@ -10153,6 +10184,8 @@ pub struct PutUserPasswordRequest {
     pub old_password: String,
 }
 
+pub type PutUserPasswordResponse = Help;
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PostSubusersRequest {
     /**
@@ -10193,12 +10226,16 @@ pub struct PostSubusersRequest {
     pub username: String,
 }
 
+pub type DeleteSubusersSubuserNameResponse = Help;
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PatchSubusersSubuserNameRequest {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub disabled: Option<bool>,
 }
 
+pub type PatchSubusersSubuserNameResponse = Help;
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct GetSubusersReputationsResponse {
     /**
@@ -10234,6 +10271,8 @@ pub struct PutSubusersSubuserNameIpsResponse {
     pub ips: Vec<std::net::Ipv4Addr>,
 }
 
+pub type DeleteSubusersSubuserNameMonitorResponse = Help;
+
 /**
  * The direction you want to sort.
  */
@@ -10432,12 +10471,16 @@ pub struct PostWhitelabelLinksLinkSubuserRequest {
     pub username: String,
 }
 
+pub type DeleteWhitelabelLinksResponse = Help;
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PatchWhitelabelLinksRequest {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub default: Option<bool>,
 }
 
+pub type DeleteWhitelabelLinksSubuserResponse = Help;
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PostIpsWarmupRequest {
     #[serde(
@@ -10476,6 +10519,8 @@ pub struct PostIpsWarmupResponse {
     pub errors: Vec<PostIpsWarmupResponseErrors>,
 }
 
+pub type DeleteIpsWarmupIpAddressResponse = Help;
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PostWhitelabelIpsRequest {
     /**
@@ -10537,6 +10582,8 @@ pub struct PostWhitelabelIpsValidateResponse {
     pub validation_results: PostWhitelabelIpsValidateResponseValidationResults,
 }
 
+pub type DeleteWhitelabelIpsResponse = Help;
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PostValidationsEmailRequest {
     /**
@@ -10890,8 +10937,10 @@ pub struct PutIpsPoolsPoolNameRequest {
     pub name: String,
 }
 
+pub type DeleteIpsPoolsPoolNameResponse = Help;
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct DeleteIpsPoolsPoolNameResponse {
+pub struct DeleteIpsPoolsPoolNameResponseData {
     /**
      * The license key provided with your New Relic account.
      */
@@ -10903,6 +10952,8 @@ pub struct DeleteIpsPoolsPoolNameResponse {
     pub error: String,
 }
 
+pub type DeleteIpsPoolsPoolNameIpResponse = Help;
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct GetIpsResponse {
     #[serde(
@@ -11041,6 +11092,8 @@ pub struct PostIpsResponseData {
     pub warmup: bool,
 }
 
+pub type PostIpsResponseDataType = PostIpsResponseData;
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct GetIpsRemainingResponseResults {
     /**
@@ -11184,6 +11237,8 @@ pub struct PostWhitelabelDomainsRequest {
     pub username: String,
 }
 
+pub type DeleteWhitelabelDomainsDomainResponse = Help;
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PatchWhitelabelDomainsDomainRequest {
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -11319,6 +11374,8 @@ pub struct PostWhitelabelDomainsValidateResponseData {
     pub errors: Vec<PostWhitelabelLinksValidateResponseErrors>,
 }
 
+pub type DeleteWhitelabelDomainsSubuserResponse = Help;
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct GetVerifiedSendersDomainsResponseResults {
     /**
@@ -11456,8 +11513,10 @@ pub struct PatchVerifiedSendersResponse {
     pub errors: Vec<GetVerifiedSendersVerifyTokenResponseErrors>,
 }
 
+pub type DeleteVerifiedSendersResponse = Help;
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct DeleteVerifiedSendersResponse {
+pub struct DeleteVerifiedSendersResponseData {
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
@@ -11466,6 +11525,10 @@ pub struct DeleteVerifiedSendersResponse {
     pub errors: Vec<GetVerifiedSendersVerifyTokenResponseErrors>,
 }
 
+pub type PostVerifiedSendersResendResponse = Help;
+
+pub type DeleteDesignResponse = Help;
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PutDesignRequest {
     #[serde(
@@ -11514,6 +11577,8 @@ pub struct ListDesignsResponse {
     pub result: Vec<DesignOutputSummaryAllOf>,
 }
 
+pub type ListSendgridPreBuiltDesignsResponse = ListDesignsResponse;
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct GetMcContatsResponse {
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "_metadata")]
@@ -12257,6 +12322,8 @@ pub struct GetMcListsResponseAllOf {
     pub get_mc_lists_response_data: GetMcListsResponseData,
 }
 
+pub type GetMcListsResponseDataType = GetMcListsResponseAllOf;
+
 /// The delete has been accepted and is processing.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct DeleteListsResponse {
@@ -12346,6 +12413,10 @@ pub struct PostMcFieldDefinitionsResponseAllOf {
     pub post_mc_field_definitions_response: PostMcFieldDefinitionsResponse,
 }
 
+pub type PostMcFieldDefinitionsResponseData = PostMcFieldDefinitionsResponseAllOf;
+
+pub type PatchMcFieldDefinitionsCustomResponse = PostMcFieldDefinitionsResponseAllOf;
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct GetMarketingSegmentsResponse {
     #[serde(
@@ -12381,6 +12452,8 @@ pub struct PostMarketingSegmentsRequestAllOf {
     pub post_marketing_segments_request: PostMarketingSegmentsRequest,
 }
 
+pub type DeleteMarketingSegmentsSegmentResponse = Help;
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PostMarketingSegmentsDeleteRequest {
     #[serde(
@@ -12487,6 +12560,8 @@ pub struct PostMarketingSinglesendsRequest {
     pub name: String,
 }
 
+pub type PostMarketingSinglesendsSearchResponse = GetMarketingSinglesendsResponse;
+
 /**
  * This is the ISO 8601 time at which to send the Single Send; must be in future, or the string "now"
  */
@@ -12640,6 +12715,8 @@ pub struct PostMarketingTestSendEmailRequest {
     pub version_id_override: String,
 }
 
+pub type PostMarketingTestSendEmailResponse = Help;
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct GetSendersResponse {
     #[serde(
@@ -12676,6 +12753,10 @@ pub struct PostSendersRequestAllOf {
     pub post_senders_request: PostSendersRequest,
 }
 
+pub type DeleteSendersSenderResponse = Help;
+
+pub type PostSendersSenderResendVerificationResponse = Help;
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ListAllListsResponse {
     #[serde(
@@ -12768,6 +12849,8 @@ pub struct PostContactdbRecipientsRequest {
     pub last_name: String,
 }
 
+pub type DeleteContactdbRecipientsResponse = Help;
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PatchContactdbRecipientsRequest {
     /**
@@ -12831,6 +12914,10 @@ pub struct GetContactdbStatusResponseData {
     pub status: Vec<GetContactdbStatusResponse>,
 }
 
+pub type GetContactdbStatusResponseDataType = GetContactdbStatusResponseData;
+
+pub type DeleteContactdbRecipientsRecipientResponse = Help;
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct GetContactdbRecipientsRecipientListsResponse {
     #[serde(
@@ -12989,6 +13076,8 @@ pub struct PostContactdbRecipientsSearchResponseData {
     pub recipients: Vec<PostContactdbRecipientsSearchResponse>,
 }
 
+pub type PostContactdbRecipientsSearchResponseDataType = PostContactdbRecipientsSearchResponseData;
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ListAllCustomFieldsResponse {
     #[serde(
@@ -13519,6 +13608,8 @@ pub struct PostTemplatesTemplateRequest {
     pub name: String,
 }
 
+pub type DeleteTemplatesTemplateResponse = Help;
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PatchTemplatesTemplateRequest {
     #[serde(
@@ -13608,6 +13699,8 @@ pub struct GetTrackingSettingsOpenResponse {
     pub enabled: bool,
 }
 
+pub type PatchUserWebhooksEventSettingsSignedResponse = GetUserWebhooksEventSettingsSignedResponse;
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PatchUserWebhooksEventSettingsSignedResponseErrors {
     /**
@@ -13631,7 +13724,7 @@ pub struct PatchUserWebhooksEventSettingsSignedResponseErrors {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PatchUserWebhooksEventSettingsSignedResponse {
+pub struct PatchUserWebhooksEventSettingsSignedResponseData {
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
@@ -13805,6 +13898,8 @@ pub struct PatchTrackingSettingsOpenRequest {
     pub enabled: Option<bool>,
 }
 
+pub type PatchTrackingSettingsOpenResponse = GetTrackingSettingsOpenResponse;
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct GetStatsResponse {
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -14121,6 +14216,8 @@ pub struct DeleteSuppressionBouncesRequest {
     pub emails: Vec<String>,
 }
 
+pub type DeleteSuppressionBouncesEmailResponse = Help;
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct DeleteSuppressionBlocksRequest {
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -14133,6 +14230,16 @@ pub struct DeleteSuppressionBlocksRequest {
     pub emails: Vec<String>,
 }
 
+pub type DeleteSuppressionBlocksResponse = Help;
+
+pub type DeleteSuppressionBlocksEmailResponse = Help;
+
+pub type DeleteSuppressionSpamReportsResponse = Help;
+
+pub type DeleteSuppressionSpamReportsEmailResponse = Help;
+
+pub type PostAsmSuppressionsGlobalResponse = SuppressionsRequestBody;
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct GetSuppressionUnsubscribesResponse {
     #[serde(
@@ -14165,6 +14272,8 @@ pub struct RetrieveAGlobalSuppressionResponse {
     pub recipient_email: String,
 }
 
+pub type DeleteAsmSuppressionsGlobalEmailResponse = Help;
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PostAsmGroupsResponse {
     /**
@@ -14238,6 +14347,10 @@ pub struct GetAsmGroupsGroupResponseAllOf {
     pub get_asm_groups_group_response: GetAsmGroupsGroupResponse,
 }
 
+pub type GetAsmGroupsGroupResponseData = GetAsmGroupsGroupResponseAllOf;
+
+pub type DeleteAsmGroupsGroupResponse = Help;
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PostAsmGroupsGroupSuppressionsResponse {
     /**
@@ -14341,3 +14454,15 @@ pub struct GetAsmSuppressionsEmailResponse {
     )]
     pub suppressions: Vec<Suppressions>,
 }
+
+pub type DeleteSuppressionInvalidEmailsResponse = Help;
+
+pub type DeleteSuppressionInvalidEmailsEmailResponse = Help;
+
+pub type DeleteUserWebhooksParseSettingsHostnameResponse = Help;
+
+pub type GetCampaignsCampaignSchedulesResponse = ScheduleACampaignRequest;
+
+pub type GetContactdbListsListRecipientsResponse = GetContactdbRecipientsSearchResponse;
+
+pub type PostIpsPoolsPoolNameResponse = GetIpsAssignedResponse;
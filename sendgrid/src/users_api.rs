@@ -97,7 +97,7 @@ impl UsersApi {
     pub async fn put_user_email(
         &self,
         body: &crate::types::PutUserEmailRequest,
-    ) -> Result<crate::types::GetUserEmailResponse> {
+    ) -> Result<crate::types::PutUserEmailResponse> {
         let url = "/user/email".to_string();
         self.client
             .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
@@ -115,7 +115,7 @@ impl UsersApi {
      *
      * * `on_behalf_of: &str` -- The license key provided with your New Relic account.
      */
-    pub async fn get_user_username(&self) -> Result<crate::types::Users> {
+    pub async fn get_user_username(&self) -> Result<crate::types::GetUserUsernameResponse> {
         let url = "/user/username".to_string();
         self.client.get(&url, None).await
     }
@@ -173,7 +173,7 @@ impl UsersApi {
     pub async fn put_user_password(
         &self,
         body: &crate::types::PutUserPasswordRequest,
-    ) -> Result<crate::types::Help> {
+    ) -> Result<crate::types::PutUserPasswordResponse> {
         let url = "/user/password".to_string();
         self.client
             .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
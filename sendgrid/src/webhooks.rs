@@ -210,7 +210,7 @@ impl Webhooks {
     pub async fn patch_user_event_settings_signed(
         &self,
         body: &crate::types::GetTrackingSettingsOpenResponse,
-    ) -> Result<crate::types::GetUserWebhooksEventSettingsSignedResponse> {
+    ) -> Result<crate::types::PatchUserWebhooksEventSettingsSignedResponse> {
         let url = "/user/webhooks/event/settings/signed".to_string();
         self.client
             .patch(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
@@ -33,7 +33,7 @@ impl AdminAppsApproved {
         cursor: &str,
         team_id: &str,
         enterprise_id: &str,
-    ) -> Result<crate::types::DndEndSchema> {
+    ) -> Result<crate::types::AdminAppsApprovedListResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !cursor.is_empty() {
             query_args.push(("cursor".to_string(), cursor.to_string()));
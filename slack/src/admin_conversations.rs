@@ -23,7 +23,7 @@ impl AdminConversations {
      *
      * * `token: &str` -- Authentication token. Requires scope: `admin.conversations:write`.
      */
-    pub async fn archive(&self) -> Result<crate::types::DndEndSchema> {
+    pub async fn archive(&self) -> Result<crate::types::AdminConversationsArchiveResponse> {
         let url = "/admin.conversations.archive".to_string();
         self.client.post(&url, None).await
     }
@@ -39,7 +39,9 @@ impl AdminConversations {
      *
      * * `token: &str` -- Authentication token. Requires scope: `admin.conversations:write`.
      */
-    pub async fn convert_private(&self) -> Result<crate::types::DndEndSchema> {
+    pub async fn convert_private(
+        &self,
+    ) -> Result<crate::types::AdminConversationsConvertPrivateResponse> {
         let url = "/admin.conversations.convertToPrivate".to_string();
         self.client.post(&url, None).await
     }
@@ -71,7 +73,7 @@ impl AdminConversations {
      *
      * * `token: &str` -- Authentication token. Requires scope: `admin.conversations:write`.
      */
-    pub async fn delete(&self) -> Result<crate::types::DndEndSchema> {
+    pub async fn delete(&self) -> Result<crate::types::AdminConversationsDeleteResponse> {
         let url = "/admin.conversations.delete".to_string();
         self.client.post(&url, None).await
     }
@@ -87,7 +89,9 @@ impl AdminConversations {
      *
      * * `token: &str` -- Authentication token. Requires scope: `admin.conversations:write`.
      */
-    pub async fn disconnect_shared(&self) -> Result<crate::types::DndEndSchema> {
+    pub async fn disconnect_shared(
+        &self,
+    ) -> Result<crate::types::AdminConversationsDisconnectSharedResponse> {
         let url = "/admin.conversations.disconnectShared".to_string();
         self.client.post(&url, None).await
     }
@@ -107,7 +111,7 @@ impl AdminConversations {
     pub async fn get_conversation_pref(
         &self,
         channel_id: &str,
-    ) -> Result<crate::types::AdminConversationsGetConversationPrefsSchemaData> {
+    ) -> Result<crate::types::AdminConversationsGetConversationPrefsResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !channel_id.is_empty() {
             query_args.push(("channel_id".to_string(), channel_id.to_string()));
@@ -165,7 +169,7 @@ impl AdminConversations {
      *
      * * `token: &str` -- Authentication token. Requires scope: `admin.conversations:write`.
      */
-    pub async fn invite(&self) -> Result<crate::types::DndEndSchema> {
+    pub async fn invite(&self) -> Result<crate::types::AdminConversationsInviteResponse> {
         let url = "/admin.conversations.invite".to_string();
         self.client.post(&url, None).await
     }
@@ -181,7 +185,7 @@ impl AdminConversations {
      *
      * * `token: &str` -- Authentication token. Requires scope: `admin.conversations:write`.
      */
-    pub async fn rename(&self) -> Result<crate::types::DndEndSchema> {
+    pub async fn rename(&self) -> Result<crate::types::AdminConversationsRenameResponse> {
         let url = "/admin.conversations.rename".to_string();
         self.client.post(&url, None).await
     }
@@ -256,7 +260,9 @@ impl AdminConversations {
      *
      * * `token: &str` -- Authentication token. Requires scope: `admin.conversations:write`.
      */
-    pub async fn set_conversation_prefs(&self) -> Result<crate::types::DndEndSchema> {
+    pub async fn set_conversation_prefs(
+        &self,
+    ) -> Result<crate::types::AdminConversationsSetConversationPrefsResponse> {
         let url = "/admin.conversations.setConversationPrefs".to_string();
         self.client.post(&url, None).await
     }
@@ -272,7 +278,7 @@ impl AdminConversations {
      *
      * * `token: &str` -- Authentication token. Requires scope: `admin.conversations:write`.
      */
-    pub async fn set_teams(&self) -> Result<crate::types::DndEndSchema> {
+    pub async fn set_teams(&self) -> Result<crate::types::AdminConversationsSetTeamsResponse> {
         let url = "/admin.conversations.setTeams".to_string();
         self.client.post(&url, None).await
     }
@@ -288,7 +294,7 @@ impl AdminConversations {
      *
      * * `token: &str` -- Authentication token. Requires scope: `admin.conversations:write`.
      */
-    pub async fn unarchive(&self) -> Result<crate::types::DndEndSchema> {
+    pub async fn unarchive(&self) -> Result<crate::types::AdminConversationsUnarchiveResponse> {
         let url = "/admin.conversations.unarchive".to_string();
         self.client.post(&url, None).await
     }
@@ -33,7 +33,7 @@ impl AdminConversationsEkm {
         team_ids: &str,
         limit: i64,
         cursor: &str,
-    ) -> Result<crate::types::DndEndSchema> {
+    ) -> Result<crate::types::AdminConversationsEkmListOriginalConnectedChannelInfoResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !channel_ids.is_empty() {
             query_args.push(("channel_ids".to_string(), channel_ids.to_string()));
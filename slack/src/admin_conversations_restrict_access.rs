@@ -19,7 +19,9 @@ impl AdminConversationsRestrictAccess {
      *
      * FROM: <https://api.slack.com/methods/admin.conversations.restrictAccess.addGroup>
      */
-    pub async fn add_group(&self) -> Result<crate::types::DndEndSchema> {
+    pub async fn add_group(
+        &self,
+    ) -> Result<crate::types::AdminConversationsRestrictAccessAddGroupResponse> {
         let url = "/admin.conversations.restrictAccess.addGroup".to_string();
         self.client.post(&url, None).await
     }
@@ -41,7 +43,7 @@ impl AdminConversationsRestrictAccess {
         &self,
         channel_id: &str,
         team_id: &str,
-    ) -> Result<crate::types::DndEndSchema> {
+    ) -> Result<crate::types::AdminConversationsRestrictAccessListGroupsResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !channel_id.is_empty() {
             query_args.push(("channel_id".to_string(), channel_id.to_string()));
@@ -62,7 +64,9 @@ impl AdminConversationsRestrictAccess {
      *
      * FROM: <https://api.slack.com/methods/admin.conversations.restrictAccess.removeGroup>
      */
-    pub async fn remove_group(&self) -> Result<crate::types::DndEndSchema> {
+    pub async fn remove_group(
+        &self,
+    ) -> Result<crate::types::AdminConversationsRestrictAccessRemoveGroupResponse> {
         let url = "/admin.conversations.restrictAccess.removeGroup".to_string();
         self.client.post(&url, None).await
     }
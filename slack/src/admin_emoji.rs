@@ -19,7 +19,7 @@ impl AdminEmoji {
      *
      * FROM: <https://api.slack.com/methods/admin.emoji.add>
      */
-    pub async fn add(&self) -> Result<crate::types::DndEndSchema> {
+    pub async fn add(&self) -> Result<crate::types::AdminEmojiAddResponse> {
         let url = "/admin.emoji.add".to_string();
         self.client.post(&url, None).await
     }
@@ -31,7 +31,7 @@ impl AdminEmoji {
      *
      * FROM: <https://api.slack.com/methods/admin.emoji.addAlias>
      */
-    pub async fn add_alias(&self) -> Result<crate::types::DndEndSchema> {
+    pub async fn add_alias(&self) -> Result<crate::types::AdminEmojiAddAliasResponse> {
         let url = "/admin.emoji.addAlias".to_string();
         self.client.post(&url, None).await
     }
@@ -49,7 +49,11 @@ impl AdminEmoji {
      * * `cursor: &str` -- Set `cursor` to `next_cursor` returned by the previous call to list items in the next page.
      * * `limit: i64` -- The maximum number of items to return. Must be between 1 - 1000 both inclusive.
      */
-    pub async fn list(&self, cursor: &str, limit: i64) -> Result<crate::types::DndEndSchema> {
+    pub async fn list(
+        &self,
+        cursor: &str,
+        limit: i64,
+    ) -> Result<crate::types::AdminEmojiListResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !cursor.is_empty() {
             query_args.push(("cursor".to_string(), cursor.to_string()));
@@ -70,7 +74,7 @@ impl AdminEmoji {
      *
      * FROM: <https://api.slack.com/methods/admin.emoji.remove>
      */
-    pub async fn remove(&self) -> Result<crate::types::DndEndSchema> {
+    pub async fn remove(&self) -> Result<crate::types::AdminEmojiRemoveResponse> {
         let url = "/admin.emoji.remove".to_string();
         self.client.post(&url, None).await
     }
@@ -82,7 +86,7 @@ impl AdminEmoji {
      *
      * FROM: <https://api.slack.com/methods/admin.emoji.rename>
      */
-    pub async fn rename(&self) -> Result<crate::types::DndEndSchema> {
+    pub async fn rename(&self) -> Result<crate::types::AdminEmojiRenameResponse> {
         let url = "/admin.emoji.rename".to_string();
         self.client.post(&url, None).await
     }
@@ -26,7 +26,7 @@ impl AdminInviteRequests {
     pub async fn approve(
         &self,
         body: &crate::types::AdminInviteRequestsApproveRequest,
-    ) -> Result<crate::types::DndEndSchema> {
+    ) -> Result<crate::types::AdminInviteRequestsApproveResponse> {
         let url = "/admin.inviteRequests.approve".to_string();
         self.client
             .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
@@ -47,7 +47,7 @@ impl AdminInviteRequests {
     pub async fn deny(
         &self,
         body: &crate::types::AdminInviteRequestsApproveRequest,
-    ) -> Result<crate::types::DndEndSchema> {
+    ) -> Result<crate::types::AdminInviteRequestsDenyResponse> {
         let url = "/admin.inviteRequests.deny".to_string();
         self.client
             .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
@@ -73,7 +73,7 @@ impl AdminInviteRequests {
         team_id: &str,
         cursor: &str,
         limit: i64,
-    ) -> Result<crate::types::DndEndSchema> {
+    ) -> Result<crate::types::AdminInviteRequestsListResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !cursor.is_empty() {
             query_args.push(("cursor".to_string(), cursor.to_string()));
@@ -31,7 +31,7 @@ impl AdminInviteRequestsApproved {
         team_id: &str,
         cursor: &str,
         limit: i64,
-    ) -> Result<crate::types::DndEndSchema> {
+    ) -> Result<crate::types::AdminInviteRequestsApprovedListResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !cursor.is_empty() {
             query_args.push(("cursor".to_string(), cursor.to_string()));
@@ -31,7 +31,7 @@ impl AdminInviteRequestsDenied {
         team_id: &str,
         cursor: &str,
         limit: i64,
-    ) -> Result<crate::types::DndEndSchema> {
+    ) -> Result<crate::types::AdminInviteRequestsDeniedListResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !cursor.is_empty() {
             query_args.push(("cursor".to_string(), cursor.to_string()));
@@ -31,7 +31,7 @@ impl AdminTeamsAdmins {
         limit: i64,
         cursor: &str,
         team_id: &str,
-    ) -> Result<crate::types::DndEndSchema> {
+    ) -> Result<crate::types::AdminTeamsAdminsListResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !cursor.is_empty() {
             query_args.push(("cursor".to_string(), cursor.to_string()));
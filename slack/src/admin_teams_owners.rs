@@ -31,7 +31,7 @@ impl AdminTeamsOwners {
         team_id: &str,
         limit: i64,
         cursor: &str,
-    ) -> Result<crate::types::DndEndSchema> {
+    ) -> Result<crate::types::AdminTeamsOwnersListResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !cursor.is_empty() {
             query_args.push(("cursor".to_string(), cursor.to_string()));
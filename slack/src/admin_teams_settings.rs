@@ -24,7 +24,10 @@ impl AdminTeamsSettings {
      * * `token: &str` -- Authentication token. Requires scope: `admin.teams:read`.
      * * `team_id: &str`
      */
-    pub async fn info(&self, team_id: &str) -> Result<crate::types::DndEndSchema> {
+    pub async fn info(
+        &self,
+        team_id: &str,
+    ) -> Result<crate::types::AdminTeamsSettingsInfoResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !team_id.is_empty() {
             query_args.push(("team_id".to_string(), team_id.to_string()));
@@ -42,7 +45,9 @@ impl AdminTeamsSettings {
      *
      * FROM: <https://api.slack.com/methods/admin.teams.settings.setDefaultChannels>
      */
-    pub async fn set_default_channels(&self) -> Result<crate::types::DndEndSchema> {
+    pub async fn set_default_channels(
+        &self,
+    ) -> Result<crate::types::AdminTeamsSettingsSetDefaultChannelsResponse> {
         let url = "/admin.teams.settings.setDefaultChannels".to_string();
         self.client.post(&url, None).await
     }
@@ -58,7 +63,9 @@ impl AdminTeamsSettings {
      *
      * * `token: &str` -- Authentication token. Requires scope: `admin.teams:write`.
      */
-    pub async fn set_description(&self) -> Result<crate::types::DndEndSchema> {
+    pub async fn set_description(
+        &self,
+    ) -> Result<crate::types::AdminTeamsSettingsSetDescriptionResponse> {
         let url = "/admin.teams.settings.setDescription".to_string();
         self.client.post(&url, None).await
     }
@@ -74,7 +81,9 @@ impl AdminTeamsSettings {
      *
      * * `token: &str` -- Authentication token. Requires scope: `admin.teams:write`.
      */
-    pub async fn set_discoverability(&self) -> Result<crate::types::DndEndSchema> {
+    pub async fn set_discoverability(
+        &self,
+    ) -> Result<crate::types::AdminTeamsSettingsSetDiscoverabilityResponse> {
         let url = "/admin.teams.settings.setDiscoverability".to_string();
         self.client.post(&url, None).await
     }
@@ -86,7 +95,7 @@ impl AdminTeamsSettings {
      *
      * FROM: <https://api.slack.com/methods/admin.teams.settings.setIcon>
      */
-    pub async fn set_icon(&self) -> Result<crate::types::DndEndSchema> {
+    pub async fn set_icon(&self) -> Result<crate::types::AdminTeamsSettingsSetIconResponse> {
         let url = "/admin.teams.settings.setIcon".to_string();
         self.client.post(&url, None).await
     }
@@ -102,7 +111,7 @@ impl AdminTeamsSettings {
      *
      * * `token: &str` -- Authentication token. Requires scope: `admin.teams:write`.
      */
-    pub async fn set_name(&self) -> Result<crate::types::DndEndSchema> {
+    pub async fn set_name(&self) -> Result<crate::types::AdminTeamsSettingsSetNameResponse> {
         let url = "/admin.teams.settings.setName".to_string();
         self.client.post(&url, None).await
     }
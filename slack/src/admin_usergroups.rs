@@ -23,7 +23,7 @@ impl AdminUsergroups {
      *
      * * `token: &str` -- Authentication token. Requires scope: `admin.usergroups:write`.
      */
-    pub async fn add_channels(&self) -> Result<crate::types::DndEndSchema> {
+    pub async fn add_channels(&self) -> Result<crate::types::AdminUsergroupsAddChannelsResponse> {
         let url = "/admin.usergroups.addChannels".to_string();
         self.client.post(&url, None).await
     }
@@ -39,7 +39,7 @@ impl AdminUsergroups {
      *
      * * `token: &str` -- Authentication token. Requires scope: `admin.teams:write`.
      */
-    pub async fn add_teams(&self) -> Result<crate::types::DndEndSchema> {
+    pub async fn add_teams(&self) -> Result<crate::types::AdminUsergroupsAddTeamsResponse> {
         let url = "/admin.usergroups.addTeams".to_string();
         self.client.post(&url, None).await
     }
@@ -63,7 +63,7 @@ impl AdminUsergroups {
         usergroup_id: &str,
         team_id: &str,
         include_num_members: bool,
-    ) -> Result<crate::types::DndEndSchema> {
+    ) -> Result<crate::types::AdminUsergroupsListChannelsResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if include_num_members {
             query_args.push((
@@ -94,7 +94,9 @@ impl AdminUsergroups {
      *
      * * `token: &str` -- Authentication token. Requires scope: `admin.usergroups:write`.
      */
-    pub async fn remove_channels(&self) -> Result<crate::types::DndEndSchema> {
+    pub async fn remove_channels(
+        &self,
+    ) -> Result<crate::types::AdminUsergroupsRemoveChannelsResponse> {
         let url = "/admin.usergroups.removeChannels".to_string();
         self.client.post(&url, None).await
     }
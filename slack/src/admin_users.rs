@@ -23,7 +23,7 @@ impl AdminUsers {
      *
      * * `token: &str` -- Authentication token. Requires scope: `admin.users:write`.
      */
-    pub async fn assign(&self) -> Result<crate::types::DndEndSchema> {
+    pub async fn assign(&self) -> Result<crate::types::AdminUsersAssignResponse> {
         let url = "/admin.users.assign".to_string();
         self.client.post(&url, None).await
     }
@@ -39,7 +39,7 @@ impl AdminUsers {
      *
      * * `token: &str` -- Authentication token. Requires scope: `admin.users:write`.
      */
-    pub async fn invite(&self) -> Result<crate::types::DndEndSchema> {
+    pub async fn invite(&self) -> Result<crate::types::AdminUsersInviteResponse> {
         let url = "/admin.users.invite".to_string();
         self.client.post(&url, None).await
     }
@@ -63,7 +63,7 @@ impl AdminUsers {
         team_id: &str,
         cursor: &str,
         limit: i64,
-    ) -> Result<crate::types::DndEndSchema> {
+    ) -> Result<crate::types::AdminUsersListResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !cursor.is_empty() {
             query_args.push(("cursor".to_string(), cursor.to_string()));
@@ -91,7 +91,7 @@ impl AdminUsers {
      *
      * * `token: &str` -- Authentication token. Requires scope: `admin.users:write`.
      */
-    pub async fn remove(&self) -> Result<crate::types::DndEndSchema> {
+    pub async fn remove(&self) -> Result<crate::types::AdminUsersRemoveResponse> {
         let url = "/admin.users.remove".to_string();
         self.client.post(&url, None).await
     }
@@ -107,7 +107,7 @@ impl AdminUsers {
      *
      * * `token: &str` -- Authentication token. Requires scope: `admin.users:write`.
      */
-    pub async fn set(&self) -> Result<crate::types::DndEndSchema> {
+    pub async fn set(&self) -> Result<crate::types::AdminUsersSetResponse> {
         let url = "/admin.users.setAdmin".to_string();
         self.client.post(&url, None).await
     }
@@ -123,7 +123,7 @@ impl AdminUsers {
      *
      * * `token: &str` -- Authentication token. Requires scope: `admin.users:write`.
      */
-    pub async fn set_expiration(&self) -> Result<crate::types::DndEndSchema> {
+    pub async fn set_expiration(&self) -> Result<crate::types::AdminUsersSetExpirationResponse> {
         let url = "/admin.users.setExpiration".to_string();
         self.client.post(&url, None).await
     }
@@ -139,7 +139,7 @@ impl AdminUsers {
      *
      * * `token: &str` -- Authentication token. Requires scope: `admin.users:write`.
      */
-    pub async fn set_owner(&self) -> Result<crate::types::DndEndSchema> {
+    pub async fn set_owner(&self) -> Result<crate::types::AdminUsersSetOwnerResponse> {
         let url = "/admin.users.setOwner".to_string();
         self.client.post(&url, None).await
     }
@@ -155,7 +155,7 @@ impl AdminUsers {
      *
      * * `token: &str` -- Authentication token. Requires scope: `admin.users:write`.
      */
-    pub async fn set_regular(&self) -> Result<crate::types::DndEndSchema> {
+    pub async fn set_regular(&self) -> Result<crate::types::AdminUsersSetRegularResponse> {
         let url = "/admin.users.setRegular".to_string();
         self.client.post(&url, None).await
     }
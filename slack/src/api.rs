@@ -24,7 +24,7 @@ impl Api {
      * * `error: &str` -- Error response to return.
      * * `foo_: &str` -- example property to return.
      */
-    pub async fn test(&self, error: &str, foo_: &str) -> Result<crate::types::DndEndSchema> {
+    pub async fn test(&self, error: &str, foo_: &str) -> Result<crate::types::ApiTestResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !error.is_empty() {
             query_args.push(("error".to_string(), error.to_string()));
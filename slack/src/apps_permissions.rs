@@ -45,7 +45,7 @@ impl AppsPermissions {
         &self,
         scopes: &str,
         trigger_id: &str,
-    ) -> Result<crate::types::DndEndSchema> {
+    ) -> Result<crate::types::AppsPermissionsRequestResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !scopes.is_empty() {
             query_args.push(("scopes".to_string(), scopes.to_string()));
@@ -23,7 +23,7 @@ impl Calls {
      *
      * * `token: &str` -- Authentication token. Requires scope: `calls:write`.
      */
-    pub async fn add(&self) -> Result<crate::types::DndEndSchema> {
+    pub async fn add(&self) -> Result<crate::types::CallsAddResponse> {
         let url = "/calls.add".to_string();
         self.client.post(&url, None).await
     }
@@ -39,7 +39,7 @@ impl Calls {
      *
      * * `token: &str` -- Authentication token. Requires scope: `calls:write`.
      */
-    pub async fn end(&self) -> Result<crate::types::DndEndSchema> {
+    pub async fn end(&self) -> Result<crate::types::CallsEndResponse> {
         let url = "/calls.end".to_string();
         self.client.post(&url, None).await
     }
@@ -56,7 +56,7 @@ impl Calls {
      * * `token: &str` -- Authentication token. Requires scope: `calls:read`.
      * * `id: &str` -- `id` of the Call returned by the [`calls.add`](/methods/calls.add) method.
      */
-    pub async fn info(&self, id: &str) -> Result<crate::types::DndEndSchema> {
+    pub async fn info(&self, id: &str) -> Result<crate::types::CallsInfoResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !id.is_empty() {
             query_args.push(("id".to_string(), id.to_string()));
@@ -78,7 +78,7 @@ impl Calls {
      *
      * * `token: &str` -- Authentication token. Requires scope: `calls:write`.
      */
-    pub async fn update(&self) -> Result<crate::types::DndEndSchema> {
+    pub async fn update(&self) -> Result<crate::types::CallsUpdateResponse> {
         let url = "/calls.update".to_string();
         self.client.post(&url, None).await
     }
@@ -23,7 +23,7 @@ impl CallsParticipants {
      *
      * * `token: &str` -- Authentication token. Requires scope: `calls:write`.
      */
-    pub async fn add(&self) -> Result<crate::types::DndEndSchema> {
+    pub async fn add(&self) -> Result<crate::types::CallsParticipantsAddResponse> {
         let url = "/calls.participants.add".to_string();
         self.client.post(&url, None).await
     }
@@ -39,7 +39,7 @@ impl CallsParticipants {
      *
      * * `token: &str` -- Authentication token. Requires scope: `calls:write`.
      */
-    pub async fn remove(&self) -> Result<crate::types::DndEndSchema> {
+    pub async fn remove(&self) -> Result<crate::types::CallsParticipantsRemoveResponse> {
         let url = "/calls.participants.remove".to_string();
         self.client.post(&url, None).await
     }
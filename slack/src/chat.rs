@@ -39,7 +39,9 @@ impl Chat {
      *
      * * `token: &str` -- Authentication token. Requires scope: `chat:write`.
      */
-    pub async fn delete_scheduled_message(&self) -> Result<crate::types::DndEndSchema> {
+    pub async fn delete_scheduled_message(
+        &self,
+    ) -> Result<crate::types::ChatDeleteScheduledMessageResponse> {
         let url = "/chat.deleteScheduledMessage".to_string();
         self.client.post(&url, None).await
     }
@@ -150,7 +152,7 @@ impl Chat {
      *
      * * `token: &str` -- Authentication token. Requires scope: `links:write`.
      */
-    pub async fn unfurl(&self) -> Result<crate::types::DndEndSchema> {
+    pub async fn unfurl(&self) -> Result<crate::types::ChatUnfurlResponse> {
         let url = "/chat.unfurl".to_string();
         self.client.post(&url, None).await
     }
@@ -23,7 +23,7 @@ impl Conversations {
      *
      * * `token: &str` -- Authentication token. Requires scope: `conversations:write`.
      */
-    pub async fn archive(&self) -> Result<crate::types::DndEndSchema> {
+    pub async fn archive(&self) -> Result<crate::types::ConversationsArchiveResponse> {
         let url = "/conversations.archive".to_string();
         self.client.post(&url, None).await
     }
@@ -55,7 +55,7 @@ impl Conversations {
      *
      * * `token: &str` -- Authentication token. Requires scope: `conversations:write`.
      */
-    pub async fn create(&self) -> Result<crate::types::ConversationsInfoSuccessSchema> {
+    pub async fn create(&self) -> Result<crate::types::ConversationsCreateResponse> {
         let url = "/conversations.create".to_string();
         self.client.post(&url, None).await
     }
@@ -161,7 +161,7 @@ impl Conversations {
      *
      * * `token: &str` -- Authentication token. Requires scope: `conversations:write`.
      */
-    pub async fn invite(&self) -> Result<crate::types::ConversationsInfoSuccessSchema> {
+    pub async fn invite(&self) -> Result<crate::types::ConversationsInviteResponse> {
         let url = "/conversations.invite".to_string();
         self.client.post(&url, None).await
     }
@@ -193,7 +193,7 @@ impl Conversations {
      *
      * * `token: &str` -- Authentication token. Requires scope: `conversations:write`.
      */
-    pub async fn kick(&self) -> Result<crate::types::DndEndSchema> {
+    pub async fn kick(&self) -> Result<crate::types::ConversationsKickResponse> {
         let url = "/conversations.kick".to_string();
         self.client.post(&url, None).await
     }
@@ -266,7 +266,7 @@ impl Conversations {
      *
      * * `token: &str` -- Authentication token. Requires scope: `conversations:write`.
      */
-    pub async fn mark(&self) -> Result<crate::types::DndEndSchema> {
+    pub async fn mark(&self) -> Result<crate::types::ConversationsMarkResponse> {
         let url = "/conversations.mark".to_string();
         self.client.post(&url, None).await
     }
@@ -334,7 +334,7 @@ impl Conversations {
      *
      * * `token: &str` -- Authentication token. Requires scope: `conversations:write`.
      */
-    pub async fn rename(&self) -> Result<crate::types::ConversationsInfoSuccessSchema> {
+    pub async fn rename(&self) -> Result<crate::types::ConversationsRenameResponse> {
         let url = "/conversations.rename".to_string();
         self.client.post(&url, None).await
     }
@@ -406,7 +406,7 @@ impl Conversations {
      *
      * * `token: &str` -- Authentication token. Requires scope: `conversations:write`.
      */
-    pub async fn set_purpose(&self) -> Result<crate::types::ConversationsInfoSuccessSchema> {
+    pub async fn set_purpose(&self) -> Result<crate::types::ConversationsSetPurposeResponse> {
         let url = "/conversations.setPurpose".to_string();
         self.client.post(&url, None).await
     }
@@ -422,7 +422,7 @@ impl Conversations {
      *
      * * `token: &str` -- Authentication token. Requires scope: `conversations:write`.
      */
-    pub async fn set_topic(&self) -> Result<crate::types::ConversationsInfoSuccessSchema> {
+    pub async fn set_topic(&self) -> Result<crate::types::ConversationsSetTopicResponse> {
         let url = "/conversations.setTopic".to_string();
         self.client.post(&url, None).await
     }
@@ -438,7 +438,7 @@ impl Conversations {
      *
      * * `token: &str` -- Authentication token. Requires scope: `conversations:write`.
      */
-    pub async fn unarchive(&self) -> Result<crate::types::DndEndSchema> {
+    pub async fn unarchive(&self) -> Result<crate::types::ConversationsUnarchiveResponse> {
         let url = "/conversations.unarchive".to_string();
         self.client.post(&url, None).await
     }
@@ -25,7 +25,11 @@ impl Dialog {
      * * `dialog: &str` -- The dialog definition. This must be a JSON-encoded string.
      * * `trigger_id: &str` -- Exchange a trigger to post to the user.
      */
-    pub async fn open(&self, dialog: &str, trigger_id: &str) -> Result<crate::types::DndEndSchema> {
+    pub async fn open(
+        &self,
+        dialog: &str,
+        trigger_id: &str,
+    ) -> Result<crate::types::DialogOpenResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !dialog.is_empty() {
             query_args.push(("dialog".to_string(), dialog.to_string()));
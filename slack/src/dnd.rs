@@ -91,7 +91,7 @@ impl Dnd {
      * * `token: &str` -- Authentication token. Requires scope: `dnd:read`.
      * * `users: &str` -- Comma-separated list of users to fetch Do Not Disturb status for.
      */
-    pub async fn team_info(&self, users: &str) -> Result<crate::types::DndEndSchema> {
+    pub async fn team_info(&self, users: &str) -> Result<crate::types::DndTeamInfoResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !users.is_empty() {
             query_args.push(("users".to_string(), users.to_string()));
@@ -23,7 +23,7 @@ impl Emoji {
      *
      * * `token: &str` -- Authentication token. Requires scope: `emoji:read`.
      */
-    pub async fn list(&self) -> Result<crate::types::DndEndSchema> {
+    pub async fn list(&self) -> Result<crate::types::EmojiListResponse> {
         let url = "/emoji.list".to_string();
         self.client.get(&url, None).await
     }
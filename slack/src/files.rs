@@ -23,7 +23,7 @@ impl Files {
      *
      * * `token: &str` -- Authentication token. Requires scope: `files:write:user`.
      */
-    pub async fn delete(&self) -> Result<crate::types::DndEndSchema> {
+    pub async fn delete(&self) -> Result<crate::types::FilesDeleteResponse> {
         let url = "/files.delete".to_string();
         self.client.post(&url, None).await
     }
@@ -149,7 +149,7 @@ impl Files {
      *
      * * `token: &str` -- Authentication token. Requires scope: `files:write:user`.
      */
-    pub async fn revoke_public_url(&self) -> Result<crate::types::FilesUploadSchema> {
+    pub async fn revoke_public_url(&self) -> Result<crate::types::FilesRevokePublicUrlResponse> {
         let url = "/files.revokePublicURL".to_string();
         self.client.post(&url, None).await
     }
@@ -165,7 +165,7 @@ impl Files {
      *
      * * `token: &str` -- Authentication token. Requires scope: `files:write:user`.
      */
-    pub async fn shared_public_url(&self) -> Result<crate::types::FilesUploadSchema> {
+    pub async fn shared_public_url(&self) -> Result<crate::types::FilesSharedPublicUrlResponse> {
         let url = "/files.sharedPublicURL".to_string();
         self.client.post(&url, None).await
     }
@@ -19,7 +19,7 @@ impl FilesRemote {
      *
      * FROM: <https://api.slack.com/methods/files.remote.add>
      */
-    pub async fn add(&self) -> Result<crate::types::DndEndSchema> {
+    pub async fn add(&self) -> Result<crate::types::FilesRemoteAddResponse> {
         let url = "/files.remote.add".to_string();
         self.client.post(&url, None).await
     }
@@ -37,7 +37,11 @@ impl FilesRemote {
      * * `file: &str` -- Specify a file by providing its ID.
      * * `external_id: &str` -- Creator defined GUID for the file.
      */
-    pub async fn info(&self, file: &str, external_id: &str) -> Result<crate::types::DndEndSchema> {
+    pub async fn info(
+        &self,
+        file: &str,
+        external_id: &str,
+    ) -> Result<crate::types::FilesRemoteInfoResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !external_id.is_empty() {
             query_args.push(("external_id".to_string(), external_id.to_string()));
@@ -74,7 +78,7 @@ impl FilesRemote {
         ts_to: f64,
         limit: i64,
         cursor: &str,
-    ) -> Result<crate::types::DndEndSchema> {
+    ) -> Result<crate::types::FilesRemoteListResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !channel.is_empty() {
             query_args.push(("channel".to_string(), channel.to_string()));
@@ -104,7 +108,7 @@ impl FilesRemote {
      *
      * FROM: <https://api.slack.com/methods/files.remote.remove>
      */
-    pub async fn remove(&self) -> Result<crate::types::DndEndSchema> {
+    pub async fn remove(&self) -> Result<crate::types::FilesRemoteRemoveResponse> {
         let url = "/files.remote.remove".to_string();
         self.client.post(&url, None).await
     }
@@ -128,7 +132,7 @@ impl FilesRemote {
         file: &str,
         external_id: &str,
         channels: &str,
-    ) -> Result<crate::types::DndEndSchema> {
+    ) -> Result<crate::types::FilesRemoteShareResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !channels.is_empty() {
             query_args.push(("channels".to_string(), channels.to_string()));
@@ -152,7 +156,7 @@ impl FilesRemote {
      *
      * FROM: <https://api.slack.com/methods/files.remote.update>
      */
-    pub async fn update(&self) -> Result<crate::types::DndEndSchema> {
+    pub async fn update(&self) -> Result<crate::types::FilesRemoteUpdateResponse> {
         let url = "/files.remote.update".to_string();
         self.client.post(&url, None).await
     }
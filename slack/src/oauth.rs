@@ -34,7 +34,7 @@ impl Oauth {
         code: &str,
         redirect_uri: &str,
         single_channel: bool,
-    ) -> Result<crate::types::DndEndSchema> {
+    ) -> Result<crate::types::OauthAccessResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !client_id.is_empty() {
             query_args.push(("client_id".to_string(), client_id.to_string()));
@@ -79,7 +79,7 @@ impl Oauth {
         code: &str,
         redirect_uri: &str,
         single_channel: bool,
-    ) -> Result<crate::types::DndEndSchema> {
+    ) -> Result<crate::types::OauthTokenResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !client_id.is_empty() {
             query_args.push(("client_id".to_string(), client_id.to_string()));
@@ -32,7 +32,7 @@ impl OauthV2 {
         client_secret: &str,
         code: &str,
         redirect_uri: &str,
-    ) -> Result<crate::types::DndEndSchema> {
+    ) -> Result<crate::types::OauthAccessResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !client_id.is_empty() {
             query_args.push(("client_id".to_string(), client_id.to_string()));
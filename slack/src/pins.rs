@@ -23,7 +23,7 @@ impl Pins {
      *
      * * `token: &str` -- Authentication token. Requires scope: `pins:write`.
      */
-    pub async fn add(&self) -> Result<crate::types::DndEndSchema> {
+    pub async fn add(&self) -> Result<crate::types::PinsAddResponse> {
         let url = "/pins.add".to_string();
         self.client.post(&url, None).await
     }
@@ -85,7 +85,7 @@ impl Pins {
      *
      * * `token: &str` -- Authentication token. Requires scope: `pins:write`.
      */
-    pub async fn remove(&self) -> Result<crate::types::DndEndSchema> {
+    pub async fn remove(&self) -> Result<crate::types::PinsRemoveResponse> {
         let url = "/pins.remove".to_string();
         self.client.post(&url, None).await
     }
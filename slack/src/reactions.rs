@@ -23,7 +23,7 @@ impl Reactions {
      *
      * * `token: &str` -- Authentication token. Requires scope: `reactions:write`.
      */
-    pub async fn add(&self) -> Result<crate::types::DndEndSchema> {
+    pub async fn add(&self) -> Result<crate::types::ReactionsAddResponse> {
         let url = "/reactions.add".to_string();
         self.client.post(&url, None).await
     }
@@ -136,7 +136,7 @@ impl Reactions {
      *
      * * `token: &str` -- Authentication token. Requires scope: `reactions:write`.
      */
-    pub async fn remove(&self) -> Result<crate::types::DndEndSchema> {
+    pub async fn remove(&self) -> Result<crate::types::ReactionsRemoveResponse> {
         let url = "/reactions.remove".to_string();
         self.client.post(&url, None).await
     }
@@ -39,7 +39,7 @@ impl Reminders {
      *
      * * `token: &str` -- Authentication token. Requires scope: `reminders:write`.
      */
-    pub async fn complete(&self) -> Result<crate::types::DndEndSchema> {
+    pub async fn complete(&self) -> Result<crate::types::RemindersCompleteResponse> {
         let url = "/reminders.complete".to_string();
         self.client.post(&url, None).await
     }
@@ -55,7 +55,7 @@ impl Reminders {
      *
      * * `token: &str` -- Authentication token. Requires scope: `reminders:write`.
      */
-    pub async fn delete(&self) -> Result<crate::types::DndEndSchema> {
+    pub async fn delete(&self) -> Result<crate::types::RemindersDeleteResponse> {
         let url = "/reminders.delete".to_string();
         self.client.post(&url, None).await
     }
@@ -72,7 +72,7 @@ impl Reminders {
      * * `token: &str` -- Authentication token. Requires scope: `reminders:read`.
      * * `reminder: &str` -- The ID of the reminder.
      */
-    pub async fn info(&self, reminder: &str) -> Result<crate::types::RemindersAddSchema> {
+    pub async fn info(&self, reminder: &str) -> Result<crate::types::RemindersInfoResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !reminder.is_empty() {
             query_args.push(("reminder".to_string(), reminder.to_string()));
@@ -37,7 +37,7 @@ impl Search {
         query: &str,
         sort: &str,
         sort_dir: &str,
-    ) -> Result<crate::types::DndEndSchema> {
+    ) -> Result<crate::types::SearchMessagesResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if count > 0 {
             query_args.push(("count".to_string(), count.to_string()));
@@ -23,7 +23,7 @@ impl Stars {
      *
      * * `token: &str` -- Authentication token. Requires scope: `stars:write`.
      */
-    pub async fn add(&self) -> Result<crate::types::DndEndSchema> {
+    pub async fn add(&self) -> Result<crate::types::StarsAddResponse> {
         let url = "/stars.add".to_string();
         self.client.post(&url, None).await
     }
@@ -80,7 +80,7 @@ impl Stars {
      *
      * * `token: &str` -- Authentication token. Requires scope: `stars:write`.
      */
-    pub async fn remove(&self) -> Result<crate::types::DndEndSchema> {
+    pub async fn remove(&self) -> Result<crate::types::StarsRemoveResponse> {
         let url = "/stars.remove".to_string();
         self.client.post(&url, None).await
     }
@@ -60,7 +60,10 @@ impl Team {
      * * `token: &str` -- Authentication token. Requires scope: `admin`.
      * * `user: &str` -- A user to retrieve the billable information for. Defaults to all users.
      */
-    pub async fn billable_info(&self, user: &str) -> Result<crate::types::DndEndSchema> {
+    pub async fn billable_info(
+        &self,
+        user: &str,
+    ) -> Result<crate::types::TeamBillableInfoResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !user.is_empty() {
             query_args.push(("user".to_string(), user.to_string()));
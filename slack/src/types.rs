@@ -3630,6 +3630,10 @@ pub struct DndEndSchema {
     pub ok: bool,
 }
 
+pub type AdminConversationsArchiveResponse = DndEndSchema;
+
+pub type AdminConversationsConvertPrivateResponse = DndEndSchema;
+
 /// Schema for successful response of admin.conversations.create
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct AdminConversationsCreateSchema {
@@ -3649,6 +3653,10 @@ pub struct AdminConversationsCreateSchema {
     pub ok: bool,
 }
 
+pub type AdminConversationsDeleteResponse = DndEndSchema;
+
+pub type AdminConversationsDisconnectSharedResponse = DndEndSchema;
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct CanThread {
     #[serde(
@@ -3689,6 +3697,9 @@ pub struct AdminConversationsGetConversationPrefsSchemaData {
     pub prefs: Option<AdminConversationsGetConversationPrefsSchema>,
 }
 
+pub type AdminConversationsGetConversationPrefsResponse =
+    AdminConversationsGetConversationPrefsSchemaData;
+
 /// Schema for successful response of admin.conversations.getTeams
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct AdminConversationsGetTeamsSchema {
@@ -3710,6 +3721,10 @@ pub struct AdminConversationsGetTeamsSchema {
     pub team_ids: Vec<String>,
 }
 
+pub type AdminConversationsInviteResponse = DndEndSchema;
+
+pub type AdminConversationsRenameResponse = DndEndSchema;
+
 /// Schema for successful response of admin.conversations.search
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct AdminConversationsSearchSchema {
@@ -3727,6 +3742,12 @@ pub struct AdminConversationsSearchSchema {
     pub next_cursor: String,
 }
 
+pub type AdminConversationsSetConversationPrefsResponse = DndEndSchema;
+
+pub type AdminConversationsUnarchiveResponse = DndEndSchema;
+
+pub type AppsEventAuthorizationsListResponse = DndEndSchema;
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Im {
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -3779,6 +3800,8 @@ pub struct AppsPermissionsInfoSchema {
     pub ok: bool,
 }
 
+pub type AppsPermissionsRequestResponse = DndEndSchema;
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Resources {
     #[serde(
@@ -3875,6 +3898,10 @@ pub struct ApiPermissionsScopesListSuccessSchema {
     pub scopes: Scopes,
 }
 
+pub type AppsPermissionsUsersListResponse = DndEndSchema;
+
+pub type AppsPermissionsUsersRequestResponse = DndEndSchema;
+
 /// Schema for successful response from auth.revoke method
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct AuthRevokeSchema {
@@ -4000,6 +4027,18 @@ pub struct BotsInfoSchema {
     pub ok: bool,
 }
 
+pub type CallsAddResponse = DndEndSchema;
+
+pub type CallsEndResponse = DndEndSchema;
+
+pub type CallsInfoResponse = DndEndSchema;
+
+pub type CallsParticipantsAddResponse = DndEndSchema;
+
+pub type CallsParticipantsRemoveResponse = DndEndSchema;
+
+pub type CallsUpdateResponse = DndEndSchema;
+
 /// Schema for successful response of chat.delete method
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ChatDeleteSuccessSchema {
@@ -4022,6 +4061,8 @@ pub struct ChatDeleteSuccessSchema {
     pub ts: String,
 }
 
+pub type ChatDeleteScheduledMessageResponse = DndEndSchema;
+
 /// Schema for successful response chat.getPermalink
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ChatGetPermalinkSuccessSchema {
@@ -4237,6 +4278,8 @@ pub struct ChatScheduledMessagesListSchema {
     pub scheduled_messages: Vec<ScheduledMessages>,
 }
 
+pub type ChatUnfurlResponse = DndEndSchema;
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct MessageObject {
     #[serde(
@@ -4285,6 +4328,8 @@ pub struct ChatUpdateSuccessSchema {
     pub ts: String,
 }
 
+pub type ConversationsArchiveResponse = DndEndSchema;
+
 /// Schema for successful response conversations.close method
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ConversationsCloseSuccessSchema {
@@ -4378,6 +4423,8 @@ pub struct ConversationsHistorySuccessSchema {
     pub pin_count: i64,
 }
 
+pub type ConversationsInviteResponse = ConversationsInfoSuccessSchema;
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ResponseMetadata {
     #[serde(
@@ -4418,6 +4465,8 @@ pub struct ConversationsJoinSuccessSchema {
     pub warning: String,
 }
 
+pub type ConversationsKickResponse = DndEndSchema;
+
 /// Schema for successful response from conversations.leave method
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ConversationsLeaveSuccessSchema {
@@ -4457,6 +4506,8 @@ pub struct ConversationsListSuccessSchema {
     pub response_metadata: Option<NewPagingStyle>,
 }
 
+pub type ConversationsMarkResponse = DndEndSchema;
+
 /// Schema for successful response conversations.members method
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ConversationsMembersSuccessSchema {
@@ -4571,6 +4622,8 @@ pub struct ConversationsOpenSuccessSchema {
     pub ok: bool,
 }
 
+pub type ConversationsRenameResponse = ConversationsInfoSuccessSchema;
+
 /// Schema for successful response from conversations.replies method
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ConversationsRepliesSuccessSchema {
@@ -4595,6 +4648,12 @@ pub struct ConversationsRepliesSuccessSchema {
     pub ok: bool,
 }
 
+pub type ConversationsSetPurposeResponse = ConversationsInfoSuccessSchema;
+
+pub type ConversationsSetTopicResponse = ConversationsInfoSuccessSchema;
+
+pub type ConversationsUnarchiveResponse = DndEndSchema;
+
 /// Schema for successful response from dnd.endSnooze method
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct DndEndSnoozeSchema {
@@ -4707,6 +4766,14 @@ pub struct DndSetSnoozeSchema {
     pub snooze_remaining: i64,
 }
 
+pub type DndTeamInfoResponse = DndEndSchema;
+
+pub type EmojiListResponse = DndEndSchema;
+
+pub type FilesCommentsDeleteResponse = DndEndSchema;
+
+pub type FilesDeleteResponse = DndEndSchema;
+
 /// Schema for successful response from files.info method
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct FilesInfoSchema {
@@ -4768,6 +4835,18 @@ pub struct FilesListSchema {
     pub paging: ObjsPaging,
 }
 
+pub type FilesRemoteAddResponse = DndEndSchema;
+
+pub type FilesRemoteInfoResponse = DndEndSchema;
+
+pub type FilesRemoteListResponse = DndEndSchema;
+
+pub type FilesRemoteRemoveResponse = DndEndSchema;
+
+pub type FilesRemoteShareResponse = DndEndSchema;
+
+pub type FilesRemoteUpdateResponse = DndEndSchema;
+
 /// Schema for successful response files.upload method
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct FilesUploadSchema {
@@ -4780,6 +4859,8 @@ pub struct FilesUploadSchema {
     pub ok: bool,
 }
 
+pub type FilesSharedPublicUrlResponse = FilesUploadSchema;
+
 /// Schema for successful response from migration.exchange method
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct MigrationExchangeSuccessSchema {
@@ -4816,6 +4897,12 @@ pub struct MigrationExchangeSuccessSchema {
     pub user_id_map: Option<Fields>,
 }
 
+pub type OauthAccessResponse = DndEndSchema;
+
+pub type OauthTokenResponse = DndEndSchema;
+
+pub type PinsAddResponse = DndEndSchema;
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub enum ItemsType {
     #[serde(rename = "file")]
@@ -4953,6 +5040,10 @@ pub struct PinsListResponse {
     pub ok: bool,
 }
 
+pub type PinsRemoveResponse = DndEndSchema;
+
+pub type ReactionsAddResponse = DndEndSchema;
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PinsListResponseData {
     #[serde(
@@ -5010,6 +5101,8 @@ pub struct ReactionsListSchema {
     pub response_metadata: Vec<ObjsResponseMetadataAnyOf>,
 }
 
+pub type ReactionsRemoveResponse = DndEndSchema;
+
 /// Schema for successful response from reminders.add method
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct RemindersAddSchema {
@@ -5022,6 +5115,12 @@ pub struct RemindersAddSchema {
     pub reminder: ObjsReminder,
 }
 
+pub type RemindersCompleteResponse = DndEndSchema;
+
+pub type RemindersDeleteResponse = DndEndSchema;
+
+pub type RemindersInfoResponse = RemindersAddSchema;
+
 /// Schema for successful response from reminders.list method
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct RemindersListSchema {
@@ -5096,6 +5195,10 @@ pub struct RtmConnectSchema {
     pub url: Option<url::Url>,
 }
 
+pub type SearchMessagesResponse = DndEndSchema;
+
+pub type StarsAddResponse = DndEndSchema;
+
 /// Schema for successful response from stars.list method
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct StarsListSchema {
@@ -5117,6 +5220,8 @@ pub struct StarsListSchema {
     pub paging: Option<ObjsPaging>,
 }
 
+pub type StarsRemoveResponse = DndEndSchema;
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Logins {
     #[serde(
@@ -5199,6 +5304,8 @@ pub struct TeamAccessLogsSchema {
     pub paging: ObjsPaging,
 }
 
+pub type TeamBillableInfoResponse = DndEndSchema;
+
 /// Schema for successful response from team.info method
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct TeamInfoSchema {
@@ -5323,6 +5430,10 @@ pub struct UsergroupsCreateSchema {
     pub usergroup: ObjsSubteam,
 }
 
+pub type UsergroupsDisableResponse = UsergroupsCreateSchema;
+
+pub type UsergroupsEnableResponse = UsergroupsCreateSchema;
+
 /// Schema for successful response from usergroups.list method
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct UsergroupsListSchema {
@@ -5339,6 +5450,8 @@ pub struct UsergroupsListSchema {
     pub usergroups: Vec<ObjsSubteam>,
 }
 
+pub type UsergroupsUpdateResponse = UsergroupsCreateSchema;
+
 /// Schema for successful response from usergroups.users.list method
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct UsergroupsUsersListSchema {
@@ -5355,6 +5468,8 @@ pub struct UsergroupsUsersListSchema {
     pub users: Vec<String>,
 }
 
+pub type UsergroupsUsersUpdateResponse = UsergroupsCreateSchema;
+
 /// Schema for successful response from users.conversations method. Returned conversation objects do not include `num_members` or `is_member`
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct UsersConversationsSuccessSchema {
@@ -5376,6 +5491,8 @@ pub struct UsersConversationsSuccessSchema {
     pub response_metadata: Option<NewPagingStyle>,
 }
 
+pub type UsersDeletePhotoResponse = DndEndSchema;
+
 /// Generated from users.getPresence with shasum e7251aec575d8863f9e0eb38663ae9dc26655f65
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ApiMethodUsersGetPresence {
@@ -5720,6 +5837,8 @@ pub struct UsersListSchema {
     pub response_metadata: Vec<ObjsResponseMetadataAnyOf>,
 }
 
+pub type UsersLookupByEmailResponse = UsersInfoSuccessSchema;
+
 /// Schema for successful response from users.profile.get method
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct UsersProfileGetSchema {
@@ -5759,6 +5878,8 @@ pub struct UsersProfileSetSchema {
     pub username: String,
 }
 
+pub type UsersSetActiveResponse = DndEndSchema;
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct UsersSetPhotoSchemaProfile {
     #[serde(
@@ -5828,3 +5949,117 @@ pub struct UsersSetPhotoSchema {
     #[serde()]
     pub profile: UsersSetPhotoSchemaProfile,
 }
+
+pub type UsersSetPresenceResponse = DndEndSchema;
+
+pub type ViewsOpenResponse = DndEndSchema;
+
+pub type ViewsPublishResponse = DndEndSchema;
+
+pub type ViewsPushResponse = DndEndSchema;
+
+pub type ViewsUpdateResponse = DndEndSchema;
+
+pub type WorkflowsStepCompletedResponse = DndEndSchema;
+
+pub type WorkflowsStepFailedResponse = DndEndSchema;
+
+pub type WorkflowsUpdateStepResponse = DndEndSchema;
+
+pub type AdminAppsApproveResponse = DndEndSchema;
+
+pub type AdminAppsApprovedListResponse = DndEndSchema;
+
+pub type AdminAppsRequestsListResponse = DndEndSchema;
+
+pub type AdminAppsRestrictResponse = DndEndSchema;
+
+pub type AdminAppsRestrictedListResponse = DndEndSchema;
+
+pub type AdminConversationsEkmListOriginalConnectedChannelInfoResponse = DndEndSchema;
+
+pub type AdminConversationsRestrictAccessAddGroupResponse = DndEndSchema;
+
+pub type AdminConversationsRestrictAccessListGroupsResponse = DndEndSchema;
+
+pub type AdminConversationsRestrictAccessRemoveGroupResponse = DndEndSchema;
+
+pub type AdminConversationsSetTeamsResponse = DndEndSchema;
+
+pub type AdminEmojiAddAliasResponse = DndEndSchema;
+
+pub type AdminEmojiAddResponse = DndEndSchema;
+
+pub type AdminEmojiListResponse = DndEndSchema;
+
+pub type AdminEmojiRemoveResponse = DndEndSchema;
+
+pub type AdminEmojiRenameResponse = DndEndSchema;
+
+pub type AdminInviteRequestsApproveResponse = DndEndSchema;
+
+pub type AdminInviteRequestsApprovedListResponse = DndEndSchema;
+
+pub type AdminInviteRequestsDeniedListResponse = DndEndSchema;
+
+pub type AdminInviteRequestsDenyResponse = DndEndSchema;
+
+pub type AdminInviteRequestsListResponse = DndEndSchema;
+
+pub type AdminTeamsAdminsListResponse = DndEndSchema;
+
+pub type AdminTeamsCreateResponse = DndEndSchema;
+
+pub type AdminTeamsListResponse = DndEndSchema;
+
+pub type AdminTeamsOwnersListResponse = DndEndSchema;
+
+pub type AdminTeamsSettingsInfoResponse = DndEndSchema;
+
+pub type AdminTeamsSettingsSetDefaultChannelsResponse = DndEndSchema;
+
+pub type AdminTeamsSettingsSetDescriptionResponse = DndEndSchema;
+
+pub type AdminTeamsSettingsSetDiscoverabilityResponse = DndEndSchema;
+
+pub type AdminTeamsSettingsSetIconResponse = DndEndSchema;
+
+pub type AdminTeamsSettingsSetNameResponse = DndEndSchema;
+
+pub type AdminUsergroupsAddChannelsResponse = DndEndSchema;
+
+pub type AdminUsergroupsAddTeamsResponse = DndEndSchema;
+
+pub type AdminUsergroupsListChannelsResponse = DndEndSchema;
+
+pub type AdminUsergroupsRemoveChannelsResponse = DndEndSchema;
+
+pub type AdminUsersAssignResponse = DndEndSchema;
+
+pub type AdminUsersInviteResponse = DndEndSchema;
+
+pub type AdminUsersListResponse = DndEndSchema;
+
+pub type AdminUsersRemoveResponse = DndEndSchema;
+
+pub type AdminUsersSessionInvalidateResponse = DndEndSchema;
+
+pub type AdminUsersSessionResetResponse = DndEndSchema;
+
+pub type AdminUsersSetExpirationResponse = DndEndSchema;
+
+pub type AdminUsersSetOwnerResponse = DndEndSchema;
+
+pub type AdminUsersSetRegularResponse = DndEndSchema;
+
+pub type AdminUsersSetResponse = DndEndSchema;
+
+pub type ApiTestResponse = DndEndSchema;
+
+pub type AppsUninstallResponse = DndEndSchema;
+
+pub type ConversationsCreateResponse = ConversationsInfoSuccessSchema;
+
+pub type DialogOpenResponse = DndEndSchema;
+
+pub type FilesRevokePublicUrlResponse = FilesUploadSchema;
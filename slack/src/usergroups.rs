@@ -39,7 +39,7 @@ impl Usergroups {
      *
      * * `token: &str` -- Authentication token. Requires scope: `usergroups:write`.
      */
-    pub async fn disable(&self) -> Result<crate::types::UsergroupsCreateSchema> {
+    pub async fn disable(&self) -> Result<crate::types::UsergroupsDisableResponse> {
         let url = "/usergroups.disable".to_string();
         self.client.post(&url, None).await
     }
@@ -55,7 +55,7 @@ impl Usergroups {
      *
      * * `token: &str` -- Authentication token. Requires scope: `usergroups:write`.
      */
-    pub async fn enable(&self) -> Result<crate::types::UsergroupsCreateSchema> {
+    pub async fn enable(&self) -> Result<crate::types::UsergroupsEnableResponse> {
         let url = "/usergroups.enable".to_string();
         self.client.post(&url, None).await
     }
@@ -107,7 +107,7 @@ impl Usergroups {
      *
      * * `token: &str` -- Authentication token. Requires scope: `usergroups:write`.
      */
-    pub async fn update(&self) -> Result<crate::types::UsergroupsCreateSchema> {
+    pub async fn update(&self) -> Result<crate::types::UsergroupsUpdateResponse> {
         let url = "/usergroups.update".to_string();
         self.client.post(&url, None).await
     }
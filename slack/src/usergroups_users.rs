@@ -54,7 +54,7 @@ impl UsergroupsUsers {
      *
      * * `token: &str` -- Authentication token. Requires scope: `usergroups:write`.
      */
-    pub async fn update(&self) -> Result<crate::types::UsergroupsCreateSchema> {
+    pub async fn update(&self) -> Result<crate::types::UsergroupsUsersUpdateResponse> {
         let url = "/usergroups.users.update".to_string();
         self.client.post(&url, None).await
     }
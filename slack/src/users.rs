@@ -65,7 +65,7 @@ impl Users {
      *
      * FROM: <https://api.slack.com/methods/users.deletePhoto>
      */
-    pub async fn delete_photo(&self) -> Result<crate::types::DndEndSchema> {
+    pub async fn delete_photo(&self) -> Result<crate::types::UsersDeletePhotoResponse> {
         let url = "/users.deletePhoto".to_string();
         self.client.post(&url, None).await
     }
@@ -205,7 +205,10 @@ impl Users {
      * * `token: &str` -- Authentication token. Requires scope: `users:read.email`.
      * * `email: &str` -- An email address belonging to a user in the workspace.
      */
-    pub async fn lookup_email(&self, email: &str) -> Result<crate::types::UsersInfoSuccessSchema> {
+    pub async fn lookup_email(
+        &self,
+        email: &str,
+    ) -> Result<crate::types::UsersLookupByEmailResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !email.is_empty() {
             query_args.push(("email".to_string(), email.to_string()));
@@ -227,7 +230,7 @@ impl Users {
      *
      * * `token: &str` -- Authentication token. Requires scope: `users:write`.
      */
-    pub async fn set_active(&self) -> Result<crate::types::DndEndSchema> {
+    pub async fn set_active(&self) -> Result<crate::types::UsersSetActiveResponse> {
         let url = "/users.setActive".to_string();
         self.client.post(&url, None).await
     }
@@ -255,7 +258,7 @@ impl Users {
      *
      * * `token: &str` -- Authentication token. Requires scope: `users:write`.
      */
-    pub async fn set_presence(&self) -> Result<crate::types::DndEndSchema> {
+    pub async fn set_presence(&self) -> Result<crate::types::UsersSetPresenceResponse> {
         let url = "/users.setPresence".to_string();
         self.client.post(&url, None).await
     }
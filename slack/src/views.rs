@@ -25,7 +25,11 @@ impl Views {
      * * `trigger_id: &str` -- Exchange a trigger to post to the user.
      * * `view: &str` -- A [view payload](/reference/surfaces/views). This must be a JSON-encoded string.
      */
-    pub async fn open(&self, trigger_id: &str, view: &str) -> Result<crate::types::DndEndSchema> {
+    pub async fn open(
+        &self,
+        trigger_id: &str,
+        view: &str,
+    ) -> Result<crate::types::ViewsOpenResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !trigger_id.is_empty() {
             query_args.push(("trigger_id".to_string(), trigger_id.to_string()));
@@ -58,7 +62,7 @@ impl Views {
         user_id: &str,
         view: &str,
         hash: &str,
-    ) -> Result<crate::types::DndEndSchema> {
+    ) -> Result<crate::types::ViewsPublishResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !hash.is_empty() {
             query_args.push(("hash".to_string(), hash.to_string()));
@@ -88,7 +92,11 @@ impl Views {
      * * `trigger_id: &str` -- Exchange a trigger to post to the user.
      * * `view: &str` -- A [view payload](/reference/surfaces/views). This must be a JSON-encoded string.
      */
-    pub async fn push(&self, trigger_id: &str, view: &str) -> Result<crate::types::DndEndSchema> {
+    pub async fn push(
+        &self,
+        trigger_id: &str,
+        view: &str,
+    ) -> Result<crate::types::ViewsPushResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !trigger_id.is_empty() {
             query_args.push(("trigger_id".to_string(), trigger_id.to_string()));
@@ -123,7 +131,7 @@ impl Views {
         external_id: &str,
         view: &str,
         hash: &str,
-    ) -> Result<crate::types::DndEndSchema> {
+    ) -> Result<crate::types::ViewsUpdateResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !external_id.is_empty() {
             query_args.push(("external_id".to_string(), external_id.to_string()));
@@ -29,7 +29,7 @@ impl Workflows {
         &self,
         workflow_step_execute_id: &str,
         outputs: &str,
-    ) -> Result<crate::types::DndEndSchema> {
+    ) -> Result<crate::types::WorkflowsStepCompletedResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !outputs.is_empty() {
             query_args.push(("outputs".to_string(), outputs.to_string()));
@@ -63,7 +63,7 @@ impl Workflows {
         &self,
         workflow_step_execute_id: &str,
         error: &str,
-    ) -> Result<crate::types::DndEndSchema> {
+    ) -> Result<crate::types::WorkflowsStepFailedResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !error.is_empty() {
             query_args.push(("error".to_string(), error.to_string()));
@@ -103,7 +103,7 @@ impl Workflows {
         outputs: &str,
         step_name: &str,
         step_image_url: &str,
-    ) -> Result<crate::types::DndEndSchema> {
+    ) -> Result<crate::types::WorkflowsUpdateStepResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !inputs.is_empty() {
             query_args.push(("inputs".to_string(), inputs.to_string()));
@@ -134,12 +134,20 @@ pub mod phone_devices;
 pub mod phone_reports;
 pub mod phone_shared_line_groups;
 pub mod phone_site;
+/// Archive cloud recordings, resuming downloads that were interrupted.
+pub mod recording_downloader;
+/// Export reports over any range of dates, one row at a time.
+pub mod report_export;
 pub mod reports;
 pub mod roles;
 pub mod rooms;
 pub mod rooms_account;
 pub mod rooms_devices;
 pub mod rooms_location;
+/// Schedule meetings with typed recurrence, settings and time zones.
+pub mod scheduling;
+/// Authenticate with the account credentials of a Server-to-Server OAuth app.
+pub mod server_to_server;
 pub mod sip_connected_audio;
 pub mod sip_phone;
 #[cfg(test)]
@@ -150,6 +158,8 @@ pub mod types;
 pub mod users;
 #[doc(hidden)]
 pub mod utils;
+/// Verify and parse the webhooks Zoom sends to apps.
+pub mod webhooks;
 pub mod webinars;
 
 use anyhow::{anyhow, Error, Result};
@@ -194,6 +204,8 @@ pub struct Client {
     redirect_uri: String,
 
     client: reqwest::Client,
+    // Set for Server-to-Server OAuth apps, which get and renew their own tokens.
+    account_credentials: Option<std::sync::Arc<crate::server_to_server::AccountCredentials>>,
 }
 
 use schemars::JsonSchema;
@@ -269,6 +281,7 @@ impl Client {
                     refresh_token: refresh_token.to_string(),
 
                     client: c,
+                    account_credentials: None,
                 }
             }
             Err(e) => panic!("creating reqwest client failed: {:?}", e),
@@ -397,7 +410,13 @@ impl Client {
     async fn url_and_auth(&self, uri: &str) -> Result<(reqwest::Url, Option<String>)> {
         let parsed_url = uri.parse::<reqwest::Url>();
 
-        let auth = format!("Bearer {}", self.token);
+        let auth = format!(
+            "Bearer {}",
+            match &self.account_credentials {
+                Some(credentials) => credentials.token(&self.client).await?,
+                None => self.token.to_string(),
+            }
+        );
         parsed_url.map(|u| (u, Some(auth))).map_err(Error::from)
     }
 
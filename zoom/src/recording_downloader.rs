@@ -0,0 +1,486 @@
+//! Archive cloud recordings, resuming downloads that were interrupted.
+//!
+//! The downloader lists the recordings of a user or an account one month at a
+//! time, since the API doesn't list longer ranges, and streams each file to a
+//! `RecordingStore`. Downloads that fail are retried from the bytes already
+//! stored, with a `Range` request, and files are checked against their
+//! `file_size` once stored.
+//!
+//! ```no_run
+//! # async fn run(zoom: zoom_api::Client) -> anyhow::Result<()> {
+//! use chrono::NaiveDate;
+//! use zoom_api::recording_downloader::{DirectoryStore, RecordingDownloader};
+//! use zoom_api::types::RecordingDeleteAction;
+//!
+//! let report = RecordingDownloader::for_account("me")
+//!     .with_download_access_token()
+//!     .with_delete_after(RecordingDeleteAction::Trash)
+//!     .archive(
+//!         &zoom,
+//!         &DirectoryStore::new("/srv/recordings"),
+//!         "2021-01-01".parse::<NaiveDate>()?,
+//!         "2021-12-31".parse::<NaiveDate>()?,
+//!     )
+//!     .await?;
+//! println!("archived {} files", report.files.len());
+//! # Ok(())
+//! # }
+//! ```
+use std::{
+    collections::HashSet,
+    io::SeekFrom,
+    path::{Path, PathBuf},
+};
+
+use anyhow::{anyhow, bail, Result};
+use chrono::NaiveDate;
+use tokio::io::{AsyncSeekExt, AsyncWriteExt};
+
+use crate::{
+    types::{
+        GetAccountCloudRecordingResponse, GetAccountCloudRecordingResponseMeetings,
+        GetAccountCloudRecordingResponseMeetingsFilesFileType as FileType,
+        GetAccountCloudRecordingResponseMeetingsFilesStatus as FileStatus, RecordingDeleteAction,
+        RecordingFiles,
+    },
+    utils::{date_windows, meeting_path_id},
+};
+
+/// The most recordings the API returns in a page.
+const PAGE_SIZE: i64 = 300;
+
+/// How much of a file to buffer before writing it to the store.
+const WRITE_BUFFER: usize = 8 * 1024 * 1024;
+
+/// How long `download_access_token`s are requested for, in seconds.
+const DOWNLOAD_ACCESS_TOKEN_TTL: u64 = 60 * 60;
+
+/// Where recording files are archived.
+#[async_trait::async_trait]
+pub trait RecordingStore: Send + Sync {
+    /// How many bytes of a file are stored, or 0 if it isn't.
+    async fn stored_len(&self, name: &str) -> Result<u64>;
+
+    /// Write part of a file at `offset`, dropping anything stored after it.
+    async fn write_at(&self, name: &str, offset: u64, chunk: &[u8]) -> Result<()>;
+}
+
+/// Stores recording files in a directory.
+///
+/// Files are written with `tokio::fs`, in chunks of a few megabytes.
+#[derive(Debug, Clone)]
+pub struct DirectoryStore {
+    root: PathBuf,
+}
+
+impl DirectoryStore {
+    pub fn new<P: AsRef<Path>>(root: P) -> Self {
+        DirectoryStore {
+            root: root.as_ref().to_path_buf(),
+        }
+    }
+
+    pub fn path(&self, name: &str) -> PathBuf {
+        self.root.join(name)
+    }
+}
+
+#[async_trait::async_trait]
+impl RecordingStore for DirectoryStore {
+    async fn stored_len(&self, name: &str) -> Result<u64> {
+        match tokio::fs::metadata(self.path(name)).await {
+            Ok(m) => Ok(m.len()),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(0),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    async fn write_at(&self, name: &str, offset: u64, chunk: &[u8]) -> Result<()> {
+        let path = self.path(name);
+        if let Some(parent) = path.parent() {
+            tokio::fs::create_dir_all(parent).await?;
+        }
+        let mut file = tokio::fs::OpenOptions::new()
+            .create(true)
+            .write(true)
+            .truncate(false)
+            .open(path)
+            .await?;
+        file.set_len(offset).await?;
+        file.seek(SeekFrom::Start(offset)).await?;
+        file.write_all(chunk).await?;
+        // Wait for the write to finish, so its errors are returned.
+        file.flush().await?;
+        Ok(())
+    }
+}
+
+/// Whose recordings to archive.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RecordingSource {
+    /// A user, by id or email, or `me`.
+    User(String),
+    /// All the users of an account, or `me` for the account of the app.
+    Account(String),
+}
+
+/// A file that was archived.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ArchivedFile {
+    pub meeting_uuid: String,
+    pub file_id: String,
+    /// The name of the file in the store.
+    pub name: String,
+    pub size: u64,
+}
+
+/// What an archive run did.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ArchiveReport {
+    pub files: Vec<ArchivedFile>,
+    /// The names of the files that were still processing, which are left for a
+    /// later run along with the rest of their meeting.
+    pub processing: Vec<String>,
+    /// The UUIDs of the meetings whose recordings were deleted after they were
+    /// archived.
+    pub deleted: Vec<String>,
+}
+
+/// Downloads the cloud recordings of a user or an account.
+#[derive(Debug, Clone)]
+pub struct RecordingDownloader {
+    pub source: RecordingSource,
+    /// Download with a `download_access_token` of each meeting rather than the
+    /// token of the client, which lets admins download every user's recordings.
+    pub use_download_access_token: bool,
+    /// What to do with the recordings of a meeting once all its files are archived.
+    pub delete_after: Option<RecordingDeleteAction>,
+    /// How many times to try downloading a file, resuming where the last try
+    /// stopped.
+    pub max_attempts: u32,
+}
+
+impl RecordingDownloader {
+    pub fn new(source: RecordingSource) -> Self {
+        RecordingDownloader {
+            source,
+            use_download_access_token: false,
+            delete_after: None,
+            max_attempts: 3,
+        }
+    }
+
+    pub fn for_user(user_id: &str) -> Self {
+        RecordingDownloader::new(RecordingSource::User(user_id.to_string()))
+    }
+
+    pub fn for_account(account_id: &str) -> Self {
+        RecordingDownloader::new(RecordingSource::Account(account_id.to_string()))
+    }
+
+    pub fn with_download_access_token(mut self) -> Self {
+        self.use_download_access_token = true;
+        self
+    }
+
+    pub fn with_delete_after(mut self, action: RecordingDeleteAction) -> Self {
+        self.delete_after = Some(action);
+        self
+    }
+
+    pub fn with_max_attempts(mut self, max_attempts: u32) -> Self {
+        self.max_attempts = max_attempts.max(1);
+        self
+    }
+
+    /// List the recorded meetings from `from` to `to`, inclusive.
+    pub async fn list(
+        &self,
+        client: &crate::Client,
+        from: NaiveDate,
+        to: NaiveDate,
+    ) -> Result<Vec<GetAccountCloudRecordingResponseMeetings>> {
+        let path = match &self.source {
+            RecordingSource::User(id) => format!(
+                "/users/{}/recordings",
+                crate::progenitor_support::encode_path(id)
+            ),
+            RecordingSource::Account(id) => format!(
+                "/accounts/{}/recordings",
+                crate::progenitor_support::encode_path(id)
+            ),
+        };
+
+        let mut seen = HashSet::new();
+        let mut meetings = Vec::new();
+        for (from, to) in date_windows(from, to) {
+            let mut page = String::new();
+            loop {
+                let mut query_args = vec![
+                    ("from".to_string(), from.to_string()),
+                    ("to".to_string(), to.to_string()),
+                    ("page_size".to_string(), PAGE_SIZE.to_string()),
+                ];
+                if !page.is_empty() {
+                    query_args.push(("next_page_token".to_string(), page.to_string()));
+                }
+                let url = format!("{}?{}", path, serde_urlencoded::to_string(&query_args)?);
+                let resp: GetAccountCloudRecordingResponse = client.get(&url, None).await?;
+
+                meetings.extend(
+                    resp.meetings
+                        .into_iter()
+                        .filter(|m| seen.insert(m.uuid.to_string())),
+                );
+
+                if resp.next_page_token.is_empty() || resp.next_page_token == page {
+                    break;
+                }
+                page = resp.next_page_token;
+            }
+        }
+
+        Ok(meetings)
+    }
+
+    /// Archive the recordings of the meetings from `from` to `to`, inclusive.
+    ///
+    /// Files already in the store are skipped, so a run that failed can be
+    /// started again.
+    pub async fn archive<S: RecordingStore>(
+        &self,
+        client: &crate::Client,
+        store: &S,
+        from: NaiveDate,
+        to: NaiveDate,
+    ) -> Result<ArchiveReport> {
+        let mut report = ArchiveReport::default();
+        for meeting in self.list(client, from, to).await? {
+            let download_access_token = if self.use_download_access_token {
+                client
+                    .cloud_recording()
+                    .recording_get(
+                        &meeting_path_id(&meeting.uuid),
+                        "download_access_token",
+                        DOWNLOAD_ACCESS_TOKEN_TTL,
+                    )
+                    .await?
+                    .recording_get_response
+                    .download_access_token
+            } else {
+                String::new()
+            };
+
+            let mut complete = true;
+            for file in &meeting.recording_files {
+                let name = file_name(&meeting, file);
+                if file.status == Some(FileStatus::Processing) {
+                    complete = false;
+                    report.processing.push(name);
+                    continue;
+                }
+
+                let size = self
+                    .download_file(client, store, file, &name, &download_access_token)
+                    .await?;
+                report.files.push(ArchivedFile {
+                    meeting_uuid: meeting.uuid.to_string(),
+                    file_id: file.id.to_string(),
+                    name,
+                    size,
+                });
+            }
+
+            if let (true, Some(action)) = (complete, &self.delete_after) {
+                client
+                    .cloud_recording()
+                    .recording_delete(&meeting_path_id(&meeting.uuid), action.clone())
+                    .await?;
+                report.deleted.push(meeting.uuid.to_string());
+            }
+        }
+
+        Ok(report)
+    }
+
+    /// Download a recording file to the store as `name`, resuming from what's
+    /// already stored, and return its size.
+    ///
+    /// Without a `download_access_token`, the file is downloaded with the token of
+    /// the client.
+    pub async fn download_file<S: RecordingStore>(
+        &self,
+        client: &crate::Client,
+        store: &S,
+        file: &RecordingFiles,
+        name: &str,
+        download_access_token: &str,
+    ) -> Result<u64> {
+        // Some files, like closed captions, don't have a size.
+        let expected = file.file_size as u64;
+
+        let mut attempt = 1;
+        loop {
+            let result = match store.stored_len(name).await? {
+                len if expected > 0 && len == expected => Ok(len),
+                len => {
+                    // Start over if the stored file is larger than it should be.
+                    let offset = if expected > 0 && len > expected {
+                        0
+                    } else {
+                        len
+                    };
+                    self.fetch(client, store, file, name, download_access_token, offset)
+                        .await
+                }
+            }
+            .and_then(|len| {
+                if expected > 0 && len != expected {
+                    Err(anyhow!(
+                        "{} is {} bytes, but the recording is {} bytes",
+                        name,
+                        len,
+                        expected
+                    ))
+                } else {
+                    Ok(len)
+                }
+            });
+
+            match result {
+                Ok(len) => return Ok(len),
+                Err(e) if attempt < self.max_attempts => {
+                    log::warn!("downloading {} failed, retrying: {}", name, e);
+                    attempt += 1;
+                }
+                Err(e) => return Err(e),
+            }
+        }
+    }
+
+    /// Fetch a recording file from `offset`, and return how much of it is stored.
+    async fn fetch<S: RecordingStore>(
+        &self,
+        client: &crate::Client,
+        store: &S,
+        file: &RecordingFiles,
+        name: &str,
+        download_access_token: &str,
+        offset: u64,
+    ) -> Result<u64> {
+        if file.download_url.is_empty() {
+            bail!("{} has no download URL", name);
+        }
+
+        // The download URL redirects to the storage host, which reqwest follows
+        // without the authorization header.
+        let (mut url, auth) = client.url_and_auth(&file.download_url).await?;
+        let mut req = if download_access_token.is_empty() {
+            let req = client.client.get(url);
+            match auth {
+                Some(auth) => req.header(http::header::AUTHORIZATION, auth),
+                None => req,
+            }
+        } else {
+            url.query_pairs_mut()
+                .append_pair("access_token", download_access_token);
+            client.client.get(url)
+        };
+        if offset > 0 {
+            req = req.header(http::header::RANGE, format!("bytes={}-", offset));
+        }
+
+        let mut resp = req.send().await?;
+        let status = resp.status();
+        let mut position = match status {
+            http::StatusCode::PARTIAL_CONTENT => offset,
+            // The range starts at the end of the file, so it's all stored.
+            http::StatusCode::RANGE_NOT_SATISFIABLE if offset > 0 => return Ok(offset),
+            s if s.is_success() => 0,
+            s => bail!(
+                "code: {}, error: {:?}",
+                s,
+                resp.text().await.unwrap_or_default()
+            ),
+        };
+
+        let mut buffer = Vec::with_capacity(WRITE_BUFFER);
+        if position == 0 {
+            // Drop what a server that ignored the range would send again.
+            store.write_at(name, 0, &[]).await?;
+        }
+        while let Some(chunk) = resp.chunk().await? {
+            buffer.extend_from_slice(&chunk);
+            if buffer.len() >= WRITE_BUFFER {
+                store.write_at(name, position, &buffer).await?;
+                position += buffer.len() as u64;
+                buffer.clear();
+            }
+        }
+        if !buffer.is_empty() {
+            store.write_at(name, position, &buffer).await?;
+            position += buffer.len() as u64;
+        }
+
+        Ok(position)
+    }
+}
+
+/// The name to store a recording file as:
+/// `{start date}/{meeting UUID}/{file id}.{extension}`.
+///
+/// UUIDs are made safe for paths by replacing `/` with `_` and `+` with `-`, as
+/// in URL-safe base64.
+pub fn file_name(
+    meeting: &GetAccountCloudRecordingResponseMeetings,
+    file: &RecordingFiles,
+) -> String {
+    let date = meeting
+        .start_time
+        .map(|t| t.format("%Y-%m-%d").to_string())
+        .unwrap_or_else(|| "undated".to_string());
+    let uuid: String = meeting
+        .uuid
+        .trim_end_matches('=')
+        .chars()
+        .map(|c| match c {
+            '/' => '_',
+            '+' => '-',
+            c => c,
+        })
+        .collect();
+    // Closed captions and timelines don't have an id.
+    let stem = if !file.id.is_empty() {
+        file.id.to_string()
+    } else {
+        match &file.recording_type {
+            Some(t) if !t.is_noop() => t.to_string(),
+            _ => file_type_name(&file.file_type),
+        }
+    };
+
+    format!(
+        "{}/{}/{}.{}",
+        date,
+        uuid,
+        stem,
+        file_extension(&file.file_type)
+    )
+}
+
+fn file_type_name(file_type: &Option<FileType>) -> String {
+    file_type
+        .as_ref()
+        .map(|t| t.to_string().to_lowercase())
+        .unwrap_or_default()
+}
+
+fn file_extension(file_type: &Option<FileType>) -> String {
+    match file_type {
+        Some(FileType::Cc) | Some(FileType::Transcript) => "vtt".to_string(),
+        Some(FileType::Chat) => "txt".to_string(),
+        Some(FileType::Tb) => "json".to_string(),
+        Some(FileType::Noop) | Some(FileType::FallthroughString) | None => "bin".to_string(),
+        t => file_type_name(t),
+    }
+}
@@ -0,0 +1,422 @@
+//! Export reports over any range of dates, one row at a time.
+//!
+//! The report endpoints only cover a month at a time and return their rows a page
+//! at a time. `ReportExporter` splits a range of dates into windows the API
+//! accepts, follows `next_page_token` through each window, skips rows it has
+//! already written (a meeting that crosses the end of a window is reported in
+//! both), and writes each row to a `ReportSink`. `CsvSink` writes them as CSV;
+//! other formats implement `ReportSink` over the `serde` rows.
+//!
+//! ```no_run
+//! # async fn run(zoom: zoom_api::Client) -> anyhow::Result<()> {
+//! use chrono::NaiveDate;
+//! use zoom_api::report_export::{CsvSink, ReportExporter};
+//!
+//! let mut sink = CsvSink::new(std::fs::File::create("participants-2021-03.csv")?);
+//! let rows = ReportExporter::new()
+//!     .meeting_participants(
+//!         &zoom,
+//!         "me",
+//!         "2021-03-01".parse::<NaiveDate>()?,
+//!         "2021-03-31".parse::<NaiveDate>()?,
+//!         &mut sink,
+//!     )
+//!     .await?;
+//! println!("exported {} participants", rows);
+//! # Ok(())
+//! # }
+//! ```
+use std::{collections::HashSet, io::Write};
+
+use anyhow::{anyhow, Result};
+use chrono::{DateTime, Datelike, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    types::{
+        DashboardMeetingParticipantsIncludeFields, Meetings, QueryDateType,
+        ReportMeetingParticipantsResponse, ReportMeetingsType,
+    },
+    utils::{date_windows, meeting_path_id},
+};
+
+/// The most rows the report endpoints return in a page.
+const PAGE_SIZE: i64 = 300;
+
+/// Where exported rows are written.
+pub trait ReportSink {
+    fn write_row<R: Serialize>(&mut self, row: &R) -> Result<()>;
+
+    /// Called once all the rows of an export are written.
+    fn finish(&mut self) -> Result<()> {
+        Ok(())
+    }
+}
+
+/// Write rows as CSV, with a header from the field names of the first row.
+pub struct CsvSink<W: Write> {
+    writer: csv::Writer<W>,
+}
+
+impl<W: Write> CsvSink<W> {
+    pub fn new(writer: W) -> Self {
+        CsvSink {
+            writer: csv::Writer::from_writer(writer),
+        }
+    }
+
+    /// Flush the rows and return the writer.
+    pub fn into_inner(self) -> Result<W> {
+        self.writer
+            .into_inner()
+            .map_err(|e| anyhow!("flushing csv: {}", e.error()))
+    }
+}
+
+impl<W: Write> ReportSink for CsvSink<W> {
+    fn write_row<R: Serialize>(&mut self, row: &R) -> Result<()> {
+        Ok(self.writer.serialize(row)?)
+    }
+
+    fn finish(&mut self) -> Result<()> {
+        Ok(self.writer.flush()?)
+    }
+}
+
+/// Collect rows in memory.
+impl ReportSink for Vec<serde_json::Value> {
+    fn write_row<R: Serialize>(&mut self, row: &R) -> Result<()> {
+        self.push(serde_json::to_value(row)?);
+        Ok(())
+    }
+}
+
+/// A participant of a past meeting, with the meeting they joined.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ParticipantRow {
+    pub meeting_uuid: String,
+    pub meeting_id: i64,
+    pub topic: String,
+    pub host_email: String,
+    pub meeting_start_time: Option<DateTime<Utc>>,
+    pub id: String,
+    pub user_id: String,
+    pub name: String,
+    pub user_email: String,
+    pub join_time: Option<DateTime<Utc>>,
+    pub leave_time: Option<DateTime<Utc>>,
+    /// How long the participant was in the meeting, in seconds.
+    pub duration: i64,
+    pub registrant_id: String,
+    pub customer_key: String,
+    pub failover: bool,
+}
+
+impl ParticipantRow {
+    pub fn new(meeting: &Meetings, participant: &ReportMeetingParticipantsResponse) -> Self {
+        ParticipantRow {
+            meeting_uuid: meeting.uuid.to_string(),
+            meeting_id: meeting.id,
+            topic: meeting.topic.to_string(),
+            host_email: meeting.user_email.to_string(),
+            meeting_start_time: meeting.start_time,
+            id: participant.id.to_string(),
+            user_id: participant.user_id.to_string(),
+            name: participant.name.to_string(),
+            user_email: participant.user_email.to_string(),
+            join_time: participant.join_time,
+            leave_time: participant.leave_time,
+            duration: participant.duration,
+            registrant_id: participant.registrant_id.to_string(),
+            customer_key: participant.customer_key.to_string(),
+            failover: participant.failover,
+        }
+    }
+
+    /// What makes a participant row unique: a participant can leave and join a
+    /// meeting again, which reports them once per stay.
+    fn key(&self) -> (String, String, String, Option<DateTime<Utc>>) {
+        let who = if self.id.is_empty() {
+            self.user_id.to_string()
+        } else {
+            self.id.to_string()
+        };
+        (
+            self.meeting_uuid.to_string(),
+            who,
+            self.name.to_string(),
+            self.join_time,
+        )
+    }
+}
+
+/// The usage of the account on a day.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DailyUsageRow {
+    pub date: NaiveDate,
+    pub new_users: i64,
+    pub meetings: i64,
+    pub participants: i64,
+    pub meeting_minutes: i64,
+}
+
+/// A call in the telephone report.
+///
+/// The generated client doesn't have a type for the report, so this is read from
+/// its `telephony_usage`.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TelephoneRow {
+    pub uuid: String,
+    pub meeting_id: i64,
+    pub meeting_type: String,
+    #[serde(rename = "type")]
+    pub type_: String,
+    pub host_name: String,
+    pub host_email: String,
+    pub dept: String,
+    pub phone_number: String,
+    pub call_in_number: String,
+    pub signaled_number: String,
+    pub country_name: String,
+    pub start_time: Option<DateTime<Utc>>,
+    pub end_time: Option<DateTime<Utc>>,
+    /// How long the call was, in minutes.
+    pub duration: i64,
+    pub rate: f64,
+    pub total: f64,
+}
+
+impl TelephoneRow {
+    fn key(&self) -> (String, String, Option<DateTime<Utc>>) {
+        (
+            self.uuid.to_string(),
+            self.phone_number.to_string(),
+            self.start_time,
+        )
+    }
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+struct TelephoneReportPage {
+    next_page_token: String,
+    telephony_usage: Vec<TelephoneRow>,
+}
+
+/// Export reports over ranges of dates longer than the API allows.
+#[derive(Debug, Clone)]
+pub struct ReportExporter {
+    page_size: i64,
+}
+
+impl Default for ReportExporter {
+    fn default() -> Self {
+        ReportExporter::new()
+    }
+}
+
+impl ReportExporter {
+    pub fn new() -> Self {
+        ReportExporter {
+            page_size: PAGE_SIZE,
+        }
+    }
+
+    /// Ask for pages of `page_size` rows, up to 300.
+    pub fn with_page_size(mut self, page_size: i64) -> Self {
+        self.page_size = page_size.clamp(1, PAGE_SIZE);
+        self
+    }
+
+    /// List the past meetings of a user from `from` to `to`, inclusive, once each.
+    pub async fn meetings(
+        &self,
+        client: &crate::Client,
+        user_id: &str,
+        from: NaiveDate,
+        to: NaiveDate,
+    ) -> Result<Vec<Meetings>> {
+        let reports = client.reports();
+        let mut seen = HashSet::new();
+        let mut meetings = Vec::new();
+        for (from, to) in date_windows(from, to) {
+            let mut page = String::new();
+            loop {
+                let resp = reports
+                    .meeting(
+                        user_id,
+                        from,
+                        to,
+                        self.page_size,
+                        &page,
+                        ReportMeetingsType::Past,
+                    )
+                    .await?
+                    .report_meetings_response;
+
+                meetings.extend(
+                    resp.meetings
+                        .into_iter()
+                        .filter(|m| seen.insert(m.uuid.to_string())),
+                );
+
+                if resp.next_page_token.is_empty() || resp.next_page_token == page {
+                    break;
+                }
+                page = resp.next_page_token;
+            }
+        }
+
+        Ok(meetings)
+    }
+
+    /// Write the participants of the past meetings of a user from `from` to `to`,
+    /// inclusive, and return how many were written.
+    pub async fn meeting_participants<S: ReportSink>(
+        &self,
+        client: &crate::Client,
+        user_id: &str,
+        from: NaiveDate,
+        to: NaiveDate,
+        sink: &mut S,
+    ) -> Result<usize> {
+        let reports = client.reports();
+        let mut seen = HashSet::new();
+        for meeting in self.meetings(client, user_id, from, to).await? {
+            let mut page = String::new();
+            loop {
+                let resp = reports
+                    .meeting_participant(
+                        &meeting_path_id(&meeting.uuid),
+                        self.page_size,
+                        &page,
+                        DashboardMeetingParticipantsIncludeFields::RegistrantId,
+                    )
+                    .await?;
+
+                for participant in &resp.report_meeting_participants_response_data.participants {
+                    let row = ParticipantRow::new(&meeting, participant);
+                    if seen.insert(row.key()) {
+                        sink.write_row(&row)?;
+                    }
+                }
+
+                let next = resp.pagination_token.next_page_token;
+                if next.is_empty() || next == page {
+                    break;
+                }
+                page = next;
+            }
+        }
+
+        sink.finish()?;
+        Ok(seen.len())
+    }
+
+    /// Write the usage of the account on each day from `from` to `to`, inclusive,
+    /// and return how many days were written.
+    pub async fn daily_usage<S: ReportSink>(
+        &self,
+        client: &crate::Client,
+        from: NaiveDate,
+        to: NaiveDate,
+        sink: &mut S,
+    ) -> Result<usize> {
+        let reports = client.reports();
+        let mut rows = 0;
+        for (year, month) in months(from, to) {
+            let resp = reports.daily(year as i64, month as i64).await?;
+            for day in resp.dates {
+                let date = match day.date {
+                    Some(date) if from <= date && date <= to => date,
+                    _ => continue,
+                };
+                sink.write_row(&DailyUsageRow {
+                    date,
+                    new_users: day.new_users,
+                    meetings: day.meetings,
+                    participants: day.participants,
+                    meeting_minutes: day.meeting_minutes,
+                })?;
+                rows += 1;
+            }
+        }
+
+        sink.finish()?;
+        Ok(rows)
+    }
+
+    /// Write the calls of an audio type that started from `from` to `to`,
+    /// inclusive, and return how many were written.
+    ///
+    /// The audio type is `1` for toll-free call-in and call-out, `2` for toll and
+    /// `3` for SIP connected audio.
+    pub async fn telephone<S: ReportSink>(
+        &self,
+        client: &crate::Client,
+        audio_type: &str,
+        from: NaiveDate,
+        to: NaiveDate,
+        sink: &mut S,
+    ) -> Result<usize> {
+        let mut seen = HashSet::new();
+        for (from, to) in date_windows(from, to) {
+            let mut page = String::new();
+            loop {
+                let mut query_args = vec![
+                    ("type".to_string(), audio_type.to_string()),
+                    (
+                        "query_date_type".to_string(),
+                        QueryDateType::StartTime.to_string(),
+                    ),
+                    ("from".to_string(), from.to_string()),
+                    ("to".to_string(), to.to_string()),
+                    ("page_size".to_string(), self.page_size.to_string()),
+                ];
+                if !page.is_empty() {
+                    query_args.push(("next_page_token".to_string(), page.to_string()));
+                }
+                let url = format!(
+                    "/report/telephone?{}",
+                    serde_urlencoded::to_string(&query_args)?
+                );
+                let resp: TelephoneReportPage = client.get(&url, None).await?;
+
+                for row in &resp.telephony_usage {
+                    if seen.insert(row.key()) {
+                        sink.write_row(row)?;
+                    }
+                }
+
+                if resp.next_page_token.is_empty() || resp.next_page_token == page {
+                    break;
+                }
+                page = resp.next_page_token;
+            }
+        }
+
+        sink.finish()?;
+        Ok(seen.len())
+    }
+}
+
+/// The years and months from `from` to `to`, inclusive.
+pub fn months(from: NaiveDate, to: NaiveDate) -> Vec<(i32, u32)> {
+    let mut months = Vec::new();
+    if from > to {
+        return months;
+    }
+
+    let (mut year, mut month) = (from.year(), from.month());
+    while (year, month) <= (to.year(), to.month()) {
+        months.push((year, month));
+        if month == 12 {
+            year += 1;
+            month = 1;
+        } else {
+            month += 1;
+        }
+    }
+    months
+}
@@ -0,0 +1,855 @@
+//! Schedule meetings with typed recurrence, settings and time zones.
+//!
+//! The body of `Meetings::create` describes the kind of a meeting, its recurrence
+//! and its settings with numbers and comma separated strings, and its time zone as
+//! a free string. `MeetingSpec` describes them with types, validates them, converts
+//! to and from the body, and expands the occurrences of a meeting locally, for
+//! example to mirror meetings into a calendar.
+//!
+//! ```no_run
+//! # async fn run(zoom: zoom_api::Client) -> anyhow::Result<()> {
+//! use chrono::{NaiveDate, TimeZone, Weekday};
+//! use zoom_api::scheduling::{MeetingKind, MeetingSpec, Recurrence, RecurrenceEnd};
+//!
+//! let tz = chrono_tz::Europe::London;
+//! let start = tz
+//!     .from_local_datetime(&"2021-03-01".parse::<NaiveDate>()?.and_hms_opt(9, 30, 0).unwrap())
+//!     .unwrap();
+//! let mut spec = MeetingSpec::new("Standup", start, chrono::Duration::minutes(15));
+//! spec.kind = MeetingKind::Recurring(Recurrence::weekly(
+//!     1,
+//!     vec![Weekday::Mon, Weekday::Wed, Weekday::Fri],
+//!     RecurrenceEnd::Times(30),
+//! ));
+//!
+//! let meeting = spec.create(&zoom, "me").await?;
+//! println!("{}", meeting.meeting_info.join_url);
+//! # Ok(())
+//! # }
+//! ```
+use std::convert::TryFrom;
+
+use anyhow::{anyhow, bail, Result};
+use chrono::{
+    DateTime, Datelike, Duration, NaiveDate, NaiveDateTime, Offset, TimeZone, Utc, Weekday,
+};
+use chrono_tz::Tz;
+use serde_json::{json, Value};
+
+use crate::types;
+
+/// The longest a daily meeting can go between occurrences, in days.
+pub const MAX_DAILY_INTERVAL: u32 = 90;
+/// The longest a weekly meeting can go between occurrences, in weeks.
+pub const MAX_WEEKLY_INTERVAL: u32 = 12;
+/// The longest a monthly meeting can go between occurrences, in months.
+pub const MAX_MONTHLY_INTERVAL: u32 = 3;
+/// The most occurrences a recurring meeting can have.
+pub const MAX_END_TIMES: u32 = 60;
+
+const MAX_TOPIC_LEN: usize = 200;
+const MAX_PASSWORD_LEN: usize = 10;
+
+/// The kind of a meeting, which is its `type` in the API.
+#[derive(Debug, Clone, PartialEq)]
+pub enum MeetingKind {
+    /// A meeting that starts when it is created.
+    Instant,
+    /// A meeting at a set time.
+    Scheduled,
+    /// A recurring meeting that can be started at any time.
+    RecurringNoFixedTime,
+    /// A recurring meeting at set times.
+    Recurring(Recurrence),
+}
+
+impl MeetingKind {
+    /// The `type` of the meeting in the API.
+    pub fn type_number(&self) -> i64 {
+        match self {
+            MeetingKind::Instant => 1,
+            MeetingKind::Scheduled => 2,
+            MeetingKind::RecurringNoFixedTime => 3,
+            MeetingKind::Recurring(_) => 8,
+        }
+    }
+
+    /// Returns if the meeting starts at a set time.
+    pub fn has_start(&self) -> bool {
+        matches!(self, MeetingKind::Scheduled | MeetingKind::Recurring(_))
+    }
+}
+
+/// How a recurring meeting repeats.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Repeat {
+    Daily,
+    /// On these days of every week the interval falls on. Weeks start on Sunday.
+    Weekly(Vec<Weekday>),
+    /// On this day of every month the interval falls on. Months without the day
+    /// are skipped.
+    MonthlyOnDay(u32),
+    /// On a day of a week of every month the interval falls on, like the last
+    /// Friday.
+    MonthlyOnWeekday(MonthWeek, Weekday),
+}
+
+/// A week of a month, for meetings that repeat on a day of it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MonthWeek {
+    First,
+    Second,
+    Third,
+    Fourth,
+    Last,
+}
+
+impl MonthWeek {
+    /// The `monthly_week` of the recurrence in the API.
+    pub fn number(&self) -> i64 {
+        match self {
+            MonthWeek::First => 1,
+            MonthWeek::Second => 2,
+            MonthWeek::Third => 3,
+            MonthWeek::Fourth => 4,
+            MonthWeek::Last => -1,
+        }
+    }
+
+    pub fn from_number(n: i64) -> Result<Self> {
+        Ok(match n {
+            1 => MonthWeek::First,
+            2 => MonthWeek::Second,
+            3 => MonthWeek::Third,
+            4 => MonthWeek::Fourth,
+            -1 => MonthWeek::Last,
+            _ => bail!("invalid monthly week: {}", n),
+        })
+    }
+}
+
+/// When a recurring meeting stops.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RecurrenceEnd {
+    /// After this many occurrences.
+    Times(u32),
+    /// After the last occurrence on or before this date, in the time zone of the
+    /// meeting.
+    Until(DateTime<Utc>),
+}
+
+/// The recurrence of a meeting at set times.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Recurrence {
+    pub repeat: Repeat,
+    /// The number of days, weeks or months between occurrences.
+    pub interval: u32,
+    pub end: RecurrenceEnd,
+}
+
+impl Recurrence {
+    pub fn daily(interval: u32, end: RecurrenceEnd) -> Self {
+        Recurrence {
+            repeat: Repeat::Daily,
+            interval,
+            end,
+        }
+    }
+
+    pub fn weekly(interval: u32, days: Vec<Weekday>, end: RecurrenceEnd) -> Self {
+        Recurrence {
+            repeat: Repeat::Weekly(days),
+            interval,
+            end,
+        }
+    }
+
+    pub fn monthly_on_day(interval: u32, day: u32, end: RecurrenceEnd) -> Self {
+        Recurrence {
+            repeat: Repeat::MonthlyOnDay(day),
+            interval,
+            end,
+        }
+    }
+
+    pub fn monthly_on_weekday(
+        interval: u32,
+        week: MonthWeek,
+        weekday: Weekday,
+        end: RecurrenceEnd,
+    ) -> Self {
+        Recurrence {
+            repeat: Repeat::MonthlyOnWeekday(week, weekday),
+            interval,
+            end,
+        }
+    }
+
+    /// Check the recurrence is one Zoom accepts.
+    pub fn validate(&self) -> Result<()> {
+        let max_interval = match &self.repeat {
+            Repeat::Daily => MAX_DAILY_INTERVAL,
+            Repeat::Weekly(days) => {
+                if days.is_empty() {
+                    bail!("a weekly recurrence needs at least one day");
+                }
+                MAX_WEEKLY_INTERVAL
+            }
+            Repeat::MonthlyOnDay(day) => {
+                if !(1..=31).contains(day) {
+                    bail!("invalid day of the month: {}", day);
+                }
+                MAX_MONTHLY_INTERVAL
+            }
+            Repeat::MonthlyOnWeekday(..) => MAX_MONTHLY_INTERVAL,
+        };
+        if !(1..=max_interval).contains(&self.interval) {
+            bail!(
+                "the interval must be between 1 and {}, not {}",
+                max_interval,
+                self.interval
+            );
+        }
+        if let RecurrenceEnd::Times(n) = self.end {
+            if !(1..=MAX_END_TIMES).contains(&n) {
+                bail!(
+                    "a recurrence can end after 1 to {} times, not {}",
+                    MAX_END_TIMES,
+                    n
+                );
+            }
+        }
+
+        Ok(())
+    }
+
+    /// The first day of the `k`th period from `start`, and the dates of the
+    /// occurrences in it.
+    fn period(&self, start: NaiveDate, k: i64) -> Option<(NaiveDate, Vec<NaiveDate>)> {
+        let interval = self.interval.max(1) as i64;
+        match &self.repeat {
+            Repeat::Daily => {
+                let date = start.checked_add_signed(Duration::days(k * interval))?;
+                Some((date, vec![date]))
+            }
+            Repeat::Weekly(days) => {
+                let sunday = start.checked_sub_signed(Duration::days(
+                    start.weekday().num_days_from_sunday() as i64,
+                ))?;
+                let first = sunday.checked_add_signed(Duration::weeks(k * interval))?;
+                let mut dates: Vec<NaiveDate> = days
+                    .iter()
+                    .filter_map(|d| {
+                        first.checked_add_signed(Duration::days(d.num_days_from_sunday() as i64))
+                    })
+                    .collect();
+                dates.sort();
+                dates.dedup();
+                Some((first, dates))
+            }
+            Repeat::MonthlyOnDay(day) => {
+                let first = add_months(start, k * interval)?;
+                let dates = NaiveDate::from_ymd_opt(first.year(), first.month(), *day);
+                Some((first, dates.into_iter().collect()))
+            }
+            Repeat::MonthlyOnWeekday(week, weekday) => {
+                let first = add_months(start, k * interval)?;
+                let date = nth_weekday(first, *week, *weekday)?;
+                Some((first, vec![date]))
+            }
+        }
+    }
+}
+
+impl From<&Recurrence> for types::Recurrence {
+    fn from(recurrence: &Recurrence) -> Self {
+        let mut r = types::Recurrence {
+            end_date_time: None,
+            end_times: 0,
+            monthly_day: 0,
+            monthly_week: 0,
+            monthly_week_day: 0,
+            repeat_interval: recurrence.interval as i64,
+            type_: 0,
+            weekly_days: "".to_string(),
+        };
+        match &recurrence.repeat {
+            Repeat::Daily => r.type_ = 1,
+            Repeat::Weekly(days) => {
+                let mut numbers: Vec<i64> = days.iter().map(|d| weekday_number(*d)).collect();
+                numbers.sort_unstable();
+                numbers.dedup();
+                r.type_ = 2;
+                r.weekly_days = numbers
+                    .iter()
+                    .map(|n| n.to_string())
+                    .collect::<Vec<_>>()
+                    .join(",");
+            }
+            Repeat::MonthlyOnDay(day) => {
+                r.type_ = 3;
+                r.monthly_day = *day as i64;
+            }
+            Repeat::MonthlyOnWeekday(week, weekday) => {
+                r.type_ = 3;
+                r.monthly_week = week.number();
+                r.monthly_week_day = weekday_number(*weekday);
+            }
+        }
+        match recurrence.end {
+            RecurrenceEnd::Times(n) => r.end_times = n as i64,
+            RecurrenceEnd::Until(until) => r.end_date_time = Some(until),
+        }
+        r
+    }
+}
+
+impl TryFrom<&types::Recurrence> for Recurrence {
+    type Error = anyhow::Error;
+
+    fn try_from(r: &types::Recurrence) -> Result<Self> {
+        let repeat = match r.type_ {
+            1 => Repeat::Daily,
+            2 => Repeat::Weekly(
+                r.weekly_days
+                    .split(',')
+                    .map(|d| {
+                        d.trim()
+                            .parse::<i64>()
+                            .map_err(|_| anyhow!("invalid weekly days: {}", r.weekly_days))
+                            .and_then(weekday_from_number)
+                    })
+                    .collect::<Result<_>>()?,
+            ),
+            3 if r.monthly_day > 0 => Repeat::MonthlyOnDay(r.monthly_day as u32),
+            3 => Repeat::MonthlyOnWeekday(
+                MonthWeek::from_number(r.monthly_week)?,
+                weekday_from_number(r.monthly_week_day)?,
+            ),
+            t => bail!("invalid recurrence type: {}", t),
+        };
+        // Zoom ends a recurrence after one occurrence unless told otherwise.
+        let end = match r.end_date_time {
+            Some(until) => RecurrenceEnd::Until(until),
+            None => RecurrenceEnd::Times(r.end_times.max(1) as u32),
+        };
+
+        Ok(Recurrence {
+            repeat,
+            interval: r.repeat_interval.max(1) as u32,
+            end,
+        })
+    }
+}
+
+/// Whether registrants need approval, for meetings that need registration.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Approval {
+    Automatic,
+    Manual,
+    NoRegistration,
+}
+
+// `#[derive(Default)]` on enums needs Rust 1.62.
+#[allow(clippy::derivable_impls)]
+impl Default for Approval {
+    fn default() -> Self {
+        Approval::NoRegistration
+    }
+}
+
+impl Approval {
+    /// The `approval_type` of the settings in the API.
+    pub fn number(&self) -> i64 {
+        match self {
+            Approval::Automatic => 0,
+            Approval::Manual => 1,
+            Approval::NoRegistration => 2,
+        }
+    }
+
+    pub fn from_number(n: i64) -> Result<Self> {
+        Ok(match n {
+            0 => Approval::Automatic,
+            1 => Approval::Manual,
+            2 => Approval::NoRegistration,
+            _ => bail!("invalid approval type: {}", n),
+        })
+    }
+}
+
+/// The settings of a meeting. Settings this doesn't cover are left to the
+/// defaults of the user or account.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Settings {
+    pub host_video: bool,
+    pub participant_video: bool,
+    /// How many minutes before the start participants can join without the host,
+    /// with zero for any time, or `None` if they can't.
+    pub join_before_host: Option<u32>,
+    pub mute_upon_entry: bool,
+    pub waiting_room: bool,
+    pub approval: Approval,
+    pub audio: Option<types::Audio>,
+    pub auto_recording: Option<types::AutoRecording>,
+    pub encryption: Option<types::EncryptionType>,
+    /// Only let authenticated users join.
+    pub meeting_authentication: bool,
+    /// The emails or IDs of the alternative hosts.
+    pub alternative_hosts: Vec<String>,
+}
+
+impl Settings {
+    /// Check the settings are ones Zoom accepts.
+    pub fn validate(&self) -> Result<()> {
+        if let Some(minutes) = self.join_before_host {
+            if ![0, 5, 10, 15].contains(&minutes) {
+                bail!(
+                    "participants can join any time or 5, 10 or 15 minutes before the host, \
+                     not {} minutes",
+                    minutes
+                );
+            }
+            if self.waiting_room {
+                bail!("participants can't join before the host with a waiting room");
+            }
+        }
+        for host in &self.alternative_hosts {
+            if host.trim().is_empty() || host.contains(';') || host.contains(',') {
+                bail!("invalid alternative host: {:?}", host);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// The settings of a meeting body, with only the fields these settings cover.
+    ///
+    /// The generated settings would also send every boolean they have, like
+    /// `watermark: false`, and leave out `approval_type` when it is automatic.
+    pub fn to_json(&self) -> Value {
+        let mut settings = json!({
+            "host_video": self.host_video,
+            "participant_video": self.participant_video,
+            "join_before_host": self.join_before_host.is_some(),
+            "mute_upon_entry": self.mute_upon_entry,
+            "waiting_room": self.waiting_room,
+            "approval_type": self.approval.number(),
+            "meeting_authentication": self.meeting_authentication,
+        });
+        if let Some(minutes) = self.join_before_host {
+            settings["jbh_time"] = json!(minutes);
+        }
+        if let Some(audio) = &self.audio {
+            settings["audio"] = json!(audio);
+        }
+        if let Some(auto_recording) = &self.auto_recording {
+            settings["auto_recording"] = json!(auto_recording);
+        }
+        if let Some(encryption) = &self.encryption {
+            settings["encryption_type"] = json!(encryption);
+        }
+        if !self.alternative_hosts.is_empty() {
+            settings["alternative_hosts"] = json!(self.alternative_hosts.join(";"));
+        }
+
+        settings
+    }
+}
+
+/// Read the typed settings from the settings of a meeting body or response, which
+/// have the same fields.
+macro_rules! settings_from {
+    ($s:expr) => {{
+        let s = $s;
+        Ok(Settings {
+            host_video: s.host_video,
+            participant_video: s.participant_video,
+            join_before_host: if s.join_before_host {
+                Some(s.jbh_time.max(0) as u32)
+            } else {
+                None
+            },
+            mute_upon_entry: s.mute_upon_entry,
+            waiting_room: s.waiting_room,
+            approval: Approval::from_number(s.approval_type)?,
+            audio: s.audio.clone(),
+            auto_recording: s.auto_recording.clone(),
+            encryption: s.encryption_type.clone(),
+            meeting_authentication: s.meeting_authentication,
+            alternative_hosts: s
+                .alternative_hosts
+                .split(|c| c == ';' || c == ',')
+                .map(|h| h.trim().to_string())
+                .filter(|h| !h.is_empty())
+                .collect(),
+        })
+    }};
+}
+
+impl TryFrom<&types::MeetingCreateSettings> for Settings {
+    type Error = anyhow::Error;
+
+    fn try_from(s: &types::MeetingCreateSettings) -> Result<Self> {
+        settings_from!(s)
+    }
+}
+
+impl TryFrom<&types::MeetingSettings> for Settings {
+    type Error = anyhow::Error;
+
+    fn try_from(s: &types::MeetingSettings) -> Result<Self> {
+        settings_from!(s)
+    }
+}
+
+/// A meeting to create, or one read back from Zoom.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MeetingSpec {
+    pub topic: String,
+    pub agenda: String,
+    pub kind: MeetingKind,
+    /// The start in the time zone of the meeting, which recurring meetings repeat
+    /// in. Meetings that don't start at a set time have none.
+    pub start: Option<DateTime<Tz>>,
+    pub duration: Duration,
+    pub password: String,
+    pub settings: Settings,
+}
+
+/// An occurrence of a meeting.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Occurrence {
+    pub start: DateTime<Tz>,
+    pub end: DateTime<Tz>,
+}
+
+impl MeetingSpec {
+    /// A meeting scheduled at `start`, with the default settings.
+    pub fn new<T>(topic: T, start: DateTime<Tz>, duration: Duration) -> Self
+    where
+        T: ToString,
+    {
+        MeetingSpec {
+            topic: topic.to_string(),
+            agenda: "".to_string(),
+            kind: MeetingKind::Scheduled,
+            start: Some(start),
+            duration,
+            password: "".to_string(),
+            settings: Default::default(),
+        }
+    }
+
+    /// Check the meeting is one Zoom accepts.
+    pub fn validate(&self) -> Result<()> {
+        if self.topic.chars().count() > MAX_TOPIC_LEN {
+            bail!("the topic is longer than {} characters", MAX_TOPIC_LEN);
+        }
+        if self.password.chars().count() > MAX_PASSWORD_LEN
+            || !self
+                .password
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || "@-_*".contains(c))
+        {
+            bail!(
+                "a password has up to {} letters, digits and @ - _ *",
+                MAX_PASSWORD_LEN
+            );
+        }
+        if self.kind.has_start() {
+            if self.start.is_none() {
+                bail!("a meeting at a set time needs a start");
+            }
+            if self.duration <= Duration::zero() {
+                bail!("a meeting at a set time needs a duration");
+            }
+        }
+        if let MeetingKind::Recurring(recurrence) = &self.kind {
+            recurrence.validate()?;
+            if let (RecurrenceEnd::Until(until), Some(start)) = (recurrence.end, self.start) {
+                if until < start {
+                    bail!("the recurrence ends before the meeting starts");
+                }
+            }
+        }
+
+        self.settings.validate()
+    }
+
+    /// The body to create the meeting with, after validating it. Fields of the
+    /// body this doesn't cover can be set on the result, and settings it doesn't
+    /// cover are left out, so Zoom uses the defaults of the user or account.
+    pub fn to_body(&self) -> Result<Value> {
+        self.validate()?;
+
+        let recurrence = match &self.kind {
+            MeetingKind::Recurring(recurrence) => Some(recurrence.into()),
+            _ => None,
+        };
+        let body = types::MeetingCreate {
+            agenda: self.agenda.to_string(),
+            duration: self.duration.num_minutes(),
+            password: self.password.to_string(),
+            pre_schedule: false,
+            recurrence,
+            schedule_for: "".to_string(),
+            settings: None,
+            start_time: self.start.map(|s| s.with_timezone(&Utc)),
+            template_id: "".to_string(),
+            timezone: self
+                .start
+                .map(|s| s.timezone().name().to_string())
+                .unwrap_or_default(),
+            topic: self.topic.to_string(),
+            tracking_fields: Default::default(),
+            type_: self.kind.type_number(),
+        };
+        let mut body = serde_json::to_value(&body)?;
+        body["settings"] = self.settings.to_json();
+
+        Ok(body)
+    }
+
+    /// Create the meeting for a user, with the body from `to_body`.
+    pub async fn create(
+        &self,
+        zoom: &crate::Client,
+        user_id: &str,
+    ) -> Result<types::MeetingCreateResponseAllOf> {
+        let body = self.to_body()?;
+        let url = format!(
+            "/users/{}/meetings",
+            crate::progenitor_support::encode_path(user_id),
+        );
+
+        zoom.post(&url, Some(reqwest::Body::from(serde_json::to_vec(&body)?)))
+            .await
+    }
+
+    /// Expand the occurrences of the meeting that overlap `time_min..time_max`.
+    /// Meetings that don't start at a set time have none.
+    ///
+    /// Occurrences of recurring meetings are at the local time of the start in the
+    /// time zone of the meeting, so they move with daylight saving time.
+    pub fn occurrences(
+        &self,
+        time_min: DateTime<Utc>,
+        time_max: DateTime<Utc>,
+    ) -> Result<Vec<Occurrence>> {
+        let start = match (&self.kind, self.start) {
+            (MeetingKind::Scheduled, Some(start)) | (MeetingKind::Recurring(_), Some(start)) => {
+                start
+            }
+            _ => return Ok(Vec::new()),
+        };
+        let recurrence = match &self.kind {
+            MeetingKind::Recurring(recurrence) => recurrence,
+            _ => {
+                let end = start + self.duration;
+                if start < time_max && time_min < end {
+                    return Ok(vec![Occurrence { start, end }]);
+                }
+                return Ok(Vec::new());
+            }
+        };
+        recurrence.validate()?;
+
+        let tz = start.timezone();
+        let local = start.naive_local();
+        let (start_date, time) = (local.date(), local.time());
+        let until = match recurrence.end {
+            RecurrenceEnd::Until(until) => Some(until.with_timezone(&tz).naive_local().date()),
+            RecurrenceEnd::Times(_) => None,
+        };
+
+        let mut occurrences = Vec::new();
+        let mut count = 0;
+        'periods: for k in 0.. {
+            let (first, dates) = match recurrence.period(start_date, k) {
+                Some(period) => period,
+                None => break,
+            };
+            if localize(tz, first.and_time(time)) >= time_max {
+                break;
+            }
+
+            for date in dates {
+                if date < start_date {
+                    continue;
+                }
+                if until.map(|u| date > u).unwrap_or(false) {
+                    break 'periods;
+                }
+                let occurrence = localize(tz, date.and_time(time));
+                if occurrence >= time_max {
+                    break 'periods;
+                }
+
+                let end = occurrence + self.duration;
+                if time_min < end {
+                    occurrences.push(Occurrence {
+                        start: occurrence,
+                        end,
+                    });
+                }
+                count += 1;
+                if let RecurrenceEnd::Times(n) = recurrence.end {
+                    if count >= n {
+                        break 'periods;
+                    }
+                }
+            }
+        }
+
+        Ok(occurrences)
+    }
+}
+
+impl TryFrom<&types::MeetingCreate> for MeetingSpec {
+    type Error = anyhow::Error;
+
+    fn try_from(body: &types::MeetingCreate) -> Result<Self> {
+        let (kind, start) = kind_and_start(
+            body.type_,
+            body.recurrence.as_ref(),
+            body.start_time,
+            &body.timezone,
+        )?;
+        let settings = match &body.settings {
+            Some(s) => Settings::try_from(s)?,
+            None => Default::default(),
+        };
+
+        Ok(MeetingSpec {
+            topic: body.topic.to_string(),
+            agenda: body.agenda.to_string(),
+            kind,
+            start,
+            duration: Duration::minutes(body.duration),
+            password: body.password.to_string(),
+            settings,
+        })
+    }
+}
+
+impl TryFrom<&types::MeetingInfoGet> for MeetingSpec {
+    type Error = anyhow::Error;
+
+    fn try_from(meeting: &types::MeetingInfoGet) -> Result<Self> {
+        let (kind, start) = kind_and_start(
+            meeting.type_,
+            meeting.recurrence.as_ref(),
+            meeting.start_time,
+            &meeting.timezone,
+        )?;
+        let settings = match &meeting.settings {
+            Some(s) => Settings::try_from(s)?,
+            None => Default::default(),
+        };
+
+        Ok(MeetingSpec {
+            topic: meeting.topic.to_string(),
+            agenda: meeting.agenda.to_string(),
+            kind,
+            start,
+            duration: Duration::minutes(meeting.duration),
+            password: meeting.password.to_string(),
+            settings,
+        })
+    }
+}
+
+/// Parse an IANA time zone name, like `America/New_York`.
+pub fn parse_time_zone(name: &str) -> Result<Tz> {
+    name.parse()
+        .map_err(|e| anyhow!("invalid time zone {}: {}", name, e))
+}
+
+/// The kind of a meeting and its start in its time zone, or UTC if it has none.
+fn kind_and_start(
+    type_: i64,
+    recurrence: Option<&types::Recurrence>,
+    start_time: Option<DateTime<Utc>>,
+    timezone: &str,
+) -> Result<(MeetingKind, Option<DateTime<Tz>>)> {
+    let kind = match type_ {
+        1 => MeetingKind::Instant,
+        2 => MeetingKind::Scheduled,
+        3 => MeetingKind::RecurringNoFixedTime,
+        8 => MeetingKind::Recurring(Recurrence::try_from(
+            recurrence.ok_or_else(|| anyhow!("a recurring meeting has no recurrence"))?,
+        )?),
+        t => bail!("invalid meeting type: {}", t),
+    };
+    let tz = if timezone.is_empty() {
+        Tz::UTC
+    } else {
+        parse_time_zone(timezone)?
+    };
+
+    Ok((kind, start_time.map(|s| s.with_timezone(&tz))))
+}
+
+/// The number of a day of the week in the API, from 1 for Sunday to 7 for Saturday.
+fn weekday_number(weekday: Weekday) -> i64 {
+    weekday.num_days_from_sunday() as i64 + 1
+}
+
+fn weekday_from_number(n: i64) -> Result<Weekday> {
+    Ok(match n {
+        1 => Weekday::Sun,
+        2 => Weekday::Mon,
+        3 => Weekday::Tue,
+        4 => Weekday::Wed,
+        5 => Weekday::Thu,
+        6 => Weekday::Fri,
+        7 => Weekday::Sat,
+        _ => bail!("invalid day of the week: {}", n),
+    })
+}
+
+/// The first day of the month `months` after the month of `date`.
+fn add_months(date: NaiveDate, months: i64) -> Option<NaiveDate> {
+    let month = (date.year() as i64) * 12 + date.month0() as i64 + months;
+    let year = i32::try_from(month.div_euclid(12)).ok()?;
+    NaiveDate::from_ymd_opt(year, month.rem_euclid(12) as u32 + 1, 1)
+}
+
+/// The day of a week of the month starting on `first`.
+fn nth_weekday(first: NaiveDate, week: MonthWeek, weekday: Weekday) -> Option<NaiveDate> {
+    let weeks = match week {
+        MonthWeek::First => 0,
+        MonthWeek::Second => 1,
+        MonthWeek::Third => 2,
+        MonthWeek::Fourth => 3,
+        MonthWeek::Last => {
+            let last = add_months(first, 1)?.pred_opt()?;
+            return last.checked_sub_signed(Duration::days(days_since(last.weekday(), weekday)));
+        }
+    };
+    first.checked_add_signed(Duration::days(
+        days_since(weekday, first.weekday()) + weeks * 7,
+    ))
+}
+
+/// The number of days from `from` to `weekday`.
+fn days_since(weekday: Weekday, from: Weekday) -> i64 {
+    (weekday.num_days_from_sunday() as i64 - from.num_days_from_sunday() as i64).rem_euclid(7)
+}
+
+/// Resolve a local time in a zone. Ambiguous times resolve to the earlier time, and
+/// times skipped by a transition are moved forward by its length.
+fn localize(tz: Tz, local: NaiveDateTime) -> DateTime<Tz> {
+    match tz.from_local_datetime(&local) {
+        chrono::LocalResult::Single(d) => d,
+        chrono::LocalResult::Ambiguous(earliest, _) => earliest,
+        chrono::LocalResult::None => {
+            // Interpret the time with the offset from before the transition.
+            let before = tz.offset_from_utc_datetime(&(local - Duration::days(1)));
+            tz.from_utc_datetime(
+                &(local - Duration::seconds(before.fix().local_minus_utc() as i64)),
+            )
+        }
+    }
+}
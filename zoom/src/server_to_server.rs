@@ -0,0 +1,173 @@
+//! Authenticate with the account credentials of a Server-to-Server OAuth app.
+//!
+//! Server-to-Server OAuth apps, which replace JWT apps, act on behalf of an
+//! account rather than a user. They get access tokens with their account id,
+//! client id and client secret, and those tokens last an hour and can't be
+//! refreshed. A client created with `Client::new_from_account_credentials` gets
+//! its token on the first request, and gets a new one shortly before it expires.
+//!
+//! ```no_run
+//! # async fn run() -> anyhow::Result<()> {
+//! use zoom_api::{server_to_server::AccountCredentials, Client};
+//!
+//! let zoom = Client::new_from_account_credentials(AccountCredentials::new(
+//!     "account-id",
+//!     "client-id",
+//!     "client-secret",
+//! ));
+//! let user = zoom
+//!     .users()
+//!     .user("me", zoom_api::types::LoginType::Noop, false)
+//!     .await?;
+//! println!("authenticated as {}", user.user.email);
+//! # Ok(())
+//! # }
+//! ```
+use std::{
+    env,
+    sync::RwLock,
+    time::{Duration, Instant},
+};
+
+use anyhow::{anyhow, Result};
+
+use crate::{AccessToken, TOKEN_ENDPOINT};
+
+/// How long before a token expires to get a new one, so requests in flight don't
+/// use an expired token.
+const RENEW_BEFORE: Duration = Duration::from_secs(5 * 60);
+
+/// The account credentials of a Server-to-Server OAuth app, and the token they got
+/// last.
+pub struct AccountCredentials {
+    pub account_id: String,
+    pub client_id: String,
+    pub client_secret: String,
+
+    cached: RwLock<Option<CachedToken>>,
+}
+
+struct CachedToken {
+    access_token: String,
+    expires_at: Instant,
+}
+
+impl std::fmt::Debug for AccountCredentials {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AccountCredentials")
+            .field("account_id", &self.account_id)
+            .field("client_id", &self.client_id)
+            .field("client_secret", &"***")
+            .finish()
+    }
+}
+
+impl AccountCredentials {
+    pub fn new<A, I, S>(account_id: A, client_id: I, client_secret: S) -> Self
+    where
+        A: ToString,
+        I: ToString,
+        S: ToString,
+    {
+        AccountCredentials {
+            account_id: account_id.to_string(),
+            client_id: client_id.to_string(),
+            client_secret: client_secret.to_string(),
+            cached: RwLock::new(None),
+        }
+    }
+
+    /// Read the credentials from the `ZOOM_ACCOUNT_ID`, `ZOOM_CLIENT_ID` and
+    /// `ZOOM_CLIENT_SECRET` environment variables.
+    pub fn new_from_env() -> Self {
+        let account_id = env::var("ZOOM_ACCOUNT_ID").expect("must set ZOOM_ACCOUNT_ID");
+        let client_id = env::var("ZOOM_CLIENT_ID").expect("must set ZOOM_CLIENT_ID");
+        let client_secret = env::var("ZOOM_CLIENT_SECRET").expect("must set ZOOM_CLIENT_SECRET");
+
+        AccountCredentials::new(account_id, client_id, client_secret)
+    }
+
+    /// The last token, if it isn't about to expire.
+    pub fn cached_token(&self) -> Option<String> {
+        let cached = self.cached.read().unwrap_or_else(|e| e.into_inner());
+        cached
+            .as_ref()
+            .filter(|t| Instant::now() + RENEW_BEFORE < t.expires_at)
+            .map(|t| t.access_token.to_string())
+    }
+
+    /// Use a token until it expires, for example one saved by another process.
+    pub fn cache_token(&self, token: &AccessToken) {
+        let expires_in = Duration::from_secs(token.expires_in.max(0) as u64);
+        *self.cached.write().unwrap_or_else(|e| e.into_inner()) = Some(CachedToken {
+            access_token: token.access_token.to_string(),
+            expires_at: Instant::now() + expires_in,
+        });
+    }
+
+    /// Get a new token, and cache it.
+    pub async fn get_access_token(&self, client: &reqwest::Client) -> Result<AccessToken> {
+        let params = [
+            ("grant_type", "account_credentials"),
+            ("account_id", &self.account_id),
+        ];
+        let resp = client
+            .post(TOKEN_ENDPOINT)
+            .header(reqwest::header::ACCEPT, "application/json")
+            .form(&params)
+            .basic_auth(&self.client_id, Some(&self.client_secret))
+            .send()
+            .await?;
+
+        let status = resp.status();
+        if !status.is_success() {
+            return Err(anyhow!(
+                "code: {}, error: {:?}",
+                status,
+                resp.text().await.unwrap_or_default()
+            ));
+        }
+
+        let t: AccessToken = resp.json().await?;
+        self.cache_token(&t);
+
+        Ok(t)
+    }
+
+    /// The cached token, or a new one if it's about to expire.
+    ///
+    /// Concurrent requests that find the token expired each get a new one, and the
+    /// last one is cached.
+    pub async fn token(&self, client: &reqwest::Client) -> Result<String> {
+        if let Some(token) = self.cached_token() {
+            return Ok(token);
+        }
+
+        Ok(self.get_access_token(client).await?.access_token)
+    }
+}
+
+impl crate::Client {
+    /// Create a client for a Server-to-Server OAuth app, which gets its access
+    /// tokens with the account credentials and renews them before they expire.
+    pub fn new_from_account_credentials(credentials: AccountCredentials) -> Self {
+        let mut client = crate::Client::new(
+            &credentials.client_id,
+            &credentials.client_secret,
+            "",
+            "",
+            "",
+        );
+        client.account_credentials = Some(std::sync::Arc::new(credentials));
+        client
+    }
+
+    /// Get a new access token with the account credentials of the client, for
+    /// example to save it for other processes. Requests renew the token themselves.
+    pub async fn get_account_credentials_token(&self) -> Result<AccessToken> {
+        match &self.account_credentials {
+            Some(credentials) => credentials.get_access_token(&self.client).await,
+            None => Err(anyhow!("the client has no account credentials")),
+        }
+    }
+}
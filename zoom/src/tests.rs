@@ -83,3 +83,487 @@ fn test_deserialize_recorded_meetings() {
         deserialized.from.unwrap()
     );
 }
+
+#[test]
+fn test_webhook_signature() {
+    use crate::webhooks::Verifier;
+
+    let verifier = Verifier::new("secret-token");
+    let body = br#"{"event":"meeting.started","event_ts":1626230691572}"#;
+    let now = Utc::now();
+    let headers = vec![
+        ("X-Zm-Request-Timestamp", now.timestamp().to_string()),
+        ("X-Zm-Signature", verifier.sign(now.timestamp(), body)),
+    ];
+
+    verifier.verify_at(headers.clone(), body, now).unwrap();
+    assert!(verifier.verify_at(headers.clone(), b"{}", now).is_err());
+    assert!(Verifier::new("other-token")
+        .verify_at(headers.clone(), body, now)
+        .is_err());
+    // Replays outside of the tolerance are rejected.
+    assert!(verifier
+        .verify_at(headers, body, now + chrono::Duration::minutes(6))
+        .is_err());
+    assert!(verifier
+        .verify_at(vec![("x-zm-signature", "v0=00")], body, now)
+        .is_err());
+
+    assert_eq!(
+        verifier.sign(1626230691, b"{}"),
+        "v0=83136745238cbcb99b0148e56bde7db6b84e9636ba20d5e9d3f8d3de47cf5f75"
+    );
+    let response = Verifier::new("U_DEMO_SECRET").url_validation_response("qgg8vlvZRS6UYooatFL8Aw");
+    assert_eq!(
+        serde_json::to_value(&response).unwrap(),
+        serde_json::json!({
+            "plainToken": "qgg8vlvZRS6UYooatFL8Aw",
+            "encryptedToken": "ac3f1ae530fb50fc934dc95c909b93b6c824d98ac4d890194808824521202c3f",
+        })
+    );
+}
+
+#[test]
+fn test_webhook_events() {
+    use crate::webhooks::{Event, WebhookRequest};
+
+    let request = WebhookRequest::from_slice(
+        br#"{"payload":{"plainToken":"qgg8vlvZRS6UYooatFL8Aw"},"event_ts":1654503849680,"event":"endpoint.url_validation"}"#,
+    )
+    .unwrap();
+    match request.event().unwrap() {
+        Event::UrlValidation { plain_token } => assert_eq!(plain_token, "qgg8vlvZRS6UYooatFL8Aw"),
+        e => panic!("unexpected event {:?}", e),
+    }
+
+    let request = WebhookRequest::from_slice(
+        br#"{
+            "event": "meeting.participant_joined",
+            "event_ts": 1626230691572,
+            "payload": {
+                "account_id": "AAAAAABBBB",
+                "object": {
+                    "id": "1234567890",
+                    "uuid": "4444AAAiAAAAAiAiAiiAii==",
+                    "host_id": "x1yCzABCDEfg23HiJKl4mN",
+                    "topic": "My Meeting",
+                    "type": 2,
+                    "start_time": "2021-07-13T21:44:51Z",
+                    "timezone": "America/Los_Angeles",
+                    "duration": 60,
+                    "participant": {
+                        "user_id": "16778240",
+                        "user_name": "Jill Chill",
+                        "id": "iFxeBPYun6SAiWUzBcEkX",
+                        "email": "jchill@example.com",
+                        "join_time": "2021-07-13T21:45:01Z"
+                    }
+                }
+            }
+        }"#,
+    )
+    .unwrap();
+    match request.event().unwrap() {
+        Event::ParticipantJoined(joined) => {
+            assert_eq!(joined.account_id, "AAAAAABBBB");
+            assert_eq!(joined.object.meeting.id, 1234567890);
+            assert_eq!(joined.object.meeting.type_, 2);
+            assert_eq!(joined.object.participant.user_name, "Jill Chill");
+            assert!(joined.object.participant.join_time.is_some());
+        }
+        e => panic!("unexpected event {:?}", e),
+    }
+
+    let request = WebhookRequest::from_slice(
+        br#"{
+            "event": "recording.completed",
+            "event_ts": 1626230691572,
+            "download_token": "abJhbGciOiJIUzUxMiJ9",
+            "payload": {
+                "account_id": "AAAAAABBBB",
+                "object": {
+                    "id": 1234567890,
+                    "uuid": "4444AAAiAAAAAiAiAiiAii==",
+                    "host_id": "x1yCzABCDEfg23HiJKl4mN",
+                    "topic": "My Personal Meeting Room",
+                    "type": 4,
+                    "start_time": "2021-07-13T21:44:51Z",
+                    "end_time": "",
+                    "host_email": "jchill@example.com",
+                    "total_size": 529758,
+                    "recording_count": 1,
+                    "recording_files": [{
+                        "id": "ed6c2f27-2ae7-42f4-b3d0-835b493e4fa8",
+                        "meeting_id": "098765ABCD",
+                        "recording_start": "2021-03-23T22:14:57Z",
+                        "recording_end": "2021-03-23T23:15:41Z",
+                        "file_type": "M4A",
+                        "file_size": 246560,
+                        "download_url": "https://example.com/recording/download/Qg75t7xZBtEbAkjdlgbfdngBBBB",
+                        "status": "completed",
+                        "recording_type": "audio_only"
+                    }]
+                }
+            }
+        }"#,
+    )
+    .unwrap();
+    match request.event().unwrap() {
+        Event::RecordingCompleted(recording) => {
+            assert_eq!(recording.download_token, "abJhbGciOiJIUzUxMiJ9");
+            assert_eq!(recording.object.meeting.id, 1234567890);
+            assert_eq!(recording.object.meeting.end_time, None);
+            assert_eq!(recording.object.recording_files[0].file_size, 246560.0);
+        }
+        e => panic!("unexpected event {:?}", e),
+    }
+
+    let request = WebhookRequest::from_slice(
+        br#"{
+            "event": "user.created",
+            "event_ts": 1626230691572,
+            "payload": {
+                "account_id": "AAAAAABBBB",
+                "operator": "admin@example.com",
+                "operator_id": "z8yCxjabcdEFGHfp8uQ",
+                "creation_type": "create",
+                "object": {
+                    "id": "z8dfkgABBBBBBBfp8uQ",
+                    "first_name": "Jill",
+                    "last_name": "Chill",
+                    "email": "jchill@example.com",
+                    "type": 1
+                }
+            }
+        }"#,
+    )
+    .unwrap();
+    match request.event().unwrap() {
+        Event::UserCreated(created) => {
+            assert_eq!(created.creation_type, "create");
+            assert_eq!(created.object.email, "jchill@example.com");
+            assert_eq!(created.object.type_, 1);
+        }
+        e => panic!("unexpected event {:?}", e),
+    }
+
+    let request =
+        WebhookRequest::from_slice(br#"{"event":"meeting.deleted","payload":{}}"#).unwrap();
+    match request.event().unwrap() {
+        Event::Other(name) => assert_eq!(name, "meeting.deleted"),
+        e => panic!("unexpected event {:?}", e),
+    }
+}
+
+#[test]
+fn test_account_credentials_token_cache() {
+    use crate::{server_to_server::AccountCredentials, AccessToken};
+
+    let credentials = AccountCredentials::new("account-id", "client-id", "client-secret");
+    assert_eq!(credentials.cached_token(), None);
+
+    credentials.cache_token(&AccessToken {
+        access_token: "fresh".to_string(),
+        expires_in: 3600,
+        ..Default::default()
+    });
+    assert_eq!(credentials.cached_token(), Some("fresh".to_string()));
+
+    // Tokens about to expire are renewed before they are used.
+    credentials.cache_token(&AccessToken {
+        access_token: "stale".to_string(),
+        expires_in: 60,
+        ..Default::default()
+    });
+    assert_eq!(credentials.cached_token(), None);
+
+    let client = crate::Client::new_from_account_credentials(credentials);
+    assert!(format!("{:?}", client.account_credentials).contains("***"));
+}
+
+#[test]
+fn test_recording_date_windows_and_file_names() {
+    use crate::{recording_downloader::file_name, utils::date_windows};
+
+    let date = |s: &str| s.parse::<NaiveDate>().unwrap();
+    let from = date("2021-01-01");
+    let windows = date_windows(from, date("2021-03-15"));
+    assert_eq!(
+        windows,
+        vec![
+            (from, date("2021-01-30")),
+            (date("2021-01-31"), date("2021-03-01")),
+            (date("2021-03-02"), date("2021-03-15")),
+        ]
+    );
+    assert_eq!(date_windows(from, from), vec![(from, from)]);
+    assert!(date_windows(from, date("2020-12-31")).is_empty());
+
+    let mut recordings: crate::types::GetAccountCloudRecordingResponse =
+        serde_json::from_str(RECORDED_MEETINGS).unwrap();
+    let mut meeting = recordings.meetings.remove(0);
+    meeting.uuid = "/ajXp1+2QmuoKj4854875==".to_string();
+    assert_eq!(
+        file_name(&meeting, &meeting.recording_files[0]),
+        "2021-08-15/_ajXp1-2QmuoKj4854875/0b3de97c-4260-436e685.m4a"
+    );
+    let mut captions = meeting.recording_files[1].clone();
+    captions.id = String::new();
+    captions.file_type =
+        Some(crate::types::GetAccountCloudRecordingResponseMeetingsFilesFileType::Cc);
+    assert_eq!(
+        file_name(&meeting, &captions),
+        "2021-08-15/_ajXp1-2QmuoKj4854875/shared_screen_with_speaker_view.vtt"
+    );
+}
+
+#[tokio::test]
+async fn test_recording_download_resumes() {
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+    use crate::recording_downloader::{DirectoryStore, RecordingDownloader, RecordingStore};
+
+    const BODY: &[u8] = b"0123456789abcdefghij";
+
+    // Serve the file, honoring ranges, and report the range of each request.
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    let (ranges_tx, mut ranges_rx) = tokio::sync::mpsc::unbounded_channel();
+    tokio::spawn(async move {
+        while let Ok((mut socket, _)) = listener.accept().await {
+            let mut request = vec![0; 4096];
+            let n = socket.read(&mut request).await.unwrap();
+            let request = String::from_utf8_lossy(&request[..n]).to_lowercase();
+            let start = request
+                .lines()
+                .find_map(|l| l.strip_prefix("range: bytes="))
+                .map(|r| r.trim_end_matches('-').parse::<usize>().unwrap());
+            ranges_tx.send(start).unwrap();
+            let (status, body) = match start {
+                Some(start) => ("206 Partial Content", &BODY[start..]),
+                None => ("200 OK", BODY),
+            };
+            let head = format!(
+                "HTTP/1.1 {}\r\ncontent-length: {}\r\nconnection: close\r\n\r\n",
+                status,
+                body.len()
+            );
+            socket.write_all(head.as_bytes()).await.unwrap();
+            socket.write_all(body).await.unwrap();
+        }
+    });
+
+    let dir = std::env::temp_dir().join(format!("zoom-recordings-{}", uuid::Uuid::new_v4()));
+    let store = DirectoryStore::new(&dir);
+    store
+        .write_at("meeting/file.mp4", 0, &BODY[..8])
+        .await
+        .unwrap();
+
+    let mut recordings: crate::types::GetAccountCloudRecordingResponse =
+        serde_json::from_str(RECORDED_MEETINGS).unwrap();
+    let mut file = recordings.meetings.remove(0).recording_files.remove(0);
+    file.download_url = format!("http://{}/rec/download/file", addr);
+    file.file_size = BODY.len() as f64;
+
+    let client = crate::Client::new("", "", "", "token", "");
+    let downloader = RecordingDownloader::for_user("me");
+    let size = downloader
+        .download_file(&client, &store, &file, "meeting/file.mp4", "")
+        .await
+        .unwrap();
+    assert_eq!(size, BODY.len() as u64);
+    assert_eq!(ranges_rx.recv().await.unwrap(), Some(8));
+    assert_eq!(std::fs::read(store.path("meeting/file.mp4")).unwrap(), BODY);
+
+    // Stored files are not downloaded again.
+    downloader
+        .download_file(&client, &store, &file, "meeting/file.mp4", "")
+        .await
+        .unwrap();
+    assert!(ranges_rx.try_recv().is_err());
+
+    // Files that don't match their size are downloaded again, then rejected.
+    file.file_size = 21.0;
+    store.write_at("meeting/file.mp4", 0, &[]).await.unwrap();
+    assert!(downloader
+        .with_max_attempts(2)
+        .download_file(&client, &store, &file, "meeting/file.mp4", "")
+        .await
+        .is_err());
+    assert_eq!(ranges_rx.recv().await.unwrap(), None);
+    assert_eq!(ranges_rx.recv().await.unwrap(), Some(20));
+
+    std::fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn test_meeting_spec_body_and_occurrences() {
+    use std::convert::TryFrom;
+
+    use chrono::{TimeZone, Weekday};
+
+    use crate::scheduling::{
+        Approval, MeetingKind, MeetingSpec, MonthWeek, Recurrence, RecurrenceEnd, Settings,
+    };
+
+    let tz = chrono_tz::Europe::London;
+    let local = |s: &str| {
+        tz.from_local_datetime(&NaiveDateTime::parse_from_str(s, "%Y-%m-%d %H:%M").unwrap())
+            .unwrap()
+    };
+    let utc = |s: &str| DateTime::parse_from_rfc3339(s).unwrap().with_timezone(&Utc);
+
+    // Weekly on Mondays, Wednesdays and Fridays, across the start of summer time.
+    let mut spec = MeetingSpec::new(
+        "Standup",
+        local("2021-03-22 09:30"),
+        chrono::Duration::minutes(15),
+    );
+    spec.kind = MeetingKind::Recurring(Recurrence::weekly(
+        1,
+        vec![Weekday::Fri, Weekday::Mon, Weekday::Wed],
+        RecurrenceEnd::Times(5),
+    ));
+    let json = spec.to_body().unwrap();
+    assert_eq!(json["recurrence"]["weekly_days"], "2,4,6");
+    assert_eq!(json["recurrence"]["end_times"], 5);
+    let body: crate::types::MeetingCreate = serde_json::from_value(json).unwrap();
+    assert_eq!(body.type_, 8);
+    assert_eq!(body.timezone, "Europe/London");
+    assert_eq!(body.start_time, Some(utc("2021-03-22T09:30:00Z")));
+
+    let read = MeetingSpec::try_from(&body).unwrap();
+    assert_eq!(
+        read.kind,
+        MeetingKind::Recurring(Recurrence::weekly(
+            1,
+            vec![Weekday::Mon, Weekday::Wed, Weekday::Fri],
+            RecurrenceEnd::Times(5),
+        ))
+    );
+    assert_eq!(read.start, spec.start);
+    assert_eq!(read.settings, Settings::default());
+
+    let occurrences = spec
+        .occurrences(utc("2021-03-01T00:00:00Z"), utc("2021-05-01T00:00:00Z"))
+        .unwrap();
+    let starts: Vec<DateTime<Utc>> = occurrences
+        .iter()
+        .map(|o| o.start.with_timezone(&Utc))
+        .collect();
+    assert_eq!(
+        starts,
+        vec![
+            utc("2021-03-22T09:30:00Z"),
+            utc("2021-03-24T09:30:00Z"),
+            utc("2021-03-26T09:30:00Z"),
+            utc("2021-03-29T08:30:00Z"),
+            utc("2021-03-31T08:30:00Z"),
+        ]
+    );
+    assert_eq!(occurrences[0].end, local("2021-03-22 09:45"));
+
+    // Monthly on the last Friday, until the end of June.
+    let mut spec = MeetingSpec::new(
+        "Review",
+        local("2021-01-29 16:00"),
+        chrono::Duration::hours(1),
+    );
+    spec.kind = MeetingKind::Recurring(Recurrence::monthly_on_weekday(
+        1,
+        MonthWeek::Last,
+        Weekday::Fri,
+        RecurrenceEnd::Until(utc("2021-06-30T00:00:00Z")),
+    ));
+    let body: crate::types::MeetingCreate =
+        serde_json::from_value(spec.to_body().unwrap()).unwrap();
+    assert_eq!(MeetingSpec::try_from(&body).unwrap(), spec);
+    let starts: Vec<String> = spec
+        .occurrences(utc("2021-03-01T00:00:00Z"), utc("2022-01-01T00:00:00Z"))
+        .unwrap()
+        .iter()
+        .map(|o| o.start.naive_local().to_string())
+        .collect();
+    assert_eq!(
+        starts,
+        vec![
+            "2021-03-26 16:00:00",
+            "2021-04-30 16:00:00",
+            "2021-05-28 16:00:00",
+            "2021-06-25 16:00:00",
+        ]
+    );
+
+    // Zoom's days of the week start with Sunday.
+    let recurrence: crate::types::Recurrence =
+        serde_json::from_str(r#"{"type": 2, "repeat_interval": 2, "weekly_days": "1,3"}"#).unwrap();
+    assert_eq!(
+        Recurrence::try_from(&recurrence).unwrap(),
+        Recurrence::weekly(2, vec![Weekday::Sun, Weekday::Tue], RecurrenceEnd::Times(1))
+    );
+
+    spec.settings.waiting_room = true;
+    spec.settings.join_before_host = Some(5);
+    assert!(spec.to_body().is_err());
+    spec.settings.waiting_room = false;
+    spec.settings.alternative_hosts =
+        vec!["a@example.com".to_string(), "b@example.com".to_string()];
+    let body: crate::types::MeetingCreate =
+        serde_json::from_value(spec.to_body().unwrap()).unwrap();
+    let settings = body.settings.as_ref().unwrap();
+    assert_eq!(settings.alternative_hosts, "a@example.com;b@example.com");
+    assert_eq!((settings.join_before_host, settings.jbh_time), (true, 5));
+    assert_eq!(MeetingSpec::try_from(&body).unwrap(), spec);
+
+    // Automatic approval is zero, which the generated settings would leave out,
+    // and settings that aren't covered are left to the defaults of the account.
+    spec.settings.approval = Approval::Automatic;
+    let json = serde_json::to_value(spec.to_body().unwrap()).unwrap();
+    assert_eq!(json["settings"]["approval_type"], 0);
+    assert_eq!(json["settings"]["host_video"], false);
+    for field in &["watermark", "use_pmi", "show_share_button", "audio"] {
+        assert!(json["settings"].get(field).is_none(), "{} was sent", field);
+    }
+}
+
+#[test]
+fn test_report_export_rows() {
+    use crate::report_export::{months, CsvSink, ReportSink, TelephoneRow};
+
+    let date = |s: &str| s.parse::<NaiveDate>().unwrap();
+    assert_eq!(
+        months(date("2020-11-15"), date("2021-02-01")),
+        vec![(2020, 11), (2020, 12), (2021, 1), (2021, 2)]
+    );
+    assert!(months(date("2021-02-01"), date("2021-01-01")).is_empty());
+
+    let calls: Vec<TelephoneRow> = serde_json::from_str(
+        r#"[
+            {"uuid": "a", "meeting_id": 1, "type": "toll-free", "phone_number": "+1 555", "start_time": "2021-01-02T10:00:00Z", "duration": 2, "rate": 0.5, "total": 1.0},
+            {"uuid": "b", "meeting_id": 2, "host_email": "host@example.com"}
+        ]"#,
+    )
+    .unwrap();
+    assert_eq!(calls[1].start_time, None);
+
+    let mut sink = CsvSink::new(Vec::new());
+    for call in &calls {
+        sink.write_row(call).unwrap();
+    }
+    sink.finish().unwrap();
+    let csv = String::from_utf8(sink.into_inner().unwrap()).unwrap();
+    let lines: Vec<&str> = csv.lines().collect();
+    assert_eq!(
+        lines,
+        vec![
+            "uuid,meeting_id,meeting_type,type,host_name,host_email,dept,phone_number,call_in_number,signaled_number,country_name,start_time,end_time,duration,rate,total",
+            "a,1,,toll-free,,,,+1 555,,,,2021-01-02T10:00:00Z,,2,0.5,1.0",
+            "b,2,,,,host@example.com,,,,,,,,0,0.0,0.0",
+        ]
+    );
+
+    let mut rows: Vec<serde_json::Value> = Vec::new();
+    rows.write_row(&calls[0]).unwrap();
+    assert_eq!(rows[0]["type"], "toll-free");
+}
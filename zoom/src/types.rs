@@ -3535,53 +3535,6 @@ pub struct MeetingSettings {
     pub watermark: bool,
 }
 
-/**
- * This field is required **if you're scheduling a recurring meeting of type** `2` to state which day(s) of the week the meeting should repeat. <br> <br> The value for this field could be a number between `1` to `7` in string format. For instance, if the meeting should recur on Sunday, provide `"1"` as the value of this field.<br><br> **Note:** If you would like the meeting to occur on multiple days of a week, you should provide comma separated values for this field. For instance, if the meeting should recur on Sundays and Tuesdays provide `"1,3"` as the value of this field.
- *   
- *    <br>`1`  - Sunday. <br>`2` - Monday.<br>`3` - Tuesday.<br>`4` -  Wednesday.<br>`5` -  Thursday.<br>`6` - Friday.<br>`7` - Saturday.
- */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub enum WeeklyDays {
-    #[serde(rename = "1")]
-    One,
-    #[serde(rename = "2")]
-    Two,
-    #[serde(rename = "3")]
-    Three,
-    #[serde(rename = "4")]
-    Four,
-    #[serde(rename = "5")]
-    Five,
-    #[serde(rename = "6")]
-    Six,
-    #[serde(rename = "7")]
-    Seven,
-    #[serde(other)]
-    FallthroughString,
-}
-
-impl std::fmt::Display for WeeklyDays {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
-            WeeklyDays::One => "1",
-            WeeklyDays::Two => "2",
-            WeeklyDays::Three => "3",
-            WeeklyDays::Four => "4",
-            WeeklyDays::Five => "5",
-            WeeklyDays::Six => "6",
-            WeeklyDays::Seven => "7",
-            WeeklyDays::FallthroughString => "*",
-        }
-        .fmt(f)
-    }
-}
-
-impl Default for WeeklyDays {
-    fn default() -> WeeklyDays {
-        WeeklyDays::One
-    }
-}
-
 /// Recurrence object. Use this object only for a meeting with type `8` i.e., a recurring meeting with fixed time.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Recurrence {
@@ -3652,8 +3605,12 @@ pub struct Recurrence {
     /**
      * Recurrence object. Use this object only for a meeting with type `8` i.e., a recurring meeting with fixed time.
      */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub weekly_days: Option<WeeklyDays>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub weekly_days: String,
 }
 
 /// Meeting object
@@ -1,3 +1,35 @@
+/// The longest range of dates the API lists recordings and reports for.
+const MAX_WINDOW_DAYS: i64 = 30;
+
+/// Split the range of dates from `from` to `to`, inclusive, into ranges the API
+/// lists recordings and reports for.
+pub fn date_windows(
+    from: chrono::NaiveDate,
+    to: chrono::NaiveDate,
+) -> Vec<(chrono::NaiveDate, chrono::NaiveDate)> {
+    let mut windows = Vec::new();
+    let mut start = from;
+    while start <= to {
+        let end = std::cmp::min(start + chrono::Duration::days(MAX_WINDOW_DAYS - 1), to);
+        windows.push((start, end));
+        start = end + chrono::Duration::days(1);
+    }
+    windows
+}
+
+/// The meeting UUID to put in a path. The path isn't encoded for `/`, so UUIDs
+/// with one are encoded here, and those that start with `/` or contain `//` have
+/// to be encoded twice.
+pub fn meeting_path_id(uuid: &str) -> String {
+    if uuid.starts_with('/') || uuid.contains("//") {
+        urlencoding::encode(&urlencoding::encode(uuid)).to_string()
+    } else if uuid.contains('/') {
+        urlencoding::encode(uuid).to_string()
+    } else {
+        uuid.to_string()
+    }
+}
+
 use std::{fmt, str::FromStr};
 
 use serde::de::{self, Visitor};
@@ -0,0 +1,409 @@
+//! Verify and parse the webhooks Zoom sends to apps.
+//!
+//! Zoom signs each request with the secret token of the app: the
+//! `x-zm-signature` header is `v0=` and the hex HMAC-SHA256 of
+//! `v0:{x-zm-request-timestamp}:{body}`. Before sending events to an endpoint,
+//! Zoom checks it by sending an `endpoint.url_validation` event, which must be
+//! answered with the plain token encrypted with the secret token.
+//!
+//! ```no_run
+//! # fn handle(headers: Vec<(String, String)>, body: Vec<u8>) -> anyhow::Result<serde_json::Value> {
+//! use zoom_api::webhooks::{Event, Verifier, WebhookRequest};
+//!
+//! let verifier = Verifier::new("secret-token");
+//! verifier.verify(headers, &body)?;
+//!
+//! let request = WebhookRequest::from_slice(&body)?;
+//! match request.event()? {
+//!     Event::UrlValidation { plain_token } => {
+//!         return Ok(serde_json::to_value(verifier.url_validation_response(&plain_token))?);
+//!     }
+//!     Event::RecordingCompleted(recording) => {
+//!         for file in &recording.object.recording_files {
+//!             println!("{}?access_token={}", file.download_url, recording.download_token);
+//!         }
+//!     }
+//!     _ => {}
+//! }
+//! # Ok(serde_json::Value::Null)
+//! # }
+//! ```
+use std::time::Duration;
+
+use anyhow::{anyhow, bail, Result};
+use ring::hmac;
+use schemars::JsonSchema;
+use serde::{Deserialize, Deserializer, Serialize};
+use serde_json::Value;
+
+/// The header with the signature of a request.
+pub const SIGNATURE_HEADER: &str = "x-zm-signature";
+
+/// The header with the time a request was sent, in seconds since the epoch.
+pub const TIMESTAMP_HEADER: &str = "x-zm-request-timestamp";
+
+/// How far the timestamp of a request can be from now, to reject replayed requests.
+pub const DEFAULT_TOLERANCE: Duration = Duration::from_secs(5 * 60);
+
+/// Verifies requests with the secret token of an app, and answers URL validation
+/// challenges.
+#[derive(Clone)]
+pub struct Verifier {
+    secret_token: String,
+    tolerance: Duration,
+}
+
+impl std::fmt::Debug for Verifier {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Verifier")
+            .field("secret_token", &"***")
+            .field("tolerance", &self.tolerance)
+            .finish()
+    }
+}
+
+impl Verifier {
+    pub fn new<S>(secret_token: S) -> Self
+    where
+        S: ToString,
+    {
+        Verifier {
+            secret_token: secret_token.to_string(),
+            tolerance: DEFAULT_TOLERANCE,
+        }
+    }
+
+    pub fn with_tolerance(mut self, tolerance: Duration) -> Self {
+        self.tolerance = tolerance;
+        self
+    }
+
+    fn key(&self) -> hmac::Key {
+        hmac::Key::new(hmac::HMAC_SHA256, self.secret_token.as_bytes())
+    }
+
+    fn message(timestamp: &str, body: &[u8]) -> Vec<u8> {
+        let mut message = format!("v0:{}:", timestamp).into_bytes();
+        message.extend_from_slice(body);
+        message
+    }
+
+    /// The `x-zm-signature` of a request sent at `timestamp`.
+    pub fn sign(&self, timestamp: i64, body: &[u8]) -> String {
+        let message = Verifier::message(&timestamp.to_string(), body);
+        format!("v0={}", hex(hmac::sign(&self.key(), &message).as_ref()))
+    }
+
+    /// Verify the signature and timestamp of a request.
+    pub fn verify<I, K, V>(&self, headers: I, body: &[u8]) -> Result<()>
+    where
+        I: IntoIterator<Item = (K, V)>,
+        K: AsRef<str>,
+        V: AsRef<str>,
+    {
+        self.verify_at(headers, body, chrono::Utc::now())
+    }
+
+    /// Verify the signature of a request, and that it was sent within the
+    /// tolerance of `now`.
+    pub fn verify_at<I, K, V>(
+        &self,
+        headers: I,
+        body: &[u8],
+        now: chrono::DateTime<chrono::Utc>,
+    ) -> Result<()>
+    where
+        I: IntoIterator<Item = (K, V)>,
+        K: AsRef<str>,
+        V: AsRef<str>,
+    {
+        let mut signature = None;
+        let mut timestamp = None;
+        for (k, v) in headers {
+            let k = k.as_ref();
+            if k.eq_ignore_ascii_case(SIGNATURE_HEADER) {
+                signature = Some(v.as_ref().trim().to_string());
+            } else if k.eq_ignore_ascii_case(TIMESTAMP_HEADER) {
+                timestamp = Some(v.as_ref().trim().to_string());
+            }
+        }
+        let signature =
+            signature.ok_or_else(|| anyhow!("the request has no {} header", SIGNATURE_HEADER))?;
+        let timestamp =
+            timestamp.ok_or_else(|| anyhow!("the request has no {} header", TIMESTAMP_HEADER))?;
+
+        let sent: i64 = timestamp
+            .parse()
+            .map_err(|_| anyhow!("invalid {} header: {}", TIMESTAMP_HEADER, timestamp))?;
+        let skew = (now.timestamp() - sent).unsigned_abs();
+        if skew > self.tolerance.as_secs() {
+            bail!(
+                "the request was sent {}s from now, more than the tolerance of {}s",
+                skew,
+                self.tolerance.as_secs()
+            );
+        }
+
+        let mac = signature
+            .strip_prefix("v0=")
+            .and_then(unhex)
+            .ok_or_else(|| anyhow!("invalid {} header: {}", SIGNATURE_HEADER, signature))?;
+        hmac::verify(&self.key(), &Verifier::message(&timestamp, body), &mac)
+            .map_err(|_| anyhow!("the signature of the request does not match"))
+    }
+
+    /// The response to an `endpoint.url_validation` event.
+    pub fn url_validation_response(&self, plain_token: &str) -> UrlValidationResponse {
+        UrlValidationResponse {
+            plain_token: plain_token.to_string(),
+            encrypted_token: hex(hmac::sign(&self.key(), plain_token.as_bytes()).as_ref()),
+        }
+    }
+}
+
+/// The body to answer an `endpoint.url_validation` event with.
+#[derive(Debug, JsonSchema, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UrlValidationResponse {
+    pub plain_token: String,
+    pub encrypted_token: String,
+}
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+fn unhex(s: &str) -> Option<Vec<u8>> {
+    // An odd length leaves a last byte with one digit, which `get` rejects.
+    (0..s.len())
+        .step_by(2)
+        .map(|i| s.get(i..i + 2).and_then(|b| u8::from_str_radix(b, 16).ok()))
+        .collect()
+}
+
+/// A webhook request, with its payload still untyped.
+#[derive(Debug, JsonSchema, Clone, Default, Serialize, Deserialize)]
+pub struct WebhookRequest {
+    /// The name of the event, like `meeting.started`.
+    #[serde(default)]
+    pub event: String,
+    /// When the event happened, in milliseconds since the epoch.
+    #[serde(default)]
+    pub event_ts: i64,
+    #[serde(default)]
+    pub payload: Value,
+    /// The token to download the files of `recording.completed` events with.
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub download_token: String,
+}
+
+impl WebhookRequest {
+    pub fn from_slice(body: &[u8]) -> Result<Self> {
+        Ok(serde_json::from_slice(body)?)
+    }
+
+    /// Deserialize the payload of the event.
+    pub fn event(&self) -> Result<Event> {
+        let payload = || self.payload.clone();
+        Ok(match self.event.as_str() {
+            "endpoint.url_validation" => Event::UrlValidation {
+                plain_token: self.payload["plainToken"]
+                    .as_str()
+                    .ok_or_else(|| anyhow!("the url validation event has no plainToken"))?
+                    .to_string(),
+            },
+            "meeting.started" => Event::MeetingStarted(serde_json::from_value(payload())?),
+            "meeting.ended" => Event::MeetingEnded(serde_json::from_value(payload())?),
+            "meeting.participant_joined" => {
+                Event::ParticipantJoined(serde_json::from_value(payload())?)
+            }
+            "recording.completed" => {
+                let mut recording: RecordingEvent = serde_json::from_value(payload())?;
+                recording.download_token = self.download_token.to_string();
+                Event::RecordingCompleted(recording)
+            }
+            "user.created" => Event::UserCreated(serde_json::from_value(payload())?),
+            name => Event::Other(name.to_string()),
+        })
+    }
+}
+
+/// An event, with its payload.
+#[derive(Debug, Clone)]
+pub enum Event {
+    /// Zoom checking that the endpoint belongs to the app. Answer it with
+    /// `Verifier::url_validation_response`.
+    UrlValidation {
+        plain_token: String,
+    },
+    MeetingStarted(MeetingEvent),
+    MeetingEnded(MeetingEvent),
+    ParticipantJoined(ParticipantEvent),
+    RecordingCompleted(RecordingEvent),
+    UserCreated(UserEvent),
+    /// An event without a typed payload, by its name. Its payload is in
+    /// `WebhookRequest::payload`.
+    Other(String),
+}
+
+/// Meeting ids are numbers in some events and strings in others.
+fn deserialize_meeting_id<'de, D>(deserializer: D) -> Result<i64, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    match Value::deserialize(deserializer)? {
+        Value::Number(n) => n
+            .as_i64()
+            .ok_or_else(|| serde::de::Error::custom(format!("invalid meeting id: {}", n))),
+        Value::String(s) if s.is_empty() => Ok(0),
+        Value::String(s) => s
+            .parse()
+            .map_err(|_| serde::de::Error::custom(format!("invalid meeting id: {}", s))),
+        _ => Ok(0),
+    }
+}
+
+/// Times that aren't known yet, like the end of a meeting that's still going, are
+/// empty strings.
+fn deserialize_time<'de, D>(
+    deserializer: D,
+) -> Result<Option<chrono::DateTime<chrono::Utc>>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    match Option::<String>::deserialize(deserializer)? {
+        Some(s) if !s.is_empty() => crate::utils::date_time_format::deserialize(Value::String(s))
+            .map_err(serde::de::Error::custom),
+        _ => Ok(None),
+    }
+}
+
+/// The meeting of an event.
+#[derive(Debug, JsonSchema, Clone, Default, Serialize, Deserialize)]
+pub struct WebhookMeeting {
+    #[serde(default, deserialize_with = "deserialize_meeting_id")]
+    pub id: i64,
+    /// The id of this instance of the meeting.
+    #[serde(default)]
+    pub uuid: String,
+    #[serde(default)]
+    pub host_id: String,
+    #[serde(default)]
+    pub topic: String,
+    /// The type of the meeting: 1 for instant, 2 for scheduled, 3 and 8 for
+    /// recurring and 4 for personal meeting room meetings.
+    #[serde(default, rename = "type")]
+    pub type_: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "deserialize_time"
+    )]
+    pub start_time: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "deserialize_time"
+    )]
+    pub end_time: Option<chrono::DateTime<chrono::Utc>>,
+    /// The scheduled duration, in minutes.
+    #[serde(default)]
+    pub duration: i64,
+    #[serde(default)]
+    pub timezone: String,
+}
+
+/// The payload of `meeting.started` and `meeting.ended` events.
+#[derive(Debug, JsonSchema, Clone, Default, Serialize, Deserialize)]
+pub struct MeetingEvent {
+    #[serde(default)]
+    pub account_id: String,
+    pub object: WebhookMeeting,
+}
+
+/// A participant of a meeting.
+#[derive(Debug, JsonSchema, Clone, Default, Serialize, Deserialize)]
+pub struct WebhookParticipant {
+    /// The id of the participant in this meeting.
+    #[serde(default)]
+    pub user_id: String,
+    #[serde(default)]
+    pub user_name: String,
+    /// The id of the Zoom user, if the participant is signed in.
+    #[serde(default)]
+    pub id: String,
+    #[serde(default)]
+    pub participant_uuid: String,
+    #[serde(default)]
+    pub email: String,
+    #[serde(default)]
+    pub registrant_id: String,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "deserialize_time"
+    )]
+    pub join_time: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+/// A meeting, and the participant an event is about.
+#[derive(Debug, JsonSchema, Clone, Default, Serialize, Deserialize)]
+pub struct ParticipantMeeting {
+    #[serde(flatten)]
+    pub meeting: WebhookMeeting,
+    pub participant: WebhookParticipant,
+}
+
+/// The payload of `meeting.participant_joined` events.
+#[derive(Debug, JsonSchema, Clone, Default, Serialize, Deserialize)]
+pub struct ParticipantEvent {
+    #[serde(default)]
+    pub account_id: String,
+    pub object: ParticipantMeeting,
+}
+
+/// A meeting and its recording files.
+#[derive(Debug, JsonSchema, Clone, Serialize, Deserialize)]
+pub struct RecordingMeeting {
+    #[serde(flatten)]
+    pub meeting: WebhookMeeting,
+    #[serde(default)]
+    pub host_email: String,
+    /// The size of all the files, in bytes.
+    #[serde(default)]
+    pub total_size: i64,
+    #[serde(default)]
+    pub recording_count: i64,
+    #[serde(default)]
+    pub share_url: String,
+    #[serde(default)]
+    pub recording_files: Vec<crate::types::RecordingFiles>,
+}
+
+/// The payload of `recording.completed` events.
+#[derive(Debug, JsonSchema, Clone, Serialize, Deserialize)]
+pub struct RecordingEvent {
+    #[serde(default)]
+    pub account_id: String,
+    pub object: RecordingMeeting,
+    /// The token to download the recording files with, valid for 24 hours. Zoom
+    /// sends it next to the payload, and it's copied here.
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub download_token: String,
+}
+
+/// The payload of `user.created` events.
+#[derive(Debug, JsonSchema, Clone, Serialize, Deserialize)]
+pub struct UserEvent {
+    #[serde(default)]
+    pub account_id: String,
+    /// The email of the user who created the user.
+    #[serde(default)]
+    pub operator: String,
+    #[serde(default)]
+    pub operator_id: String,
+    /// How the user was created: `create`, `autoCreate`, `custCreate` or `ssoCreate`.
+    #[serde(default)]
+    pub creation_type: String,
+    pub object: crate::types::UserCreateResponse,
+}